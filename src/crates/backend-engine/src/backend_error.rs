@@ -1,3 +1,5 @@
+use game_api::types::{Card, CardColor, DrawPile};
+use rules::engine::{self, Action};
 use std::error::Error;
 use std::fmt::{Display, Formatter};
 use std::fmt;
@@ -48,13 +50,44 @@ pub enum Cause {
 }
 
 /// This is basically the "rules" enum. For each rule dictating allowed plays, there will be an entry here.
-#[derive(Debug)]
+/// Rules about a specific card or pile say which one, so the player can be told exactly what was wrong.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub enum Reason {
     NotYourTurn,
-    CardNotInHand,
-    CantPlayDecreasingCardValue,
-    NeutralDrawPileEmpty,
-    CantRedrawCardJustPlayed,
+    CardNotInHand(Card),
+    CantPlayDecreasingCardValue(Card),
+    NeutralDrawPileEmpty(CardColor),
+    MainDrawPileEmpty,
+    CantRedrawCardJustPlayed(Card),
+    GameOver,
+    OutOfTime,
+    DrawAlreadyOffered,
+    NoDrawOffer,
+    NoMoveToTakeBack,
+    TakeBackAlreadyRequested,
+    NoTakeBackRequest,
+    NoDrawOnFinalTurn,
+    InvalidRuleSet,
+    TwoPlayerOnly,
+    AlreadySeated,
+    GameNotOver,
+}
+
+impl Reason {
+    /// The engine only says which rule was broken. The action says which card or pile broke it.
+    pub fn from_engine(reason: engine::Reason, action: &Action) -> Self {
+        match reason {
+            engine::Reason::CardNotInHand => Reason::CardNotInHand(*action.card()),
+            engine::Reason::CantPlayDecreasingCardValue => Reason::CantPlayDecreasingCardValue(*action.card()),
+            engine::Reason::NeutralDrawPileEmpty => match action.draw_pile() {
+                DrawPile::Neutral(color) => Reason::NeutralDrawPileEmpty(*color),
+                DrawPile::Main => Reason::MainDrawPileEmpty,
+            },
+            engine::Reason::CantRedrawCardJustPlayed => Reason::CantRedrawCardJustPlayed(*action.card()),
+            engine::Reason::NoDrawOnFinalTurn => Reason::NoDrawOnFinalTurn,
+            engine::Reason::GameOver => Reason::GameOver,
+        }
+    }
 }
 
 /// User-facing message to educate the user how to play.
@@ -62,10 +95,23 @@ impl Display for Reason {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         match self {
             Reason::NotYourTurn => write!(f, "It is not your turn."),
-            Reason::CardNotInHand => write!(f, "The card is not in your hand."),
-            Reason::CantPlayDecreasingCardValue => write!(f, "For a specific color, you must play cards of the same or higher value."),
-            Reason::NeutralDrawPileEmpty => write!(f, "You can't draw from the neutral discard pile for that color because it is empty."),
-            Reason::CantRedrawCardJustPlayed => write!(f, "You are not allowed to redraw the same card you just discarded."),
+            Reason::CardNotInHand(card) => write!(f, "The {} is not in your hand.", card),
+            Reason::CantPlayDecreasingCardValue(card) => write!(f, "You can't play the {}. For a specific color, you must play cards of the same or higher value.", card),
+            Reason::NeutralDrawPileEmpty(color) => write!(f, "You can't draw from the neutral discard pile for {:?} because it is empty.", color),
+            Reason::MainDrawPileEmpty => write!(f, "You can't draw from the main draw pile because it is empty."),
+            Reason::CantRedrawCardJustPlayed(card) => write!(f, "You are not allowed to redraw the {} you just discarded.", card),
+            Reason::GameOver => write!(f, "The game is already over."),
+            Reason::OutOfTime => write!(f, "You ran out of time, so you forfeit the game."),
+            Reason::DrawAlreadyOffered => write!(f, "A draw has already been offered."),
+            Reason::NoDrawOffer => write!(f, "Your opponent hasn't offered a draw."),
+            Reason::NoMoveToTakeBack => write!(f, "You can only take back the most recent move, and only if you made it."),
+            Reason::TakeBackAlreadyRequested => write!(f, "You already asked to take back your last move."),
+            Reason::NoTakeBackRequest => write!(f, "Your opponent hasn't asked to take back a move."),
+            Reason::NoDrawOnFinalTurn => write!(f, "The main draw pile is empty, so this is the final turn. Play or discard a card without drawing."),
+            Reason::InvalidRuleSet => write!(f, "Those house rules don't work. There must be 2 to 4 players, every hand must fit in the deck with cards to spare, and the bonus threshold must be 1 to 12 cards."),
+            Reason::TwoPlayerOnly => write!(f, "Matches, draw offers, take-backs, analysis, and the computer opponent are only for two player games."),
+            Reason::AlreadySeated => write!(f, "You already have a seat in this game."),
+            Reason::GameNotOver => write!(f, "The game isn't over yet."),
         }
     }
 }
@@ -18,6 +18,11 @@ impl<V> Slots<V> {
         self.get_raw(key)
     }
 
+    /// For queries which aren't indexed by the key, and must be asked of every slot.
+    pub fn all(&self) -> &[V] {
+        &self.slots
+    }
+
     // If I find that this struct could be useful with a variety of key types, I can expose
     // this publically.
     fn get_raw<K: Hash + Debug>(&self, key: &K) -> &V {
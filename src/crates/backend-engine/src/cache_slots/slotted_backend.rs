@@ -2,14 +2,15 @@ use crate::cache_slots::slots::Slots;
 use crate::game_api::{GameApi2Immut, GameApiResult};
 use crate::task::backend_task_client;
 use crate::task::backend_task_client::BackendTaskClientAdapter;
-use game_api::types::{GameMetadata, Play, GameState};
+use game_api::types::{GameMetadata, Play, GameState, SpectatorGameState, MatchState, TimeControl, Opponent, GameAnalysis, RuleSet};
+use crate::game_engine::match_rounds;
 use std::cmp;
 use std::error::Error;
 use storage::v2::config;
 use storage::v2::config::DatabaseMode;
 
-pub fn spawn_slotted_backend() -> Result<SlottedGameApi2Immut, Box<dyn Error>> {
-    let db_client = config::connect_to_database(DatabaseMode::Prod)?;
+pub fn spawn_slotted_backend(database_mode: DatabaseMode) -> Result<SlottedGameApi2Immut, Box<dyn Error>> {
+    let db_client = config::connect_to_database(database_mode)?;
 
     let num_tasks = get_num_backend_tasks_to_spawn();
 
@@ -35,6 +36,8 @@ fn get_num_backend_tasks_to_spawn() -> usize {
 /// A GameId-consistent-hash-based cache over the backend DB. This will yield higher parallelism
 /// (yes, parallelism, not just concurrency) than a single-tasked approach.
 ///
+/// The rounds of a match are hashed by their MatchId, so a match and its games live in the same slot.
+///
 /// Is this a premature optimization? Definitely! But I'm in it for the engineering challenge.
 pub struct SlottedGameApi2Immut {
     slots: Slots<BackendTaskClientAdapter>,
@@ -42,44 +45,131 @@ pub struct SlottedGameApi2Immut {
 
 #[async_trait::async_trait]
 impl GameApi2Immut for SlottedGameApi2Immut {
-    async fn host_game(&self, game_id: String, p1_id: String) -> GameApiResult<()> {
+    async fn host_game(&self, game_id: String, p1_id: String, time_control: Option<TimeControl>, opponent: Opponent, rule_set: RuleSet) -> GameApiResult<()> {
         self.slots
-            .get(&game_id)
-            .host_game(game_id, p1_id)
+            .get(&match_rounds::routing_key(&game_id))
+            .host_game(game_id, p1_id, time_control, opponent, rule_set)
             .await
     }
 
     async fn join_game(&self, game_id: String, p2_id: String) -> GameApiResult<()> {
         self.slots
-            .get(&game_id)
+            .get(&match_rounds::routing_key(&game_id))
             .join_game(game_id, p2_id)
             .await
     }
 
     async fn describe_game(&self, game_id: String) -> GameApiResult<GameMetadata> {
         self.slots
-            .get(&game_id)
+            .get(&match_rounds::routing_key(&game_id))
             .describe_game(game_id)
             .await
     }
 
     async fn get_game_state(&self, game_id: String, player_id: String) -> GameApiResult<GameState> {
         self.slots
-            .get(&game_id)
+            .get(&match_rounds::routing_key(&game_id))
             .get_game_state(game_id, player_id)
             .await
     }
 
+    async fn get_spectator_game_state(&self, game_id: String, player_id: String) -> GameApiResult<SpectatorGameState> {
+        self.slots
+            .get(&match_rounds::routing_key(&game_id))
+            .get_spectator_game_state(game_id, player_id)
+            .await
+    }
+
     async fn play_card(&self, play: Play) -> GameApiResult<()> {
         self.slots
-            .get(play.game_id())
+            .get(&match_rounds::routing_key(play.game_id()))
             .play_card(play)
             .await
     }
 
+    async fn resign(&self, game_id: String, player_id: String) -> GameApiResult<()> {
+        self.slots
+            .get(&match_rounds::routing_key(&game_id))
+            .resign(game_id, player_id)
+            .await
+    }
+
+    async fn offer_draw(&self, game_id: String, player_id: String) -> GameApiResult<()> {
+        self.slots
+            .get(&match_rounds::routing_key(&game_id))
+            .offer_draw(game_id, player_id)
+            .await
+    }
+
+    async fn respond_to_draw_offer(&self, game_id: String, player_id: String, accept: bool) -> GameApiResult<()> {
+        self.slots
+            .get(&match_rounds::routing_key(&game_id))
+            .respond_to_draw_offer(game_id, player_id, accept)
+            .await
+    }
+
+    async fn request_take_back(&self, game_id: String, player_id: String) -> GameApiResult<()> {
+        self.slots
+            .get(&match_rounds::routing_key(&game_id))
+            .request_take_back(game_id, player_id)
+            .await
+    }
+
+    async fn respond_to_take_back(&self, game_id: String, player_id: String, accept: bool) -> GameApiResult<()> {
+        self.slots
+            .get(&match_rounds::routing_key(&game_id))
+            .respond_to_take_back(game_id, player_id, accept)
+            .await
+    }
+
+    async fn analyze_game(&self, game_id: String, player_id: String) -> GameApiResult<GameAnalysis> {
+        self.slots
+            .get(&match_rounds::routing_key(&game_id))
+            .analyze_game(game_id, player_id)
+            .await
+    }
+
+    async fn export_game(&self, game_id: String, player_id: String) -> GameApiResult<String> {
+        self.slots
+            .get(&match_rounds::routing_key(&game_id))
+            .export_game(game_id, player_id)
+            .await
+    }
+
+    async fn host_match(&self, match_id: String, p1_id: String, num_rounds: u32, time_control: Option<TimeControl>, rule_set: RuleSet) -> GameApiResult<()> {
+        self.slots
+            .get(&match_rounds::routing_key(&match_id))
+            .host_match(match_id, p1_id, num_rounds, time_control, rule_set)
+            .await
+    }
+
+    async fn join_match(&self, match_id: String, p2_id: String) -> GameApiResult<()> {
+        self.slots
+            .get(&match_rounds::routing_key(&match_id))
+            .join_match(match_id, p2_id)
+            .await
+    }
+
+    async fn get_match_state(&self, match_id: String, player_id: String) -> GameApiResult<MatchState> {
+        self.slots
+            .get(&match_rounds::routing_key(&match_id))
+            .get_match_state(match_id, player_id)
+            .await
+    }
+
     /// TODO: Non-GameId based APIs are currently unsupported (in this hash-based impl and in general)
     async fn query_unmatched_games(&self, _player_id: String) -> GameApiResult<Vec<GameMetadata>> { unimplemented!() }
     async fn query_in_progress_games(&self, _player_id: String) -> GameApiResult<Vec<GameMetadata>> { unimplemented!() }
     async fn query_completed_games(&self, _player_id: String) -> GameApiResult<Vec<GameMetadata>> { unimplemented!() }
     async fn query_all_unmatched_games(&self, _player_id: String) -> GameApiResult<Vec<GameMetadata>> { unimplemented!() }
+
+    /// Games are spread across all slots, so ask each slot and merge the results.
+    async fn query_watchable_games(&self, player_id: String) -> GameApiResult<Vec<GameMetadata>> {
+        let mut games = Vec::new();
+        for slot in self.slots.all() {
+            games.append(&mut slot.query_watchable_games(player_id.clone()).await?);
+        }
+
+        Ok(games)
+    }
 }
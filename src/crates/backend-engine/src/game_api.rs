@@ -1,5 +1,5 @@
 use crate::backend_error::BackendGameError;
-use game_api::types::{GameMetadata, Play, GameState};
+use game_api::types::{GameMetadata, Play, GameState, SpectatorGameState, MatchState, TimeControl, Opponent, GameAnalysis, RuleSet};
 
 /// Short hand type to help impl stuff in this crate.
 pub(crate) type GameApiResult<O> = Result<O, BackendGameError>;
@@ -11,9 +11,9 @@ pub trait GameApi2Immut {
 
     /// Create a new game with only the host player present.
     /// Returns game_id used for all future queries
-    async fn host_game(&self, game_id: String, p1_id: String) -> GameApiResult<()>;
+    async fn host_game(&self, game_id: String, p1_id: String, time_control: Option<TimeControl>, opponent: Opponent, rule_set: RuleSet) -> GameApiResult<()>;
 
-    /// Player 2 joins the game.
+    /// A guest joins the game. Once every seat is taken, the game starts.
     async fn join_game(&self, game_id: String, p2_id: String) -> GameApiResult<()>;
 
     /// Get status of a game
@@ -22,9 +22,42 @@ pub trait GameApi2Immut {
     /// Load the state of the game as observed by the requested player.
     async fn get_game_state(&self, game_id: String, player_id: String) -> GameApiResult<GameState>;
 
+    /// Load the state of the game as observed by a spectator. Neither player's hand is visible.
+    async fn get_spectator_game_state(&self, game_id: String, player_id: String) -> GameApiResult<SpectatorGameState>;
+
     /// Make a turn. Should call get_game_state() after this. Maybe not needed? Idk yet.
     async fn play_card(&self, play: Play) -> GameApiResult<()>;
 
+    /// Give up the game. The opponent wins, regardless of the score.
+    async fn resign(&self, game_id: String, player_id: String) -> GameApiResult<()>;
+
+    /// Offer the opponent to end the game as a draw.
+    async fn offer_draw(&self, game_id: String, player_id: String) -> GameApiResult<()>;
+
+    /// Accept or decline the opponent's pending draw offer.
+    async fn respond_to_draw_offer(&self, game_id: String, player_id: String, accept: bool) -> GameApiResult<()>;
+
+    /// Ask the opponent to let me undo my last move.
+    async fn request_take_back(&self, game_id: String, player_id: String) -> GameApiResult<()>;
+
+    /// Accept or decline the opponent's pending take-back request.
+    async fn respond_to_take_back(&self, game_id: String, player_id: String, accept: bool) -> GameApiResult<()>;
+
+    /// Rank my moves right now, or find my mistakes once the game is over.
+    async fn analyze_game(&self, game_id: String, player_id: String) -> GameApiResult<GameAnalysis>;
+
+    /// Write down a finished game in text notation.
+    async fn export_game(&self, game_id: String, player_id: String) -> GameApiResult<String>;
+
+    /// Create a new match (a series of `num_rounds` games) with only the host player present.
+    async fn host_match(&self, match_id: String, p1_id: String, num_rounds: u32, time_control: Option<TimeControl>, rule_set: RuleSet) -> GameApiResult<()>;
+
+    /// Player 2 joins the match. This deals the first round.
+    async fn join_match(&self, match_id: String, p2_id: String) -> GameApiResult<()>;
+
+    /// Load the state of the match as observed by the requested player.
+    async fn get_match_state(&self, match_id: String, player_id: String) -> GameApiResult<MatchState>;
+
     /// Get games that I'm hosting/offering
     async fn query_unmatched_games(&self, player_id: String) -> GameApiResult<Vec<GameMetadata>>;
 
@@ -36,4 +69,7 @@ pub trait GameApi2Immut {
 
     /// Get all (global) unmatched games - aka matchmaking LOL
     async fn query_all_unmatched_games(&self, player_id: String) -> GameApiResult<Vec<GameMetadata>>;
+
+    /// Get all (global) in-progress games that can be watched by a spectator.
+    async fn query_watchable_games(&self, player_id: String) -> GameApiResult<Vec<GameMetadata>>;
 }
@@ -0,0 +1,154 @@
+use crate::backend_error::BackendGameError;
+use crate::game_engine::lost_cities::to_rules_state;
+use crate::game_engine::take_back;
+use bot::monte_carlo::{Budget, MonteCarloStrategy};
+use bot::view;
+use game_api::types::{GameState, GameStatus, GameAnalysis, Mistake, MoveEvaluation};
+use rules::engine::Action;
+use storage::storage_types::{StorageGameState, StorageMove};
+
+/// Simulated games per move hint. Same as the medium computer opponent, a bit more to be safe.
+const HINT_ITERATIONS: u32 = 500;
+
+/// Simulated games per turn when going over a finished game. There are 20-30 turns to look at.
+const REVIEW_ITERATIONS: u32 = 300;
+
+/// A move is a mistake if the best move was expected to end the game this many points better.
+const MISTAKE_THRESHOLD: f64 = 10.0;
+
+/// One player's view of a game, waiting to be analyzed. The simulations take seconds, so this
+/// holds everything they need and can be sent to another thread to run.
+pub struct PendingAnalysis {
+    game_state: GameState,
+    storage_game_state: StorageGameState,
+    seat: usize,
+}
+
+impl PendingAnalysis {
+    pub fn new(game_state: GameState, storage_game_state: StorageGameState, seat: usize) -> Self {
+        PendingAnalysis {
+            game_state,
+            storage_game_state,
+            seat,
+        }
+    }
+
+    /// If so, the analysis will never change.
+    pub fn is_game_over(&self) -> bool {
+        matches!(self.game_state.status(), GameStatus::Complete(_, _))
+    }
+
+    /// Move hints if it's the player's turn, or a review of their mistakes once the game is over.
+    pub fn run(self) -> Result<GameAnalysis, BackendGameError> {
+        let analysis = match self.game_state.status() {
+            GameStatus::InProgress(true) => GameAnalysis::new(rank_moves(&self.game_state), Vec::new()),
+            GameStatus::InProgress(false) => GameAnalysis::new(Vec::new(), Vec::new()),
+            GameStatus::Complete(_, _) => GameAnalysis::new(Vec::new(), find_mistakes(self.storage_game_state, self.seat)?),
+        };
+
+        Ok(analysis)
+    }
+}
+
+/// All legal moves, best first.
+pub fn rank_moves(game_state: &GameState) -> Vec<MoveEvaluation> {
+    MonteCarloStrategy::new(Budget::Iterations(HINT_ITERATIONS))
+        .evaluate_actions(game_state)
+        .into_iter()
+        .map(into_move_evaluation)
+        .collect()
+}
+
+/// Rewind the game one move at a time, and compare each of the player's moves to the best move
+/// in the position they had. Only what the player could see at the time goes into the evaluation.
+pub fn find_mistakes(
+    mut storage_game_state: StorageGameState,
+    seat: usize
+) -> Result<Vec<Mistake>, BackendGameError> {
+    let mut evaluator = MonteCarloStrategy::new(Budget::Iterations(REVIEW_ITERATIONS));
+    let mut mistakes = Vec::new();
+
+    while let Some(last_move) = storage_game_state.move_history().last().copied() {
+        let turn = storage_game_state.move_history().len() as u32;
+        storage_game_state = take_back::take_back_last_move(storage_game_state)?;
+        if *last_move.seat() != seat {
+            continue;
+        }
+
+        let game_state = view::player_view(&to_rules_state(&storage_game_state), seat);
+        let evaluations = evaluator.evaluate_actions(&game_state);
+        let played = evaluations.iter()
+            .find(|(action, _)| is_same_move(action, &last_move));
+        if let (Some(best), Some(played)) = (evaluations.first(), played) {
+            if best.1 - played.1 >= MISTAKE_THRESHOLD {
+                mistakes.push(Mistake::new(turn, into_move_evaluation(*played), into_move_evaluation(*best)));
+            }
+        }
+    }
+
+    mistakes.reverse();
+    Ok(mistakes)
+}
+
+fn is_same_move(action: &Action, storage_move: &StorageMove) -> bool {
+    action.card() == storage_move.card()
+        && action.target() == storage_move.target()
+        && action.draw_pile() == storage_move.draw_pile()
+}
+
+fn into_move_evaluation((action, value): (Action, f64)) -> MoveEvaluation {
+    MoveEvaluation::new(*action.card(), *action.target(), *action.draw_pile(), value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use game_api::types::{Card, CardColor, CardTarget, CardValue, DrawPile};
+    use std::collections::HashMap;
+    use std::convert::TryFrom;
+
+    fn card(color: CardColor, value: u32) -> Card {
+        Card::new(color, CardValue::try_from(value).unwrap())
+    }
+
+    fn value(value: u32) -> CardValue {
+        CardValue::try_from(value).unwrap()
+    }
+
+    #[test]
+    fn flags_discarding_the_winning_card() {
+        // On the last turn of the game, p1 could have finished their red expedition with the R10
+        // for +14, but discarded the G2 instead, for -6.
+        let mut p1_plays = HashMap::new();
+        p1_plays.insert(CardColor::Red, vec![value(1), value(8), value(9)]);
+        let mut neutral_draw_pile = HashMap::new();
+        neutral_draw_pile.insert(CardColor::Green, vec![value(2)]);
+        let mut sgs = StorageGameState::new(
+            "game".to_owned(),
+            vec![vec![card(CardColor::Red, 10), card(CardColor::White, 4)], vec![card(CardColor::Blue, 3)]],
+            vec![p1_plays, HashMap::new()],
+            neutral_draw_pile,
+            vec![],
+            1,
+        );
+        sgs.move_history_mut().push(StorageMove::new(
+            0,
+            1,
+            card(CardColor::Green, 2),
+            CardTarget::Neutral,
+            DrawPile::Main,
+            Some(card(CardColor::White, 4)),
+        ));
+
+        let mistakes = find_mistakes(sgs.clone(), 0).unwrap();
+        assert_eq!(mistakes.len(), 1);
+        assert_eq!(*mistakes[0].turn(), 1);
+        assert_eq!(*mistakes[0].played().card(), card(CardColor::Green, 2));
+        assert_eq!(*mistakes[0].best().card(), card(CardColor::Red, 10));
+        assert_eq!(*mistakes[0].best().target(), CardTarget::Player);
+        assert_eq!(*mistakes[0].best().value() - *mistakes[0].played().value(), 20.0);
+
+        // p2 didn't move, so they didn't make any mistakes
+        assert!(find_mistakes(sgs, 1).unwrap().is_empty());
+    }
+}
@@ -1,14 +1,25 @@
 use crate::backend_error::{BackendGameError, Cause, Reason};
 use game_api::api::GameApi2;
-use game_api::types::{GameState, Play, Card, GameBoard, CardTarget, CardColor, CardValue, DrawPile, GameMetadata, GameStatus, GameResult};
-use rules::deck::DeckFactory;
-use rules::{plays, scoring, endgame};
+use game_api::types::{GameState, Play, GameMetadata, GameStatus, GameResult, SpectatorGameState, SpectatorSeat, SpectatorStatus, MatchState, MatchRound, MatchStatus, TimeControl, Opponent, Difficulty, GameAnalysis, RuleSet};
+use bot::BOT_PLAYER_ID;
+use rules::engine::Action;
+use rules::scoring;
+use std::cmp;
 use std::collections::HashMap;
 use std::sync::Arc;
 use storage::local_storage::InMemoryStore;
-use storage::storage_api::GameStore;
-use storage::storage_types::{StorageGameMetadata, StorageGameStatus, StorageError, StorageGameState};
+use storage::storage_api::{GameStore, GameStateRecord};
+use storage::storage_types::{StorageGameMetadata, StorageGameStatus, StorageError, StorageGameState, StorageMatch, StorageEarlyEnd};
 use storage::v2::db_api::GameDatabase;
+use storage::v2::db_types::{DbGameSummary, DbGameData, DbGameStatus};
+use crate::game_engine::{lost_cities, match_rounds, turn_clock, take_back};
+use crate::game_engine::analysis::PendingAnalysis;
+use crate::game_engine::lost_cities::{LostCities, LostCitiesConfig};
+use crate::platform::lobby;
+use crate::platform::platform_game::PlatformGame;
+
+/// The computer always takes the guest's seat.
+const COMPUTER_SEAT: usize = 1;
 
 /// Impl of `GameApi2` which applies rules engine to game model and persists game
 /// in the storage layer.
@@ -16,80 +27,86 @@ use storage::v2::db_api::GameDatabase;
 /// An instance of this can be multi-tenanted and manage multiple games concurrently.
 /// In the future, when I use a proper database, this should be a single-instance-per-game
 /// which acts as a cache over the database.
-pub struct BackendGameApi {
-    storage: Box<dyn GameStore + Send>,
+///
+/// Lobbies, seating, clocks, matches, and persistence work for any `PlatformGame`. Lost Cities is
+/// the only game with a wire API so far, so that's the only one `GameApi2` is implemented for.
+pub struct BackendGameApi<G: PlatformGame> {
+    game: G,
+    storage: Box<dyn GameStore<G::State> + Send>,
     storage2: Arc<dyn GameDatabase + Send + Sync>,
-    deck_factory: DeckFactory,
+    /// Finished games never change, and analyzing one takes a few seconds, so keep the results.
+    /// Keyed by (game ID, player ID).
+    completed_game_analyses: HashMap<(String, String), GameAnalysis>,
 }
 
-impl BackendGameApi {
-    pub fn new(db_client: Arc<dyn GameDatabase + Send + Sync>) -> Self {
+impl<G: PlatformGame> BackendGameApi<G> {
+    pub fn new(game: G, db_client: Arc<dyn GameDatabase + Send + Sync>) -> Self {
         BackendGameApi {
+            game,
             storage: Box::new(InMemoryStore::new()),
             storage2: db_client,
-            deck_factory: DeckFactory::new(),
+            completed_game_analyses: HashMap::new(),
         }
     }
 
-    fn update_game_metadata(&mut self, game_id: &str, p2_id: String) -> Result<(), BackendGameError> {
-        let mut metadata = self.load_game_metadata(game_id)?;
+    /// List the game in the lobby with only the host seated.
+    fn list_hosted_game(&mut self, storage_game_metadata: StorageGameMetadata) -> Result<(), BackendGameError> {
+        self.storage.create_game_metadata(storage_game_metadata)
+            .map_err(|e| BackendGameError::Internal(Cause::Storage("Failed to list game as hosted.", Arc::new(e))))
+    }
 
-        if let Some(existing_p2_id) = metadata.p2_id_opt() {
-            return Err(BackendGameError::GameAlreadyMatched(existing_p2_id.clone()));
+    /// Seat the guest at the next open seat, and deal the game once the table is full.
+    fn take_seat(&mut self, game_id: String, guest_id: String) -> Result<(), BackendGameError> {
+        let metadata = self.update_game_metadata(&game_id, guest_id)?;
+        if !metadata.is_full() {
+            // Still waiting on more guests.
+            return Ok(());
         }
-        metadata.set_p2_id(p2_id);
 
-        self.storage.update_game_metadata(metadata)
+        let first_seat = lobby::random_first_seat(*metadata.num_seats());
+        let config = self.game.load_config(&metadata);
+        self.start_game(game_id, first_seat, &config)
+    }
+
+    /// Seat the guest at the next open seat. Returns the updated metadata.
+    fn update_game_metadata(&mut self, game_id: &str, guest_id: String) -> Result<StorageGameMetadata, BackendGameError> {
+        let mut metadata = self.load_game_metadata(game_id)?;
+        lobby::seat_guest(&mut metadata, guest_id)?;
+
+        self.storage.update_game_metadata(metadata.clone())
             .map_err(|e| match e {
                 StorageError::NotFound => BackendGameError::NotFound("Game metadata"),
                 _ => BackendGameError::Internal(Cause::Storage("Failed to save game metadata", Arc::new(e)))
-            })
-    }
+            })?;
 
-    fn create_initial_game_state(&mut self, game_id: String) -> Result<(), BackendGameError> {
-        let (mut deck, seed) = self.deck_factory.new_shuffled_deck();
-        println!("INFO: Seeding RNG with '{}' to shuffle deck for game '{}'", seed, game_id);
+        Ok(metadata)
+    }
 
-        let mut p1_hand: Vec<Card> = Vec::with_capacity(8);
-        let mut p2_hand: Vec<Card> = Vec::with_capacity(8);
-        for _ in 0..8 {
-            p1_hand.push(deck.pop().ok_or_else(|| BackendGameError::Internal(Cause::Impossible))?);
-            p2_hand.push(deck.pop().ok_or_else(|| BackendGameError::Internal(Cause::Impossible))?);
-        }
-
-        let game_state = StorageGameState::new(
-            game_id,
-            p1_hand,
-            p2_hand,
-            HashMap::new(),
-            HashMap::new(),
-            HashMap::new(),
-            deck,
-            is_first_turn_p1(),
-        );
+    /// Deal the game once every seat is taken.
+    fn start_game(&mut self, game_id: String, first_seat: usize, config: &G::Config) -> Result<(), BackendGameError> {
+        let game_state = self.game.setup(game_id, config, first_seat)?;
 
         self.storage.create_game_state(game_state)
             .map_err(|e| BackendGameError::Internal(Cause::Storage("Failed to save initial game state", Arc::new(e))))
     }
 
-    fn load_game(&self, game_id: &str, player_id: &str) -> Result<(StorageGameState, bool), BackendGameError> {
+    /// Also returns the player's seat.
+    fn load_game(&self, game_id: &str, player_id: &str) -> Result<(G::State, usize), BackendGameError> {
         let metadata = self.load_game_metadata(game_id)?;
 
-        let is_player_1 = if player_id == metadata.p1_id() {
-            true
-        } else if player_id == metadata.p2_id() {
-            false
-        } else {
-            return Err(BackendGameError::NotFound("Player in game"));
-        };
+        let seat = lobby::find_seat(&metadata, player_id)?;
+
+        let game_state = self.load_game_state(game_id)?;
+
+        Ok((game_state, seat))
+    }
 
-        let storage_game_state = self.storage.load_game_state(game_id)
+    fn load_game_state(&self, game_id: &str) -> Result<G::State, BackendGameError> {
+        self.storage.load_game_state(game_id)
             .map_err(|e| match e {
                 StorageError::NotFound => BackendGameError::NotFound("Game state"),
                 _ => BackendGameError::Internal(Cause::Storage("Failed to load game state.", Arc::new(e))),
-            })?;
-
-        Ok((storage_game_state, is_player_1))
+            })
     }
 
     fn load_game_metadata(&self, game_id: &str) -> Result<StorageGameMetadata, BackendGameError> {
@@ -99,52 +116,234 @@ impl BackendGameApi {
                 _ => BackendGameError::Internal(Cause::Storage("Failed to load game", Arc::new(e)))
             })
     }
-}
 
-#[async_trait::async_trait]
-impl GameApi2<BackendGameError> for BackendGameApi {
-    async fn host_game(&mut self, game_id: String, p1_id: String) -> Result<(), BackendGameError> {
-        let storage_game_metadata = StorageGameMetadata::new(
-            game_id,
-            p1_id,
-            None,
+    /// Matched games are listed with their status from the host's perspective.
+    fn list_game(&self, storage_game_metadata: StorageGameMetadata) -> Result<GameMetadata, BackendGameError> {
+        let host_status = if storage_game_metadata.is_full() {
+            let game_state = self.load_game_state(storage_game_metadata.game_id())?;
+            Some(self.game.status(&game_state, 0))
+        } else {
+            None
+        };
+
+        Ok(lobby::convert_game_metadata(&storage_game_metadata, host_status))
+    }
+
+    fn validate_game_not_over(&self, game_state: &G::State) -> Result<(), BackendGameError> {
+        if self.game.is_terminal(game_state) {
+            return Err(BackendGameError::InvalidPlay(Reason::GameOver));
+        }
+
+        Ok(())
+    }
+
+    /// Stops the clock and flags the current player if they're out of time.
+    fn check_timeout(&self, game_state: &mut G::State, now_ms: u64) -> bool {
+        let turn = self.game.turn(game_state);
+        match self.game.clock_mut(game_state) {
+            Some(clock) => turn_clock::check_timeout(clock, turn, now_ms),
+            None => false,
+        }
+    }
+
+    fn load_match(&self, match_id: &str) -> Result<StorageMatch, BackendGameError> {
+        self.storage.load_match(match_id)
+            .map_err(|e| match e {
+                StorageError::NotFound => BackendGameError::NotFound("Match"),
+                _ => BackendGameError::Internal(Cause::Storage("Failed to load match", Arc::new(e)))
+            })
+    }
+
+    fn update_match(&mut self, storage_match: StorageMatch) -> Result<(), BackendGameError> {
+        self.storage.update_match(storage_match)
+            .map_err(|e| BackendGameError::Internal(Cause::Storage("Failed to save match", Arc::new(e))))
+    }
+
+    /// Create the game for the next round of the match. Caller is responsible for saving the match.
+    fn deal_next_round(&mut self, storage_match: &mut StorageMatch) -> Result<(), BackendGameError> {
+        let round_index = storage_match.game_ids().len();
+        let game_id = match_rounds::round_game_id(storage_match.match_id(), round_index + 1);
+
+        let mut metadata = StorageGameMetadata::new(
+            game_id.clone(),
+            vec![storage_match.p1_id().to_owned(), storage_match.p2_id().to_owned()],
             StorageGameStatus::InProgress,
         );
+        metadata.set_game_type(self.game.game_type());
+        metadata.set_match_id(storage_match.match_id().to_owned());
+        if let Some(time_control) = storage_match.time_control() {
+            metadata.set_time_control(*time_control);
+        }
+        metadata.set_rule_set(*storage_match.rule_set());
+        let config = self.game.load_config(&metadata);
+        self.storage.create_game_metadata(metadata)
+            .map_err(|e| BackendGameError::Internal(Cause::Storage("Failed to create next round of match.", Arc::new(e))))?;
 
-        self.storage.create_game_metadata(storage_game_metadata)
-            .map_err(|e| BackendGameError::Internal(Cause::Storage("Failed to list game as hosted.", Arc::new(e))))
+        let first_seat = if storage_match.p1_starts_round(round_index) { 0 } else { 1 };
+        self.start_game(game_id.clone(), first_seat, &config)?;
+
+        storage_match.push_game_id(game_id);
+        Ok(())
+    }
+
+    /// Save a game that just ended before its main draw pile ran out, i.e. by timeout,
+    /// resignation, or agreed draw.
+    async fn end_game_early(&mut self, mut game_state: G::State) -> Result<(), BackendGameError> {
+        let game_id = game_state.game_id().to_owned();
+        self.game.set_end_time_ms(&mut game_state, turn_clock::now_ms());
+
+        self.storage.update_game_state(game_state)
+            .map_err(|e| BackendGameError::Internal(Cause::Storage("Failed to save the ended game state", Arc::new(e))))?;
+
+        self.complete_game(&game_id).await
+    }
+
+    /// Forfeit every timed game where the current player's clock has run out. This is
+    /// periodically triggered by a background task, so games don't get stuck waiting on a
+    /// player who has walked away. One broken game doesn't hold up the rest.
+    pub async fn forfeit_timed_out_games(&mut self) -> Result<(), BackendGameError> {
+        let now_ms = turn_clock::now_ms();
+        let in_progress_games = self.storage.query_game_metadata(StorageGameStatus::InProgress)
+            .map_err(|e| BackendGameError::Internal(Cause::Storage("Failed to query in-progress games", Arc::new(e))))?;
+
+        for storage_game_metadata in in_progress_games {
+            if storage_game_metadata.time_control().is_none() || !storage_game_metadata.is_full() {
+                continue;
+            }
+
+            if let Err(e) = self.forfeit_if_timed_out(storage_game_metadata.game_id(), now_ms).await {
+                println!("WARN: Failed to check game '{}' for a timeout: {}", storage_game_metadata.game_id(), e);
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn forfeit_if_timed_out(&mut self, game_id: &str, now_ms: u64) -> Result<(), BackendGameError> {
+        let mut game_state = self.load_game_state(game_id)?;
+        if self.check_timeout(&mut game_state, now_ms) {
+            println!("INFO: A player ran out of time in game '{}'", game_id);
+            self.end_game_early(game_state).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Called once the game ends, however it ended. If the game was a round of a match, this deals
+    /// the match's next round, and archives the game for the analytics export.
+    async fn complete_game(&mut self, game_id: &str) -> Result<(), BackendGameError> {
+        let mut metadata = self.load_game_metadata(game_id)?;
+        metadata.set_game_status(StorageGameStatus::Completed);
+        let match_id_opt = metadata.match_id_opt().clone();
+        self.storage.update_game_metadata(metadata.clone())
+            .map_err(|e| BackendGameError::Internal(Cause::Storage("Failed to mark game as completed", Arc::new(e))))?;
+
+        if let Some(match_id) = match_id_opt {
+            let mut storage_match = self.load_match(&match_id)?;
+            if storage_match.has_more_rounds() {
+                self.deal_next_round(&mut storage_match)?;
+                self.update_match(storage_match)?;
+            }
+        }
+
+        self.archive_completed_game(&metadata).await
+    }
+
+    /// Write the finished game to the database, where the analytics export picks it up. The data
+    /// holds the game in its text notation.
+    async fn archive_completed_game(&mut self, metadata: &StorageGameMetadata) -> Result<(), BackendGameError> {
+        let game_state = self.load_game_state(metadata.game_id())?;
+        let record = self.game.write_record(&game_state, metadata.player_ids())?;
+
+        let game_summary = DbGameSummary {
+            game_id: metadata.game_id().to_owned(),
+            game_creation_time_sec: (metadata.creation_time_ms() / 1000) as u32,
+            game_type: *metadata.game_type(),
+            game_status: DbGameStatus::Completed,
+            player_ids: metadata.player_ids().to_owned(),
+            game_summary_blob_opt: None,
+        };
+        let game_data = DbGameData {
+            game_id: metadata.game_id().to_owned(),
+            game_data_blob: record.into_bytes(),
+        };
+
+        // Data first, so there's never a summary without it.
+        self.storage2.create_game_data(game_data).await
+            .map_err(|e| BackendGameError::Internal(Cause::Storage("Failed to archive the game data", Arc::new(e))))?;
+        self.storage2.create_game_summary(game_summary).await
+            .map_err(|e| BackendGameError::Internal(Cause::Storage("Failed to archive the game summary", Arc::new(e))))
+    }
+}
+
+/// Everything that only makes sense for Lost Cities: the computer opponent, analysis, draw offers,
+/// and take-backs.
+impl BackendGameApi<LostCities> {
+    /// If the computer is seated in this game and it's its turn, returns how hard it plays and the
+    /// game from its seat, which is everything it needs to pick its move.
+    pub fn pending_computer_turn(&mut self, game_id: &str) -> Result<Option<(Difficulty, GameState)>, BackendGameError> {
+        let difficulty = match self.load_game_metadata(game_id)?.computer() {
+            Some(difficulty) => *difficulty,
+            None => return Ok(None),
+        };
+
+        let storage_game_state = self.load_game_state(game_id)?;
+        if *storage_game_state.turn() != COMPUTER_SEAT || self.game.is_terminal(&storage_game_state) {
+            return Ok(None);
+        }
+
+        Ok(Some((difficulty, self.game.player_view(&storage_game_state, COMPUTER_SEAT))))
+    }
+
+    pub fn cached_analysis(&self, game_id: &str, player_id: &str) -> Option<GameAnalysis> {
+        self.completed_game_analyses
+            .get(&(game_id.to_owned(), player_id.to_owned()))
+            .cloned()
+    }
+
+    pub fn cache_analysis(&mut self, game_id: String, player_id: String, analysis: GameAnalysis) {
+        self.completed_game_analyses.insert((game_id, player_id), analysis);
+    }
+
+    /// Load everything the analysis needs, so that the slow part can run without `self`.
+    pub fn prepare_analysis(&self, game_id: &str, player_id: &str) -> Result<PendingAnalysis, BackendGameError> {
+        let (storage_game_state, seat) = self.load_game(game_id, player_id)?;
+        // The simulations only know how to deal a hand to one opponent.
+        validate_two_player_game(&storage_game_state)?;
+        let game_state = self.game.player_view(&storage_game_state, seat);
+
+        Ok(PendingAnalysis::new(game_state, storage_game_state, seat))
+    }
+}
+
+#[async_trait::async_trait]
+impl GameApi2<BackendGameError> for BackendGameApi<LostCities> {
+    async fn host_game(&mut self, game_id: String, p1_id: String, time_control: Option<TimeControl>, opponent: Opponent, rule_set: RuleSet) -> Result<(), BackendGameError> {
+        let config = LostCitiesConfig::new(time_control, rule_set);
+        let mut storage_game_metadata = lobby::host(&self.game, game_id.clone(), p1_id, &config)?;
+        if let Opponent::Computer(difficulty) = opponent {
+            if *storage_game_metadata.num_seats() != 2 {
+                return Err(BackendGameError::InvalidPlay(Reason::TwoPlayerOnly));
+            }
+            storage_game_metadata.set_computer(difficulty);
+        }
+
+        self.list_hosted_game(storage_game_metadata)?;
+
+        if let Opponent::Computer(_) = opponent {
+            self.join_game(game_id.clone(), BOT_PLAYER_ID.to_owned()).await?;
+        }
+
+        Ok(())
     }
 
     async fn join_game(&mut self, game_id: String, p2_id: String) -> Result<(), BackendGameError> {
-        self.update_game_metadata(&game_id, p2_id)?;
-        self.create_initial_game_state(game_id)
+        self.take_seat(game_id, p2_id)
     }
 
     async fn describe_game(&mut self, game_id: String) -> Result<GameMetadata, BackendGameError> {
-        self.load_game_metadata(&game_id)
-            .map(|storage_game_metadata| {
-                if let Some(p2_id) = storage_game_metadata.p2_id_opt() {
-                    let status = match storage_game_metadata.game_status() {
-                        // TODO Use the actual data. The contained data in the GameStatus
-                        // is stored in GameState, not Metadata, so just put something random here.
-                        StorageGameStatus::InProgress => GameStatus::InProgress(false),
-                        StorageGameStatus::Completed => GameStatus::Complete(GameResult::Draw),
-                    };
-                    GameMetadata::new_matched(
-                        game_id,
-                        storage_game_metadata.p1_id().to_owned(),
-                        storage_game_metadata.creation_time_ms(),
-                        p2_id.clone(),
-                        status
-                    )
-                } else {
-                    GameMetadata::new_unmatched(
-                        game_id,
-                        storage_game_metadata.p1_id().to_owned(),
-                        storage_game_metadata.creation_time_ms(),
-                    )
-                }
-            })
+        let storage_game_metadata = self.load_game_metadata(&game_id)?;
+        self.list_game(storage_game_metadata)
     }
 
     async fn query_unmatched_games(&mut self, _player_id: String) -> Result<Vec<GameMetadata>, BackendGameError> {
@@ -163,184 +362,430 @@ impl GameApi2<BackendGameError> for BackendGameApi {
         unimplemented!()
     }
 
+    async fn query_watchable_games(&mut self, _player_id: String) -> Result<Vec<GameMetadata>, BackendGameError> {
+        let in_progress_games = self.storage.query_game_metadata(StorageGameStatus::InProgress)
+            .map_err(|e| BackendGameError::Internal(Cause::Storage("Failed to query in-progress games", Arc::new(e))))?;
+
+        in_progress_games.into_iter()
+            // Unmatched games are also "in progress", but there's nothing to watch yet.
+            .filter(|storage_game_metadata| storage_game_metadata.is_full())
+            .map(|storage_game_metadata| self.list_game(storage_game_metadata))
+            .collect()
+    }
+
     async fn get_game_state(&mut self, game_id: String, player_id: String) -> Result<GameState, BackendGameError> {
-        let (storage_game_state, is_player_1) = self.load_game(&game_id, &player_id)?;
+        let (storage_game_state, seat) = self.load_game(&game_id, &player_id)?;
 
-        let game_state = convert_game_state(storage_game_state, is_player_1);
+        let game_state = self.game.player_view(&storage_game_state, seat);
 
         return Ok(game_state);
     }
 
+    async fn get_spectator_game_state(&mut self, game_id: String, _player_id: String) -> Result<SpectatorGameState, BackendGameError> {
+        let metadata = self.load_game_metadata(&game_id)?;
+        if !metadata.is_full() {
+            return Err(BackendGameError::NotFound("Game state"));
+        }
+
+        let storage_game_state = self.load_game_state(&game_id)?;
+
+        Ok(convert_spectator_game_state(storage_game_state, metadata))
+    }
+
     async fn play_card(&mut self, play: Play) -> Result<(), BackendGameError> {
-        let (storage_game_state, is_player_1) = self.load_game(play.game_id(), play.player_id())?;
+        let (mut storage_game_state, seat) = self.load_game(play.game_id(), play.player_id())?;
+
+        self.validate_game_not_over(&storage_game_state)?;
+
+        let now_ms = turn_clock::now_ms();
+        let turn = *storage_game_state.turn();
+        if self.check_timeout(&mut storage_game_state, now_ms) {
+            // The background task just hasn't gotten to it yet.
+            println!("INFO: A player ran out of time in game '{}'", play.game_id());
+            self.end_game_early(storage_game_state).await?;
+            return Err(BackendGameError::InvalidPlay(if turn == seat {
+                Reason::OutOfTime
+            } else {
+                Reason::GameOver
+            }));
+        }
 
-        let updated_game_state = apply_play_to_game_state(play, storage_game_state, is_player_1)?;
+        let action = Action::new(*play.card(), *play.target(), *play.draw_pile());
+        let mut updated_game_state = self.game.apply_action(storage_game_state, seat, action)?;
+        if let Some(clock) = updated_game_state.clock_mut() {
+            turn_clock::record_move(clock, seat, now_ms);
+        }
+        // Playing a card instead of responding to the opponent's draw offer declines it.
+        if updated_game_state.draw_offer().filter(|offered_by| *offered_by != seat).is_some() {
+            updated_game_state.clear_draw_offer();
+        }
+        // Same for a take-back request. The opponent's move isn't the latest one anymore anyway.
+        updated_game_state.clear_take_back_request();
+        let is_game_over = self.game.is_terminal(&updated_game_state);
+        if is_game_over {
+            updated_game_state.set_end_time_ms(now_ms);
+        }
+        let game_id = updated_game_state.game_id().to_owned();
 
         self.storage.update_game_state(updated_game_state)
-            .map_err(|e| BackendGameError::Internal(Cause::Storage("Failed to save the updated game state", Arc::new(e))))
+            .map_err(|e| BackendGameError::Internal(Cause::Storage("Failed to save the updated game state", Arc::new(e))))?;
+
+        if is_game_over {
+            self.complete_game(&game_id).await?;
+        }
+
+        Ok(())
     }
-}
 
-// ================ private, static (stateless) methods related to StorageBackedGameApi =================
+    async fn resign(&mut self, game_id: String, player_id: String) -> Result<(), BackendGameError> {
+        let (mut storage_game_state, seat) = self.load_game(&game_id, &player_id)?;
+        self.validate_game_not_over(&storage_game_state)?;
 
-fn is_first_turn_p1() -> bool {
-    rand::random()
-}
+        println!("INFO: Player '{}' resigned game '{}'", player_id, game_id);
+        storage_game_state.set_early_end(StorageEarlyEnd::Resigned(seat));
+        storage_game_state.clear_draw_offer();
+
+        self.end_game_early(storage_game_state).await
+    }
+
+    async fn offer_draw(&mut self, game_id: String, player_id: String) -> Result<(), BackendGameError> {
+        let (mut storage_game_state, seat) = self.load_game(&game_id, &player_id)?;
+        self.validate_game_not_over(&storage_game_state)?;
+        validate_two_player_game(&storage_game_state)?;
 
-// Expensive cloning incoming... :P
-fn convert_game_state(storage_game_state: StorageGameState, is_player_1: bool) -> GameState {
-    // Here is where we only show what the player is allowed to see.
-    let mut concealed_neutral_draw_pile = HashMap::new();
-    for (color, value_vec) in storage_game_state.neutral_draw_pile().iter() {
-        if let Some(top_card) = value_vec.last() {
-            concealed_neutral_draw_pile.insert(*color, (*top_card, value_vec.len()));
+        if storage_game_state.draw_offer().is_some() {
+            return Err(BackendGameError::InvalidPlay(Reason::DrawAlreadyOffered));
         }
+        storage_game_state.set_draw_offer(seat);
+
+        self.storage.update_game_state(storage_game_state)
+            .map_err(|e| BackendGameError::Internal(Cause::Storage("Failed to save the draw offer", Arc::new(e))))
     }
 
-    let (my_plays, op_plays) = if is_player_1 {
-        (storage_game_state.p1_plays(), storage_game_state.p2_plays())
-    } else {
-        (storage_game_state.p2_plays(), storage_game_state.p1_plays())
-    };
+    async fn respond_to_draw_offer(&mut self, game_id: String, player_id: String, accept: bool) -> Result<(), BackendGameError> {
+        let (mut storage_game_state, seat) = self.load_game(&game_id, &player_id)?;
+        self.validate_game_not_over(&storage_game_state)?;
 
-    let (my_score_total, my_score_per_color) = scoring::compute_score(my_plays);
-    let (op_score_total, op_score_per_color) = scoring::compute_score(op_plays);
+        // You can't respond to your own offer.
+        if storage_game_state.draw_offer().filter(|offered_by| *offered_by != seat).is_none() {
+            return Err(BackendGameError::InvalidPlay(Reason::NoDrawOffer));
+        }
+        storage_game_state.clear_draw_offer();
 
-    let game_board = GameBoard::new(
-        my_plays.to_owned(),
-        op_plays.to_owned(),
-        my_score_total,
-        op_score_total,
-        my_score_per_color,
-        op_score_per_color,
-        concealed_neutral_draw_pile,
-        storage_game_state.main_draw_pile().len(),
-    );
+        if accept {
+            println!("INFO: Players agreed to a draw in game '{}'", game_id);
+            storage_game_state.set_early_end(StorageEarlyEnd::DrawAgreed);
+            return self.end_game_early(storage_game_state).await;
+        }
 
-    let (my_hand, my_previous_plays, is_my_turn) = get_players_info(&storage_game_state, is_player_1);
-    let game_status = endgame::get_game_status(&game_board, is_my_turn);
+        self.storage.update_game_state(storage_game_state)
+            .map_err(|e| BackendGameError::Internal(Cause::Storage("Failed to save the declined draw offer", Arc::new(e))))
+    }
 
-    GameState::new(
-        game_board,
-        plays::decorate_hand(my_hand.to_owned(), my_previous_plays),
-        game_status
-    )
-}
+    async fn request_take_back(&mut self, game_id: String, player_id: String) -> Result<(), BackendGameError> {
+        let (mut storage_game_state, seat) = self.load_game(&game_id, &player_id)?;
+        self.validate_game_not_over(&storage_game_state)?;
+        validate_two_player_game(&storage_game_state)?;
+
+        // Only the most recent move can be taken back, and only by whoever made it.
+        let made_last_move = storage_game_state.move_history()
+            .last()
+            .map(|last_move| *last_move.seat()) == Some(seat);
+        if !made_last_move {
+            return Err(BackendGameError::InvalidPlay(Reason::NoMoveToTakeBack));
+        }
+        if storage_game_state.take_back_request().is_some() {
+            return Err(BackendGameError::InvalidPlay(Reason::TakeBackAlreadyRequested));
+        }
+        storage_game_state.set_take_back_request(seat);
 
-/// Extract the player-specific info from the storage state, based on the player making the backend request.
-fn get_players_info(
-    storage_game_state: &StorageGameState,
-    is_player_1: bool
-) -> (
-    &Vec<Card>, /* player's hand */
-    &HashMap<CardColor, Vec<CardValue>>, /* player's previous plays */
-    bool, /* is player's turn */
-) {
-    if is_player_1 {
-        (
-            storage_game_state.p1_hand(),
-            storage_game_state.p1_plays(),
-            *storage_game_state.p1_turn()
-        )
-    } else {
-        (
-            storage_game_state.p2_hand(),
-            storage_game_state.p2_plays(),
-            !*storage_game_state.p1_turn()
-        )
+        self.storage.update_game_state(storage_game_state)
+            .map_err(|e| BackendGameError::Internal(Cause::Storage("Failed to save the take-back request", Arc::new(e))))
     }
-}
 
-fn apply_play_to_game_state(
-    play: Play,
-    storage_game_state: StorageGameState,
-    is_player_1: bool
-) -> Result<StorageGameState, BackendGameError> {
+    async fn respond_to_take_back(&mut self, game_id: String, player_id: String, accept: bool) -> Result<(), BackendGameError> {
+        let (mut storage_game_state, seat) = self.load_game(&game_id, &player_id)?;
+        self.validate_game_not_over(&storage_game_state)?;
 
-    let pa_sgs = storage_game_state.convert_to_player_aware(is_player_1);
+        // You can't respond to your own request.
+        if storage_game_state.take_back_request().filter(|requested_by| *requested_by != seat).is_none() {
+            return Err(BackendGameError::InvalidPlay(Reason::NoTakeBackRequest));
+        }
+        storage_game_state.clear_take_back_request();
 
-    let card_in_hand_index = validate_play(
-        &play,
-        pa_sgs.my_hand(),
-        pa_sgs.my_plays(),
-        pa_sgs.inner().neutral_draw_pile(),
-        pa_sgs.is_my_turn()
-    ).map_err(|e| BackendGameError::InvalidPlay(e))?;
+        if accept {
+            println!("INFO: Taking back the last move in game '{}'", game_id);
+            storage_game_state = take_back::take_back_last_move(storage_game_state)?;
 
-    let mut pa_sgs = pa_sgs;
-    // Model a turn like in real life:
+            // Whoever had the turn pays for the time they spent before agreeing.
+            if let Some(clock) = storage_game_state.clock_mut() {
+                turn_clock::record_move(clock, seat, turn_clock::now_ms());
+            }
+        }
 
-    // 1. Remove the card from hand
-    let _removed_card = pa_sgs.my_hand_mut().remove(card_in_hand_index);
+        self.storage.update_game_state(storage_game_state)
+            .map_err(|e| BackendGameError::Internal(Cause::Storage("Failed to save the take-back response", Arc::new(e))))
+    }
 
-    // 2. Add card on top of target pile
-    let target_pile = match play.target() {
-        CardTarget::Player => pa_sgs.my_plays_mut(),
-        CardTarget::Neutral => pa_sgs.neutral_draw_pile_mut(),
-    };
+    /// The task handler doesn't call this, since it runs the analysis off its event loop. Same
+    /// steps, though.
+    async fn analyze_game(&mut self, game_id: String, player_id: String) -> Result<GameAnalysis, BackendGameError> {
+        if let Some(analysis) = self.cached_analysis(&game_id, &player_id) {
+            return Ok(analysis);
+        }
+
+        let pending_analysis = self.prepare_analysis(&game_id, &player_id)?;
+        let is_game_over = pending_analysis.is_game_over();
+        let analysis = pending_analysis.run()?;
+        if is_game_over {
+            self.cache_analysis(game_id, player_id, analysis.clone());
+        }
 
-    target_pile.entry(*play.card().card_color())
-        .or_insert_with(|| Vec::new())
-        .push(*play.card().card_value());
+        Ok(analysis)
+    }
 
-    // 3. Draw new card
-    let new_card_opt = match play.draw_pile() {
-        DrawPile::Main => pa_sgs.main_draw_pile_mut().pop(),
-        DrawPile::Neutral(color) => {
-            pa_sgs.neutral_draw_pile_mut()
-                .get_mut(color)
-                .and_then(|draw_pile| draw_pile.pop())
-                .map(|drawn_value| Card::new(*color, drawn_value))
+    async fn export_game(&mut self, game_id: String, _player_id: String) -> Result<String, BackendGameError> {
+        let metadata = self.load_game_metadata(&game_id)?;
+        if !metadata.is_full() {
+            return Err(BackendGameError::NotFound("Game state"));
         }
-    };
-    let new_card = new_card_opt.ok_or_else(|| BackendGameError::Internal(Cause::Impossible))?;
-    pa_sgs.my_hand_mut().push(new_card);
 
-    // 4. Flip the turn marker
-    let mut sgs = pa_sgs.convert_to_inner();
-    sgs.swap_turn();
+        let storage_game_state = self.load_game_state(&game_id)?;
+        if !self.game.is_terminal(&storage_game_state) {
+            return Err(BackendGameError::InvalidPlay(Reason::GameNotOver));
+        }
 
-    Ok(sgs)
+        self.game.write_record(&storage_game_state, metadata.player_ids())
+    }
+
+    async fn host_match(&mut self, match_id: String, p1_id: String, num_rounds: u32, time_control: Option<TimeControl>, rule_set: RuleSet) -> Result<(), BackendGameError> {
+        self.game.validate_config(&LostCitiesConfig::new(time_control, rule_set))?;
+        if *rule_set.num_players() != 2 {
+            return Err(BackendGameError::InvalidPlay(Reason::TwoPlayerOnly));
+        }
+
+        let mut storage_match = StorageMatch::new(
+            match_id,
+            p1_id,
+            None,
+            cmp::max(num_rounds, 1),
+            is_first_turn_p1(),
+            Vec::new(),
+        );
+        if let Some(time_control) = time_control {
+            storage_match.set_time_control(time_control);
+        }
+        storage_match.set_rule_set(rule_set);
+
+        self.storage.create_match(storage_match)
+            .map_err(|e| BackendGameError::Internal(Cause::Storage("Failed to list match as hosted.", Arc::new(e))))
+    }
+
+    async fn join_match(&mut self, match_id: String, p2_id: String) -> Result<(), BackendGameError> {
+        let mut storage_match = self.load_match(&match_id)?;
+
+        if let Some(existing_p2_id) = storage_match.p2_id_opt() {
+            return Err(BackendGameError::GameAlreadyMatched(existing_p2_id.clone()));
+        }
+        storage_match.set_p2_id(p2_id);
+
+        self.deal_next_round(&mut storage_match)?;
+        self.update_match(storage_match)
+    }
+
+    async fn get_match_state(&mut self, match_id: String, player_id: String) -> Result<MatchState, BackendGameError> {
+        let storage_match = self.load_match(&match_id)?;
+
+        // Every round is dealt with player 1 in the first seat.
+        let seat = if player_id == storage_match.p1_id() {
+            0
+        } else if storage_match.p2_id_opt().as_ref() == Some(&player_id) {
+            1
+        } else {
+            return Err(BackendGameError::NotFound("Player in match"));
+        };
+
+        let mut rounds = Vec::with_capacity(storage_match.game_ids().len());
+        for game_id in storage_match.game_ids() {
+            let storage_game_state = self.load_game_state(game_id)?;
+            rounds.push(convert_match_round(&storage_game_state, seat));
+        }
+
+        Ok(convert_match_state(&storage_match, rounds, seat))
+    }
 }
 
-fn validate_play(
-    play: &Play,
-    my_hand: &Vec<Card>,
-    my_previous_plays: &HashMap<CardColor, Vec<CardValue>>,
-    neutral_draw_pile: &HashMap<CardColor, Vec<CardValue>>,
-    is_my_turn: bool
-) -> Result<usize, Reason> {
+// ================ private, static (stateless) methods related to StorageBackedGameApi =================
 
-    // RULE: You can only play on your turn.
-    if !is_my_turn {
-        return Err(Reason::NotYourTurn);
+fn is_first_turn_p1() -> bool {
+    rand::random()
+}
+
+/// Draw offers, take-backs, analysis, and the computer opponent only work with one opponent.
+fn validate_two_player_game(storage_game_state: &StorageGameState) -> Result<(), BackendGameError> {
+    if storage_game_state.num_seats() != 2 {
+        return Err(BackendGameError::InvalidPlay(Reason::TwoPlayerOnly));
     }
 
-    // RULE: You can only play cards that are in your hand.
-    let card_in_hand_index = my_hand.iter()
-        .position(|card| card == play.card())
-        .ok_or_else(|| Reason::CardNotInHand)?;
+    Ok(())
+}
 
-    // RULE: You must play cards in increasing order.
-    if *play.target() == CardTarget::Player && !plays::is_card_playable(play.card(), my_previous_plays) {
-        return Err(Reason::CantPlayDecreasingCardValue);
+/// Spectators see every seat, minus the hands. The host is seat 0.
+fn convert_spectator_game_state(storage_game_state: StorageGameState, metadata: StorageGameMetadata) -> SpectatorGameState {
+    let seats: Vec<SpectatorSeat> = metadata.player_ids()
+        .iter()
+        .zip(storage_game_state.plays())
+        .map(|(player_id, plays)| {
+            let (score_total, score_per_color) = scoring::compute_score(plays, storage_game_state.rule_set());
+            SpectatorSeat::new(player_id.to_owned(), plays.to_owned(), score_total, score_per_color)
+        })
+        .collect();
+
+    // Everyone who won or shares the best score is winning. Only the end reason is the same from
+    // every seat.
+    let mut winning_seats = Vec::new();
+    let mut final_end_reason = None;
+    for seat in 0..seats.len() {
+        let game_board = lost_cities::convert_game_board(&storage_game_state, seat);
+        if let GameStatus::Complete(result, end_reason) = lost_cities::get_game_status(&storage_game_state, &game_board, seat) {
+            if result != GameResult::Lose {
+                winning_seats.push(seat);
+            }
+            final_end_reason = Some(end_reason);
+        }
     }
+    let status = match final_end_reason {
+        Some(end_reason) => SpectatorStatus::Complete(winning_seats, end_reason),
+        None => SpectatorStatus::InProgress(*storage_game_state.turn()),
+    };
 
-    if let DrawPile::Neutral(color_to_draw) = play.draw_pile() {
-        // RULE: You can't redraw the same card you just played.
-        if *play.target() == CardTarget::Neutral && color_to_draw == play.card().card_color() {
-            return Err(Reason::CantRedrawCardJustPlayed);
+    SpectatorGameState::new(
+        seats,
+        lost_cities::conceal_neutral_draw_pile(storage_game_state.neutral_draw_pile()),
+        storage_game_state.main_draw_pile().len(),
+        status,
+    ).with_rule_set(*storage_game_state.rule_set())
+}
+
+fn convert_match_round(storage_game_state: &StorageGameState, seat: usize) -> MatchRound {
+    let game_board = lost_cities::convert_game_board(storage_game_state, seat);
+    let game_status = lost_cities::get_game_status(storage_game_state, &game_board, seat);
+
+    MatchRound::new(
+        storage_game_state.game_id().to_owned(),
+        game_status,
+        *game_board.my_score_total(),
+        *game_board.op_score_total(),
+    )
+}
+
+fn convert_match_state(storage_match: &StorageMatch, rounds: Vec<MatchRound>, seat: usize) -> MatchState {
+    let op_player_id = if seat == 0 {
+        storage_match.p2_id_opt().clone()
+    } else {
+        Some(storage_match.p1_id().to_owned())
+    };
+
+    let my_score_total: i32 = rounds.iter().map(|round| round.my_score()).sum();
+    let op_score_total: i32 = rounds.iter().map(|round| round.op_score()).sum();
+
+    let is_last_round_complete = matches!(rounds.last().map(|round| round.status()), Some(GameStatus::Complete(_, _)));
+    let status = if op_player_id.is_none() {
+        MatchStatus::Unmatched
+    } else if storage_match.has_more_rounds() || !is_last_round_complete {
+        MatchStatus::InProgress
+    } else if my_score_total > op_score_total {
+        MatchStatus::Complete(GameResult::Win)
+    } else if my_score_total == op_score_total {
+        MatchStatus::Complete(GameResult::Draw)
+    } else {
+        MatchStatus::Complete(GameResult::Lose)
+    };
+
+    MatchState::new(
+        op_player_id,
+        *storage_match.num_rounds(),
+        rounds,
+        my_score_total,
+        op_score_total,
+        status,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use game_api::types::{EndReason, Card};
+    use storage::v2::config::{self, DatabaseMode};
+    use storage::test_utils::{TestFileHandle, rand_str};
+
+    #[tokio::test]
+    async fn one_broken_game_doesnt_stop_the_timeout_sweep() -> Result<(), Box<dyn std::error::Error>> {
+        let db_file = TestFileHandle::new(format!("./safe-to-delete.test-{}.db", rand_str()));
+        let mut api = BackendGameApi::new(LostCities, config::connect_to_database(DatabaseMode::Test(db_file.file_path.clone()))?);
+        let game_id = "sweep-timed-out";
+
+        api.host_game(game_id.to_owned(), "mememe".to_owned(), Some(TimeControl::PerMove(30)), Opponent::Human, RuleSet::standard()).await?;
+        api.join_game(game_id.to_owned(), "youyou".to_owned()).await?;
+        let mut storage_game_state = api.load_game_state(game_id)?;
+        if let Some(clock) = storage_game_state.clock_mut() {
+            clock.set_turn_start_ms(0);
         }
+        api.storage.update_game_state(storage_game_state)?;
+
+        // Listed as being played, but its state never got saved.
+        let mut broken_metadata = StorageGameMetadata::new(
+            "sweep-broken".to_owned(),
+            vec!["mememe".to_owned(), "youyou".to_owned()],
+            StorageGameStatus::InProgress,
+        );
+        broken_metadata.set_time_control(TimeControl::PerMove(30));
+        api.storage.create_game_metadata(broken_metadata)?;
+
+        api.forfeit_timed_out_games().await?;
 
-        // RULE: You can't draw from an empty pile.
-        let neutral_draw_pile_size = neutral_draw_pile
-            .get(color_to_draw)
-            .map(|vec| vec.len())
-            .unwrap_or(0);
-        if neutral_draw_pile_size == 0 {
-            return Err(Reason::NeutralDrawPileEmpty);
+        assert_eq!(*api.load_game_metadata(game_id)?.game_status(), StorageGameStatus::Completed);
+        match api.get_game_state(game_id.to_owned(), "mememe".to_owned()).await?.status() {
+            GameStatus::Complete(_, EndReason::Timeout) => {},
+            status => panic!("Expected the game to end on time, got {:?}", status),
         }
+        Ok(())
     }
 
-    return Ok(card_in_hand_index);
+    #[tokio::test]
+    async fn forfeiting_on_time_reveals_the_final_hands() -> Result<(), Box<dyn std::error::Error>> {
+        let db_file = TestFileHandle::new(format!("./safe-to-delete.test-{}.db", rand_str()));
+        let mut api = BackendGameApi::new(LostCities, config::connect_to_database(DatabaseMode::Test(db_file.file_path.clone()))?);
+        let game_id = "forfeit-summary";
+        let player_ids = ["mememe".to_owned(), "youyou".to_owned()];
+
+        api.host_game(game_id.to_owned(), player_ids[0].clone(), Some(TimeControl::PerMove(30)), Opponent::Human, RuleSet::standard()).await?;
+        api.join_game(game_id.to_owned(), player_ids[1].clone()).await?;
+        let mut storage_game_state = api.load_game_state(game_id)?;
+        let slow_seat = *storage_game_state.turn();
+        let other_seat = 1 - slow_seat;
+        for player_id in player_ids.iter() {
+            assert_eq!(*api.get_game_state(game_id.to_owned(), player_id.clone()).await?.summary(), None);
+        }
+        if let Some(clock) = storage_game_state.clock_mut() {
+            clock.set_turn_start_ms(0);
+        }
+        api.storage.update_game_state(storage_game_state)?;
+
+        api.forfeit_timed_out_games().await?;
+
+        let mut other_hand: Vec<Card> = api.get_game_state(game_id.to_owned(), player_ids[other_seat].clone()).await?
+            .my_hand()
+            .iter()
+            .map(|decorated_card| *decorated_card.card())
+            .collect();
+        other_hand.sort();
+        let game_state = api.get_game_state(game_id.to_owned(), player_ids[slow_seat].clone()).await?;
+        assert_eq!(*game_state.status(), GameStatus::Complete(GameResult::Lose, EndReason::Timeout));
+        let summary = game_state.summary().as_ref().unwrap();
+        assert_eq!(*summary.end_reason(), EndReason::Timeout);
+        assert_eq!(*summary.num_turns(), 0);
+        assert_eq!(*summary.players()[1].final_hand(), other_hand);
+        Ok(())
+    }
 }
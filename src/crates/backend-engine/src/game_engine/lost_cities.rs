@@ -0,0 +1,416 @@
+use crate::backend_error::{BackendGameError, Cause, Reason};
+use crate::game_engine::{turn_clock, unseen_cards};
+use crate::platform::platform_game::PlatformGame;
+use game_api::types::{GameState, GameBoard, OpponentBoard, CardColor, CardValue, GameStatus, GameResult, EndReason, TimeControl, DrawOffer, TakeBackRequest, RuleSet, LastMove, LastDraw, DrawPile, GameSummary, PlayerSummary};
+use rules::deck::{self, DeckFactory};
+use rules::{plays, scoring, endgame, engine};
+use rules::engine::{Action, RulesState};
+use rules::notation::{self, GameRecord, RecordedResult};
+use std::collections::HashMap;
+use storage::storage_types::{StorageGameMetadata, StorageGameState, StorageClock, StorageEarlyEnd, StorageMove};
+use storage::v2::db_types::DbGameType;
+
+/// Lost Cities, as hosted by the platform.
+pub struct LostCities;
+
+/// Picked by the host. The clock isn't part of the rules, but the game state keeps track of it
+/// along with everything else.
+#[derive(Copy, Clone, Debug)]
+pub struct LostCitiesConfig {
+    time_control: Option<TimeControl>,
+    rule_set: RuleSet,
+}
+
+impl LostCitiesConfig {
+    pub fn new(time_control: Option<TimeControl>, rule_set: RuleSet) -> Self {
+        LostCitiesConfig {
+            time_control,
+            rule_set,
+        }
+    }
+
+    pub fn time_control(&self) -> &Option<TimeControl> {
+        &self.time_control
+    }
+
+    pub fn rule_set(&self) -> &RuleSet {
+        &self.rule_set
+    }
+}
+
+impl PlatformGame for LostCities {
+    type Config = LostCitiesConfig;
+    type State = StorageGameState;
+    type Action = Action;
+    type View = GameState;
+
+    fn game_type(&self) -> DbGameType {
+        DbGameType::LostCities
+    }
+
+    fn validate_config(&self, config: &LostCitiesConfig) -> Result<(), BackendGameError> {
+        if !engine::is_valid_rule_set(config.rule_set()) {
+            return Err(BackendGameError::InvalidPlay(Reason::InvalidRuleSet));
+        }
+
+        Ok(())
+    }
+
+    fn num_seats(&self, config: &LostCitiesConfig) -> usize {
+        *config.rule_set().num_players() as usize
+    }
+
+    fn save_config(&self, config: &LostCitiesConfig, metadata: &mut StorageGameMetadata) {
+        if let Some(time_control) = config.time_control() {
+            metadata.set_time_control(*time_control);
+        }
+        metadata.set_rule_set(*config.rule_set());
+    }
+
+    fn load_config(&self, metadata: &StorageGameMetadata) -> LostCitiesConfig {
+        LostCitiesConfig::new(*metadata.time_control(), *metadata.rule_set())
+    }
+
+    fn setup(&self, game_id: String, config: &LostCitiesConfig, first_seat: usize) -> Result<StorageGameState, BackendGameError> {
+        let rule_set = *config.rule_set();
+        let (mut deck, seed) = DeckFactory::new(&rule_set).new_shuffled_deck();
+        println!("INFO: Seeding RNG with '{}' to shuffle deck for game '{}'", seed, game_id);
+
+        // The rule set was validated when the game was hosted.
+        let hands = deck::deal(&mut deck, &rule_set)
+            .ok_or(BackendGameError::Internal(Cause::Impossible))?;
+        let num_seats = hands.len();
+
+        let mut game_state = StorageGameState::new(
+            game_id,
+            hands,
+            vec![HashMap::new(); num_seats],
+            HashMap::new(),
+            deck,
+            first_seat,
+        );
+        let now_ms = turn_clock::now_ms();
+        if let Some(time_control) = config.time_control() {
+            game_state.set_clock(turn_clock::start_clock(*time_control, num_seats, now_ms));
+        }
+        game_state.set_start_time_ms(now_ms);
+        game_state.set_rule_set(rule_set);
+        game_state.set_seed(seed);
+
+        Ok(game_state)
+    }
+
+    fn apply_action(&self, state: StorageGameState, seat: usize, action: Action) -> Result<StorageGameState, BackendGameError> {
+        apply_action_to_game_state(action, state, seat)
+    }
+
+    fn player_view(&self, state: &StorageGameState, seat: usize) -> GameState {
+        convert_game_state(state, seat)
+    }
+
+    fn status(&self, state: &StorageGameState, seat: usize) -> GameStatus {
+        let game_board = convert_game_board(state, seat);
+        get_game_status(state, &game_board, seat)
+    }
+
+    fn is_terminal(&self, state: &StorageGameState) -> bool {
+        is_game_over(state)
+    }
+
+    fn turn(&self, state: &StorageGameState) -> usize {
+        *state.turn()
+    }
+
+    fn clock_mut<'a>(&self, state: &'a mut StorageGameState) -> Option<&'a mut StorageClock> {
+        state.clock_mut().as_mut()
+    }
+
+    fn set_end_time_ms(&self, state: &mut StorageGameState, end_time_ms: u64) {
+        state.set_end_time_ms(end_time_ms);
+    }
+
+    fn write_record(&self, state: &StorageGameState, player_ids: &[String]) -> Result<String, BackendGameError> {
+        write_notation(state, player_ids)
+    }
+}
+
+// Expensive cloning incoming... :P
+fn convert_game_state(storage_game_state: &StorageGameState, seat: usize) -> GameState {
+    let game_board = convert_game_board(storage_game_state, seat);
+
+    let my_hand = &storage_game_state.hands()[seat];
+    let my_previous_plays = &storage_game_state.plays()[seat];
+    let game_status = get_game_status(storage_game_state, &game_board, seat);
+    let clock = storage_game_state.clock()
+        .as_ref()
+        .map(|clock| turn_clock::convert_clock(clock, seat, *storage_game_state.turn(), turn_clock::now_ms()));
+
+    let draw_offer = storage_game_state.draw_offer()
+        .map(|offered_by| if offered_by == seat {
+            DrawOffer::OfferedByMe
+        } else {
+            DrawOffer::OfferedByOpponent
+        });
+
+    let take_back_request = storage_game_state.take_back_request()
+        .map(|requested_by| if requested_by == seat {
+            TakeBackRequest::RequestedByMe
+        } else {
+            TakeBackRequest::RequestedByOpponent
+        });
+
+    let game_state = GameState::new(
+        game_board,
+        plays::decorate_hand(my_hand.to_owned(), my_previous_plays),
+        game_status,
+        clock,
+        draw_offer,
+        take_back_request,
+        unseen_cards::unseen_cards(storage_game_state, seat),
+    ).with_rule_set(*storage_game_state.rule_set());
+
+    let game_state = match convert_last_move(storage_game_state, seat) {
+        Some(last_move) => game_state.with_last_move(last_move),
+        None => game_state,
+    };
+
+    match game_status {
+        GameStatus::Complete(_, end_reason) => game_state.with_summary(convert_game_summary(storage_game_state, seat, end_reason)),
+        GameStatus::InProgress(_) => game_state,
+    }
+}
+
+/// Every hand is revealed once the game is over. The players go in the same order as the board:
+/// me first, then the opponents in turn order.
+fn convert_game_summary(storage_game_state: &StorageGameState, seat: usize, end_reason: EndReason) -> GameSummary {
+    let num_seats = storage_game_state.num_seats();
+    let players = (0..num_seats)
+        .map(|offset| {
+            let player_seat = (seat + offset) % num_seats;
+            let mut final_hand = storage_game_state.hands()[player_seat].to_owned();
+            final_hand.sort();
+            let (score_total, score_per_color) = scoring::compute_score(&storage_game_state.plays()[player_seat], storage_game_state.rule_set());
+            PlayerSummary::new(final_hand, score_total, score_per_color)
+        })
+        .collect();
+
+    let duration_ms = match (storage_game_state.start_time_ms(), storage_game_state.end_time_ms()) {
+        (Some(start_time_ms), Some(end_time_ms)) => Some(end_time_ms.saturating_sub(*start_time_ms)),
+        _ => None,
+    };
+
+    GameSummary::new(players, storage_game_state.move_history().len(), duration_ms, end_reason)
+}
+
+/// The most recent turn, from this seat's side of the table.
+fn convert_last_move(storage_game_state: &StorageGameState, seat: usize) -> Option<LastMove> {
+    let storage_move = storage_game_state.move_history().last()?;
+
+    let num_seats = storage_game_state.num_seats();
+    let opponent_index = match (storage_move.seat() + num_seats - seat) % num_seats {
+        0 => None,
+        seat_offset => Some(seat_offset - 1),
+    };
+    let draw = match (storage_move.draw_pile(), storage_move.drawn_card()) {
+        (_, None) => LastDraw::Nothing,
+        (DrawPile::Main, Some(_)) => LastDraw::MainPile,
+        (DrawPile::Neutral(_), Some(drawn_card)) => LastDraw::DiscardPile(*drawn_card),
+    };
+
+    Some(LastMove::new(opponent_index, *storage_move.card(), *storage_move.target(), draw))
+}
+
+/// Whether the deck ran out, someone resigned or ran out of time, or a draw was agreed.
+fn is_game_over(storage_game_state: &StorageGameState) -> bool {
+    let is_timed_out = match storage_game_state.clock() {
+        Some(clock) => clock.timed_out().is_some(),
+        None => false,
+    };
+
+    is_deck_exhausted(storage_game_state)
+        || storage_game_state.early_end().is_some()
+        || is_timed_out
+}
+
+/// The game played out to the end, final turn included if there is one.
+fn is_deck_exhausted(storage_game_state: &StorageGameState) -> bool {
+    let hand_sizes: Vec<usize> = storage_game_state.hands()
+        .iter()
+        .map(|hand| hand.len())
+        .collect();
+
+    endgame::is_game_over(
+        storage_game_state.rule_set(),
+        storage_game_state.main_draw_pile().len(),
+        &hand_sizes,
+        *storage_game_state.turn(),
+    )
+}
+
+pub(crate) fn convert_game_board(storage_game_state: &StorageGameState, seat: usize) -> GameBoard {
+    let concealed_neutral_draw_pile = conceal_neutral_draw_pile(storage_game_state.neutral_draw_pile());
+
+    // Everyone's expeditions are face up. The opponents go in turn order, starting with whoever
+    // plays after me.
+    let num_seats = storage_game_state.num_seats();
+    let my_plays = &storage_game_state.plays()[seat];
+    let mut opponents: Vec<OpponentBoard> = (1..num_seats)
+        .map(|offset| {
+            let op_plays = &storage_game_state.plays()[(seat + offset) % num_seats];
+            let (op_score_total, op_score_per_color) = scoring::compute_score(op_plays, storage_game_state.rule_set());
+            OpponentBoard::new(op_plays.to_owned(), op_score_total, op_score_per_color)
+        })
+        .collect();
+    let more_opponents = opponents.split_off(1);
+    let next_opponent = opponents.remove(0);
+
+    let (my_score_total, my_score_per_color) = scoring::compute_score(my_plays, storage_game_state.rule_set());
+
+    GameBoard::new(
+        my_plays.to_owned(),
+        next_opponent.plays().to_owned(),
+        my_score_total,
+        *next_opponent.score_total(),
+        my_score_per_color,
+        next_opponent.score_per_color().to_owned(),
+        concealed_neutral_draw_pile,
+        storage_game_state.main_draw_pile().len(),
+    ).with_more_opponents(more_opponents)
+}
+
+/// Here is where we only show what the player is allowed to see: the top card of each pile.
+pub(crate) fn conceal_neutral_draw_pile(neutral_draw_pile: &HashMap<CardColor, Vec<CardValue>>) -> HashMap<CardColor, (CardValue, usize)> {
+    let mut concealed_neutral_draw_pile = HashMap::new();
+    for (color, value_vec) in neutral_draw_pile.iter() {
+        if let Some(top_card) = value_vec.last() {
+            concealed_neutral_draw_pile.insert(*color, (*top_card, value_vec.len()));
+        }
+    }
+
+    concealed_neutral_draw_pile
+}
+
+/// Resigning, agreeing to a draw, or running out of time ends the game right away, regardless of
+/// what's on the board. Whoever resigned or ran out of time loses, and everyone else wins.
+pub(crate) fn get_game_status(storage_game_state: &StorageGameState, game_board: &GameBoard, seat: usize) -> GameStatus {
+    let lost_or_won = |loser_seat: usize| if loser_seat == seat {
+        GameResult::Lose
+    } else {
+        GameResult::Win
+    };
+
+    match storage_game_state.early_end() {
+        Some(StorageEarlyEnd::Resigned(resigned_seat)) => return GameStatus::Complete(lost_or_won(*resigned_seat), EndReason::Resignation),
+        Some(StorageEarlyEnd::DrawAgreed) => return GameStatus::Complete(GameResult::Draw, EndReason::DrawAgreed),
+        None => {},
+    }
+
+    let timed_out = storage_game_state.clock()
+        .as_ref()
+        .and_then(|clock| *clock.timed_out());
+    if let Some(timed_out_seat) = timed_out {
+        return GameStatus::Complete(lost_or_won(timed_out_seat), EndReason::Timeout);
+    }
+
+    let is_my_turn = seat == *storage_game_state.turn();
+    endgame::get_game_status(game_board, is_my_turn, is_deck_exhausted(storage_game_state))
+}
+
+fn apply_action_to_game_state(
+    action: Action,
+    mut storage_game_state: StorageGameState,
+    seat: usize
+) -> Result<StorageGameState, BackendGameError> {
+
+    // RULE: You can only play on your turn. The rules engine always acts for whoever's turn it is,
+    // so this is the one rule we check ourselves.
+    if *storage_game_state.turn() != seat {
+        return Err(BackendGameError::InvalidPlay(Reason::NotYourTurn));
+    }
+
+    let rules_state = to_rules_state(&storage_game_state);
+    let card_in_hand_index = engine::validate(&rules_state, &action)
+        .map_err(|e| BackendGameError::InvalidPlay(Reason::from_engine(e, &action)))?;
+    let is_final_turn = engine::is_final_turn(&rules_state);
+    let rules_state = engine::apply(rules_state, &action)
+        .map_err(|e| BackendGameError::InvalidPlay(Reason::from_engine(e, &action)))?;
+
+    // The engine always puts the drawn card at the end of the hand. Nothing's drawn on the final turn.
+    let my_hand = &rules_state.hands()[seat];
+    let new_card = if is_final_turn {
+        None
+    } else {
+        Some(*my_hand.last().ok_or(BackendGameError::Internal(Cause::Impossible))?)
+    };
+
+    update_from_rules_state(&mut storage_game_state, &rules_state);
+
+    // Remember exactly what happened, in case it's taken back
+    storage_game_state.move_history_mut().push(StorageMove::new(
+        seat,
+        card_in_hand_index,
+        *action.card(),
+        *action.target(),
+        *action.draw_pile(),
+        new_card,
+    ));
+
+    Ok(storage_game_state)
+}
+
+/// The game in text notation, checked against the rules engine. See `rules::notation`.
+pub(crate) fn write_notation(storage_game_state: &StorageGameState, player_ids: &[String]) -> Result<String, BackendGameError> {
+    let seed = storage_game_state.seed()
+        .ok_or(BackendGameError::Internal(Cause::Internal("The deck wasn't shuffled from a seed, so the game can't be replayed.")))?;
+
+    let timed_out = storage_game_state.clock()
+        .as_ref()
+        .and_then(|clock| *clock.timed_out());
+    let result = match (storage_game_state.early_end(), timed_out) {
+        (Some(StorageEarlyEnd::Resigned(seat)), _) => RecordedResult::Resigned(*seat),
+        (Some(StorageEarlyEnd::DrawAgreed), _) => RecordedResult::DrawAgreed,
+        (None, Some(seat)) => RecordedResult::TimedOut(seat),
+        (None, None) if is_deck_exhausted(storage_game_state) => RecordedResult::Scores(notation::final_scores(&to_rules_state(storage_game_state))),
+        (None, None) => RecordedResult::Unfinished,
+    };
+
+    let actions = storage_game_state.move_history()
+        .iter()
+        .map(|storage_move| Action::new(*storage_move.card(), *storage_move.target(), *storage_move.draw_pile()))
+        .collect();
+
+    let record = GameRecord::new(
+        player_ids.to_owned(),
+        seed,
+        *storage_game_state.first_turn(),
+        *storage_game_state.rule_set(),
+        result,
+        actions,
+    );
+
+    // Anything the engine rejects now was let through when it was played.
+    notation::write(&record)
+        .map_err(|e| {
+            println!("WARN: Game '{}' doesn't replay: {}", storage_game_state.game_id(), e);
+            BackendGameError::Internal(Cause::Internal("The game doesn't replay by the rules."))
+        })
+}
+
+// More expensive cloning. The engine doesn't know about IDs, clocks, or history, so it gets its own copy of the cards.
+pub(crate) fn to_rules_state(storage_game_state: &StorageGameState) -> RulesState {
+    RulesState::new(
+        storage_game_state.hands().to_owned(),
+        storage_game_state.plays().to_owned(),
+        storage_game_state.neutral_draw_pile().to_owned(),
+        storage_game_state.main_draw_pile().to_owned(),
+        *storage_game_state.turn(),
+    ).with_rule_set(*storage_game_state.rule_set())
+}
+
+fn update_from_rules_state(storage_game_state: &mut StorageGameState, rules_state: &RulesState) {
+    *storage_game_state.hands_mut() = rules_state.hands().to_owned();
+    *storage_game_state.plays_mut() = rules_state.plays().to_owned();
+    *storage_game_state.neutral_draw_pile_mut() = rules_state.neutral_draw_pile().to_owned();
+    *storage_game_state.main_draw_pile_mut() = rules_state.main_draw_pile().to_owned();
+    storage_game_state.set_turn(*rules_state.turn());
+}
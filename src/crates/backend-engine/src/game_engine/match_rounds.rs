@@ -0,0 +1,34 @@
+/// Separates the match id from the round number in a round's game id. Game ids generated by the
+/// client are hex, so this won't clash with them.
+const ROUND_SEPARATOR: char = '#';
+
+/// Each round of a match is a regular game, with a game id derived from the match id.
+pub fn round_game_id(match_id: &str, round_number: usize) -> String {
+    format!("{}{}{}", match_id, ROUND_SEPARATOR, round_number)
+}
+
+/// The key to route a game or match request by. All rounds of a match must be handled by the
+/// same backend task as the match itself, since dealing the next round happens as part of
+/// playing the last card of the previous one.
+pub fn routing_key(game_or_match_id: &str) -> String {
+    game_or_match_id
+        .split(ROUND_SEPARATOR)
+        .next()
+        .unwrap_or(game_or_match_id)
+        .to_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rounds_route_to_match() {
+        let match_id = "abc123";
+
+        assert_eq!(routing_key(match_id), match_id);
+        assert_eq!(routing_key(&round_game_id(match_id, 1)), match_id);
+        assert_eq!(routing_key(&round_game_id(match_id, 3)), match_id);
+        assert_ne!(round_game_id(match_id, 1), round_game_id(match_id, 2));
+    }
+}
@@ -1 +1,7 @@
+pub mod analysis;
 pub mod backend_game_api;
+pub mod lost_cities;
+pub mod match_rounds;
+pub mod turn_clock;
+pub mod unseen_cards;
+pub mod take_back;
@@ -0,0 +1,145 @@
+use crate::backend_error::{BackendGameError, Cause, Reason};
+use game_api::types::{CardTarget, DrawPile};
+use storage::storage_types::StorageGameState;
+
+/// Undo the most recent move exactly as it happened: the drawn card goes back on top of the pile
+/// it came from, the played card goes back to the same spot in the mover's hand, and it's the
+/// mover's turn again.
+pub fn take_back_last_move(mut storage_game_state: StorageGameState) -> Result<StorageGameState, BackendGameError> {
+    let last_move = storage_game_state.move_history_mut()
+        .pop()
+        .ok_or(BackendGameError::InvalidPlay(Reason::NoMoveToTakeBack))?;
+
+    let mut pa_sgs = storage_game_state.convert_to_player_aware(*last_move.seat());
+
+    // 1. Put the drawn card back. It's always the last card in hand, since drawing pushes it.
+    // Nothing was drawn on a final turn.
+    if let Some(drawn_card) = *last_move.drawn_card() {
+        if pa_sgs.my_hand_mut().pop() != Some(drawn_card) {
+            return Err(BackendGameError::Internal(Cause::Impossible));
+        }
+        match last_move.draw_pile() {
+            DrawPile::Main => pa_sgs.main_draw_pile_mut().push(drawn_card),
+            DrawPile::Neutral(color) => pa_sgs.neutral_draw_pile_mut()
+                .entry(*color)
+                .or_default()
+                .push(*drawn_card.card_value()),
+        }
+    }
+
+    // 2. Pick the played card back up
+    let target_pile = match last_move.target() {
+        CardTarget::Player => pa_sgs.my_plays_mut(),
+        CardTarget::Neutral => pa_sgs.neutral_draw_pile_mut(),
+    };
+    let played_value = target_pile.get_mut(last_move.card().card_color())
+        .and_then(|pile| pile.pop());
+    if played_value != Some(*last_move.card().card_value()) {
+        return Err(BackendGameError::Internal(Cause::Impossible));
+    }
+
+    // 3. Back into the hand, where it was
+    let hand = pa_sgs.my_hand_mut();
+    if *last_move.hand_index() > hand.len() {
+        return Err(BackendGameError::Internal(Cause::Impossible));
+    }
+    hand.insert(*last_move.hand_index(), *last_move.card());
+
+    // 4. Give the turn back to the mover
+    let mut sgs = pa_sgs.convert_to_inner();
+    sgs.set_turn(*last_move.seat());
+
+    Ok(sgs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use game_api::types::{Card, CardColor, CardValue};
+    use std::collections::HashMap;
+    use std::convert::TryFrom;
+    use storage::storage_types::StorageMove;
+
+    fn card(color: CardColor, value: u32) -> Card {
+        Card::new(color, CardValue::try_from(value).unwrap())
+    }
+
+    #[test]
+    fn reverts_discard_and_neutral_draw() {
+        // Before the move: p1 holds R3 and G7, and discards G7, then draws the Y5 from the yellow
+        // discard pile. Afterwards, it's p2's turn.
+        let mut neutral_draw_pile = HashMap::new();
+        neutral_draw_pile.insert(CardColor::Green, vec![CardValue::try_from(7).unwrap()]);
+        let mut sgs = StorageGameState::new(
+            "game".to_owned(),
+            vec![
+                vec![card(CardColor::Red, 3), card(CardColor::Yellow, 5)],
+                vec![card(CardColor::Blue, 2)],
+            ],
+            vec![HashMap::new(), HashMap::new()],
+            neutral_draw_pile,
+            vec![card(CardColor::White, 4)],
+            1,
+        );
+        sgs.move_history_mut().push(StorageMove::new(
+            0,
+            1,
+            card(CardColor::Green, 7),
+            CardTarget::Neutral,
+            DrawPile::Neutral(CardColor::Yellow),
+            Some(card(CardColor::Yellow, 5)),
+        ));
+
+        let sgs = take_back_last_move(sgs).unwrap();
+
+        assert_eq!(sgs.hands()[0], vec![card(CardColor::Red, 3), card(CardColor::Green, 7)]);
+        assert_eq!(sgs.neutral_draw_pile().get(&CardColor::Green), Some(&Vec::new()));
+        assert_eq!(sgs.neutral_draw_pile().get(&CardColor::Yellow), Some(&vec![CardValue::try_from(5).unwrap()]));
+        assert_eq!(*sgs.main_draw_pile(), vec![card(CardColor::White, 4)]);
+        assert_eq!(*sgs.turn(), 0);
+        assert!(sgs.move_history().is_empty());
+    }
+
+    #[test]
+    fn reverts_play_and_main_draw() {
+        let mut p2_plays = HashMap::new();
+        p2_plays.insert(CardColor::Blue, vec![CardValue::try_from(2).unwrap()]);
+        let mut sgs = StorageGameState::new(
+            "game".to_owned(),
+            vec![vec![card(CardColor::Red, 3)], vec![card(CardColor::White, 4)]],
+            vec![HashMap::new(), p2_plays],
+            HashMap::new(),
+            vec![],
+            0,
+        );
+        sgs.move_history_mut().push(StorageMove::new(
+            1,
+            0,
+            card(CardColor::Blue, 2),
+            CardTarget::Player,
+            DrawPile::Main,
+            Some(card(CardColor::White, 4)),
+        ));
+
+        let sgs = take_back_last_move(sgs).unwrap();
+
+        assert_eq!(sgs.hands()[1], vec![card(CardColor::Blue, 2)]);
+        assert_eq!(sgs.plays()[1].get(&CardColor::Blue), Some(&Vec::new()));
+        assert_eq!(*sgs.main_draw_pile(), vec![card(CardColor::White, 4)]);
+        assert_eq!(*sgs.turn(), 1);
+    }
+
+    #[test]
+    fn nothing_to_take_back() {
+        let sgs = StorageGameState::new(
+            "game".to_owned(),
+            vec![vec![], vec![]],
+            vec![HashMap::new(), HashMap::new()],
+            HashMap::new(),
+            vec![],
+            0,
+        );
+
+        assert!(take_back_last_move(sgs).is_err());
+    }
+}
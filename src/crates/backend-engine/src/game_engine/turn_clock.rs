@@ -0,0 +1,147 @@
+use game_api::types::{TimeControl, GameClock};
+use std::time::{SystemTime, UNIX_EPOCH};
+use storage::storage_types::StorageClock;
+
+pub fn now_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_millis() as u64)
+        .unwrap_or(0)
+}
+
+/// Every player starts with a full clock. Whoever's turn it is, it doesn't matter, the clock
+/// starts now.
+pub fn start_clock(time_control: TimeControl, num_seats: usize, now_ms: u64) -> StorageClock {
+    let initial_ms = initial_remaining_ms(&time_control);
+
+    StorageClock::new(time_control, vec![initial_ms; num_seats], now_ms)
+}
+
+fn initial_remaining_ms(time_control: &TimeControl) -> u64 {
+    match time_control {
+        TimeControl::PerMove(seconds) => *seconds as u64 * 1000,
+        TimeControl::TimeBank(seconds) => *seconds as u64 * 1000,
+    }
+}
+
+/// How much time the player has left right now. Only the current player's clock is running.
+pub fn remaining_ms(clock: &StorageClock, seat: usize, turn: usize, now_ms: u64) -> u64 {
+    let remaining_ms = *clock.remaining_ms(seat);
+    if seat != turn {
+        return remaining_ms;
+    }
+
+    let elapsed_ms = now_ms.saturating_sub(*clock.turn_start_ms());
+    remaining_ms.saturating_sub(elapsed_ms)
+}
+
+/// Flags the current player if they're out of time. Returns true if this call flagged them.
+pub fn check_timeout(clock: &mut StorageClock, turn: usize, now_ms: u64) -> bool {
+    if clock.timed_out().is_some() {
+        return false;
+    }
+
+    if remaining_ms(clock, turn, turn, now_ms) == 0 {
+        clock.set_timed_out(turn);
+        true
+    } else {
+        false
+    }
+}
+
+/// Stop the mover's clock and start the next player's. Caller should have called
+/// `check_timeout()` first.
+pub fn record_move(clock: &mut StorageClock, mover_seat: usize, now_ms: u64) {
+    let remaining_ms = match clock.time_control() {
+        TimeControl::PerMove(_) => initial_remaining_ms(clock.time_control()),
+        TimeControl::TimeBank(_) => remaining_ms(clock, mover_seat, mover_seat, now_ms),
+    };
+
+    *clock.remaining_ms_mut(mover_seat) = remaining_ms;
+    clock.set_turn_start_ms(now_ms);
+}
+
+/// The clock from the requesting player's perspective. With more than one opponent, the one that
+/// matters is whoever's on the move, or whoever's next if it's my move.
+pub fn convert_clock(clock: &StorageClock, seat: usize, turn: usize, now_ms: u64) -> GameClock {
+    let op_seat = if turn != seat {
+        turn
+    } else {
+        (seat + 1) % clock.num_seats()
+    };
+
+    GameClock::new(
+        *clock.time_control(),
+        remaining_ms(clock, seat, turn, now_ms),
+        remaining_ms(clock, op_seat, turn, now_ms),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_current_players_clock_runs() {
+        let clock = start_clock(TimeControl::TimeBank(60), 2, 1_000);
+
+        assert_eq!(remaining_ms(&clock, 0, 0, 11_000), 50_000);
+        assert_eq!(remaining_ms(&clock, 1, 0, 11_000), 60_000);
+        assert_eq!(remaining_ms(&clock, 0, 0, 100_000), 0);
+    }
+
+    #[test]
+    fn time_bank_is_spent_across_moves() {
+        let mut clock = start_clock(TimeControl::TimeBank(60), 2, 0);
+
+        record_move(&mut clock, 0, 10_000);
+        assert_eq!(*clock.remaining_ms(0), 50_000);
+        assert_eq!(*clock.turn_start_ms(), 10_000);
+
+        record_move(&mut clock, 1, 15_000);
+        assert_eq!(*clock.remaining_ms(1), 55_000);
+
+        record_move(&mut clock, 0, 25_000);
+        assert_eq!(*clock.remaining_ms(0), 40_000);
+    }
+
+    #[test]
+    fn per_move_clock_resets_after_each_move() {
+        let mut clock = start_clock(TimeControl::PerMove(30), 2, 0);
+
+        record_move(&mut clock, 0, 20_000);
+        assert_eq!(*clock.remaining_ms(0), 30_000);
+        assert_eq!(remaining_ms(&clock, 1, 1, 45_000), 5_000);
+    }
+
+    #[test]
+    fn timeout_flags_current_player_once() {
+        let mut clock = start_clock(TimeControl::PerMove(30), 2, 0);
+
+        assert!(!check_timeout(&mut clock, 1, 29_999));
+        assert_eq!(*clock.timed_out(), None);
+
+        assert!(check_timeout(&mut clock, 1, 30_000));
+        assert_eq!(*clock.timed_out(), Some(1));
+
+        assert!(!check_timeout(&mut clock, 1, 40_000));
+        assert_eq!(*clock.timed_out(), Some(1));
+    }
+
+    #[test]
+    fn opponent_clock_is_whoever_is_on_the_move() {
+        let mut clock = start_clock(TimeControl::TimeBank(60), 3, 0);
+        record_move(&mut clock, 0, 10_000);
+        record_move(&mut clock, 1, 30_000);
+
+        // My move, so the next seat's clock is shown.
+        let game_clock = convert_clock(&clock, 2, 2, 35_000);
+        assert_eq!(*game_clock.my_remaining_ms(), 55_000);
+        assert_eq!(*game_clock.op_remaining_ms(), 50_000);
+
+        // Seat 2's move, so theirs is shown, and it's running.
+        let game_clock = convert_clock(&clock, 1, 2, 35_000);
+        assert_eq!(*game_clock.my_remaining_ms(), 40_000);
+        assert_eq!(*game_clock.op_remaining_ms(), 55_000);
+    }
+}
@@ -0,0 +1,150 @@
+use game_api::types::{Card, DrawPile};
+use storage::storage_types::StorageGameState;
+
+/// Every card the player hasn't seen, or has seen but lost track of, sorted by color then value.
+///
+/// Discards are face up, so everything in a neutral pile has been seen, not just the top card.
+/// Likewise, a card an opponent picked up from a neutral pile is known to be in their hand until
+/// they play or discard it. That leaves the main draw pile and the rest of the opponents' hands.
+pub fn unseen_cards(storage_game_state: &StorageGameState, seat: usize) -> Vec<Card> {
+    // Cards each opponent picked up from a neutral pile and still holds, by seat.
+    let mut known_cards: Vec<Vec<Card>> = vec![Vec::new(); storage_game_state.num_seats()];
+    for storage_move in storage_game_state.move_history().iter() {
+        if *storage_move.seat() == seat {
+            continue;
+        }
+
+        let op_known_cards = &mut known_cards[*storage_move.seat()];
+        if let Some(i) = op_known_cards.iter().position(|card| card == storage_move.card()) {
+            op_known_cards.remove(i);
+        }
+        if let (DrawPile::Neutral(_), Some(drawn_card)) = (storage_move.draw_pile(), storage_move.drawn_card()) {
+            op_known_cards.push(*drawn_card);
+        }
+    }
+
+    let mut unseen: Vec<Card> = storage_game_state.main_draw_pile().clone();
+    for (op_seat, op_hand) in storage_game_state.hands().iter().enumerate() {
+        if op_seat == seat {
+            continue;
+        }
+
+        let op_known_cards = &mut known_cards[op_seat];
+        for card in op_hand.iter() {
+            match op_known_cards.iter().position(|known| known == card) {
+                Some(i) => { op_known_cards.remove(i); },
+                None => unseen.push(*card),
+            }
+        }
+    }
+
+    unseen.sort();
+    unseen
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use game_api::types::{CardColor, CardTarget, CardValue};
+    use std::collections::HashMap;
+    use std::convert::TryFrom;
+    use storage::storage_types::StorageMove;
+
+    fn card(color: CardColor, value: u32) -> Card {
+        Card::new(color, CardValue::try_from(value).unwrap())
+    }
+
+    #[test]
+    fn draw_pile_and_opponent_hand() {
+        // The buried G4 in the neutral pile was seen when it was discarded.
+        let mut neutral_draw_pile = HashMap::new();
+        neutral_draw_pile.insert(CardColor::Green, vec![CardValue::Four, CardValue::Nine]);
+        let sgs = StorageGameState::new(
+            "game".to_owned(),
+            vec![
+                vec![card(CardColor::Red, 3)],
+                vec![card(CardColor::White, 4), card(CardColor::Blue, 2)],
+            ],
+            vec![HashMap::new(), HashMap::new()],
+            neutral_draw_pile,
+            vec![card(CardColor::Yellow, 10), card(CardColor::Red, 1)],
+            0,
+        );
+
+        assert_eq!(unseen_cards(&sgs, 0), vec![
+            card(CardColor::Red, 1),
+            card(CardColor::White, 4),
+            card(CardColor::Blue, 2),
+            card(CardColor::Yellow, 10),
+        ]);
+        assert_eq!(unseen_cards(&sgs, 1), vec![
+            card(CardColor::Red, 1),
+            card(CardColor::Red, 3),
+            card(CardColor::Yellow, 10),
+        ]);
+    }
+
+    #[test]
+    fn remembers_what_the_opponent_picked_up() {
+        let mut sgs = StorageGameState::new(
+            "game".to_owned(),
+            vec![
+                vec![card(CardColor::Red, 3)],
+                vec![card(CardColor::White, 4), card(CardColor::Blue, 2)],
+            ],
+            vec![HashMap::new(), HashMap::new()],
+            HashMap::new(),
+            vec![],
+            0,
+        );
+        // p2 picked up the W4 from the neutral pile, and the B5, which they've since discarded.
+        sgs.move_history_mut().push(StorageMove::new(
+            1,
+            0,
+            card(CardColor::Green, 2),
+            CardTarget::Player,
+            DrawPile::Neutral(CardColor::White),
+            Some(card(CardColor::White, 4)),
+        ));
+        sgs.move_history_mut().push(StorageMove::new(
+            1,
+            0,
+            card(CardColor::Green, 3),
+            CardTarget::Player,
+            DrawPile::Neutral(CardColor::Blue),
+            Some(card(CardColor::Blue, 5)),
+        ));
+        sgs.move_history_mut().push(StorageMove::new(
+            1,
+            0,
+            card(CardColor::Blue, 5),
+            CardTarget::Neutral,
+            DrawPile::Main,
+            Some(card(CardColor::Blue, 2)),
+        ));
+
+        assert_eq!(unseen_cards(&sgs, 0), vec![card(CardColor::Blue, 2)]);
+    }
+
+    #[test]
+    fn every_opponent_hand_is_unseen() {
+        let sgs = StorageGameState::new(
+            "game".to_owned(),
+            vec![
+                vec![card(CardColor::Red, 3)],
+                vec![card(CardColor::White, 4)],
+                vec![card(CardColor::Blue, 2)],
+            ],
+            vec![HashMap::new(), HashMap::new(), HashMap::new()],
+            HashMap::new(),
+            vec![card(CardColor::Yellow, 10)],
+            0,
+        );
+
+        assert_eq!(unseen_cards(&sgs, 1), vec![
+            card(CardColor::Red, 3),
+            card(CardColor::Blue, 2),
+            card(CardColor::Yellow, 10),
+        ]);
+    }
+}
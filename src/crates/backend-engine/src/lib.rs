@@ -1,7 +1,9 @@
 use std::error::Error;
+use storage::v2::config::DatabaseMode;
 
 pub mod backend_error;
 pub mod game_api;
+pub mod platform;
 
 mod cache_slots;
 mod game_engine;
@@ -12,20 +14,656 @@ pub fn start_backend() -> Result<
     Box<dyn game_api::GameApi2Immut + Send + Sync>,
     Box<dyn Error>
 > {
-    Ok(Box::new(cache_slots::slotted_backend::spawn_slotted_backend()?))
+    start_backend_with_database(DatabaseMode::Prod)
+}
+
+/// Same as `start_backend`, but against the given database, e.g. a throwaway one for tests.
+pub fn start_backend_with_database(database_mode: DatabaseMode) -> Result<
+    Box<dyn game_api::GameApi2Immut + Send + Sync>,
+    Box<dyn Error>
+> {
+    Ok(Box::new(cache_slots::slotted_backend::spawn_slotted_backend(database_mode)?))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use bot::Strategy;
+    use bot::heuristic::HeuristicStrategy;
+    use std::time::Duration;
+    use tokio::time;
+    use ::game_api::types::{Opponent, Difficulty, MatchStatus, GameStatus, GameMetadata, SpectatorStatus, TimeControl, GameResult, EndReason, DrawOffer, TakeBackRequest, Play, CardTarget, DrawPile, RuleSet, CardColor, LastMove, LastDraw, Card};
+    use backend_error::{BackendGameError, Reason};
+    use rules::notation::{self, RecordedResult};
+    use storage::test_utils::{TestFileHandle, rand_str};
+
+    /// Finished games get archived, so every test gets its own database. Otherwise fixed game IDs
+    /// would collide with the last run's.
+    fn start_test_backend() -> Result<(TestFileHandle, Box<dyn game_api::GameApi2Immut + Send + Sync>), Box<dyn Error>> {
+        let db_file = TestFileHandle::new(format!("./safe-to-delete.test-{}.db", rand_str()));
+        let client = start_backend_with_database(DatabaseMode::Test(db_file.file_path.clone()))?;
+        Ok((db_file, client))
+    }
 
     #[tokio::test]
     async fn hello() -> Result<(), Box<dyn Error>> {
-        let client = start_backend()?;
+        let (_db_file, client) = start_test_backend()?;
         let game_id = "game";
 
-        client.host_game(game_id.to_owned(), "mememe".to_owned()).await?;
+        client.host_game(game_id.to_owned(), "mememe".to_owned(), None, Opponent::Human, RuleSet::standard()).await?;
+        client.join_game(game_id.to_owned(), "youyou".to_owned()).await?;
+
+        Ok(())
+    }
+    #[tokio::test]
+    async fn lobby_shows_whose_turn_it_is() -> Result<(), Box<dyn Error>> {
+        let (_db_file, client) = start_test_backend()?;
+        let game_id = "lobby-turn";
+
+        client.host_game(game_id.to_owned(), "mememe".to_owned(), None, Opponent::Human, RuleSet::standard()).await?;
+        client.join_game(game_id.to_owned(), "youyou".to_owned()).await?;
+
+        // Listed from the host's perspective.
+        let host_status = *client.get_game_state(game_id.to_owned(), "mememe".to_owned()).await?.status();
+        let listed_status = |game_metadata: &GameMetadata| game_metadata.matched_data().as_ref().map(|(_, status)| *status);
+        assert_eq!(listed_status(&client.describe_game(game_id.to_owned()).await?), Some(host_status));
+        let watchable_games = client.query_watchable_games("spectator".to_owned()).await?;
+        let watchable_game = watchable_games.iter().find(|game_metadata| game_metadata.game_id() == game_id).unwrap();
+        assert_eq!(listed_status(watchable_game), Some(host_status));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn spectators_see_every_seat() -> Result<(), Box<dyn Error>> {
+        let (_db_file, client) = start_test_backend()?;
+        let game_id = "spectated";
+
+        client.host_game(game_id.to_owned(), "mememe".to_owned(), None, Opponent::Human, RuleSet::standard()).await?;
+        client.join_game(game_id.to_owned(), "youyou".to_owned()).await?;
+
+        let game_state = client.get_spectator_game_state(game_id.to_owned(), "spectator".to_owned()).await?;
+        let player_ids: Vec<&str> = game_state.seats().iter().map(|seat| seat.player_id()).collect();
+        assert_eq!(player_ids, vec!["mememe", "youyou"]);
+
+        let host_turn = *client.get_game_state(game_id.to_owned(), "mememe".to_owned()).await?.status() == GameStatus::InProgress(true);
+        let expected_seat = if host_turn { 0 } else { 1 };
+        assert_eq!(*game_state.status(), SpectatorStatus::InProgress(expected_seat));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn match_deals_first_round_on_join() -> Result<(), Box<dyn Error>> {
+        let (_db_file, client) = start_test_backend()?;
+        let match_id = "match";
+
+        client.host_match(match_id.to_owned(), "mememe".to_owned(), 3, None, RuleSet::standard()).await?;
+        let match_state = client.get_match_state(match_id.to_owned(), "mememe".to_owned()).await?;
+        assert_eq!(*match_state.status(), MatchStatus::Unmatched);
+        assert!(match_state.rounds().is_empty());
+
+        client.join_match(match_id.to_owned(), "youyou".to_owned()).await?;
+        let match_state = client.get_match_state(match_id.to_owned(), "youyou".to_owned()).await?;
+        assert_eq!(*match_state.status(), MatchStatus::InProgress);
+        assert_eq!(match_state.op_player_id().as_deref(), Some("mememe"));
+        assert_eq!(match_state.rounds().len(), 1);
+
+        // The round is a regular game, which either player can load.
+        let round_game_id = match_state.current_round().unwrap().game_id().to_owned();
+        client.get_game_state(round_game_id.clone(), "mememe".to_owned()).await?;
+        client.get_game_state(round_game_id, "youyou".to_owned()).await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn every_round_of_a_match_uses_the_hosts_rules() -> Result<(), Box<dyn Error>> {
+        let (_db_file, client) = start_test_backend()?;
+        let match_id = "house-rules-match";
+
+        let unplayable = RuleSet::new(40, 20, 1, 8, 20, false, false);
+        match client.host_match(match_id.to_owned(), "mememe".to_owned(), 2, None, unplayable).await {
+            Err(BackendGameError::InvalidPlay(_)) => {},
+            other => panic!("Expected the rule set to be rejected, got {:?}", other),
+        }
+        let three_players = RuleSet::standard().with_num_players(3);
+        match client.host_match(match_id.to_owned(), "mememe".to_owned(), 2, None, three_players).await {
+            Err(BackendGameError::InvalidPlay(Reason::TwoPlayerOnly)) => {},
+            other => panic!("Expected a match to be two player only, got {:?}", other),
+        }
+
+        let house_rules = RuleSet::new(5, 15, 2, 6, 10, true, false);
+        client.host_match(match_id.to_owned(), "mememe".to_owned(), 2, Some(TimeControl::PerMove(60)), house_rules).await?;
+        client.join_match(match_id.to_owned(), "youyou".to_owned()).await?;
+
+        let round_1_game_id = client.get_match_state(match_id.to_owned(), "mememe".to_owned()).await?
+            .current_round().unwrap().game_id().to_owned();
+        let game_state = client.get_game_state(round_1_game_id.clone(), "mememe".to_owned()).await?;
+        assert_eq!(*game_state.rule_set(), house_rules);
+        assert_eq!(*game_state.clock().as_ref().unwrap().time_control(), TimeControl::PerMove(60));
+        client.resign(round_1_game_id.clone(), "youyou".to_owned()).await?;
+
+        let match_state = client.get_match_state(match_id.to_owned(), "mememe".to_owned()).await?;
+        assert_eq!(match_state.rounds().len(), 2);
+        let round_2_game_id = match_state.current_round().unwrap().game_id().to_owned();
+        assert_ne!(round_2_game_id, round_1_game_id);
+
+        let game_state = client.get_game_state(round_2_game_id, "mememe".to_owned()).await?;
+        assert_eq!(*game_state.rule_set(), house_rules);
+        assert_eq!(game_state.my_hand().len(), 5);
+        assert_eq!(*game_state.clock().as_ref().unwrap().time_control(), TimeControl::PerMove(60));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn resigning_ends_game_regardless_of_score() -> Result<(), Box<dyn Error>> {
+        let (_db_file, client) = start_test_backend()?;
+        let game_id = "resign";
+
+        client.host_game(game_id.to_owned(), "mememe".to_owned(), None, Opponent::Human, RuleSet::standard()).await?;
+        client.join_game(game_id.to_owned(), "youyou".to_owned()).await?;
+        let mut youyou_hand: Vec<Card> = client.get_game_state(game_id.to_owned(), "youyou".to_owned()).await?
+            .my_hand()
+            .iter()
+            .map(|decorated_card| *decorated_card.card())
+            .collect();
+        youyou_hand.sort();
+        assert_eq!(*client.get_game_state(game_id.to_owned(), "mememe".to_owned()).await?.summary(), None);
+        client.resign(game_id.to_owned(), "youyou".to_owned()).await?;
+
+        let game_state = client.get_game_state(game_id.to_owned(), "mememe".to_owned()).await?;
+        assert_eq!(*game_state.status(), GameStatus::Complete(GameResult::Win, EndReason::Resignation));
+        // The opponent's hand is revealed once it's over.
+        let summary = game_state.summary().as_ref().unwrap();
+        assert_eq!(*summary.end_reason(), EndReason::Resignation);
+        assert_eq!(*summary.num_turns(), 0);
+        assert!(summary.duration_ms().is_some());
+        assert_eq!(*summary.players()[1].final_hand(), youyou_hand);
+        let game_state = client.get_game_state(game_id.to_owned(), "youyou".to_owned()).await?;
+        assert_eq!(*game_state.status(), GameStatus::Complete(GameResult::Lose, EndReason::Resignation));
+        assert_eq!(*game_state.summary().as_ref().unwrap().players()[0].final_hand(), youyou_hand);
+        let game_state = client.get_spectator_game_state(game_id.to_owned(), "spectator".to_owned()).await?;
+        assert_eq!(*game_state.status(), SpectatorStatus::Complete(vec![0], EndReason::Resignation));
+
+        // Can't keep going after the game is over.
+        assert!(client.resign(game_id.to_owned(), "mememe".to_owned()).await.is_err());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn final_hands_stay_hidden_until_the_deck_runs_out() -> Result<(), Box<dyn Error>> {
+        let (_db_file, client) = start_test_backend()?;
+        let game_id = "hidden-hands";
+        let players = ["mememe", "youyou"];
+
+        client.host_game(game_id.to_owned(), players[0].to_owned(), None, Opponent::Human, RuleSet::standard()).await?;
+        client.join_game(game_id.to_owned(), players[1].to_owned()).await?;
+
+        let mut strategy = HeuristicStrategy;
+        'game: loop {
+            for player_id in players.iter() {
+                let game_state = client.get_game_state(game_id.to_owned(), (*player_id).to_owned()).await?;
+                match game_state.status() {
+                    GameStatus::InProgress(_) => assert_eq!(*game_state.summary(), None, "'{}' saw the summary early", player_id),
+                    GameStatus::Complete(_, _) => break 'game,
+                }
+                if *game_state.status() != GameStatus::InProgress(true) {
+                    continue;
+                }
+
+                let action = strategy.choose_action(&game_state).unwrap();
+                client.play_card(Play::new(
+                    game_id.to_owned(),
+                    (*player_id).to_owned(),
+                    *action.card(),
+                    *action.target(),
+                    *action.draw_pile(),
+                )).await?;
+            }
+        }
+
+        // Now everyone sees everyone's hand, me first.
+        let mememe_state = client.get_game_state(game_id.to_owned(), players[0].to_owned()).await?;
+        let youyou_state = client.get_game_state(game_id.to_owned(), players[1].to_owned()).await?;
+        let mut youyou_hand: Vec<Card> = youyou_state.my_hand().iter().map(|decorated_card| *decorated_card.card()).collect();
+        youyou_hand.sort();
+        let summary = mememe_state.summary().as_ref().unwrap();
+        assert_eq!(*summary.end_reason(), EndReason::DeckExhausted);
+        assert_eq!(*summary.players()[1].final_hand(), youyou_hand);
+        assert_eq!(*youyou_state.summary().as_ref().unwrap().players()[0].final_hand(), youyou_hand);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn draw_offer_must_be_accepted_by_opponent() -> Result<(), Box<dyn Error>> {
+        let (_db_file, client) = start_test_backend()?;
+        let game_id = "draw";
+
+        client.host_game(game_id.to_owned(), "mememe".to_owned(), None, Opponent::Human, RuleSet::standard()).await?;
+        client.join_game(game_id.to_owned(), "youyou".to_owned()).await?;
+        client.offer_draw(game_id.to_owned(), "mememe".to_owned()).await?;
+
+        let game_state = client.get_game_state(game_id.to_owned(), "youyou".to_owned()).await?;
+        assert_eq!(*game_state.draw_offer(), Some(DrawOffer::OfferedByOpponent));
+
+        // Only the opponent can respond.
+        assert!(client.respond_to_draw_offer(game_id.to_owned(), "mememe".to_owned(), true).await.is_err());
+
+        client.respond_to_draw_offer(game_id.to_owned(), "youyou".to_owned(), true).await?;
+        let game_state = client.get_game_state(game_id.to_owned(), "mememe".to_owned()).await?;
+        assert_eq!(*game_state.status(), GameStatus::Complete(GameResult::Draw, EndReason::DrawAgreed));
+        assert_eq!(*game_state.draw_offer(), None);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn take_back_restores_position_when_accepted() -> Result<(), Box<dyn Error>> {
+        let (_db_file, client) = start_test_backend()?;
+        let game_id = "take-back";
+
+        client.host_game(game_id.to_owned(), "mememe".to_owned(), None, Opponent::Human, RuleSet::standard()).await?;
+        client.join_game(game_id.to_owned(), "youyou".to_owned()).await?;
+
+        let (mover, other) = match client.get_game_state(game_id.to_owned(), "mememe".to_owned()).await?.status() {
+            GameStatus::InProgress(true) => ("mememe", "youyou"),
+            _ => ("youyou", "mememe"),
+        };
+        let before = client.get_game_state(game_id.to_owned(), mover.to_owned()).await?;
+
+        // Nothing to take back yet.
+        assert!(client.request_take_back(game_id.to_owned(), mover.to_owned()).await.is_err());
+
+        let card = *before.my_hand()[0].card();
+        client.play_card(Play::new(
+            game_id.to_owned(),
+            mover.to_owned(),
+            card,
+            CardTarget::Neutral,
+            DrawPile::Main,
+        )).await?;
+
+        // Only the player who just moved can ask, and only the opponent can answer.
+        assert!(client.request_take_back(game_id.to_owned(), other.to_owned()).await.is_err());
+        client.request_take_back(game_id.to_owned(), mover.to_owned()).await?;
+        let game_state = client.get_game_state(game_id.to_owned(), other.to_owned()).await?;
+        assert_eq!(*game_state.take_back_request(), Some(TakeBackRequest::RequestedByOpponent));
+        assert!(client.respond_to_take_back(game_id.to_owned(), mover.to_owned(), true).await.is_err());
+
+        client.respond_to_take_back(game_id.to_owned(), other.to_owned(), true).await?;
+        let after = client.get_game_state(game_id.to_owned(), mover.to_owned()).await?;
+        assert_eq!(after.my_hand(), before.my_hand());
+        assert_eq!(*after.status(), GameStatus::InProgress(true));
+        assert_eq!(*after.take_back_request(), None);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn last_move_is_shown_from_each_side_of_the_table() -> Result<(), Box<dyn Error>> {
+        let (_db_file, client) = start_test_backend()?;
+        let game_id = "last-move";
+
+        client.host_game(game_id.to_owned(), "mememe".to_owned(), None, Opponent::Human, RuleSet::standard()).await?;
+        client.join_game(game_id.to_owned(), "youyou".to_owned()).await?;
+
+        let (mover, other) = match client.get_game_state(game_id.to_owned(), "mememe".to_owned()).await?.status() {
+            GameStatus::InProgress(true) => ("mememe", "youyou"),
+            _ => ("youyou", "mememe"),
+        };
+        assert_eq!(*client.get_game_state(game_id.to_owned(), mover.to_owned()).await?.last_move(), None);
+
+        let discarded = *client.get_game_state(game_id.to_owned(), mover.to_owned()).await?.my_hand()[0].card();
+        client.play_card(Play::new(game_id.to_owned(), mover.to_owned(), discarded, CardTarget::Neutral, DrawPile::Main)).await?;
+
+        let game_state = client.get_game_state(game_id.to_owned(), mover.to_owned()).await?;
+        assert_eq!(*game_state.last_move(), Some(LastMove::new(None, discarded, CardTarget::Neutral, LastDraw::MainPile)));
+        let game_state = client.get_game_state(game_id.to_owned(), other.to_owned()).await?;
+        assert_eq!(*game_state.last_move(), Some(LastMove::new(Some(0), discarded, CardTarget::Neutral, LastDraw::MainPile)));
+
+        // Everyone sees which card came off a discard pile.
+        let played = *game_state.my_hand()[0].card();
+        client.play_card(Play::new(game_id.to_owned(), other.to_owned(), played, CardTarget::Player, DrawPile::Neutral(*discarded.card_color()))).await?;
+
+        let game_state = client.get_game_state(game_id.to_owned(), mover.to_owned()).await?;
+        assert_eq!(*game_state.last_move(), Some(LastMove::new(Some(0), played, CardTarget::Player, LastDraw::DiscardPile(discarded))));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn rule_violations_say_which_card_or_pile() -> Result<(), Box<dyn Error>> {
+        let (_db_file, client) = start_test_backend()?;
+        let game_id = "rule-violations";
+
+        client.host_game(game_id.to_owned(), "mememe".to_owned(), None, Opponent::Human, RuleSet::standard()).await?;
         client.join_game(game_id.to_owned(), "youyou".to_owned()).await?;
+        let (mover, other) = match client.get_game_state(game_id.to_owned(), "mememe".to_owned()).await?.status() {
+            GameStatus::InProgress(true) => ("mememe", "youyou"),
+            _ => ("youyou", "mememe"),
+        };
+
+        // Wagers come in threes, so the other player's card might be in the mover's hand too.
+        let my_hand = client.get_game_state(game_id.to_owned(), mover.to_owned()).await?.my_hand().clone();
+        let not_mine = client.get_game_state(game_id.to_owned(), other.to_owned()).await?.my_hand().iter()
+            .map(|card| *card.card())
+            .find(|card| my_hand.iter().all(|my_card| my_card.card() != card))
+            .expect("Both hands can't be all wagers of the same colors.");
+        match client.play_card(Play::new(game_id.to_owned(), mover.to_owned(), not_mine, CardTarget::Player, DrawPile::Main)).await {
+            Err(BackendGameError::InvalidPlay(reason)) => assert_eq!(reason, Reason::CardNotInHand(not_mine)),
+            other => panic!("Expected the card to be rejected, got {:?}", other),
+        }
+
+        // Nothing has been discarded yet.
+        let mine = *client.get_game_state(game_id.to_owned(), mover.to_owned()).await?.my_hand()[0].card();
+        match client.play_card(Play::new(game_id.to_owned(), mover.to_owned(), mine, CardTarget::Player, DrawPile::Neutral(CardColor::Green))).await {
+            Err(BackendGameError::InvalidPlay(reason)) => assert_eq!(reason, Reason::NeutralDrawPileEmpty(CardColor::Green)),
+            other => panic!("Expected the draw to be rejected, got {:?}", other),
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn analysis_hints_during_game_and_reviews_after() -> Result<(), Box<dyn Error>> {
+        let (_db_file, client) = start_test_backend()?;
+        let game_id = "analysis";
+
+        client.host_game(game_id.to_owned(), "mememe".to_owned(), None, Opponent::Human, RuleSet::standard()).await?;
+        client.join_game(game_id.to_owned(), "youyou".to_owned()).await?;
+        let (mover, other) = match client.get_game_state(game_id.to_owned(), "mememe".to_owned()).await?.status() {
+            GameStatus::InProgress(true) => ("mememe", "youyou"),
+            _ => ("youyou", "mememe"),
+        };
+
+        // Hints only for whoever's turn it is, best first
+        let analysis = client.analyze_game(game_id.to_owned(), mover.to_owned()).await?;
+        assert!(!analysis.move_hints().is_empty());
+        assert!(analysis.move_hints().windows(2).all(|pair| pair[0].value() >= pair[1].value()));
+        assert!(analysis.mistakes().is_empty());
+        assert!(client.analyze_game(game_id.to_owned(), other.to_owned()).await?.move_hints().is_empty());
+
+        let best = analysis.move_hints()[0];
+        client.play_card(Play::new(
+            game_id.to_owned(),
+            mover.to_owned(),
+            *best.card(),
+            *best.target(),
+            *best.draw_pile(),
+        )).await?;
+        client.resign(game_id.to_owned(), other.to_owned()).await?;
+
+        // Once it's over, there's nothing left to hint, and only the mover's one move to review.
+        let analysis = client.analyze_game(game_id.to_owned(), mover.to_owned()).await?;
+        assert!(analysis.move_hints().is_empty());
+        assert!(analysis.mistakes().iter().all(|mistake| *mistake.turn() == 1));
+        // The game can't change anymore, so asking again gets the same review.
+        assert_eq!(client.analyze_game(game_id.to_owned(), mover.to_owned()).await?, analysis);
+        assert!(client.analyze_game(game_id.to_owned(), other.to_owned()).await?.mistakes().is_empty());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn computer_plays_its_turns() -> Result<(), Box<dyn Error>> {
+        let (_db_file, client) = start_test_backend()?;
+        let game_id = "vs-computer";
+
+        client.host_game(game_id.to_owned(), "mememe".to_owned(), None, Opponent::Computer(Difficulty::Easy), RuleSet::standard()).await?;
+        let game_metadata = client.describe_game(game_id.to_owned()).await?;
+        assert_eq!(game_metadata.matched_data().as_ref().map(|(p2_id, _)| p2_id.as_str()), Some(bot::BOT_PLAYER_ID));
+
+        // Let the computer play against itself, through my seat, until the deck runs out. The
+        // computer thinks in the background, so wait for it between my turns.
+        let mut my_seat = HeuristicStrategy;
+        loop {
+            let game_state = client.get_game_state(game_id.to_owned(), "mememe".to_owned()).await?;
+            match game_state.status() {
+                GameStatus::InProgress(false) => {
+                    time::delay_for(Duration::from_millis(1)).await;
+                    continue;
+                },
+                GameStatus::InProgress(true) => {},
+                GameStatus::Complete(_, end_reason) => {
+                    assert_eq!(*end_reason, EndReason::DeckExhausted);
+                    break;
+                },
+            }
+
+            let action = my_seat.choose_action(&game_state).unwrap();
+            client.play_card(Play::new(
+                game_id.to_owned(),
+                "mememe".to_owned(),
+                *action.card(),
+                *action.target(),
+                *action.draw_pile(),
+            )).await?;
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn computer_thinks_without_holding_up_the_game() -> Result<(), Box<dyn Error>> {
+        let (_db_file, client) = start_test_backend()?;
+        let game_id = "vs-hard-computer";
+
+        client.host_game(game_id.to_owned(), "mememe".to_owned(), None, Opponent::Computer(Difficulty::Hard), RuleSet::standard()).await?;
+        let mut game_state = client.get_game_state(game_id.to_owned(), "mememe".to_owned()).await?;
+        // The computer may have gone first.
+        while *game_state.status() == GameStatus::InProgress(false) {
+            time::delay_for(Duration::from_millis(10)).await;
+            game_state = client.get_game_state(game_id.to_owned(), "mememe".to_owned()).await?;
+        }
+        let action = HeuristicStrategy.choose_action(&game_state).unwrap();
+        client.play_card(Play::new(
+            game_id.to_owned(),
+            "mememe".to_owned(),
+            *action.card(),
+            *action.target(),
+            *action.draw_pile(),
+        )).await?;
+
+        // The hard computer thinks for a full second, and the game still answers in the meantime.
+        let game_state = client.get_game_state(game_id.to_owned(), "mememe".to_owned()).await?;
+        assert_eq!(*game_state.status(), GameStatus::InProgress(false));
+
+
+        Ok(())
+    }
+
+    #[tokio::test]
+
+    async fn house_rules_with_final_turn() -> Result<(), Box<dyn Error>> {
+        let (_db_file, client) = start_test_backend()?;
+        let game_id = "house-rules";
+
+        let unplayable = RuleSet::new(40, 20, 1, 8, 20, false, false);
+        match client.host_game(game_id.to_owned(), "mememe".to_owned(), None, Opponent::Human, unplayable).await {
+            Err(BackendGameError::InvalidPlay(_)) => {},
+            other => panic!("Expected the rule set to be rejected, got {:?}", other),
+        }
+
+        let house_rules = RuleSet::new(5, 15, 2, 6, 10, true, true);
+        client.host_game(game_id.to_owned(), "mememe".to_owned(), None, Opponent::Computer(Difficulty::Easy), house_rules).await?;
+
+        // Six colors: 72 cards, 10 of them dealt. The computer may have moved already.
+        let game_state = client.get_game_state(game_id.to_owned(), "mememe".to_owned()).await?;
+        assert!(*game_state.game_board().draw_pile_cards_remaining() >= 61);
+        assert!(game_state.unseen_cards().iter().any(|card| *card.card_color() == CardColor::Purple));
+
+        // Whoever doesn't draw the last card gets one more turn, so I see an empty deck at most once.
+        let mut my_seat = HeuristicStrategy;
+        let mut final_turns = 0;
+        loop {
+            let game_state = client.get_game_state(game_id.to_owned(), "mememe".to_owned()).await?;
+            assert_eq!(*game_state.rule_set(), house_rules);
+            match game_state.status() {
+                GameStatus::InProgress(false) => {
+                    time::delay_for(Duration::from_millis(1)).await;
+                    continue;
+                },
+                GameStatus::InProgress(true) => {},
+                GameStatus::Complete(_, end_reason) => {
+                    assert_eq!(*end_reason, EndReason::DeckExhausted);
+                    break;
+                },
+            }
+            if *game_state.game_board().draw_pile_cards_remaining() == 0 {
+                final_turns += 1;
+            } else {
+                assert_eq!(game_state.my_hand().len(), 5);
+            }
+
+            let action = my_seat.choose_action(&game_state).unwrap();
+            client.play_card(Play::new(
+                game_id.to_owned(),
+                "mememe".to_owned(),
+                *action.card(),
+                *action.target(),
+                *action.draw_pile(),
+            )).await?;
+        }
+        assert!(final_turns <= 1);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn finished_games_export_to_notation() -> Result<(), Box<dyn Error>> {
+        let (_db_file, client) = start_test_backend()?;
+        let game_id = "export";
+
+        client.host_game(game_id.to_owned(), "mememe".to_owned(), None, Opponent::Computer(Difficulty::Easy), RuleSet::standard()).await?;
+        match client.export_game(game_id.to_owned(), "mememe".to_owned()).await {
+            Err(BackendGameError::InvalidPlay(_)) => {},
+            other => panic!("Expected the game in progress to be refused, got {:?}", other),
+        }
+
+        let mut my_seat = HeuristicStrategy;
+        loop {
+            let game_state = client.get_game_state(game_id.to_owned(), "mememe".to_owned()).await?;
+            match game_state.status() {
+                GameStatus::InProgress(false) => {
+                    time::delay_for(Duration::from_millis(1)).await;
+                    continue;
+                },
+                GameStatus::InProgress(true) => {},
+                GameStatus::Complete(_, _) => break,
+            }
+
+            let action = my_seat.choose_action(&game_state).unwrap();
+            client.play_card(Play::new(
+                game_id.to_owned(),
+                "mememe".to_owned(),
+                *action.card(),
+                *action.target(),
+                *action.draw_pile(),
+            )).await?;
+        }
+
+        // Anyone can export a finished game, and it replays to the same result.
+        let text = client.export_game(game_id.to_owned(), "spectator".to_owned()).await?;
+        let record = notation::parse(&text)?;
+        assert_eq!(record.player_ids()[0], "mememe");
+        let game_state = client.get_game_state(game_id.to_owned(), "mememe".to_owned()).await?;
+        let game_board = game_state.game_board();
+        assert_eq!(
+            *record.result(),
+            RecordedResult::Scores(vec![*game_board.my_score_total(), *game_board.op_score_total()])
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn three_player_game_goes_around_the_table() -> Result<(), Box<dyn Error>> {
+        let (_db_file, client) = start_test_backend()?;
+        let game_id = "three-players";
+        let players = ["mememe", "youyou", "themthem"];
+        let rule_set = RuleSet::standard().with_num_players(3);
+
+        match client.host_game(game_id.to_owned(), "mememe".to_owned(), None, Opponent::Computer(Difficulty::Easy), rule_set).await {
+            Err(BackendGameError::InvalidPlay(_)) => {},
+            other => panic!("Expected the computer to refuse a three player game, got {:?}", other),
+        }
+
+        client.host_game(game_id.to_owned(), "mememe".to_owned(), None, Opponent::Human, rule_set).await?;
+        client.join_game(game_id.to_owned(), "youyou".to_owned()).await?;
+        assert!(client.describe_game(game_id.to_owned()).await?.matched_data().is_none());
+        match client.join_game(game_id.to_owned(), "youyou".to_owned()).await {
+            Err(BackendGameError::InvalidPlay(Reason::AlreadySeated)) => {},
+            other => panic!("Expected a second seat for the same player to be refused, got {:?}", other),
+        }
+        client.join_game(game_id.to_owned(), "themthem".to_owned()).await?;
+        assert!(client.describe_game(game_id.to_owned()).await?.matched_data().is_some());
+        assert!(client.join_game(game_id.to_owned(), "latecomer".to_owned()).await.is_err());
+
+        let spectator_game_state = client.get_spectator_game_state(game_id.to_owned(), "spectator".to_owned()).await?;
+        let player_ids: Vec<&str> = spectator_game_state.seats().iter().map(|seat| seat.player_id()).collect();
+        assert_eq!(player_ids, players.to_vec());
+
+        // Only for two player games.
+        assert!(client.offer_draw(game_id.to_owned(), "mememe".to_owned()).await.is_err());
+
+        let mut strategy = HeuristicStrategy;
+        let mut last_mover = None;
+        'game: loop {
+            for (seat, player_id) in players.iter().enumerate() {
+                let game_state = client.get_game_state(game_id.to_owned(), (*player_id).to_owned()).await?;
+                assert_eq!(game_state.game_board().opponents().len(), 2);
+                match game_state.status() {
+                    GameStatus::InProgress(true) => {},
+                    GameStatus::InProgress(false) => continue,
+                    GameStatus::Complete(_, end_reason) => {
+                        assert_eq!(*end_reason, EndReason::DeckExhausted);
+                        break 'game;
+                    },
+                }
+
+                // Whoever is up is always the next seat after the last mover.
+                if let Some(last_mover) = last_mover {
+                    assert_eq!(seat, (last_mover + 1) % players.len());
+                }
+                last_mover = Some(seat);
+
+                let action = strategy.choose_action(&game_state).unwrap();
+                client.play_card(Play::new(
+                    game_id.to_owned(),
+                    (*player_id).to_owned(),
+                    *action.card(),
+                    *action.target(),
+                    *action.draw_pile(),
+                )).await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn resigning_ends_a_three_player_game_for_everyone() -> Result<(), Box<dyn Error>> {
+        let (_db_file, client) = start_test_backend()?;
+        let game_id = "three-player-resign";
+
+        client.host_game(game_id.to_owned(), "mememe".to_owned(), None, Opponent::Human, RuleSet::standard().with_num_players(3)).await?;
+        client.join_game(game_id.to_owned(), "youyou".to_owned()).await?;
+        client.join_game(game_id.to_owned(), "themthem".to_owned()).await?;
+        client.resign(game_id.to_owned(), "youyou".to_owned()).await?;
+
+        for (player_id, result) in [("mememe", GameResult::Win), ("youyou", GameResult::Lose), ("themthem", GameResult::Win)].iter() {
+            let game_state = client.get_game_state(game_id.to_owned(), (*player_id).to_owned()).await?;
+            assert_eq!(*game_state.status(), GameStatus::Complete(*result, EndReason::Resignation), "for '{}'", player_id);
+        }
+        let game_state = client.get_spectator_game_state(game_id.to_owned(), "spectator".to_owned()).await?;
+        assert_eq!(*game_state.status(), SpectatorStatus::Complete(vec![0, 2], EndReason::Resignation));
+
+        // Nobody plays on without them.
+        assert!(client.resign(game_id.to_owned(), "themthem".to_owned()).await.is_err());
 
         Ok(())
     }
@@ -0,0 +1,127 @@
+use crate::backend_error::{BackendGameError, Reason};
+use crate::platform::platform_game::PlatformGame;
+use game_api::types::{GameMetadata, GameStatus, GameType};
+use rand::Rng;
+use storage::storage_types::{StorageGameMetadata, StorageGameStatus};
+use storage::v2::db_types::DbGameType;
+
+/// List a new game with only the host seated. Caller is responsible for saving it.
+pub fn host<G: PlatformGame>(
+    game: &G,
+    game_id: String,
+    host_id: String,
+    config: &G::Config,
+) -> Result<StorageGameMetadata, BackendGameError> {
+    game.validate_config(config)?;
+
+    let mut metadata = StorageGameMetadata::new(
+        game_id,
+        vec![host_id],
+        StorageGameStatus::InProgress,
+    );
+    metadata.set_game_type(game.game_type());
+    metadata.set_num_seats(game.num_seats(config));
+    game.save_config(config, &mut metadata);
+
+    Ok(metadata)
+}
+
+/// Seat the guest at the next open seat. Caller is responsible for saving the metadata.
+pub fn seat_guest(metadata: &mut StorageGameMetadata, guest_id: String) -> Result<(), BackendGameError> {
+    if metadata.is_full() {
+        let last_guest_id = metadata.player_ids().last().cloned().unwrap_or_default();
+        return Err(BackendGameError::GameAlreadyMatched(last_guest_id));
+    }
+    // Otherwise they'd play against themselves from two seats.
+    if metadata.seat(&guest_id).is_some() {
+        return Err(BackendGameError::InvalidPlay(Reason::AlreadySeated));
+    }
+    metadata.push_player_id(guest_id);
+
+    Ok(())
+}
+
+pub fn find_seat(metadata: &StorageGameMetadata, player_id: &str) -> Result<usize, BackendGameError> {
+    metadata.seat(player_id)
+        .ok_or(BackendGameError::NotFound("Player in game"))
+}
+
+/// Whoever goes first is picked once the table is full.
+pub fn random_first_seat(num_seats: usize) -> usize {
+    rand::thread_rng().gen_range(0, num_seats)
+}
+
+/// The game as listed in the lobby. Matched games are listed with their status from the host's
+/// perspective, which only exists once the game has been dealt.
+pub fn convert_game_metadata(metadata: &StorageGameMetadata, host_status: Option<GameStatus>) -> GameMetadata {
+    let game_metadata = match host_status {
+        Some(status) if metadata.is_full() => GameMetadata::new_matched(
+            metadata.game_id().to_owned(),
+            metadata.host_id().to_owned(),
+            metadata.creation_time_ms(),
+            metadata.player_ids()[1].clone(),
+            status,
+        ),
+        _ => GameMetadata::new_unmatched(
+            metadata.game_id().to_owned(),
+            metadata.host_id().to_owned(),
+            metadata.creation_time_ms(),
+        ),
+    };
+
+    game_metadata
+        .with_rule_set(*metadata.rule_set())
+        .with_game_type(convert_game_type(*metadata.game_type()))
+}
+
+fn convert_game_type(db_game_type: DbGameType) -> GameType {
+    match db_game_type {
+        DbGameType::LostCities => GameType::LostCities,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn three_seat_table() -> StorageGameMetadata {
+        let mut metadata = StorageGameMetadata::new(
+            "game".to_owned(),
+            vec!["host".to_owned()],
+            StorageGameStatus::InProgress,
+        );
+        metadata.set_num_seats(3);
+        metadata
+    }
+
+    #[test]
+    fn guests_fill_seats_in_the_order_they_join() {
+        let mut metadata = three_seat_table();
+
+        seat_guest(&mut metadata, "first".to_owned()).unwrap();
+        assert!(!metadata.is_full());
+        assert!(convert_game_metadata(&metadata, None).matched_data().is_none());
+
+        match seat_guest(&mut metadata, "first".to_owned()) {
+            Err(BackendGameError::InvalidPlay(Reason::AlreadySeated)) => {},
+            other => panic!("Expected the guest to already have a seat, got {:?}", other),
+        }
+        seat_guest(&mut metadata, "second".to_owned()).unwrap();
+        assert!(metadata.is_full());
+        assert_eq!(find_seat(&metadata, "host").unwrap(), 0);
+        assert_eq!(find_seat(&metadata, "second").unwrap(), 2);
+        assert!(find_seat(&metadata, "stranger").is_err());
+
+        match seat_guest(&mut metadata, "third".to_owned()) {
+            Err(BackendGameError::GameAlreadyMatched(last_guest_id)) => assert_eq!(last_guest_id, "second"),
+            other => panic!("Expected the table to be full, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn lobby_lists_the_game_type() {
+        let game_metadata = convert_game_metadata(&three_seat_table(), None);
+        assert_eq!(*game_metadata.game_type(), GameType::LostCities);
+        assert_eq!(game_metadata.host_player_id(), "host");
+    }
+}
@@ -0,0 +1,2 @@
+pub mod lobby;
+pub mod platform_game;
@@ -0,0 +1,62 @@
+use crate::backend_error::BackendGameError;
+use game_api::types::GameStatus;
+use storage::storage_api::GameStateRecord;
+use storage::storage_types::{StorageClock, StorageGameMetadata};
+use storage::v2::db_types::DbGameType;
+
+/// Everything the platform needs from a game in order to host it. Accounts, lobbies, seating,
+/// clocks, and persistence are the platform's job and work the same for every game. The rules are
+/// the game's.
+///
+/// Players are identified by their seat, from 0 (the host) up to `num_seats() - 1`.
+pub trait PlatformGame {
+    /// Picked by the host, e.g. house rules.
+    type Config;
+
+    /// The whole game, hidden information included. This is what gets persisted.
+    type State: GameStateRecord + Send + 'static;
+
+    /// A single move by one seat.
+    type Action;
+
+    /// The game as one seat is allowed to see it.
+    type View;
+
+    fn game_type(&self) -> DbGameType;
+
+    /// Reject a config the game can't be played with, before anyone joins.
+    fn validate_config(&self, config: &Self::Config) -> Result<(), BackendGameError>;
+
+    fn num_seats(&self, config: &Self::Config) -> usize;
+
+    /// The config is kept with the lobby listing until the table is full.
+    fn save_config(&self, config: &Self::Config, metadata: &mut StorageGameMetadata);
+
+    fn load_config(&self, metadata: &StorageGameMetadata) -> Self::Config;
+
+    /// Deal out a new game. Called once every seat is taken.
+    fn setup(&self, game_id: String, config: &Self::Config, first_seat: usize) -> Result<Self::State, BackendGameError>;
+
+    /// Validate and apply the action. It's up to the game whose turn it is.
+    fn apply_action(&self, state: Self::State, seat: usize, action: Self::Action) -> Result<Self::State, BackendGameError>;
+
+    fn player_view(&self, state: &Self::State, seat: usize) -> Self::View;
+
+    /// How the game stands for one seat, e.g. for the lobby.
+    fn status(&self, state: &Self::State, seat: usize) -> GameStatus;
+
+    /// However the game ended, no more actions are accepted once it has.
+    fn is_terminal(&self, state: &Self::State) -> bool;
+
+    /// The seat whose clock is running.
+    fn turn(&self, state: &Self::State) -> usize;
+
+    /// Timed games keep their clock in the game state, so it's saved along with every move.
+    fn clock_mut<'a>(&self, state: &'a mut Self::State) -> Option<&'a mut StorageClock>;
+
+    /// Stamped once the game ends, however it ended.
+    fn set_end_time_ms(&self, state: &mut Self::State, end_time_ms: u64);
+
+    /// The finished game in the game's own text notation. This is what gets archived and exported.
+    fn write_record(&self, state: &Self::State, player_ids: &[String]) -> Result<String, BackendGameError>;
+}
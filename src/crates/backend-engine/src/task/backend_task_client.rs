@@ -2,23 +2,38 @@ use crate::backend_error::{BackendGameError, Cause};
 use crate::game_api::{GameApi2Immut, GameApiResult};
 use crate::task::backend_task_event::{BackendTaskEvent, EventPayload};
 use crate::task::backend_task_handler::BackendTaskHandler;
-use game_api::types::{GameMetadata, GameState, Play};
+use game_api::types::{GameMetadata, GameState, Play, SpectatorGameState, MatchState, TimeControl, Opponent, GameAnalysis, RuleSet};
 use std::sync::Arc;
 use storage::v2::db_api::GameDatabase;
 use tokio::sync::mpsc;
 use tokio::sync::oneshot;
+use tokio::time;
+use std::time::Duration;
 
 pub fn spawn_backend_task(
     db_client: Arc<dyn GameDatabase + Send + Sync>
 ) -> BackendTaskClientAdapter {
     let (sender, receiver) = mpsc::unbounded_channel::<BackendTaskEvent>();
 
-    let task = BackendTaskHandler::new(receiver, db_client);
+    let task = BackendTaskHandler::new(receiver, sender.clone(), db_client);
     tokio::spawn(task.start_event_loop());
+    tokio::spawn(tick_clocks(sender.clone()));
 
     BackendTaskClientAdapter::new(sender)
 }
 
+/// Nudge the event loop every second so timed games get forfeited even if nobody is polling
+/// them. Stops once the event loop goes away.
+async fn tick_clocks(sender: mpsc::UnboundedSender<BackendTaskEvent>) {
+    let mut interval = time::interval(Duration::from_secs(1));
+    loop {
+        interval.tick().await;
+        if sender.send(BackendTaskEvent::ExpireClocks).is_err() {
+            return;
+        }
+    }
+}
+
 /// Adapts the task model to the `GameApi2Immut` API model. This is the beauty of interior
 /// mutability offered by the shared-nothing task model. The MPSC sender doesn't need to be
 /// `mut` but it passes a message to a single-threaded MPSC receiver who has `mut` ownership
@@ -53,8 +68,8 @@ impl BackendTaskClientAdapter {
 
 #[async_trait::async_trait]
 impl GameApi2Immut for BackendTaskClientAdapter {
-    async fn host_game(&self, game_id: String, p1_id: String) -> GameApiResult<()> {
-        let (payload, receiver) = EventPayload::wrap_with_channel((game_id, p1_id));
+    async fn host_game(&self, game_id: String, p1_id: String, time_control: Option<TimeControl>, opponent: Opponent, rule_set: RuleSet) -> GameApiResult<()> {
+        let (payload, receiver) = EventPayload::wrap_with_channel((game_id, p1_id, time_control, opponent, rule_set));
         self.send_and_await(BackendTaskEvent::HostGame(payload), receiver).await
     }
 
@@ -73,11 +88,66 @@ impl GameApi2Immut for BackendTaskClientAdapter {
         self.send_and_await(BackendTaskEvent::GetGameState(payload), receiver).await
     }
 
+    async fn get_spectator_game_state(&self, game_id: String, player_id: String) -> GameApiResult<SpectatorGameState> {
+        let (payload, receiver) = EventPayload::wrap_with_channel((game_id, player_id));
+        self.send_and_await(BackendTaskEvent::GetSpectatorGameState(payload), receiver).await
+    }
+
     async fn play_card(&self, play: Play) -> GameApiResult<()> {
         let (payload, receiver) = EventPayload::wrap_with_channel(play);
         self.send_and_await(BackendTaskEvent::PlayCard(payload), receiver).await
     }
 
+    async fn resign(&self, game_id: String, player_id: String) -> GameApiResult<()> {
+        let (payload, receiver) = EventPayload::wrap_with_channel((game_id, player_id));
+        self.send_and_await(BackendTaskEvent::Resign(payload), receiver).await
+    }
+
+    async fn offer_draw(&self, game_id: String, player_id: String) -> GameApiResult<()> {
+        let (payload, receiver) = EventPayload::wrap_with_channel((game_id, player_id));
+        self.send_and_await(BackendTaskEvent::OfferDraw(payload), receiver).await
+    }
+
+    async fn respond_to_draw_offer(&self, game_id: String, player_id: String, accept: bool) -> GameApiResult<()> {
+        let (payload, receiver) = EventPayload::wrap_with_channel((game_id, player_id, accept));
+        self.send_and_await(BackendTaskEvent::RespondToDrawOffer(payload), receiver).await
+    }
+
+    async fn request_take_back(&self, game_id: String, player_id: String) -> GameApiResult<()> {
+        let (payload, receiver) = EventPayload::wrap_with_channel((game_id, player_id));
+        self.send_and_await(BackendTaskEvent::RequestTakeBack(payload), receiver).await
+    }
+
+    async fn respond_to_take_back(&self, game_id: String, player_id: String, accept: bool) -> GameApiResult<()> {
+        let (payload, receiver) = EventPayload::wrap_with_channel((game_id, player_id, accept));
+        self.send_and_await(BackendTaskEvent::RespondToTakeBack(payload), receiver).await
+    }
+
+    async fn analyze_game(&self, game_id: String, player_id: String) -> GameApiResult<GameAnalysis> {
+        let (payload, receiver) = EventPayload::wrap_with_channel((game_id, player_id));
+        self.send_and_await(BackendTaskEvent::AnalyzeGame(payload), receiver).await
+    }
+
+    async fn export_game(&self, game_id: String, player_id: String) -> GameApiResult<String> {
+        let (payload, receiver) = EventPayload::wrap_with_channel((game_id, player_id));
+        self.send_and_await(BackendTaskEvent::ExportGame(payload), receiver).await
+    }
+
+    async fn host_match(&self, match_id: String, p1_id: String, num_rounds: u32, time_control: Option<TimeControl>, rule_set: RuleSet) -> GameApiResult<()> {
+        let (payload, receiver) = EventPayload::wrap_with_channel((match_id, p1_id, num_rounds, time_control, rule_set));
+        self.send_and_await(BackendTaskEvent::HostMatch(payload), receiver).await
+    }
+
+    async fn join_match(&self, match_id: String, p2_id: String) -> GameApiResult<()> {
+        let (payload, receiver) = EventPayload::wrap_with_channel((match_id, p2_id));
+        self.send_and_await(BackendTaskEvent::JoinMatch(payload), receiver).await
+    }
+
+    async fn get_match_state(&self, match_id: String, player_id: String) -> GameApiResult<MatchState> {
+        let (payload, receiver) = EventPayload::wrap_with_channel((match_id, player_id));
+        self.send_and_await(BackendTaskEvent::GetMatchState(payload), receiver).await
+    }
+
     async fn query_unmatched_games(&self, player_id: String) -> GameApiResult<Vec<GameMetadata>> {
         let (payload, receiver) = EventPayload::wrap_with_channel(player_id);
         self.send_and_await(BackendTaskEvent::QueryUnmatchedGames(payload), receiver).await
@@ -97,4 +167,9 @@ impl GameApi2Immut for BackendTaskClientAdapter {
         let (payload, receiver) = EventPayload::wrap_with_channel(player_id);
         self.send_and_await(BackendTaskEvent::QueryAllUnmatchedGames(payload), receiver).await
     }
+
+    async fn query_watchable_games(&self, player_id: String) -> GameApiResult<Vec<GameMetadata>> {
+        let (payload, receiver) = EventPayload::wrap_with_channel(player_id);
+        self.send_and_await(BackendTaskEvent::QueryWatchableGames(payload), receiver).await
+    }
 }
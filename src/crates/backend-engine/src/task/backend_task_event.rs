@@ -1,5 +1,5 @@
 use crate::backend_error::BackendGameError;
-use game_api::types::{GameMetadata, GameState, Play};
+use game_api::types::{GameMetadata, GameState, Play, SpectatorGameState, MatchState, TimeControl, Opponent, GameAnalysis, RuleSet};
 use std::fmt::{Debug, Formatter};
 use std::fmt;
 use tokio::sync::oneshot;
@@ -8,17 +8,38 @@ use tokio::sync::oneshot;
 #[derive(Debug)]
 pub enum BackendTaskEvent {
     // GameId indexed APIs
-    HostGame(EventPayload<(String, String), ()>),
+    HostGame(EventPayload<(String, String, Option<TimeControl>, Opponent, RuleSet), ()>),
     JoinGame(EventPayload<(String, String), ()>),
     GetGameMetadata(EventPayload<String, GameMetadata>),
     GetGameState(EventPayload<(String, String), GameState>),
+    GetSpectatorGameState(EventPayload<(String, String), SpectatorGameState>),
     PlayCard(EventPayload<Play, ()>),
+    Resign(EventPayload<(String, String), ()>),
+    OfferDraw(EventPayload<(String, String), ()>),
+    RespondToDrawOffer(EventPayload<(String, String, bool), ()>),
+    RequestTakeBack(EventPayload<(String, String), ()>),
+    RespondToTakeBack(EventPayload<(String, String, bool), ()>),
+    AnalyzeGame(EventPayload<(String, String), GameAnalysis>),
+    ExportGame(EventPayload<(String, String), String>),
+
+    // MatchId indexed APIs
+    HostMatch(EventPayload<(String, String, u32, Option<TimeControl>, RuleSet), ()>),
+    JoinMatch(EventPayload<(String, String), ()>),
+    GetMatchState(EventPayload<(String, String), MatchState>),
 
     // PlayerId indexed APIs
     QueryUnmatchedGames(EventPayload<String, Vec<GameMetadata>>),
     QueryInProgressGames(EventPayload<String, Vec<GameMetadata>>),
     QueryCompletedGames(EventPayload<String, Vec<GameMetadata>>),
     QueryAllUnmatchedGames(EventPayload<String, Vec<GameMetadata>>),
+    QueryWatchableGames(EventPayload<String, Vec<GameMetadata>>),
+
+    // Housekeeping. Not part of the GameApi2, and nobody waits on the result.
+    ExpireClocks,
+    /// The move the computer picked, off the event loop. Applied like any other play.
+    ComputerPlay(Play),
+    /// An analysis of a finished game, done off the event loop, to be kept for next time.
+    CacheAnalysis(String, String, GameAnalysis),
 }
 
 pub struct EventPayload<I, O> {
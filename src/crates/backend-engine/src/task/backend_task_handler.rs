@@ -1,8 +1,11 @@
 use crate::backend_error::BackendGameError;
 use game_api::api::GameApi2;
+use game_api::types::{Play, GameAnalysis};
+use bot::BOT_PLAYER_ID;
 use tokio::sync::mpsc;
 use tokio::sync::oneshot;
 use crate::game_engine::backend_game_api::BackendGameApi;
+use crate::game_engine::lost_cities::LostCities;
 use crate::task::backend_task_event::BackendTaskEvent;
 use std::fmt::Debug;
 use storage::v2::db_api::GameDatabase;
@@ -10,23 +13,30 @@ use std::sync::Arc;
 
 pub struct BackendTaskHandler {
     receiver: mpsc::UnboundedReceiver<BackendTaskEvent>,
-    game_api: Box<dyn GameApi2<BackendGameError> + Send>,
+    /// For sending the computer's moves back to this same event loop.
+    sender: mpsc::UnboundedSender<BackendTaskEvent>,
+    game_api: BackendGameApi<LostCities>,
 }
 
 impl BackendTaskHandler {
     pub fn new(
         receiver: mpsc::UnboundedReceiver<BackendTaskEvent>,
+        sender: mpsc::UnboundedSender<BackendTaskEvent>,
         db_client: Arc<dyn GameDatabase + Send + Sync>
     ) -> Self {
         BackendTaskHandler {
             receiver,
-            game_api: Box::new(BackendGameApi::new(db_client)),
+            sender,
+            game_api: BackendGameApi::new(LostCities, db_client),
         }
     }
 
     pub async fn start_event_loop(mut self) {
         while let Some(event) = self.receiver.recv().await {
-            println!("Received {:?}", event);
+            // Clock ticks arrive every second, so don't spam the log with them.
+            if let BackendTaskEvent::ExpireClocks = event {} else {
+                println!("Received {:?}", event);
+            }
             self.handle_event(event).await;
         }
 
@@ -36,11 +46,12 @@ impl BackendTaskHandler {
     async fn handle_event(&mut self, event: BackendTaskEvent) {
         match event {
             BackendTaskEvent::HostGame(payload) => {
-                let (game_id, player_id) = payload.input;
-                pipe_result_to_sender(
-                    self.game_api.host_game(game_id, player_id).await,
-                    payload.output_sender
-                );
+                let (game_id, player_id, time_control, opponent, rule_set) = payload.input;
+                let result = self.game_api.host_game(game_id.clone(), player_id, time_control, opponent, rule_set).await;
+                if result.is_ok() {
+                    self.start_computer_turn(&game_id);
+                }
+                pipe_result_to_sender(result, payload.output_sender);
             },
             BackendTaskEvent::JoinGame(payload) => {
                 let (game_id, player_id) = payload.input;
@@ -63,10 +74,86 @@ impl BackendTaskHandler {
                     payload.output_sender
                 );
             },
+            BackendTaskEvent::GetSpectatorGameState(payload) => {
+                let (game_id, player_id) = payload.input;
+                pipe_result_to_sender(
+                    self.game_api.get_spectator_game_state(game_id, player_id).await,
+                    payload.output_sender
+                );
+            },
             BackendTaskEvent::PlayCard(payload) => {
                 let play = payload.input;
+                let game_id = play.game_id().to_owned();
+                let result = self.game_api.play_card(play).await;
+                if result.is_ok() {
+                    self.start_computer_turn(&game_id);
+                }
+                pipe_result_to_sender(result, payload.output_sender);
+            },
+            BackendTaskEvent::Resign(payload) => {
+                let (game_id, player_id) = payload.input;
+                pipe_result_to_sender(
+                    self.game_api.resign(game_id, player_id).await,
+                    payload.output_sender
+                );
+            },
+            BackendTaskEvent::OfferDraw(payload) => {
+                let (game_id, player_id) = payload.input;
+                pipe_result_to_sender(
+                    self.game_api.offer_draw(game_id, player_id).await,
+                    payload.output_sender
+                );
+            },
+            BackendTaskEvent::RespondToDrawOffer(payload) => {
+                let (game_id, player_id, accept) = payload.input;
+                pipe_result_to_sender(
+                    self.game_api.respond_to_draw_offer(game_id, player_id, accept).await,
+                    payload.output_sender
+                );
+            },
+            BackendTaskEvent::RequestTakeBack(payload) => {
+                let (game_id, player_id) = payload.input;
+                pipe_result_to_sender(
+                    self.game_api.request_take_back(game_id, player_id).await,
+                    payload.output_sender
+                );
+            },
+            BackendTaskEvent::RespondToTakeBack(payload) => {
+                let (game_id, player_id, accept) = payload.input;
+                pipe_result_to_sender(
+                    self.game_api.respond_to_take_back(game_id, player_id, accept).await,
+                    payload.output_sender
+                );
+            },
+            BackendTaskEvent::AnalyzeGame(payload) => {
+                let (game_id, player_id) = payload.input;
+                self.start_analysis(game_id, player_id, payload.output_sender);
+            },
+            BackendTaskEvent::ExportGame(payload) => {
+                let (game_id, player_id) = payload.input;
                 pipe_result_to_sender(
-                    self.game_api.play_card(play).await,
+                    self.game_api.export_game(game_id, player_id).await,
+                    payload.output_sender
+                );
+            },
+            BackendTaskEvent::HostMatch(payload) => {
+                let (match_id, player_id, num_rounds, time_control, rule_set) = payload.input;
+                pipe_result_to_sender(
+                    self.game_api.host_match(match_id, player_id, num_rounds, time_control, rule_set).await,
+                    payload.output_sender
+                );
+            },
+            BackendTaskEvent::JoinMatch(payload) => {
+                let (match_id, player_id) = payload.input;
+                pipe_result_to_sender(
+                    self.game_api.join_match(match_id, player_id).await,
+                    payload.output_sender
+                );
+            },
+            BackendTaskEvent::GetMatchState(payload) => {
+                let (match_id, player_id) = payload.input;
+                pipe_result_to_sender(
+                    self.game_api.get_match_state(match_id, player_id).await,
                     payload.output_sender
                 );
             },
@@ -98,7 +185,92 @@ impl BackendTaskHandler {
                     payload.output_sender
                 )
             }
+            BackendTaskEvent::QueryWatchableGames(payload) => {
+                let player_id = payload.input;
+                pipe_result_to_sender(
+                    self.game_api.query_watchable_games(player_id).await,
+                    payload.output_sender
+                )
+            }
+            BackendTaskEvent::ExpireClocks => {
+                if let Err(e) = self.game_api.forfeit_timed_out_games().await {
+                    println!("ERROR: Failed to expire game clocks: {:?}", e);
+                }
+            }
+            BackendTaskEvent::ComputerPlay(play) => {
+                let game_id = play.game_id().to_owned();
+                // The game may have moved on while the computer was thinking, e.g. by resignation.
+                // Then this is rejected like any other late play.
+                if let Err(e) = self.game_api.play_card(play).await {
+                    println!("WARN: The computer failed to take its turn in game '{}': {}", game_id, e);
+                }
+            }
+            BackendTaskEvent::CacheAnalysis(game_id, player_id, analysis) => {
+                self.game_api.cache_analysis(game_id, player_id, analysis);
+            }
+        }
+    }
+
+    /// Analyses run thousands of simulated games, so they run on a blocking thread, which answers
+    /// the request itself. Analyses of finished games are sent back here to be cached.
+    fn start_analysis(
+        &mut self,
+        game_id: String,
+        player_id: String,
+        output_sender: oneshot::Sender<Result<GameAnalysis, BackendGameError>>,
+    ) {
+        if let Some(analysis) = self.game_api.cached_analysis(&game_id, &player_id) {
+            return pipe_result_to_sender(Ok(analysis), output_sender);
         }
+        let pending_analysis = match self.game_api.prepare_analysis(&game_id, &player_id) {
+            Ok(pending_analysis) => pending_analysis,
+            Err(e) => return pipe_result_to_sender(Err(e), output_sender),
+        };
+
+        let sender = self.sender.clone();
+        tokio::task::spawn_blocking(move || {
+            let is_game_over = pending_analysis.is_game_over();
+            let result = pending_analysis.run();
+            if let (true, Ok(analysis)) = (is_game_over, &result) {
+                // Only fails if the event loop is gone, and then there's nothing to cache it in.
+                let _ = sender.send(BackendTaskEvent::CacheAnalysis(game_id, player_id, analysis.clone()));
+            }
+            pipe_result_to_sender(result, output_sender);
+        });
+    }
+
+    /// If it's the computer's turn, let it think on a blocking thread, so the harder difficulties
+    /// don't hold up every other game in this slot. Its move comes back as a `ComputerPlay` event.
+    fn start_computer_turn(&mut self, game_id: &str) {
+        let (difficulty, game_state) = match self.game_api.pending_computer_turn(game_id) {
+            Ok(Some(computer_turn)) => computer_turn,
+            Ok(None) => return,
+            Err(e) => {
+                println!("WARN: Failed to check whether the computer moves next in game '{}': {}", game_id, e);
+                return;
+            },
+        };
+
+        let game_id = game_id.to_owned();
+        let sender = self.sender.clone();
+        tokio::task::spawn_blocking(move || {
+            let action = match bot::for_difficulty(difficulty).choose_action(&game_state) {
+                Some(action) => action,
+                None => {
+                    println!("WARN: The computer has no move to make in game '{}'", game_id);
+                    return;
+                },
+            };
+            let play = Play::new(
+                game_id,
+                BOT_PLAYER_ID.to_owned(),
+                *action.card(),
+                *action.target(),
+                *action.draw_pile(),
+            );
+            // Only fails if the event loop is gone, and then there's no game to play anyway.
+            let _ = sender.send(BackendTaskEvent::ComputerPlay(play));
+        });
     }
 }
 
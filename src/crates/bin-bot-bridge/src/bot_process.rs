@@ -0,0 +1,91 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::fmt;
+use std::io;
+use std::process::Stdio;
+use std::time::Duration;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader, Lines};
+use tokio::process::{Child, ChildStdin, ChildStdout, Command};
+use tokio::time;
+
+#[derive(Debug)]
+pub enum BotProcessError {
+    /// The bot didn't answer in time.
+    Timeout,
+    /// The bot closed its stdout, which usually means it crashed.
+    Exited,
+    Io(io::Error),
+}
+
+impl Error for BotProcessError {}
+
+impl Display for BotProcessError {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        match self {
+            BotProcessError::Timeout => f.write_str("Bot didn't answer in time."),
+            BotProcessError::Exited => f.write_str("Bot exited."),
+            BotProcessError::Io(e) => f.write_str(&format!("Couldn't talk to bot: {}", e)),
+        }
+    }
+}
+
+impl From<io::Error> for BotProcessError {
+    fn from(e: io::Error) -> Self {
+        BotProcessError::Io(e)
+    }
+}
+
+/// A bot running as a child process, talked to line by line over its stdin/stdout. Anything the
+/// bot writes to stderr goes straight to ours. The bot is killed when this is dropped.
+pub struct BotProcess {
+    _child: Child,
+    stdin: ChildStdin,
+    stdout: Lines<BufReader<ChildStdout>>,
+}
+
+impl BotProcess {
+    pub fn spawn(command: &str, args: &[String]) -> Result<Self, BotProcessError> {
+        let mut child = Command::new(command)
+            .args(args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .kill_on_drop(true)
+            .spawn()?;
+
+        let stdin = child.stdin.take().ok_or(BotProcessError::Exited)?;
+        let stdout = child.stdout.take().ok_or(BotProcessError::Exited)?;
+
+        Ok(BotProcess {
+            _child: child,
+            stdin,
+            stdout: BufReader::new(stdout).lines(),
+        })
+    }
+
+    pub async fn send(&mut self, line: &str) -> Result<(), BotProcessError> {
+        println!("[BOT <-] {}", line);
+        self.stdin.write_all(format!("{}\n", line).as_bytes()).await?;
+        self.stdin.flush().await?;
+        Ok(())
+    }
+
+    /// Wait for the next non-blank line from the bot.
+    pub async fn receive(&mut self, timeout: Duration) -> Result<String, BotProcessError> {
+        let stdout = &mut self.stdout;
+        let read_line = async {
+            loop {
+                match stdout.next_line().await? {
+                    Some(line) if line.trim().is_empty() => continue,
+                    Some(line) => return Ok(line),
+                    None => return Err(BotProcessError::Exited),
+                }
+            }
+        };
+
+        let line = time::timeout(timeout, read_line)
+            .await
+            .map_err(|_| BotProcessError::Timeout)??;
+        println!("[BOT ->] {}", line);
+        Ok(line)
+    }
+}
@@ -0,0 +1,181 @@
+use crate::bot_process::{BotProcess, BotProcessError};
+use crate::protocol::{self, BotMessage};
+use client_engine::client_game_api::error::ClientGameError;
+use game_api::api::GameApi2;
+use game_api::types::{Card, CardTarget, DrawPile, GameState, GameStatus, Play};
+use std::error::Error;
+use std::time::{Duration, Instant};
+use tokio::time;
+
+/// How long the bot gets to start up and answer `lci`.
+const HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// How often to check whether the opponent has moved.
+const POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// A bot gets this many tries at making a legal move each turn before it resigns.
+const MAX_ILLEGAL_MOVES: u32 = 3;
+
+pub async fn handshake(bot: &mut BotProcess) -> Result<(), Box<dyn Error>> {
+    bot.send(protocol::HANDSHAKE).await?;
+
+    let deadline = Instant::now() + HANDSHAKE_TIMEOUT;
+    loop {
+        let line = bot.receive(deadline.saturating_duration_since(Instant::now())).await?;
+        match protocol::parse_bot_message(&line) {
+            Ok(BotMessage::Ready) => return Ok(()),
+            Ok(_) => continue,
+            Err(message) => println!("Ignoring bot message: {}", message),
+        }
+    }
+}
+
+/// Relay one game between the server and the bot, until it's over. The game must already be
+/// matched. Whenever the bot can't come up with a legal move in time, it resigns.
+pub async fn play_game(
+    game_api: &mut Box<dyn GameApi2<ClientGameError>>,
+    bot: &mut BotProcess,
+    game_id: String,
+    my_player_id: String,
+    move_time: Duration,
+) -> Result<GameState, Box<dyn Error>> {
+    let game_metadata = game_api.describe_game(game_id.clone()).await?;
+    let op_player_id = match game_metadata.matched_data() {
+        None => return Err(format!("Game '{}' has no opponent yet", game_id).into()),
+        Some((guest_player_id, _)) => {
+            if my_player_id == game_metadata.host_player_id() {
+                guest_player_id.to_owned()
+            } else if my_player_id == *guest_player_id {
+                game_metadata.host_player_id().to_owned()
+            } else {
+                return Err(format!("'{}' is not playing in game '{}'", my_player_id, game_id).into());
+            }
+        },
+    };
+    bot.send(&protocol::new_game(&game_id, &op_player_id)).await?;
+
+    loop {
+        let game_state = game_api.get_game_state(game_id.clone(), my_player_id.clone()).await?;
+        match game_state.status() {
+            GameStatus::InProgress(false) => {
+                time::delay_for(POLL_INTERVAL).await;
+                continue;
+            },
+            GameStatus::Complete(_, _) => {
+                for line in protocol::game_state(&game_state) {
+                    bot.send(&line).await?;
+                }
+                if let Some(line) = protocol::game_over(&game_state) {
+                    bot.send(&line).await?;
+                }
+                return Ok(game_state);
+            },
+            GameStatus::InProgress(true) => {},
+        }
+
+        // Never think past our own clock
+        let move_time = match game_state.clock() {
+            Some(clock) => move_time.min(Duration::from_millis(*clock.my_remaining_ms())),
+            None => move_time,
+        };
+
+        match take_turn(game_api, bot, &game_id, &my_player_id, &game_state, move_time).await {
+            Ok(()) => {},
+            Err(reason) => {
+                println!("Resigning game '{}': {}", game_id, reason);
+                game_api.resign(game_id.clone(), my_player_id.clone()).await?;
+            },
+        }
+    }
+}
+
+/// Ok once a move went through. Err if the bot gave up its turn, and should resign.
+async fn take_turn(
+    game_api: &mut Box<dyn GameApi2<ClientGameError>>,
+    bot: &mut BotProcess,
+    game_id: &str,
+    my_player_id: &str,
+    game_state: &GameState,
+    move_time: Duration,
+) -> Result<(), String> {
+    let deadline = Instant::now() + move_time;
+    for line in protocol::game_state(game_state) {
+        send(bot, &line).await?;
+    }
+    send(bot, &protocol::go(move_time.as_millis() as u64)).await?;
+
+    let mut illegal_moves = 0;
+    loop {
+        let bot_move = match wait_for_move(bot, deadline).await {
+            Ok(bot_move) => bot_move,
+            Err(BotProcessError::Timeout) => return Err("Bot ran out of time".to_owned()),
+            Err(e) => return Err(e.to_string()),
+        };
+
+        let rejection = match bot_move.and_then(|(card, target, draw_pile)| {
+            validate_move(game_state, &card, target, draw_pile).map(|_| (card, target, draw_pile))
+        }) {
+            Err(reason) => Some(reason),
+            Ok((card, target, draw_pile)) => {
+                let play = Play::new(game_id.to_owned(), my_player_id.to_owned(), card, target, draw_pile);
+                game_api.play_card(play).await
+                    .err()
+                    .map(|e| format!("Server rejected move: {}", e))
+            },
+        };
+
+        match rejection {
+            None => return Ok(()),
+            Some(reason) => {
+                illegal_moves += 1;
+                if illegal_moves >= MAX_ILLEGAL_MOVES {
+                    return Err(format!("Bot made {} illegal moves", illegal_moves));
+                }
+                send(bot, &protocol::illegal(&reason)).await?;
+            },
+        }
+    }
+}
+
+async fn send(bot: &mut BotProcess, line: &str) -> Result<(), String> {
+    bot.send(line).await.map_err(|e| e.to_string())
+}
+
+/// Wait for the bot's next move. A line that doesn't parse counts as an (illegal) move too, so
+/// the inner Err is the reason to tell the bot.
+async fn wait_for_move(
+    bot: &mut BotProcess,
+    deadline: Instant,
+) -> Result<Result<(Card, CardTarget, DrawPile), String>, BotProcessError> {
+    loop {
+        let line = bot.receive(deadline.saturating_duration_since(Instant::now())).await?;
+        match protocol::parse_bot_message(&line) {
+            Ok(BotMessage::Move(card, target, draw_pile)) => return Ok(Ok((card, target, draw_pile))),
+            Ok(_) => continue,
+            Err(message) => return Ok(Err(message)),
+        }
+    }
+}
+
+/// Catch the obvious mistakes before bothering the server, so the bot gets a useful reason.
+fn validate_move(game_state: &GameState, card: &Card, target: CardTarget, draw_pile: DrawPile) -> Result<(), String> {
+    let decorated_card = game_state.my_hand()
+        .iter()
+        .find(|decorated_card| decorated_card.card() == card)
+        .ok_or_else(|| format!("{} is not in your hand", protocol::card_to_string(card)))?;
+
+    if target == CardTarget::Player && !*decorated_card.is_playable() {
+        return Err(format!("{} is lower than your expedition", protocol::card_to_string(card)));
+    }
+
+    if let DrawPile::Neutral(color) = draw_pile {
+        if !game_state.game_board().neutral_draw_pile().contains_key(&color) {
+            return Err("That discard pile is empty".to_owned());
+        }
+        if target == CardTarget::Neutral && color == *card.card_color() {
+            return Err("Can't draw the card you just discarded".to_owned());
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,3 @@
+pub mod bot_process;
+pub mod bridge;
+pub mod protocol;
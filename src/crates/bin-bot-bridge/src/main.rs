@@ -0,0 +1,151 @@
+use bin_bot_bridge::bot_process::BotProcess;
+use bin_bot_bridge::bridge;
+use bin_bot_bridge::protocol;
+use client_engine::client_game_api::error::ClientGameError;
+use client_engine::client_game_api::provider;
+use game_api::api::GameApi2;
+use game_api::types::{GameStatus, Opponent, RuleSet};
+use std::error::Error;
+use std::time::Duration;
+use std::{env, process};
+use tokio::time;
+
+/// How often to check whether someone joined the game we're hosting.
+const JOIN_POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Which game the bot should play.
+enum GameChoice {
+    Host,
+    Join(/* GameId */ String),
+}
+
+struct CliArgs {
+    hostname: String,
+    port: u16,
+    player_id: String,
+    game_choice: GameChoice,
+    move_time: Duration,
+    bot_command: String,
+    bot_args: Vec<String>,
+}
+
+/// Log into the server as a player, and let a bot in a child process play one game for it.
+/// See the `protocol` module for how to talk to the bridge.
+#[tokio::main]
+async fn main() {
+    let (program_name, cli_args) = get_cli_args();
+
+    let mut game_api = provider::new_frontend_game_api(cli_args.hostname.clone(), cli_args.port)
+        .await
+        .unwrap_or_else(|e| {
+            if let Some(e @ ClientGameError::UpgradeRequired(_)) = e.downcast_ref::<ClientGameError>() {
+                eprintln!("ERROR: {}", e);
+                process::exit(1);
+            }
+            eprintln!("ERROR: {:?}", e);
+            eprintln!();
+            eprintln!("Failed to connect to the server. Are you sure you entered the right hostname? Is the server up?");
+            print_usage_exit(&program_name);
+        });
+
+    let mut bot = BotProcess::spawn(&cli_args.bot_command, &cli_args.bot_args).unwrap_or_else(|e| {
+        eprintln!("Failed to start bot '{}': {}", cli_args.bot_command, e);
+        process::exit(1);
+    });
+
+    if let Err(e) = run(&mut game_api, &mut bot, &cli_args).await {
+        eprintln!("ERROR: {}", e);
+        let _ = bot.send(protocol::QUIT).await;
+        process::exit(1);
+    }
+    let _ = bot.send(protocol::QUIT).await;
+}
+
+async fn run(
+    game_api: &mut Box<dyn GameApi2<ClientGameError>>,
+    bot: &mut BotProcess,
+    cli_args: &CliArgs,
+) -> Result<(), Box<dyn Error>> {
+    bridge::handshake(bot).await?;
+
+    let game_id = choose_game(game_api, &cli_args.player_id, &cli_args.game_choice).await?;
+    println!("Playing game '{}'", game_id);
+
+    let game_state = bridge::play_game(game_api, bot, game_id, cli_args.player_id.clone(), cli_args.move_time).await?;
+    if let GameStatus::Complete(result, reason) = game_state.status() {
+        println!("Game over: {:?} ({:?})", result, reason);
+    }
+
+    Ok(())
+}
+
+/// Return the ID of a matched game to play.
+async fn choose_game(
+    game_api: &mut Box<dyn GameApi2<ClientGameError>>,
+    player_id: &str,
+    game_choice: &GameChoice,
+) -> Result<String, Box<dyn Error>> {
+    match game_choice {
+        GameChoice::Host => {
+            let game_id = format!("{:x}", rand::random::<u128>());
+            game_api.host_game(game_id.clone(), player_id.to_owned(), None, Opponent::Human, RuleSet::standard()).await?;
+            println!("Created Game ID = '{}'. Waiting for player to join...", game_id);
+
+            while game_api.describe_game(game_id.clone()).await?.matched_data().is_none() {
+                time::delay_for(JOIN_POLL_INTERVAL).await;
+            }
+
+            Ok(game_id)
+        },
+        GameChoice::Join(game_id) => {
+            game_api.join_game(game_id.clone(), player_id.to_owned()).await?;
+
+            Ok(game_id.clone())
+        },
+    }
+}
+
+fn get_cli_args() -> (String, CliArgs) {
+    let mut cli_args = env::args();
+
+    // Arg 0
+    let program_name = cli_args.next().unwrap_or_else(|| {
+        eprintln!("Program name is somehow missing? You should never see this.");
+        process::exit(1);
+    });
+
+    // Args 1-5
+    let mut next_arg = || cli_args.next().unwrap_or_else(|| print_usage_exit(&program_name));
+    let hostname = next_arg();
+    let port = next_arg().parse().unwrap_or_else(|_| print_usage_exit(&program_name));
+    let player_id = next_arg();
+    let game_choice = match next_arg().as_str() {
+        "host" => GameChoice::Host,
+        game_id => GameChoice::Join(game_id.to_owned()),
+    };
+    let move_time = next_arg().parse()
+        .map(Duration::from_millis)
+        .unwrap_or_else(|_| print_usage_exit(&program_name));
+
+    // Arg 6..
+    let bot_command = next_arg();
+    let bot_args = cli_args.collect();
+
+    (program_name, CliArgs {
+        hostname,
+        port,
+        player_id,
+        game_choice,
+        move_time,
+        bot_command,
+        bot_args,
+    })
+}
+
+fn print_usage_exit(program_name: &str) -> ! {
+    eprintln!();
+    eprintln!("Usage:  \t{} <server hostname> <port> <player id> <host|game id> <move time ms> <bot command> [bot args...]", program_name);
+    eprintln!("Example:\t{} localhost 8051 pybot host 5000 python3 my_bot.py", program_name);
+    eprintln!();
+    process::exit(1);
+}
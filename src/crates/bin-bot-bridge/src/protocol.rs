@@ -0,0 +1,319 @@
+//! == Lost Cities Interface (LCI) ==
+//!
+//! A line-based text protocol for bots that run as a separate process, loosely modeled after the
+//! UCI protocol used by chess engines. The bridge writes to the bot's stdin and reads from its
+//! stdout, one command per line. Bots can be written in any language that can do that.
+//!
+//! **Cards** are a color letter followed by a value: `R`ed, `G`reen, `W`hite, `B`lue, `Y`ellow,
+//! `P`urple (six color games only), and `w` (wager), `2`..`10`. E.g. `Rw`, `G7`, `B10`.
+//!
+//! **Bridge -> bot**
+//!
+//! * `lci` - Sent once at startup. The bot may answer with any number of `id ...` lines, and then
+//!   must answer `lciok`.
+//! * `newgame <game id> <opponent id>` - A game is starting.
+//! * `hand <card> <card> ...` - My hand.
+//! * `myplays <color>:<value>,<value>... ...` - My expeditions, e.g. `myplays R:w,3,5 G:2`.
+//! * `opplays ...` - My opponent's expeditions, same format as `myplays`.
+//! * `discards <color>:<top value>/<pile size> ...` - The top of each discard pile, e.g. `discards B:7/2`.
+//! * `deck <cards remaining>` - Size of the main draw pile.
+//! * `score <my score> <opponent's score>`
+//! * `go <milliseconds>` - It's my turn. The lines above describe the whole game state as of now,
+//!   and the bot has this long to answer with a `move`, or it forfeits.
+//! * `illegal <reason>` - The last move was rejected. The game state hasn't changed; answer with
+//!   another `move`.
+//! * `gameover <win|lose|draw> <my score> <opponent's score>` - The game is over.
+//! * `quit` - The bot should exit.
+//!
+//! **Bot -> bridge**
+//!
+//! * `id <anything>` - E.g. `id name Bot McBotface`. Only logged.
+//! * `lciok` - Done starting up.
+//! * `info <anything>` - Only logged.
+//! * `move <card> <play|discard> <deck|color letter>` - Play or discard a card, then draw from
+//!   the main draw pile or the discard pile of that color, e.g. `move R5 play deck` or
+//!   `move B2 discard G`.
+//!
+//! Colors are always listed in the order above, and empty sections are sent as just the
+//! keyword, e.g. `opplays`.
+use game_api::types::{Card, CardColor, CardTarget, CardValue, DrawPile, GameResult, GameState, GameStatus};
+use std::collections::HashMap;
+use std::convert::TryFrom;
+
+pub const HANDSHAKE: &str = "lci";
+pub const QUIT: &str = "quit";
+
+const COLORS: [CardColor; 6] = [
+    CardColor::Red,
+    CardColor::Green,
+    CardColor::White,
+    CardColor::Blue,
+    CardColor::Yellow,
+    CardColor::Purple,
+];
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum BotMessage {
+    Id(String),
+    Ready,
+    Info(String),
+    Move(Card, CardTarget, DrawPile),
+}
+
+pub fn new_game(game_id: &str, op_player_id: &str) -> String {
+    format!("newgame {} {}", game_id, op_player_id)
+}
+
+/// Everything the bot may know about the game, one line per section.
+pub fn game_state(game_state: &GameState) -> Vec<String> {
+    let board = game_state.game_board();
+    let mut hand: Vec<Card> = game_state.my_hand()
+        .iter()
+        .map(|decorated_card| *decorated_card.card())
+        .collect();
+    hand.sort_by_key(|card| (color_index(card.card_color()), *card.card_value()));
+
+    let discards = COLORS.iter()
+        .filter_map(|color| board.neutral_draw_pile()
+            .get(color)
+            .map(|(top_value, size)| format!("{}:{}/{}", color_to_string(color), value_to_string(top_value), size)))
+        .collect();
+
+    vec![
+        section("hand", hand.iter().map(card_to_string).collect()),
+        section("myplays", plays_to_strings(board.my_plays())),
+        section("opplays", plays_to_strings(board.op_plays())),
+        section("discards", discards),
+        format!("deck {}", board.draw_pile_cards_remaining()),
+        format!("score {} {}", board.my_score_total(), board.op_score_total()),
+    ]
+}
+
+pub fn go(move_time_ms: u64) -> String {
+    format!("go {}", move_time_ms)
+}
+
+pub fn illegal(reason: &str) -> String {
+    format!("illegal {}", reason)
+}
+
+/// None if the game isn't over yet.
+pub fn game_over(game_state: &GameState) -> Option<String> {
+    match game_state.status() {
+        GameStatus::InProgress(_) => None,
+        GameStatus::Complete(result, _) => {
+            let result = match result {
+                GameResult::Win => "win",
+                GameResult::Lose => "lose",
+                GameResult::Draw => "draw",
+            };
+            let board = game_state.game_board();
+            Some(format!("gameover {} {} {}", result, board.my_score_total(), board.op_score_total()))
+        },
+    }
+}
+
+pub fn parse_bot_message(line: &str) -> Result<BotMessage, String> {
+    let line = line.trim();
+    let (command, rest) = match line.find(' ') {
+        Some(index) => (&line[..index], line[index + 1..].trim()),
+        None => (line, ""),
+    };
+
+    match command {
+        "id" => Ok(BotMessage::Id(rest.to_owned())),
+        "lciok" => Ok(BotMessage::Ready),
+        "info" => Ok(BotMessage::Info(rest.to_owned())),
+        "move" => parse_move(rest),
+        _ => Err(format!("Unknown command '{}'", command)),
+    }
+}
+
+fn parse_move(args: &str) -> Result<BotMessage, String> {
+    let args: Vec<&str> = args.split_whitespace().collect();
+    if args.len() != 3 {
+        return Err(format!("Expected 'move <card> <play|discard> <deck|color>', got {} arguments", args.len()));
+    }
+
+    let card = parse_card(args[0])?;
+    let target = match args[1] {
+        "play" => CardTarget::Player,
+        "discard" => CardTarget::Neutral,
+        target => return Err(format!("Illegal target '{}'", target)),
+    };
+    let draw_pile = match args[2] {
+        "deck" => DrawPile::Main,
+        color => DrawPile::Neutral(parse_color(color)?),
+    };
+
+    Ok(BotMessage::Move(card, target, draw_pile))
+}
+
+pub fn card_to_string(card: &Card) -> String {
+    format!("{}{}", color_to_string(card.card_color()), value_to_string(card.card_value()))
+}
+
+pub fn parse_card(card_str: &str) -> Result<Card, String> {
+    if card_str.len() < 2 || !card_str.is_char_boundary(1) {
+        return Err(format!("Illegal card '{}'", card_str));
+    }
+    let (color_str, value_str) = card_str.split_at(1);
+
+    let value = if value_str == "w" {
+        CardValue::Wager
+    } else {
+        value_str.parse::<u32>()
+            .ok()
+            .filter(|value| *value >= 2)
+            .and_then(|value| CardValue::try_from(value).ok())
+            .ok_or_else(|| format!("Illegal card value in '{}'", card_str))?
+    };
+
+    Ok(Card::new(parse_color(color_str)?, value))
+}
+
+fn parse_color(color_str: &str) -> Result<CardColor, String> {
+    COLORS.iter()
+        .find(|color| color_to_string(color) == color_str)
+        .copied()
+        .ok_or_else(|| format!("Illegal color '{}'", color_str))
+}
+
+fn section(keyword: &str, items: Vec<String>) -> String {
+    if items.is_empty() {
+        keyword.to_owned()
+    } else {
+        format!("{} {}", keyword, items.join(" "))
+    }
+}
+
+fn plays_to_strings(plays: &HashMap<CardColor, Vec<CardValue>>) -> Vec<String> {
+    COLORS.iter()
+        .filter_map(|color| plays.get(color)
+            .filter(|column| !column.is_empty())
+            .map(|column| {
+                let values: Vec<&str> = column.iter().map(value_to_string).collect();
+                format!("{}:{}", color_to_string(color), values.join(","))
+            }))
+        .collect()
+}
+
+fn color_index(color: &CardColor) -> usize {
+    COLORS.iter().position(|c| c == color).unwrap_or(COLORS.len())
+}
+
+fn color_to_string(color: &CardColor) -> &'static str {
+    match color {
+        CardColor::Red => "R",
+        CardColor::Green => "G",
+        CardColor::White => "W",
+        CardColor::Blue => "B",
+        CardColor::Yellow => "Y",
+        CardColor::Purple => "P",
+    }
+}
+
+fn value_to_string(value: &CardValue) -> &'static str {
+    match value {
+        CardValue::Wager => "w",
+        CardValue::Two => "2",
+        CardValue::Three => "3",
+        CardValue::Four => "4",
+        CardValue::Five => "5",
+        CardValue::Six => "6",
+        CardValue::Seven => "7",
+        CardValue::Eight => "8",
+        CardValue::Nine => "9",
+        CardValue::Ten => "10",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use game_api::types::{DecoratedCard, EndReason, GameBoard};
+
+    fn card(color: CardColor, value: u32) -> Card {
+        Card::new(color, CardValue::try_from(value).unwrap())
+    }
+
+    #[test]
+    fn describes_game_state() {
+        let mut my_plays = HashMap::new();
+        my_plays.insert(CardColor::Blue, vec![CardValue::Wager, CardValue::Three]);
+        my_plays.insert(CardColor::Red, vec![CardValue::Ten]);
+        let mut neutral_draw_pile = HashMap::new();
+        neutral_draw_pile.insert(CardColor::Green, (CardValue::Seven, 2));
+        let game_state = GameState::new(
+            GameBoard::new(my_plays, HashMap::new(), -14, 0, HashMap::new(), HashMap::new(), neutral_draw_pile, 31),
+            vec![
+                DecoratedCard::new(card(CardColor::Yellow, 4), true),
+                DecoratedCard::new(card(CardColor::Red, 1), false),
+            ],
+            GameStatus::InProgress(true),
+            None,
+            None,
+            None,
+            vec![],
+        );
+
+        assert_eq!(super::game_state(&game_state), vec![
+            "hand Rw Y4",
+            "myplays R:10 B:w,3",
+            "opplays",
+            "discards G:7/2",
+            "deck 31",
+            "score -14 0",
+        ]);
+        assert_eq!(game_over(&game_state), None);
+    }
+
+    #[test]
+    fn describes_game_over() {
+        let game_state = GameState::new(
+            GameBoard::new(HashMap::new(), HashMap::new(), 12, 20, HashMap::new(), HashMap::new(), HashMap::new(), 0),
+            vec![],
+            GameStatus::Complete(GameResult::Lose, EndReason::DeckExhausted),
+            None,
+            None,
+            None,
+            vec![],
+        );
+
+        assert_eq!(game_over(&game_state), Some("gameover lose 12 20".to_owned()));
+    }
+
+    #[test]
+    fn parses_moves() {
+        assert_eq!(
+            parse_bot_message("move R5 play deck"),
+            Ok(BotMessage::Move(card(CardColor::Red, 5), CardTarget::Player, DrawPile::Main))
+        );
+        assert_eq!(
+            parse_bot_message("  move Bw discard G\r"),
+            Ok(BotMessage::Move(card(CardColor::Blue, 1), CardTarget::Neutral, DrawPile::Neutral(CardColor::Green)))
+        );
+        assert!(parse_bot_message("move R1 play deck").is_err());
+        assert!(parse_bot_message("move X5 play deck").is_err());
+        assert!(parse_bot_message("move R5 keep deck").is_err());
+        assert!(parse_bot_message("move R5 play").is_err());
+        assert!(parse_bot_message("bestmove R5").is_err());
+    }
+
+    #[test]
+    fn parses_other_messages() {
+        assert_eq!(parse_bot_message("lciok"), Ok(BotMessage::Ready));
+        assert_eq!(parse_bot_message("id name Foo Bar"), Ok(BotMessage::Id("name Foo Bar".to_owned())));
+        assert_eq!(parse_bot_message("info thinking"), Ok(BotMessage::Info("thinking".to_owned())));
+    }
+
+    #[test]
+    fn cards_round_trip() {
+        for color in COLORS.iter() {
+            for value in 1..=10 {
+                let card = card(*color, value);
+                assert_eq!(parse_card(&card_to_string(&card)), Ok(card));
+            }
+        }
+    }
+}
@@ -23,7 +23,8 @@ pub mod raw_cli {
 pub mod smart_cli {
     use super::raw_cli::prompt_for_input;
     use std::borrow::Cow;
-    use game_api::types::{CardTarget, Card, DrawPile, CardColor, DecoratedCard};
+    use game_api::types::{CardTarget, Card, DrawPile, CardColor, DecoratedCard, TimeControl, Difficulty, RuleSet};
+    use rules::engine;
 
     pub type PromptResult<T> = Result<T, Cow<'static, str>>;
 
@@ -31,20 +32,157 @@ pub mod smart_cli {
         Ok(prompt_for_input("Please enter your name: "))
     }
 
-    pub fn prompt_for_card(hand: &Vec<DecoratedCard>) -> PromptResult<&DecoratedCard> {
-        let cli_hand_index = prompt_for_input("[1/3] Which card would you like to play? (press 1-8 to select card)");
+    pub fn prompt_for_difficulty() -> PromptResult<Difficulty> {
+        let cli_difficulty = prompt_for_input("How good should the computer be? (press: [E]asy [M]edium [H]ard)");
 
-        let hand_index: usize = cli_hand_index.parse().unwrap_or(100);
-        if hand_index > 8 || hand_index < 1 {
-            return Err(Cow::from("Please enter a number between 1 and 8."));
+        match cli_difficulty.to_lowercase().as_str() {
+            "e" => Ok(Difficulty::Easy),
+            "m" => Ok(Difficulty::Medium),
+            "h" => Ok(Difficulty::Hard),
+            _ => Err(Cow::from("Please press either 'e', 'm', or 'h'.")),
+        }
+    }
+
+    pub fn prompt_for_time_control() -> PromptResult<Option<TimeControl>> {
+        let cli_time_control = prompt_for_input("Time control? (press enter for none, 'm<seconds>' for a per-move clock, or 'b<seconds>' for a per-game time bank, e.g. 'm60' or 'b600')");
+        let cli_time_control = cli_time_control.to_lowercase();
+        if cli_time_control.is_empty() {
+            return Ok(None);
+        }
+
+        let (kind, seconds) = cli_time_control.split_at(1);
+        let seconds: u32 = match seconds.parse() {
+            Ok(seconds) if seconds > 0 => seconds,
+            _ => return Err(Cow::from("Please enter a positive number of seconds after 'm' or 'b'.")),
+        };
+
+        match kind {
+            "m" => Ok(Some(TimeControl::PerMove(seconds))),
+            "b" => Ok(Some(TimeControl::TimeBank(seconds))),
+            _ => Err(Cow::from("Please start with 'm' for a per-move clock or 'b' for a time bank.")),
+        }
+    }
+
+    /// House rules are entered as comma separated overrides of the standard rules.
+    pub fn prompt_for_rule_set() -> PromptResult<RuleSet> {
+        let cli_rule_set = prompt_for_input("Rules? (press enter for standard, or override any of 'h<hand size>', 'c<expedition cost>', 'w<multiplier per wager>', 'b<bonus threshold>', 'p<bonus points>', 'f' for a final turn, 'e' for the purple expedition, 'n<number of players>', e.g. 'h6,b7,f')");
+        let cli_rule_set = cli_rule_set.to_lowercase();
+
+        let standard = RuleSet::standard();
+        let mut hand_size = *standard.hand_size();
+        let mut expedition_cost = *standard.expedition_cost();
+        let mut multiplier_per_wager = *standard.multiplier_per_wager();
+        let mut bonus_threshold = *standard.bonus_threshold();
+        let mut bonus_points = *standard.bonus_points();
+        let mut final_turn = *standard.final_turn();
+        let mut purple = *standard.purple();
+        let mut num_players = *standard.num_players();
+
+        for rule in cli_rule_set.split(',').map(str::trim).filter(|rule| !rule.is_empty()) {
+            if rule == "f" {
+                final_turn = true;
+                continue;
+            }
+            if rule == "e" {
+                purple = true;
+                continue;
+            }
+
+            let (kind, number) = rule.split_at(1);
+            let number: u32 = number.parse()
+                .map_err(|_| Cow::from(format!("Please enter a number after '{}'.", kind)))?;
+
+            match kind {
+                "h" => hand_size = number,
+                "c" => expedition_cost = number,
+                "w" => multiplier_per_wager = number,
+                "b" => bonus_threshold = number,
+                "p" => bonus_points = number,
+                "n" => num_players = number,
+                _ => return Err(Cow::from(format!("Unknown rule '{}'. Please use 'h', 'c', 'w', 'b', 'p', 'f', 'e', or 'n'.", rule))),
+            }
         }
 
-        assert!(hand.len() == 8, "BUG hand was size {}, not expected size 8", hand.len());
+        let rule_set = RuleSet::new(hand_size, expedition_cost, multiplier_per_wager, bonus_threshold, bonus_points, final_turn, purple)
+            .with_num_players(num_players);
+        if !engine::is_valid_rule_set(&rule_set) {
+            return Err(Cow::from("Those rules can't be played. Hands must fit in the deck, 2 to 4 players can play, and the bonus threshold must be between 1 and 12 cards."));
+        }
+
+        Ok(rule_set)
+    }
+
+    /// Instead of picking a card, the player can also give up, offer a draw, or look at the
+    /// expedition outlook or the unseen cards before deciding.
+    pub enum CardChoice<'a> {
+        Card(&'a DecoratedCard),
+        Resign,
+        OfferDraw,
+        Outlook,
+        UnseenCards,
+    }
+
+    pub fn prompt_for_card(hand: &Vec<DecoratedCard>) -> PromptResult<CardChoice<'_>> {
+        let cli_hand_index = prompt_for_input(&format!("[1/3] Which card would you like to play? (press 1-{} to select card, [R]esign, offer a [D]raw, or show expedition [O]utlook or [U]nseen cards)", hand.len()));
+
+        match cli_hand_index.to_lowercase().as_str() {
+            "r" => return Ok(CardChoice::Resign),
+            "d" => return Ok(CardChoice::OfferDraw),
+            "o" => return Ok(CardChoice::Outlook),
+            "u" => return Ok(CardChoice::UnseenCards),
+            _ => {},
+        }
+
+        let hand_index: usize = cli_hand_index.parse().unwrap_or(100);
+        if hand_index > hand.len() || hand_index < 1 {
+            return Err(Cow::from(format!("Please enter a number between 1 and {}.", hand.len())));
+        }
 
         hand.get(hand_index - 1)
+            .map(CardChoice::Card)
             .ok_or_else(|| Cow::from(format!("Couldn't find card number '{:?}' in your hand. This is likely a bug.", hand_index)))
     }
 
+    pub fn prompt_confirm_resign() -> PromptResult<bool> {
+        let cli_y_n = prompt_for_input("Are you sure you want to resign? Your opponent will win the game. [y/n]");
+
+        match cli_y_n.to_lowercase().as_str() {
+            "y" => Ok(true),
+            "n" => Ok(false),
+            _ => Err(Cow::from("Please enter either 'y' to resign or 'n' to keep playing.")),
+        }
+    }
+
+    pub fn prompt_respond_to_draw_offer() -> PromptResult<bool> {
+        let cli_y_n = prompt_for_input("Your opponent offered a draw. Do you accept? [y/n]");
+
+        match cli_y_n.to_lowercase().as_str() {
+            "y" => Ok(true),
+            "n" => Ok(false),
+            _ => Err(Cow::from("Please enter either 'y' to accept the draw or 'n' to keep playing.")),
+        }
+    }
+
+    pub fn prompt_for_take_back() -> PromptResult<bool> {
+        let cli_input = prompt_for_input("Press [enter] to end your turn, or 'u' to ask your opponent to undo it.");
+
+        match cli_input.to_lowercase().as_str() {
+            "" => Ok(false),
+            "u" => Ok(true),
+            _ => Err(Cow::from("Please press either [enter] or 'u'.")),
+        }
+    }
+
+    pub fn prompt_respond_to_take_back() -> PromptResult<bool> {
+        let cli_y_n = prompt_for_input("Your opponent asked to take back their last move. Do you allow it? [y/n]");
+
+        match cli_y_n.to_lowercase().as_str() {
+            "y" => Ok(true),
+            "n" => Ok(false),
+            _ => Err(Cow::from("Please enter either 'y' to allow the take-back or 'n' to keep playing.")),
+        }
+    }
+
     pub fn prompt_for_card_target(card: &Card) -> PromptResult<CardTarget> {
         let cli_card_target = prompt_for_input(&format!("[2/3] Where would you like to play the {}? (press: [M]ain [D]iscard)", card));
 
@@ -55,17 +193,69 @@ pub mod smart_cli {
         }
     }
 
-    pub fn prompt_draw_pile() -> PromptResult<DrawPile> {
-        let cli_draw_pile = prompt_for_input("[3/3] Where would you like to draw your new card from? (press: [M]ain [R]ed [G]reen [W]hite [B]lue [Y]ellow)");
+    /// Only the colors in play can be drawn from.
+    pub fn prompt_draw_pile(colors: &[CardColor]) -> PromptResult<DrawPile> {
+        let color_options: Vec<String> = colors.iter()
+            .map(|color| {
+                let name = format!("{:?}", color);
+                let (key, rest) = name.split_at(1);
+                format!("[{}]{}", key, rest)
+            })
+            .collect();
+        let cli_draw_pile = prompt_for_input(&format!("[3/3] Where would you like to draw your new card from? (press: [M]ain {})", color_options.join(" ")));
+
+        let color = match cli_draw_pile.to_lowercase().as_str() {
+            "m" => return Ok(DrawPile::Main),
+            "r" => Some(CardColor::Red),
+            "g" => Some(CardColor::Green),
+            "w" => Some(CardColor::White),
+            "b" => Some(CardColor::Blue),
+            "y" => Some(CardColor::Yellow),
+            "p" => Some(CardColor::Purple),
+            _ => None,
+        };
+
+        match color {
+            Some(color) if colors.contains(&color) => Ok(DrawPile::Neutral(color)),
+            _ => {
+                let color_keys: String = colors.iter()
+                    .map(|color| format!("{:?}", color).to_lowercase().remove(0))
+                    .collect();
+                Err(Cow::from(format!("Please press either 'm' for main draw pile or '{}' for one of the colored discard piles.", color_keys)))
+            },
+        }
+    }
+
+    /// How to move through a replay. Turns are numbered from 1, with the deal as turn 0.
+    pub enum ReplayStep {
+        Next,
+        Previous,
+        Jump(usize),
+        SwitchSeat,
+        Quit,
+    }
+
+    pub fn prompt_for_replay_step() -> PromptResult<ReplayStep> {
+        let cli_step = prompt_for_input("Press [enter] for the next turn, 'p' for the previous turn, a turn number to jump to it, 's' to switch players, or 'q' to quit.");
+
+        match cli_step.to_lowercase().as_str() {
+            "" | "n" => Ok(ReplayStep::Next),
+            "p" => Ok(ReplayStep::Previous),
+            "s" => Ok(ReplayStep::SwitchSeat),
+            "q" => Ok(ReplayStep::Quit),
+            number => number.parse()
+                .map(ReplayStep::Jump)
+                .map_err(|_| Cow::from("Please press [enter], 'p', 's', 'q', or enter a turn number.")),
+        }
+    }
+
+    pub fn prompt_for_replay() -> PromptResult<bool> {
+        let cli_input = prompt_for_input("Press 'v' to view a replay of the game, or [enter] to go back to the main menu.");
 
-        match cli_draw_pile.to_lowercase().as_str() {
-            "m" => Ok(DrawPile::Main),
-            "r" => Ok(DrawPile::Neutral(CardColor::Red)),
-            "g" => Ok(DrawPile::Neutral(CardColor::Green)),
-            "w" => Ok(DrawPile::Neutral(CardColor::White)),
-            "b" => Ok(DrawPile::Neutral(CardColor::Blue)),
-            "y" => Ok(DrawPile::Neutral(CardColor::Yellow)),
-            _ => Err(Cow::from("Please press either 'm' for main draw pile or 'rgwby' for one of the colored discard piles.")),
+        match cli_input.to_lowercase().as_str() {
+            "" => Ok(false),
+            "v" => Ok(true),
+            _ => Err(Cow::from("Please press either 'v' or [enter].")),
         }
     }
 
@@ -1,3 +1,4 @@
+use client_engine::client_game_api::error::ClientGameError;
 use client_engine::client_game_api::provider;
 use bin_client::cli::smart_cli;
 use bin_client::screens::main_menu;
@@ -14,6 +15,10 @@ async fn main() {
     let mut game_api = provider::new_frontend_game_api(hostname, port)
         .await
         .unwrap_or_else(|e| {
+            if let Some(e @ ClientGameError::UpgradeRequired(_)) = e.downcast_ref::<ClientGameError>() {
+                eprintln!("ERROR: {}", e);
+                process::exit(1);
+            }
             eprintln!("ERROR: {:?}", e);
             eprintln!();
             eprintln!("Failed to connect to the server. Are you sure you entered the right hostname? Is the server up?");
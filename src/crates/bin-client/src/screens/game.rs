@@ -1,7 +1,10 @@
-use game_api::types::{GameState, Card, CardTarget, DrawPile, GameStatus, GameResult, Play};
+use game_api::types::{GameState, Card, CardTarget, DrawPile, GameStatus, GameResult, EndReason, DrawOffer, TakeBackRequest, Play, RuleSet};
 use crate::cli::smart_cli;
+use crate::cli::smart_cli::CardChoice;
+use crate::screens::{replay, results};
 use client_engine::client_game_api::error::ClientGameError;
 use game_api::api::GameApi2;
+use rules::scoring;
 use std::error::Error;
 use std::time::Duration;
 use std::thread;
@@ -43,7 +46,7 @@ pub async fn execute_game_loop(
         }
         Some((guest_player_id, status)) => {
             match status {
-                GameStatus::Complete(_) => {
+                GameStatus::Complete(_, _) => {
                     println!("Can't execute game loop of completed game!");
                     return Ok(());
                 },
@@ -70,6 +73,10 @@ pub async fn execute_game_loop(
     // This is kind of lame for flow control and printing. Oh well. :P
     let mut first_loop = true;
     let game_state = game_api.get_game_state(game_id.clone(), my_player_id.clone()).await?;
+    if *game_state.rule_set() != RuleSet::standard() {
+        println!("House rules: {}.", game_state.rule_set());
+        println!();
+    }
     if will_wait_on_first_loop(&game_state) {
         println!("{}", game_state);
         println!("-- {}'s turn --", op_player_id);
@@ -99,7 +106,7 @@ pub async fn execute_game_loop(
         println!("{}", game_state);
 
         // 2. Check for end game
-        if check_is_game_over_and_print_outcome(&game_state) {
+        if check_is_game_over_and_print_outcome(&game_state, &my_player_id, &op_player_id) {
             break;
         }
 
@@ -116,7 +123,7 @@ pub async fn execute_game_loop(
         let game_state = game_api.get_game_state(game_id.clone(), my_player_id.clone()).await?;
         println!();
         println!("{}", game_state);
-        if check_is_game_over_and_print_outcome(&game_state) {
+        if check_is_game_over_and_print_outcome(&game_state, &my_player_id, &op_player_id) {
             break;
         }
 
@@ -125,6 +132,11 @@ pub async fn execute_game_loop(
         }
     }
 
+    if prompt_loop_for_replay() {
+        let record = replay::load_game_record(game_api, game_id, my_player_id.clone()).await?;
+        replay::execute_replay_loop(&record, &my_player_id)?;
+    }
+
     println!("Thanks for playing! Goodbye.");
     Ok(())
 }
@@ -135,8 +147,45 @@ async fn turn_loop(
     my_player_id: &String,
     game_state: &GameState
 ) {
+    if let Some(TakeBackRequest::RequestedByOpponent) = game_state.take_back_request() {
+        let accept = prompt_loop_for_take_back_response();
+        match game_api.respond_to_take_back(game_id.clone(), my_player_id.clone(), accept).await {
+            // It's their turn again.
+            Ok(()) if accept => return,
+            Ok(()) => println!("You declined the take-back. Your turn."),
+            Err(e) => println!("Server responded with error: '{}'. Your turn.", e),
+        }
+    }
+
+    if let Some(DrawOffer::OfferedByOpponent) = game_state.draw_offer() {
+        let accept = prompt_loop_for_draw_response();
+        match game_api.respond_to_draw_offer(game_id.clone(), my_player_id.clone(), accept).await {
+            Ok(()) if accept => return,
+            Ok(()) => println!("You declined the draw. Let's keep playing."),
+            Err(e) => println!("Server responded with error: '{}'. Let's keep playing.", e),
+        }
+    }
+
     loop {
-        let (card, card_target, draw_pile) = get_next_play_from_cli(&game_state);
+        let (card, card_target, draw_pile) = match get_next_action_from_cli(&game_state) {
+            TurnAction::Play(card, card_target, draw_pile) => (card, card_target, draw_pile),
+            TurnAction::Resign => {
+                match game_api.resign(game_id.clone(), my_player_id.clone()).await {
+                    Ok(()) => return,
+                    Err(e) => {
+                        println!("Server responded with error: '{}'.", e);
+                        continue;
+                    }
+                }
+            },
+            TurnAction::OfferDraw => {
+                match game_api.offer_draw(game_id.clone(), my_player_id.clone()).await {
+                    Ok(()) => println!("You offered a draw. Your opponent can respond on their turn. Now finish your turn."),
+                    Err(e) => println!("Server responded with error: '{}'.", e),
+                }
+                continue;
+            },
+        };
         let play = Play::new(
             game_id.clone(),
             my_player_id.clone(),
@@ -146,15 +195,46 @@ async fn turn_loop(
         );
 
         if let Err(e) = game_api.play_card(play).await {
-            println!("Server responded with error: '{}'. Please try your turn again.", e);
+            match e {
+                ClientGameError::RuleViolation(rule_violation) => println!("You can't make that move. {}", rule_violation),
+                e => println!("Server responded with error: '{}'.", e),
+            }
+            println!();
+
+            // No point retrying if we ran out of time or the game otherwise ended under us.
+            if is_game_over(game_api, game_id, my_player_id).await {
+                break;
+            }
+
+            println!("Please try your turn again.");
             println!();
             continue;
         } else {
+            if prompt_loop_for_take_back() {
+                match game_api.request_take_back(game_id.clone(), my_player_id.clone()).await {
+                    Ok(()) => println!("You asked to take back your move. Your opponent will decide on their turn."),
+                    Err(e) => println!("Server responded with error: '{}'.", e),
+                }
+            }
             break;
         }
     }
 }
 
+async fn is_game_over(
+    game_api: &mut Box<dyn GameApi2<ClientGameError>>,
+    game_id: &str,
+    my_player_id: &str,
+) -> bool {
+    match game_api.get_game_state(game_id.to_owned(), my_player_id.to_owned()).await {
+        Ok(game_state) => match game_state.status() {
+            GameStatus::Complete(_, _) => true,
+            GameStatus::InProgress(_) => false,
+        },
+        Err(_) => false,
+    }
+}
+
 async fn wait_for_my_turn(
     game_api: &mut Box<dyn GameApi2<ClientGameError>>,
     game_id: String,
@@ -186,13 +266,72 @@ fn will_wait_on_first_loop(game_state: &GameState) -> bool {
     }
 }
 
-fn get_next_play_from_cli(game_state: &GameState) -> (&Card, CardTarget, DrawPile) {
+fn prompt_loop_for_draw_response() -> bool {
+    loop {
+        match smart_cli::prompt_respond_to_draw_offer() {
+            Ok(accept) => return accept,
+            Err(msg) => println!("{}", msg),
+        }
+    }
+}
+
+fn prompt_loop_for_take_back() -> bool {
+    loop {
+        match smart_cli::prompt_for_take_back() {
+            Ok(take_back) => return take_back,
+            Err(msg) => println!("{}", msg),
+        }
+    }
+}
+
+fn prompt_loop_for_replay() -> bool {
+    loop {
+        match smart_cli::prompt_for_replay() {
+            Ok(replay) => return replay,
+            Err(msg) => println!("{}", msg),
+        }
+    }
+}
+
+fn prompt_loop_for_take_back_response() -> bool {
+    loop {
+        match smart_cli::prompt_respond_to_take_back() {
+            Ok(accept) => return accept,
+            Err(msg) => println!("{}", msg),
+        }
+    }
+}
+
+enum TurnAction<'a> {
+    Play(&'a Card, CardTarget, DrawPile),
+    Resign,
+    OfferDraw,
+}
+
+fn get_next_action_from_cli(game_state: &GameState) -> TurnAction<'_> {
     loop {
         println!();
 
         // Card
         let decorated_card = match smart_cli::prompt_for_card(game_state.my_hand()) {
-            Ok(v) => v,
+            Ok(CardChoice::Card(v)) => v,
+            Ok(CardChoice::Resign) => match smart_cli::prompt_confirm_resign() {
+                Ok(true) => return TurnAction::Resign,
+                Ok(false) => continue,
+                Err(msg) => {
+                    println!("{}", msg);
+                    continue;
+                }
+            },
+            Ok(CardChoice::OfferDraw) => return TurnAction::OfferDraw,
+            Ok(CardChoice::Outlook) => {
+                println!("{}", draw_expedition_outlook(game_state));
+                continue;
+            },
+            Ok(CardChoice::UnseenCards) => {
+                println!("{}", game_state.draw_unseen_cards());
+                continue;
+            },
             Err(msg) => {
                 println!("{}", msg);
                 continue;
@@ -216,12 +355,17 @@ fn get_next_play_from_cli(game_state: &GameState) -> (&Card, CardTarget, DrawPil
             continue;
         }
 
-        // DrawPile
-        let draw_pile = match smart_cli::prompt_draw_pile() {
-            Ok(v) => v,
-            Err(msg) => {
-                println!("{}", msg);
-                continue;
+        // DrawPile. On the final turn there's nothing left to draw.
+        let draw_pile = if *game_state.game_board().draw_pile_cards_remaining() == 0 {
+            println!("This is the final turn, so you won't draw a card.");
+            DrawPile::Main
+        } else {
+            match smart_cli::prompt_draw_pile(game_state.rule_set().colors()) {
+                Ok(v) => v,
+                Err(msg) => {
+                    println!("{}", msg);
+                    continue;
+                }
             }
         };
         // This is also validated in backend, but to short-circuit well-behaving clients, we check here first.
@@ -239,7 +383,7 @@ fn get_next_play_from_cli(game_state: &GameState) -> (&Card, CardTarget, DrawPil
             match smart_cli::prompt_confirm_play(card_to_play, &card_target, &draw_pile) {
                 Ok(confirmed) => {
                     if confirmed {
-                        return (card_to_play, card_target, draw_pile);
+                        return TurnAction::Play(card_to_play, card_target, draw_pile);
                     } else {
                         // break inner loop, will continue in outer loop
                         break;
@@ -258,21 +402,76 @@ fn get_next_play_from_cli(game_state: &GameState) -> (&Card, CardTarget, DrawPil
     }
 }
 
-fn check_is_game_over_and_print_outcome(game_state: &GameState) -> bool {
+/// Optional panel, shown on request, with the projections from `rules::scoring` for each of my
+/// expeditions. Lines up with the columns of the board above it.
+fn draw_expedition_outlook(game_state: &GameState) -> String {
+    let outlooks = scoring::expedition_outlooks(game_state, game_state.game_board().opponents().len());
+    let colors = game_state.rule_set().colors();
+
+    let mut header_cells = Vec::with_capacity(colors.len());
+    let mut max_cells = Vec::with_capacity(colors.len());
+    let mut break_even_cells = Vec::with_capacity(colors.len());
+    let mut expected_cells = Vec::with_capacity(colors.len());
+    let mut verdict_cells = Vec::with_capacity(colors.len());
+    for color in colors.iter() {
+        let outlook = &outlooks[color];
+        let is_started = game_state.game_board().my_plays().get(color).is_some_and(|column| !column.is_empty());
+        let verdict = match (is_started, outlook.is_worth_it()) {
+            (false, true) => "start",
+            (false, false) => "skip",
+            (true, true) => "on track",
+            (true, false) => "at risk",
+        };
+
+        header_cells.push(format!("{:^11}", format!("{:?}", color)));
+        max_cells.push(format!("{:^11}", format!("max {}", outlook.max_score())));
+        break_even_cells.push(format!("{:^11}", format!("need {}", outlook.points_to_break_even())));
+        expected_cells.push(format!("{:^11}", format!("exp {:.0}", outlook.expected_score())));
+        verdict_cells.push(format!("{:^11}", verdict));
+    }
+
+    // Same width as the board: 11 wide columns, with a border between and around them.
+    let inner_width = colors.len() * 12 - 1;
+    let border = format!("+{}+", "-".repeat(inner_width));
+    [
+        border.clone(),
+        format!("| {:<width$}|", "Expedition outlook", width = inner_width - 1),
+        format!("|{}|", header_cells.join(" ")),
+        format!("|{}|", max_cells.join(" ")),
+        format!("|{}|", break_even_cells.join(" ")),
+        format!("|{}|", expected_cells.join(" ")),
+        format!("|{}|", verdict_cells.join(" ")),
+        border,
+    ].join("\n")
+}
+
+fn check_is_game_over_and_print_outcome(game_state: &GameState, my_player_id: &str, op_player_id: &str) -> bool {
     match game_state.status() {
         GameStatus::InProgress(_) => false,
-        GameStatus::Complete(result) => {
-            match result {
-                GameResult::Win => print!("Congratulations, you win! "),
-                GameResult::Lose => print!("Sorry, you lost. "),
-                GameResult::Draw => print!("It was a draw! How rare! "),
+        GameStatus::Complete(result, end_reason) => {
+            match (result, end_reason) {
+                (GameResult::Win, EndReason::Timeout) => print!("Your opponent ran out of time. You win! "),
+                (GameResult::Lose, EndReason::Timeout) => print!("You ran out of time. Sorry, you lost. "),
+                (GameResult::Win, EndReason::Resignation) => print!("Your opponent resigned. You win! "),
+                (GameResult::Lose, EndReason::Resignation) => print!("You resigned. Better luck next time. "),
+                (_, EndReason::DrawAgreed) => print!("You agreed to a draw. "),
+                (GameResult::Win, _) => print!("Congratulations, you win! "),
+                (GameResult::Lose, _) => print!("Sorry, you lost. "),
+                (GameResult::Draw, _) => print!("It was a draw! How rare! "),
             }
 
+            let op_score_totals: Vec<String> = game_state.game_board().opponents().iter()
+                .map(|opponent| opponent.score_total().to_string())
+                .collect();
             println!(
                 "Score: {} to {}",
                 game_state.game_board().my_score_total(),
-                game_state.game_board().op_score_total()
+                op_score_totals.join(" to ")
             );
+            // Older servers don't send a summary.
+            if let Some(summary) = game_state.summary() {
+                results::print_results(game_state, summary, my_player_id, op_player_id);
+            }
             return true;
         }
     }
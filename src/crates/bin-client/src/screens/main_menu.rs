@@ -2,9 +2,13 @@ use crate::cli::smart_cli;
 use crate::cli::raw_cli;
 use std::borrow::Cow;
 use crate::screens::game;
+use crate::screens::spectate;
+use crate::screens::match_series;
+use crate::screens::replay;
 use std::error::Error;
 use client_engine::client_game_api::error::ClientGameError;
 use game_api::api::GameApi2;
+use game_api::types::{TimeControl, Opponent, Difficulty, RuleSet};
 
 /// Layer of indirection to handle errors (and so we can easily use `?` syntax).
 ///
@@ -18,16 +22,31 @@ pub async fn handle_menu(
         MainMenuAction::HostGame => {
             // Create game
             let game_id = create_game_id();
-            game_api.host_game(game_id.clone(), player_id.clone()).await?;
+            let time_control = prompt_loop_for_time_control();
+            let rule_set = prompt_loop_for_rule_set();
+            game_api.host_game(game_id.clone(), player_id.clone(), time_control, Opponent::Human, rule_set).await?;
             println!("Created Game ID = '{}'", game_id);
 
-            // Poll for guest joining game
+            // Poll for guests joining game
             println!();
-            println!("Waiting for player to join...");
+            if *rule_set.num_players() > 2 {
+                println!("Waiting for {} players to join...", rule_set.num_players() - 1);
+            } else {
+                println!("Waiting for player to join...");
+            }
             game::wait_for_game_to_fill(&mut game_api, game_id.clone()).await?;
 
             game_id
         },
+        MainMenuAction::PlayComputer => {
+            let game_id = create_game_id();
+            let difficulty = prompt_loop_for_difficulty();
+            let time_control = prompt_loop_for_time_control();
+            let rule_set = prompt_loop_for_rule_set();
+            game_api.host_game(game_id.clone(), player_id.clone(), time_control, Opponent::Computer(difficulty), rule_set).await?;
+
+            game_id
+        },
         MainMenuAction::JoinGame(game_id) => {
             // Get game status
             let game_metadata = game_api.describe_game(game_id.clone()).await?;
@@ -40,8 +59,38 @@ pub async fn handle_menu(
             // Join game
             game_api.join_game(game_id.clone(), player_id.clone()).await?;
 
+            // Bigger games only start once every seat is taken.
+            if game_api.describe_game(game_id.clone()).await?.matched_data().is_none() {
+                println!("Waiting for the other players to join...");
+                game::wait_for_game_to_fill(game_api, game_id.clone()).await?;
+            }
+
             game_id
         },
+        MainMenuAction::HostMatch => {
+            let match_id = create_game_id();
+            let time_control = prompt_loop_for_time_control();
+            let rule_set = prompt_loop_for_rule_set();
+            match_series::host_match(game_api, match_id.clone(), player_id.clone(), time_control, rule_set).await?;
+            match_series::execute_match_loop(game_api, match_id, player_id).await?;
+            return Ok(());
+        },
+        MainMenuAction::JoinMatch(match_id) => {
+            game_api.join_match(match_id.clone(), player_id.clone()).await?;
+            match_series::execute_match_loop(game_api, match_id, player_id).await?;
+            return Ok(());
+        },
+        MainMenuAction::WatchGame => {
+            if let Some(game_id) = spectate::pick_game_to_watch(game_api, player_id.clone()).await? {
+                spectate::execute_spectate_loop(game_api, game_id, player_id).await?;
+            }
+            return Ok(());
+        },
+        MainMenuAction::ViewReplay(game_id_or_path) => {
+            let record = replay::load_game_record(game_api, game_id_or_path, player_id.clone()).await?;
+            replay::execute_replay_loop(&record, &player_id)?;
+            return Ok(());
+        },
         MainMenuAction::ReadRules => {
             println!("I haven't added this to the game yet. For now, go read https://github.com/fridge-dev/lost-cities-game/blob/master/rules.md");
             return Ok(());
@@ -60,7 +109,12 @@ fn create_game_id() -> String {
 
 enum MainMenuAction {
     HostGame,
+    PlayComputer,
     JoinGame(/* GameId */ String),
+    HostMatch,
+    JoinMatch(/* MatchId */ String),
+    WatchGame,
+    ViewReplay(/* GameId, or path to a notation file */ String),
     ReadRules,
 }
 
@@ -68,6 +122,10 @@ const MAIN_MENU_PROMPT: &str = "\n\
 What would you like to do? (press one of the following keys)\n\
 h => [h]ost new game\n\
 j => [j]oin existing game\n\
+c => play against the [c]omputer\n\
+m => play a best-of-three [m]atch\n\
+w => [w]atch a game in progress\n\
+v => [v]iew a replay of a finished game\n\
 r => [r]ead the rules\n\
 ";
 
@@ -90,13 +148,53 @@ fn prompt_loop() -> MainMenuAction {
     }
 }
 
+fn prompt_loop_for_time_control() -> Option<TimeControl> {
+    loop {
+        match smart_cli::prompt_for_time_control() {
+            Ok(time_control) => return time_control,
+            Err(msg) => println!("{}", msg),
+        }
+    }
+}
+
+fn prompt_loop_for_rule_set() -> RuleSet {
+    loop {
+        match smart_cli::prompt_for_rule_set() {
+            Ok(rule_set) => return rule_set,
+            Err(msg) => println!("{}", msg),
+        }
+    }
+}
+
+fn prompt_loop_for_difficulty() -> Difficulty {
+    loop {
+        match smart_cli::prompt_for_difficulty() {
+            Ok(difficulty) => return difficulty,
+            Err(msg) => println!("{}", msg),
+        }
+    }
+}
+
 fn prompt_for_main_menu_action() -> smart_cli::PromptResult<MainMenuAction> {
     let cli_host_or_join = raw_cli::prompt_for_input(MAIN_MENU_PROMPT);
     match cli_host_or_join.to_lowercase().as_str() {
         "h" => Ok(MainMenuAction::HostGame),
         "j" => Ok(MainMenuAction::JoinGame(raw_cli::prompt_for_input("Please enter the Game ID you'd like to join: "))),
+        "c" => Ok(MainMenuAction::PlayComputer),
+        "m" => prompt_for_match_action(),
+        "w" => Ok(MainMenuAction::WatchGame),
+        "v" => Ok(MainMenuAction::ViewReplay(raw_cli::prompt_for_input("Please enter the Game ID, or the path to a game notation file: "))),
         "r" => Ok(MainMenuAction::ReadRules),
-        _ => Err(Cow::from("Please press either 'h', 'j', or 'r'.")),
+        _ => Err(Cow::from("Please press either 'h', 'j', 'c', 'm', 'w', 'v', or 'r'.")),
+    }
+}
+
+fn prompt_for_match_action() -> smart_cli::PromptResult<MainMenuAction> {
+    let cli_match_id = raw_cli::prompt_for_input("Press 'h' to host a new match, or enter the Match ID you'd like to join: ");
+    match cli_match_id.to_lowercase().as_str() {
+        "" => Err(Cow::from("Please press 'h' or enter a Match ID.")),
+        "h" => Ok(MainMenuAction::HostMatch),
+        _ => Ok(MainMenuAction::JoinMatch(cli_match_id)),
     }
 }
 
@@ -0,0 +1,87 @@
+use game_api::types::{MatchState, MatchStatus, GameResult, GameStatus, TimeControl, RuleSet, DEFAULT_MATCH_ROUNDS};
+use game_api::api::GameApi2;
+use client_engine::client_game_api::error::ClientGameError;
+use crate::screens::game;
+use std::error::Error;
+use std::time::Duration;
+use std::thread;
+
+/// Host a match and return once someone has joined it.
+pub async fn host_match(
+    game_api: &mut Box<dyn GameApi2<ClientGameError>>,
+    match_id: String,
+    my_player_id: String,
+    time_control: Option<TimeControl>,
+    rule_set: RuleSet,
+) -> Result<(), Box<dyn Error>> {
+    game_api.host_match(match_id.clone(), my_player_id.clone(), DEFAULT_MATCH_ROUNDS, time_control, rule_set).await?;
+    println!("Created Match ID = '{}'", match_id);
+
+    println!();
+    println!("Waiting for player to join...");
+    loop {
+        let match_state = game_api.get_match_state(match_id.clone(), my_player_id.clone()).await?;
+        if let Some(op_player_id) = match_state.op_player_id() {
+            println!("Player '{}' joined. Let's play!", op_player_id);
+            return Ok(());
+        }
+
+        // Sleep 5 seconds
+        thread::sleep(Duration::new(5, 0));
+    }
+}
+
+/// Play every round of the match, one after the other. The backend deals the next round as
+/// soon as the previous one ends, so we just keep jumping into whatever the current round is.
+pub async fn execute_match_loop(
+    game_api: &mut Box<dyn GameApi2<ClientGameError>>,
+    match_id: String,
+    my_player_id: String,
+) -> Result<(), Box<dyn Error>> {
+    loop {
+        let match_state = game_api.get_match_state(match_id.clone(), my_player_id.clone()).await?;
+        println!();
+        println!("{}", match_state);
+
+        let round_game_id = match match_state.current_round() {
+            Some(round) => round.game_id().to_owned(),
+            None => {
+                print_match_outcome(&match_state);
+                return Ok(());
+            }
+        };
+
+        println!();
+        println!("-- Round {} of {} --", match_state.rounds().len(), match_state.num_rounds());
+        game::execute_game_loop(game_api, round_game_id.clone(), my_player_id.clone()).await?;
+
+        // If the round didn't actually finish (e.g. the game loop bailed out early), don't spin
+        // on it forever.
+        let match_state = game_api.get_match_state(match_id.clone(), my_player_id.clone()).await?;
+        if let Some(round) = match_state.rounds().iter().find(|round| round.game_id() == round_game_id) {
+            if let GameStatus::InProgress(_) = round.status() {
+                println!("Round {} is still in progress. Leaving the match.", match_state.rounds().len());
+                return Ok(());
+            }
+        }
+    }
+}
+
+fn print_match_outcome(match_state: &MatchState) {
+    match match_state.status() {
+        MatchStatus::Complete(GameResult::Win) => print!("Congratulations, you won the match! "),
+        MatchStatus::Complete(GameResult::Lose) => print!("Sorry, you lost the match. "),
+        MatchStatus::Complete(GameResult::Draw) => print!("The match was a draw! How rare! "),
+        // Shouldn't happen, since we wait for a guest before starting the match loop.
+        MatchStatus::Unmatched | MatchStatus::InProgress => {
+            println!("The match isn't over yet.");
+            return;
+        },
+    }
+
+    println!(
+        "Total score: {} to {}",
+        match_state.my_score_total(),
+        match_state.op_score_total()
+    );
+}
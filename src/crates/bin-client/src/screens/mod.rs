@@ -1,2 +1,6 @@
 pub mod game;
 pub mod main_menu;
+pub mod match_series;
+pub mod replay;
+pub mod results;
+pub mod spectate;
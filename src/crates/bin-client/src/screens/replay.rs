@@ -0,0 +1,270 @@
+use game_api::types::{GameState, GameBoard, OpponentBoard, DecoratedCard, GameStatus, GameResult, EndReason, CardTarget, DrawPile};
+use game_api::api::GameApi2;
+use client_engine::client_game_api::error::ClientGameError;
+use crate::cli::smart_cli;
+use crate::cli::smart_cli::ReplayStep;
+use rules::deck::DeckFactory;
+use rules::engine::{self, RulesState};
+use rules::notation::{self, GameRecord, NotationError, RecordedResult};
+use rules::{endgame, plays, scoring};
+use std::error::Error;
+use std::fs;
+use std::path::Path;
+
+/// Load a finished game from the server, or from a file in text notation if there's one at that
+/// path.
+pub async fn load_game_record(
+    game_api: &mut Box<dyn GameApi2<ClientGameError>>,
+    game_id_or_path: String,
+    my_player_id: String,
+) -> Result<GameRecord, Box<dyn Error>> {
+    let text = if Path::new(&game_id_or_path).is_file() {
+        fs::read_to_string(&game_id_or_path)?
+    } else {
+        game_api.export_game(game_id_or_path, my_player_id).await?
+    };
+
+    Ok(notation::parse(&text)?)
+}
+
+/// Step through the game one turn at a time, starting from the deal. The board is drawn from my
+/// side of the table, or the first seat's if I didn't play, and I can switch seats at any time.
+pub fn execute_replay_loop(record: &GameRecord, my_player_id: &str) -> Result<(), Box<dyn Error>> {
+    let mut viewer_seat = record.player_ids()
+        .iter()
+        .position(|player_id| player_id == my_player_id)
+        .unwrap_or(0);
+
+    // Every position of the game, from the deal to after the last turn.
+    let mut states = vec![notation::deal(record)?];
+    for (i, action) in record.actions().iter().enumerate() {
+        let state = engine::apply(states[i].clone(), action)
+            .map_err(|reason| NotationError::IllegalTurn(i + 1, reason))?;
+        states.push(state);
+    }
+
+    let last_turn = states.len() - 1;
+    let mut turn = 0;
+    println!();
+    println!("Replay of {}.", record.player_ids().join(" vs "));
+
+    loop {
+        print_turn(record, &states, turn, viewer_seat);
+
+        loop {
+            match smart_cli::prompt_for_replay_step() {
+                Ok(ReplayStep::Next) if turn == last_turn => println!("That was the last turn."),
+                Ok(ReplayStep::Next) => turn += 1,
+                Ok(ReplayStep::Previous) if turn == 0 => println!("This is the deal. There's nothing before it."),
+                Ok(ReplayStep::Previous) => turn -= 1,
+                Ok(ReplayStep::Jump(to_turn)) if to_turn > last_turn => println!("The game only lasted {} turns.", last_turn),
+                Ok(ReplayStep::Jump(to_turn)) => turn = to_turn,
+                Ok(ReplayStep::SwitchSeat) => viewer_seat = (viewer_seat + 1) % record.player_ids().len(),
+                Ok(ReplayStep::Quit) => return Ok(()),
+                Err(msg) => {
+                    println!("{}", msg);
+                    continue;
+                },
+            }
+            break;
+        }
+    }
+}
+
+fn print_turn(record: &GameRecord, states: &[RulesState], turn: usize, viewer_seat: usize) {
+    let player_ids = record.player_ids();
+    let last_turn = states.len() - 1;
+
+    println!();
+    println!("{}", view_game_state(&states[turn], viewer_seat, record, turn == last_turn));
+    println!();
+    println!("(Seen by '{}')", player_ids[viewer_seat]);
+
+    if turn == 0 {
+        println!("-- The deal. '{}' goes first. --", player_ids[*record.first_seat()]);
+    } else {
+        let action = &record.actions()[turn - 1];
+        let mover_seat = *states[turn - 1].turn();
+        let play = match action.target() {
+            CardTarget::Player => format!("played the {} on their expedition", action.card()),
+            CardTarget::Neutral => format!("discarded the {}", action.card()),
+        };
+        let draw = match action.draw_pile() {
+            DrawPile::Main if states[turn - 1].main_draw_pile().is_empty() => "It was the final turn, so they didn't draw.".to_owned(),
+            draw_pile => format!("Then they drew from the {}.", draw_pile),
+        };
+
+        println!("-- Turn {} of {}: '{}' {}. {} --", turn, last_turn, player_ids[mover_seat], play, draw);
+        print!("{}", action.card().draw_single());
+    }
+
+    if turn == last_turn {
+        println!("{}", describe_result(record));
+    }
+}
+
+fn describe_result(record: &GameRecord) -> String {
+    let player_ids = record.player_ids();
+    match record.result() {
+        RecordedResult::Scores(scores) => {
+            let scores: Vec<String> = player_ids.iter()
+                .zip(scores.iter())
+                .map(|(player_id, score)| format!("'{}' {}", player_id, score))
+                .collect();
+            format!("The deck ran out. Final score: {}", scores.join(", "))
+        },
+        RecordedResult::Resigned(seat) => format!("'{}' resigned.", player_ids[*seat]),
+        RecordedResult::TimedOut(seat) => format!("'{}' ran out of time.", player_ids[*seat]),
+        RecordedResult::DrawAgreed => "The players agreed to a draw.".to_owned(),
+        RecordedResult::Unfinished => "The game isn't over yet.".to_owned(),
+    }
+}
+
+/// What the viewer would have seen at this point of the game. Nothing is hidden in a replay, so
+/// the unseen cards are simply everything outside of the viewer's hand and the board.
+fn view_game_state(state: &RulesState, viewer_seat: usize, record: &GameRecord, is_last_turn: bool) -> GameState {
+    let rule_set = state.rule_set();
+    let num_seats = state.hands().len();
+
+    let mut opponents: Vec<OpponentBoard> = (1..num_seats)
+        .map(|offset| {
+            let op_plays = &state.plays()[(viewer_seat + offset) % num_seats];
+            let (op_score_total, op_score_per_color) = scoring::compute_score(op_plays, rule_set);
+            OpponentBoard::new(op_plays.to_owned(), op_score_total, op_score_per_color)
+        })
+        .collect();
+    let more_opponents = opponents.split_off(1);
+    let next_opponent = opponents.remove(0);
+
+    let neutral_draw_pile = state.neutral_draw_pile()
+        .iter()
+        .filter_map(|(color, values)| values.last().map(|top_value| (*color, (*top_value, values.len()))))
+        .collect();
+
+    let my_plays = &state.plays()[viewer_seat];
+    let (my_score_total, my_score_per_color) = scoring::compute_score(my_plays, rule_set);
+    let game_board = GameBoard::new(
+        my_plays.to_owned(),
+        next_opponent.plays().to_owned(),
+        my_score_total,
+        *next_opponent.score_total(),
+        my_score_per_color,
+        next_opponent.score_per_color().to_owned(),
+        neutral_draw_pile,
+        state.main_draw_pile().len(),
+    ).with_more_opponents(more_opponents);
+
+    let lost_or_won = |loser_seat: usize| if loser_seat == viewer_seat {
+        GameResult::Lose
+    } else {
+        GameResult::Win
+    };
+    let status = match record.result() {
+        RecordedResult::Resigned(seat) if is_last_turn => GameStatus::Complete(lost_or_won(*seat), EndReason::Resignation),
+        RecordedResult::TimedOut(seat) if is_last_turn => GameStatus::Complete(lost_or_won(*seat), EndReason::Timeout),
+        RecordedResult::DrawAgreed if is_last_turn => GameStatus::Complete(GameResult::Draw, EndReason::DrawAgreed),
+        _ => endgame::get_game_status(&game_board, viewer_seat == *state.turn(), engine::is_terminal(state)),
+    };
+
+    let my_hand = &state.hands()[viewer_seat];
+    let decorated_hand = my_hand.iter()
+        .map(|card| DecoratedCard::new(*card, plays::is_card_playable(card, my_plays)))
+        .collect();
+
+    let mut unseen_cards = DeckFactory::new(rule_set).unshuffled_deck().to_owned();
+    let played_cards = state.plays()
+        .iter()
+        .chain(std::iter::once(state.neutral_draw_pile()))
+        .flat_map(|piles| piles.iter().flat_map(|(color, values)| values.iter().map(move |value| (*color, *value))));
+    for (color, value) in played_cards {
+        remove_card(&mut unseen_cards, |card| *card.card_color() == color && *card.card_value() == value);
+    }
+    for seen_card in my_hand.iter() {
+        remove_card(&mut unseen_cards, |card| card == seen_card);
+    }
+    unseen_cards.sort();
+
+    GameState::new(game_board, decorated_hand, status, None, None, None, unseen_cards)
+        .with_rule_set(*rule_set)
+}
+
+fn remove_card<T, F: Fn(&T) -> bool>(cards: &mut Vec<T>, is_card: F) {
+    if let Some(index) = cards.iter().position(is_card) {
+        cards.remove(index);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use game_api::types::RuleSet;
+
+    #[test]
+    fn every_card_is_in_hand_on_the_board_or_unseen() {
+        let player_ids = vec!["mememe".to_owned(), "youyou".to_owned()];
+        let record = GameRecord::new(player_ids, 42, 0, RuleSet::standard(), RecordedResult::Unfinished, Vec::new());
+        let mut state = notation::deal(&record).unwrap();
+        for _ in 0..10 {
+            let action = engine::legal_actions(&state)[0];
+            state = engine::apply(state, &action).unwrap();
+        }
+
+        let game_state = view_game_state(&state, 1, &record, false);
+        let board = game_state.game_board();
+        let cards_on_board: usize = board.my_plays().values().chain(board.op_plays().values()).map(Vec::len).sum::<usize>()
+            + board.neutral_draw_pile().values().map(|(_, size)| size).sum::<usize>();
+
+        assert_eq!(game_state.my_hand().len() + cards_on_board + game_state.unseen_cards().len(), 60);
+        assert_eq!(game_state.unseen_cards().len(), state.main_draw_pile().len() + state.hands()[0].len());
+        assert_eq!(*game_state.status(), GameStatus::InProgress(false));
+    }
+
+    #[test]
+    fn resignation_only_shows_on_the_last_turn() {
+        let player_ids = vec!["mememe".to_owned(), "youyou".to_owned()];
+        let record = GameRecord::new(player_ids, 42, 0, RuleSet::standard(), RecordedResult::Resigned(1), Vec::new());
+        let state = notation::deal(&record).unwrap();
+
+        assert_eq!(*view_game_state(&state, 0, &record, true).status(), GameStatus::Complete(GameResult::Win, EndReason::Resignation));
+        assert_eq!(*view_game_state(&state, 1, &record, true).status(), GameStatus::Complete(GameResult::Lose, EndReason::Resignation));
+        assert_eq!(*view_game_state(&state, 0, &record, false).status(), GameStatus::InProgress(true));
+    }
+
+    #[test]
+    fn last_turn_of_a_game_played_out_is_complete() {
+        let player_ids = vec!["mememe".to_owned(), "youyou".to_owned()];
+        let record = GameRecord::new(player_ids, 7, 1, RuleSet::standard(), RecordedResult::Unfinished, Vec::new());
+        let mut state = notation::deal(&record).unwrap();
+        // The first legal action always draws from the main pile, so the deck runs out.
+        while !engine::is_terminal(&state) {
+            let action = engine::legal_actions(&state)[0];
+            state = engine::apply(state, &action).unwrap();
+        }
+
+        let game_state = view_game_state(&state, 0, &record, true);
+        assert_eq!(*game_state.game_board().draw_pile_cards_remaining(), 0);
+        match game_state.status() {
+            GameStatus::Complete(_, end_reason) => assert_eq!(*end_reason, EndReason::DeckExhausted),
+            other => panic!("Expected the game to be over, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn opponents_are_seated_after_the_viewer() {
+        let player_ids = vec!["mememe".to_owned(), "youyou".to_owned(), "themthem".to_owned()];
+        let record = GameRecord::new(player_ids, 42, 0, RuleSet::standard().with_num_players(3), RecordedResult::Unfinished, Vec::new());
+        let mut state = notation::deal(&record).unwrap();
+        // Everyone starts an expedition.
+        for _ in 0..3 {
+            let action = engine::legal_actions(&state)[0];
+            assert_eq!(*action.target(), CardTarget::Player);
+            state = engine::apply(state, &action).unwrap();
+        }
+
+        // The last seat is followed by the host.
+        let game_state = view_game_state(&state, 2, &record, false);
+        assert_eq!(game_state.game_board().opponents().len(), 2);
+        assert_eq!(game_state.game_board().op_plays(), &state.plays()[0]);
+        assert_eq!(*game_state.status(), GameStatus::InProgress(false));
+    }
+}
@@ -0,0 +1,117 @@
+use game_api::types::{GameState, GameSummary, EndReason, CardColor, Card};
+
+/// The end of game screen: how the game ended, everyone's score per expedition side by side, and
+/// the hands nobody got to play.
+pub fn print_results(game_state: &GameState, summary: &GameSummary, my_player_id: &str, op_player_id: &str) {
+    let player_labels = player_labels(summary.players().len(), my_player_id, op_player_id);
+
+    println!();
+    println!("========================= Results =========================");
+    println!("{}", describe_ending(summary));
+    println!();
+    println!("{}", draw_score_table(game_state.rule_set().colors(), summary, &player_labels));
+    println!();
+    println!("Final hands:");
+    for (player_summary, label) in summary.players().iter().zip(player_labels.iter()) {
+        println!("  {:<width$}  {}", label, describe_hand(player_summary.final_hand()), width = label_width(&player_labels));
+    }
+    println!("===========================================================");
+}
+
+/// The client only knows the first opponent's name. Anyone after them is numbered.
+fn player_labels(num_players: usize, my_player_id: &str, op_player_id: &str) -> Vec<String> {
+    let mut labels = vec![my_player_id.to_owned(), op_player_id.to_owned()];
+    for i in 2..num_players {
+        labels.push(format!("Opponent {}", i));
+    }
+    labels.truncate(num_players);
+    labels
+}
+
+fn label_width(player_labels: &[String]) -> usize {
+    player_labels.iter()
+        .map(String::len)
+        .max()
+        .unwrap_or(0)
+}
+
+fn describe_ending(summary: &GameSummary) -> String {
+    let ending = match summary.end_reason() {
+        EndReason::DeckExhausted => "The deck ran out",
+        EndReason::Timeout => "The game ended on time",
+        EndReason::Resignation => "The game ended by resignation",
+        EndReason::DrawAgreed => "The game ended in an agreed draw",
+    };
+    let length = match summary.duration_ms() {
+        Some(duration_ms) => format!(" and took {}:{:02}", duration_ms / 60_000, duration_ms / 1000 % 60),
+        None => String::new(),
+    };
+
+    format!("{} after {} turns{}.", ending, summary.num_turns(), length)
+}
+
+fn draw_score_table(colors: &[CardColor], summary: &GameSummary, player_labels: &[String]) -> String {
+    let width = label_width(player_labels);
+    let mut header = format!("{:<width$}", "", width = width);
+    for color in colors.iter() {
+        header.push_str(&format!(" {:>7}", format!("{:?}", color)));
+    }
+    header.push_str(&format!(" {:>7}", "Total"));
+
+    let mut rows = vec![header];
+    for (player_summary, label) in summary.players().iter().zip(player_labels.iter()) {
+        let mut row = format!("{:<width$}", label, width = width);
+        for color in colors.iter() {
+            let score = player_summary.score_per_color().get(color).copied().unwrap_or(0);
+            row.push_str(&format!(" {:>7}", score));
+        }
+        row.push_str(&format!(" {:>7}", player_summary.score_total()));
+        rows.push(row);
+    }
+
+    rows.join("\n")
+}
+
+fn describe_hand(hand: &[Card]) -> String {
+    if hand.is_empty() {
+        return "(empty)".to_owned();
+    }
+
+    hand.iter()
+        .map(Card::to_string)
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use game_api::types::{PlayerSummary, RuleSet, CardValue};
+    use std::collections::HashMap;
+    use std::convert::TryFrom;
+
+    #[test]
+    fn scores_are_side_by_side() {
+        let mut my_score_per_color = HashMap::new();
+        my_score_per_color.insert(CardColor::Red, 12);
+        my_score_per_color.insert(CardColor::Blue, -3);
+        let summary = GameSummary::new(
+            vec![
+                PlayerSummary::new(vec![], 9, my_score_per_color),
+                PlayerSummary::new(vec![Card::new(CardColor::White, CardValue::try_from(7).unwrap())], 0, HashMap::new()),
+            ],
+            44,
+            Some(754_000),
+            EndReason::DeckExhausted,
+        );
+        let labels = player_labels(2, "mememe", "youyou");
+
+        assert_eq!(describe_ending(&summary), "The deck ran out after 44 turns and took 12:34.");
+        assert_eq!(draw_score_table(RuleSet::standard().colors(), &summary, &labels).lines().collect::<Vec<&str>>(), vec![
+            "           Red   Green   White    Blue  Yellow   Total",
+            "mememe      12       0       0      -3       0       9",
+            "youyou       0       0       0       0       0       0",
+        ]);
+        assert_eq!(describe_hand(summary.players()[1].final_hand()), "White 7");
+    }
+}
@@ -0,0 +1,61 @@
+use game_api::types::SpectatorStatus;
+use game_api::api::GameApi2;
+use client_engine::client_game_api::error::ClientGameError;
+use crate::cli::raw_cli;
+use std::error::Error;
+use std::time::Duration;
+use std::thread;
+
+/// List the games that can be watched, and let the player pick one.
+///
+/// Returns None if there's nothing to watch.
+pub async fn pick_game_to_watch(
+    game_api: &mut Box<dyn GameApi2<ClientGameError>>,
+    my_player_id: String,
+) -> Result<Option<String>, Box<dyn Error>> {
+    let games = game_api.query_watchable_games(my_player_id).await?;
+    if games.is_empty() {
+        println!("There are no games in progress right now. Try again later.");
+        return Ok(None);
+    }
+
+    println!("Games in progress:");
+    for game_metadata in games.iter() {
+        if let Some((guest_player_id, _)) = game_metadata.matched_data() {
+            println!("  {} => '{}' vs '{}'", game_metadata.game_id(), game_metadata.host_player_id(), guest_player_id);
+        }
+    }
+    println!();
+
+    let game_id = raw_cli::prompt_for_input("Please enter the Game ID you'd like to watch: ");
+    Ok(Some(game_id))
+}
+
+/// Watch the game until it's over. Every seat is labeled by player, and whose turn it is (or who
+/// won) is shown under the board.
+pub async fn execute_spectate_loop(
+    game_api: &mut Box<dyn GameApi2<ClientGameError>>,
+    game_id: String,
+    my_player_id: String,
+) -> Result<(), Box<dyn Error>> {
+    let mut last_drawn_board = String::new();
+
+    loop {
+        let game_state = game_api.get_spectator_game_state(game_id.clone(), my_player_id.clone()).await?;
+
+        // Only redraw when something changed, otherwise the screen just scrolls forever.
+        let drawn_board = game_state.to_string();
+        if drawn_board != last_drawn_board {
+            println!();
+            println!("{}", drawn_board);
+            last_drawn_board = drawn_board;
+        }
+
+        if let SpectatorStatus::Complete(_, _) = game_state.status() {
+            return Ok(());
+        }
+
+        // Sleep 2 seconds. Same as when waiting on an opponent in game loop.
+        thread::sleep(Duration::new(2, 0));
+    }
+}
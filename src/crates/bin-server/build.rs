@@ -6,6 +6,7 @@ use std::io;
 ///
 /// If you change anything in lost_cities_wire.proto, then uncomment the line below, run
 /// `cargo build`, then `git add` the newly generated src file, then comment the line again.
+/// tonic-build runs rustfmt over every file in `src/wire_api/`, so `git checkout` the other ones.
 ///
 /// This isn't a necessarily happy end state, but I want to move on to solving other problems
 /// for now.
@@ -0,0 +1,149 @@
+use game_api::types::{GameMetadata, RuleSet};
+use game_api::wire_protocol;
+use tonic::{Request, Status};
+use tonic::metadata::MetadataMap;
+
+/// Runs before every RPC, so clients too old to understand this server are turned away with
+/// instructions, whichever RPC they call first.
+#[allow(clippy::result_large_err)] // tonic decides the signature.
+pub fn check_protocol_version(request: Request<()>) -> Result<Request<()>, Status> {
+    // Clients from before protocol versions don't send one. A version that doesn't parse isn't
+    // known to be incompatible either, so it gets the same benefit of the doubt.
+    let client_version = request.metadata()
+        .get(wire_protocol::PROTOCOL_VERSION_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(wire_protocol::LEGACY_PROTOCOL_VERSION);
+
+    match client_version_error(client_version, wire_protocol::MIN_CLIENT_PROTOCOL_VERSION) {
+        Some(status) => Err(status),
+        None => Ok(request),
+    }
+}
+
+/// The status to turn the client away with, if its version is one the server knows it can't talk
+/// to. Newer clients than this server are let through, since they're expected to still speak the
+/// older versions.
+pub fn client_version_error(client_version: u32, min_client_version: u32) -> Option<Status> {
+    if client_version >= min_client_version {
+        return None;
+    }
+
+    println!(
+        "INFO: Rejected a client that speaks protocol version {}. The oldest supported is {}.",
+        client_version,
+        min_client_version,
+    );
+    Some(Status::failed_precondition(wire_protocol::client_upgrade_message(client_version, min_client_version)))
+}
+
+/// What the client behind a request can handle, from the features it sends with every request.
+/// Clients from before features were sent have none of them.
+pub struct ClientFeatures {
+    features: Vec<String>,
+}
+
+impl ClientFeatures {
+    pub fn new(features: Vec<String>) -> Self {
+        ClientFeatures {
+            features,
+        }
+    }
+
+    pub fn from_metadata(metadata: &MetadataMap) -> Self {
+        let features = metadata.get(wire_protocol::PROTOCOL_FEATURES_HEADER)
+            .and_then(|value| value.to_str().ok())
+            .map(|value| value.split(',')
+                .filter(|feature| !feature.is_empty())
+                .map(str::to_owned)
+                .collect())
+            .unwrap_or_default();
+
+        ClientFeatures::new(features)
+    }
+
+    pub fn has(&self, feature: &str) -> bool {
+        self.features.iter().any(|client_feature| client_feature == feature)
+    }
+
+    /// Whether the client can decode everything in a game played by these rules.
+    pub fn can_show(&self, rule_set: &RuleSet) -> bool {
+        !*rule_set.purple() || self.has(wire_protocol::FEATURE_PURPLE)
+    }
+
+    /// Leave out games the client couldn't show, so it never offers them to the player.
+    pub fn showable(&self, games: Vec<GameMetadata>) -> Vec<GameMetadata> {
+        games.into_iter()
+            .filter(|game| self.can_show(game.rule_set()))
+            .collect()
+    }
+
+    /// The status to refuse a game with, if the client couldn't show it.
+    pub fn rule_set_error(&self, rule_set: &RuleSet) -> Option<Status> {
+        if !self.can_show(rule_set) {
+            println!("INFO: Refused a six color game to a client without the '{}' feature.", wire_protocol::FEATURE_PURPLE);
+            return Some(Status::failed_precondition(wire_protocol::missing_feature_message(wire_protocol::FEATURE_PURPLE)));
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tonic::Code;
+    use tonic::metadata::MetadataValue;
+
+    fn request_with_version(version: Option<&str>) -> Request<()> {
+        let mut request = Request::new(());
+        if let Some(version) = version {
+            request.metadata_mut().insert(wire_protocol::PROTOCOL_VERSION_HEADER, MetadataValue::from_str(version).unwrap());
+        }
+        request
+    }
+
+    #[test]
+    fn current_clients_get_through() {
+        let version = wire_protocol::PROTOCOL_VERSION.to_string();
+        assert!(check_protocol_version(request_with_version(Some(&version))).is_ok());
+    }
+
+    #[test]
+    fn clients_without_a_version_get_through() {
+        // Legacy clients.
+        assert!(check_protocol_version(request_with_version(None)).is_ok());
+        assert!(check_protocol_version(request_with_version(Some("not a number"))).is_ok());
+    }
+
+    #[test]
+    fn incompatible_clients_are_told_to_upgrade() {
+        let status = client_version_error(1, 2).unwrap();
+
+        assert_eq!(status.code(), Code::FailedPrecondition);
+        assert_eq!(status.message(), wire_protocol::client_upgrade_message(1, 2));
+        assert!(client_version_error(2, 2).is_none());
+        assert!(client_version_error(3, 2).is_none());
+    }
+
+    #[test]
+    fn six_color_games_need_the_purple_feature() {
+        let purple = RuleSet::new(8, 20, 1, 8, 20, false, true);
+        let mut metadata = MetadataMap::new();
+        metadata.insert(wire_protocol::PROTOCOL_FEATURES_HEADER, MetadataValue::from_str("some-future-feature").unwrap());
+        let old_client = ClientFeatures::from_metadata(&metadata);
+        let current_client = ClientFeatures::new(wire_protocol::FEATURES.iter().map(|feature| (*feature).to_owned()).collect());
+
+        assert!(old_client.has("some-future-feature"));
+        assert!(old_client.rule_set_error(&RuleSet::standard()).is_none());
+        let status = old_client.rule_set_error(&purple).unwrap();
+        assert_eq!(status.code(), Code::FailedPrecondition);
+        assert_eq!(status.message(), wire_protocol::missing_feature_message(wire_protocol::FEATURE_PURPLE));
+
+        assert!(current_client.rule_set_error(&purple).is_none());
+        // Clients from before features were sent can still play standard games.
+        let legacy_client = ClientFeatures::from_metadata(&MetadataMap::new());
+        assert!(legacy_client.rule_set_error(&RuleSet::standard()).is_none());
+        assert!(legacy_client.rule_set_error(&purple).is_some());
+    }
+}
@@ -0,0 +1,299 @@
+use chrono::{NaiveDate, TimeZone, Utc};
+use game_api::types::{CardColor, CardTarget, CardValue, DrawPile, RuleSet};
+use rules::engine;
+use rules::notation::{self, GameRecord, NotationError, RecordedResult};
+use rules::scoring;
+use std::error::Error;
+use std::io::Write;
+use storage::v2::completed_games;
+use storage::v2::config::DatabaseMode;
+use storage::v2::db_types::{DbGameData, DbGameSummary};
+
+const SECONDS_PER_DAY: u32 = 24 * 60 * 60;
+
+/// Which completed games to export. Dates are in UTC and both ends are inclusive.
+pub struct ExportFilter {
+    from_date: Option<NaiveDate>,
+    to_date: Option<NaiveDate>,
+    player_id: Option<String>,
+}
+
+impl ExportFilter {
+    pub fn all_games() -> Self {
+        ExportFilter {
+            from_date: None,
+            to_date: None,
+            player_id: None,
+        }
+    }
+
+    pub fn with_from_date(mut self, from_date: NaiveDate) -> Self {
+        self.from_date = Some(from_date);
+        self
+    }
+
+    pub fn with_to_date(mut self, to_date: NaiveDate) -> Self {
+        self.to_date = Some(to_date);
+        self
+    }
+
+    pub fn with_player_id(mut self, player_id: String) -> Self {
+        self.player_id = Some(player_id);
+        self
+    }
+
+    fn min_creation_time_sec(&self) -> u32 {
+        self.from_date
+            .map(seconds_since_epoch)
+            .unwrap_or(0)
+    }
+
+    fn max_creation_time_sec(&self) -> u32 {
+        self.to_date
+            .map(|date| seconds_since_epoch(date) + SECONDS_PER_DAY - 1)
+            .unwrap_or(u32::MAX)
+    }
+}
+
+/// Midnight UTC at the start of the date. Dates before 1970 are clamped to it.
+fn seconds_since_epoch(date: NaiveDate) -> u32 {
+    let epoch = NaiveDate::from_ymd_opt(1970, 1, 1).expect("The epoch is a valid date");
+    date.signed_duration_since(epoch).num_seconds().max(0) as u32
+}
+
+/// Write every completed game that passes the filter as JSON Lines, one game per line, oldest
+/// first. Returns how many games were written. Games that can't be read are skipped with a
+/// warning on stderr, since stdout is the export itself.
+pub fn export_completed_games<W: Write>(mode: &DatabaseMode, filter: &ExportFilter, out: &mut W) -> Result<usize, Box<dyn Error>> {
+    let games = completed_games::load_completed_games(
+        mode,
+        filter.min_creation_time_sec(),
+        filter.max_creation_time_sec(),
+        filter.player_id.as_deref(),
+    )?;
+
+    let mut num_exported = 0;
+    for (game_summary, game_data) in games {
+        match to_json_line(&game_summary, &game_data) {
+            Ok(json_line) => {
+                writeln!(out, "{}", json_line)?;
+                num_exported += 1;
+            },
+            Err(e) => eprintln!("WARN: Skipping game '{}': {}", game_summary.game_id, e),
+        }
+    }
+
+    Ok(num_exported)
+}
+
+/// The game data blob holds the game in text notation. Replaying it gives the final boards.
+pub(crate) fn to_json_line(game_summary: &DbGameSummary, game_data: &DbGameData) -> Result<String, Box<dyn Error>> {
+    let record = notation::parse(&String::from_utf8(game_data.game_data_blob.clone())?)?;
+    let player_ids = record.player_ids();
+
+    let mut state = notation::deal(&record)?;
+    let mut moves = Vec::with_capacity(record.actions().len());
+    for (i, action) in record.actions().iter().enumerate() {
+        let mover_seat = *state.turn();
+        // The final turn of a game played without the house rule doesn't draw at all.
+        let draw = match action.draw_pile() {
+            DrawPile::Main if state.main_draw_pile().is_empty() => "null".to_owned(),
+            DrawPile::Main => json_string("main"),
+            DrawPile::Neutral(color) => json_string(color_name(*color)),
+        };
+        moves.push(format!(
+            "{{\"turn\":{},\"player\":{},\"card\":{{\"color\":{},\"value\":{}}},\"play\":{},\"draw\":{}}}",
+            i + 1,
+            json_string(&player_ids[mover_seat]),
+            json_string(color_name(*action.card().card_color())),
+            json_string(value_name(*action.card().card_value())),
+            json_string(match action.target() {
+                CardTarget::Player => "expedition",
+                CardTarget::Neutral => "discard",
+            }),
+            draw,
+        ));
+        state = engine::apply(state, action)
+            .map_err(|reason| NotationError::IllegalTurn(i + 1, reason))?;
+    }
+
+    let rule_set = state.rule_set();
+    let boards: Vec<String> = player_ids.iter()
+        .zip(state.plays().iter())
+        .map(|(player_id, plays)| {
+            let (score_total, score_per_color) = scoring::compute_score(plays, rule_set);
+            let expeditions = json_object(rule_set.colors(), |color| {
+                let values: Vec<String> = plays.get(color)
+                    .map(|values| values.iter().map(|value| json_string(value_name(*value))).collect())
+                    .unwrap_or_default();
+                format!("[{}]", values.join(","))
+            });
+            let scores = json_object(rule_set.colors(), |color| {
+                score_per_color.get(color).copied().unwrap_or(0).to_string()
+            });
+            format!(
+                "{{\"player\":{},\"expeditions\":{},\"score\":{},\"score_per_color\":{}}}",
+                json_string(player_id),
+                expeditions,
+                score_total,
+                scores,
+            )
+        })
+        .collect();
+
+    let created_at = Utc.timestamp_opt(i64::from(game_summary.game_creation_time_sec), 0)
+        .single()
+        .map(|date_time| date_time.format("%Y-%m-%dT%H:%M:%SZ").to_string())
+        .ok_or("The game's creation time is out of range")?;
+    let players: Vec<String> = player_ids.iter().map(|player_id| json_string(player_id)).collect();
+
+    Ok(format!(
+        "{{\"game_id\":{},\"created_at\":{},\"players\":[{}],\"first_player\":{},\"seed\":{},\"rules\":{},\"result\":{},\"moves\":[{}],\"boards\":[{}]}}",
+        json_string(&game_summary.game_id),
+        json_string(&created_at),
+        players.join(","),
+        json_string(&player_ids[*record.first_seat()]),
+        record.seed(),
+        rules_json(record.rule_set()),
+        result_json(&record),
+        moves.join(","),
+        boards.join(","),
+    ))
+}
+
+fn rules_json(rule_set: &RuleSet) -> String {
+    format!(
+        "{{\"hand_size\":{},\"expedition_cost\":{},\"multiplier_per_wager\":{},\"bonus_threshold\":{},\"bonus_points\":{},\"final_turn\":{},\"purple\":{},\"num_players\":{}}}",
+        rule_set.hand_size(),
+        rule_set.expedition_cost(),
+        rule_set.multiplier_per_wager(),
+        rule_set.bonus_threshold(),
+        rule_set.bonus_points(),
+        rule_set.final_turn(),
+        rule_set.purple(),
+        rule_set.num_players(),
+    )
+}
+
+fn result_json(record: &GameRecord) -> String {
+    let player_ids = record.player_ids();
+    match record.result() {
+        RecordedResult::Scores(scores) => {
+            let scores: Vec<String> = scores.iter().map(i32::to_string).collect();
+            format!("{{\"kind\":\"deck_exhausted\",\"scores\":[{}]}}", scores.join(","))
+        },
+        RecordedResult::Resigned(seat) => format!("{{\"kind\":\"resigned\",\"player\":{}}}", json_string(&player_ids[*seat])),
+        RecordedResult::TimedOut(seat) => format!("{{\"kind\":\"timed_out\",\"player\":{}}}", json_string(&player_ids[*seat])),
+        RecordedResult::DrawAgreed => "{\"kind\":\"draw_agreed\"}".to_owned(),
+        RecordedResult::Unfinished => "{\"kind\":\"unfinished\"}".to_owned(),
+    }
+}
+
+/// An object keyed by color name, in the order the expeditions are laid out.
+fn json_object<F: Fn(&CardColor) -> String>(colors: &[CardColor], value_of: F) -> String {
+    let fields: Vec<String> = colors.iter()
+        .map(|color| format!("{}:{}", json_string(color_name(*color)), value_of(color)))
+        .collect();
+    format!("{{{}}}", fields.join(","))
+}
+
+fn json_string(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len() + 2);
+    escaped.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if (c as u32) < 0x20 => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+pub(crate) fn color_name(color: CardColor) -> &'static str {
+    match color {
+        CardColor::Red => "red",
+        CardColor::Green => "green",
+        CardColor::White => "white",
+        CardColor::Blue => "blue",
+        CardColor::Yellow => "yellow",
+        CardColor::Purple => "purple",
+    }
+}
+
+pub(crate) fn value_name(value: CardValue) -> &'static str {
+    match value {
+        CardValue::Wager => "wager",
+        CardValue::Two => "2",
+        CardValue::Three => "3",
+        CardValue::Four => "4",
+        CardValue::Five => "5",
+        CardValue::Six => "6",
+        CardValue::Seven => "7",
+        CardValue::Eight => "8",
+        CardValue::Nine => "9",
+        CardValue::Ten => "10",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rules::engine::RulesState;
+
+    fn played_out_record() -> GameRecord {
+        let player_ids = vec!["al\"ice".to_owned(), "bob".to_owned()];
+        let unplayed = GameRecord::new(player_ids.clone(), 7, 1, RuleSet::standard(), RecordedResult::Unfinished, Vec::new());
+        let mut state: RulesState = notation::deal(&unplayed).unwrap();
+        let mut actions = Vec::new();
+        while !engine::is_terminal(&state) {
+            let action = engine::legal_actions(&state)[0];
+            state = engine::apply(state, &action).unwrap();
+            actions.push(action);
+        }
+
+        GameRecord::new(player_ids, 7, 1, RuleSet::standard(), RecordedResult::Scores(notation::final_scores(&state)), actions)
+    }
+
+    #[test]
+    fn one_line_per_game_with_every_move() {
+        let record = played_out_record();
+        let game_summary = DbGameSummary {
+            game_id: "game1".to_owned(),
+            game_creation_time_sec: 1590969600,
+            game_type: storage::v2::db_types::DbGameType::LostCities,
+            game_status: storage::v2::db_types::DbGameStatus::Completed,
+            player_ids: vec!["al\"ice".to_owned(), "bob".to_owned()],
+            game_summary_blob_opt: None,
+        };
+        let game_data = DbGameData {
+            game_id: "game1".to_owned(),
+            game_data_blob: notation::write(&record).unwrap().into_bytes(),
+        };
+
+        let json_line = to_json_line(&game_summary, &game_data).unwrap();
+
+        assert!(!json_line.contains('\n'));
+        assert!(json_line.starts_with("{\"game_id\":\"game1\",\"created_at\":\"2020-06-01T00:00:00Z\",\"players\":[\"al\\\"ice\",\"bob\"],\"first_player\":\"bob\",\"seed\":7,"));
+        assert_eq!(json_line.matches("\"turn\":").count(), record.actions().len());
+        assert!(json_line.contains("{\"turn\":1,\"player\":\"bob\","));
+        assert!(json_line.contains("\"kind\":\"deck_exhausted\""));
+        assert_eq!(json_line.matches("\"expeditions\":{\"red\":[").count(), 2);
+    }
+
+    #[test]
+    fn date_filter_covers_whole_days() {
+        let filter = ExportFilter::all_games()
+            .with_from_date(NaiveDate::from_ymd_opt(2020, 6, 1).unwrap())
+            .with_to_date(NaiveDate::from_ymd_opt(2020, 6, 1).unwrap());
+
+        assert_eq!(filter.min_creation_time_sec(), 1590969600);
+        assert_eq!(filter.max_creation_time_sec(), 1590969600 + SECONDS_PER_DAY - 1);
+        assert_eq!(ExportFilter::all_games().max_creation_time_sec(), u32::MAX);
+    }
+}
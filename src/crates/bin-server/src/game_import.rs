@@ -0,0 +1,320 @@
+use crate::game_export::{color_name, value_name};
+use chrono::DateTime;
+use game_api::types::{Card, CardColor, CardTarget, CardValue, DrawPile, RuleSet};
+use rules::engine::Action;
+use rules::notation::{self, GameRecord, RecordedResult};
+use serde_json::Value;
+use std::convert::TryFrom;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::fmt;
+use std::fs;
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+use storage::v2::completed_games;
+use storage::v2::config::DatabaseMode;
+use storage::v2::db_types::{DbGameData, DbGameStatus, DbGameSummary, DbGameType};
+
+/// A completed game, ready to be saved.
+struct ImportedGame {
+    game_id: String,
+    game_creation_time_sec: u32,
+    record: GameRecord,
+}
+
+/// Running totals over every file imported.
+pub struct ImportSummary {
+    imported: usize,
+    already_imported: usize,
+    failed: usize,
+}
+
+impl ImportSummary {
+    pub fn empty() -> Self {
+        ImportSummary {
+            imported: 0,
+            already_imported: 0,
+            failed: 0,
+        }
+    }
+
+    pub fn failed(&self) -> &usize {
+        &self.failed
+    }
+}
+
+impl Display for ImportSummary {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Imported {} games. Skipped {} that were already imported, and {} that failed.",
+            self.imported,
+            self.already_imported,
+            self.failed,
+        )
+    }
+}
+
+/// Import the completed games in a file, either the JSON Lines from `export-games` (`.jsonl` or
+/// `.json`) or a single game in text notation (anything else). Notation doesn't have a game ID,
+/// so the file name is used instead, and the game is dated to when it was imported.
+///
+/// Every game is replayed by the rules engine before it's saved, and games that were already
+/// imported are skipped, so it's safe to run more than once. Bad games are skipped with a warning
+/// on stderr, but the rest of the file is still imported.
+///
+/// There are no player ratings or stats yet, so a completed game is only its summary and its
+/// moves, and there's nothing else to update.
+pub fn import_games_from_file(mode: &DatabaseMode, path: &Path, summary: &mut ImportSummary) -> Result<(), Box<dyn Error>> {
+    let text = fs::read_to_string(path)?;
+
+    let is_json_lines = path.extension()
+        .map(|extension| extension == "jsonl" || extension == "json")
+        .unwrap_or(false);
+    if !is_json_lines {
+        let game_id = path.file_stem()
+            .map(|file_stem| file_stem.to_string_lossy().into_owned())
+            .ok_or("The file has no name to use as the game ID")?;
+        let now_sec = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs() as u32;
+        let game = notation::parse(&text)
+            .map(|record| ImportedGame { game_id, game_creation_time_sec: now_sec, record })
+            .map_err(Box::<dyn Error>::from);
+        save_game(mode, game, &path.display().to_string(), summary)?;
+        return Ok(());
+    }
+
+    for (i, line) in text.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let source = format!("{} line {}", path.display(), i + 1);
+        save_game(mode, from_json_line(line), &source, summary)?;
+    }
+
+    Ok(())
+}
+
+/// Only a database error stops the import. Anything wrong with the game itself just skips it.
+fn save_game(
+    mode: &DatabaseMode,
+    game: Result<ImportedGame, Box<dyn Error>>,
+    source: &str,
+    summary: &mut ImportSummary,
+) -> Result<(), Box<dyn Error>> {
+    let (game_summary, game_data) = match game.and_then(to_db_rows) {
+        Ok(rows) => rows,
+        Err(e) => {
+            eprintln!("WARN: Skipping the game at {}: {}", source, e);
+            summary.failed += 1;
+            return Ok(());
+        }
+    };
+
+    let game_id = game_summary.game_id.clone();
+    if completed_games::save_completed_game(mode, game_summary, game_data)? {
+        println!("INFO: Imported game '{}'", game_id);
+        summary.imported += 1;
+    } else {
+        println!("INFO: Game '{}' was already imported", game_id);
+        summary.already_imported += 1;
+    }
+
+    Ok(())
+}
+
+/// The same rows the server writes when a game ends. Writing the notation replays the game, so
+/// this is also where an illegal move or a wrong result is caught.
+fn to_db_rows(game: ImportedGame) -> Result<(DbGameSummary, DbGameData), Box<dyn Error>> {
+    if let RecordedResult::Unfinished = game.record.result() {
+        return Err("The game isn't over yet".into());
+    }
+
+    let game_data = DbGameData {
+        game_id: game.game_id.clone(),
+        game_data_blob: notation::write(&game.record)?.into_bytes(),
+    };
+    let game_summary = DbGameSummary {
+        game_id: game.game_id,
+        game_creation_time_sec: game.game_creation_time_sec,
+        game_type: DbGameType::LostCities,
+        game_status: DbGameStatus::Completed,
+        player_ids: game.record.player_ids().to_owned(),
+        game_summary_blob_opt: None,
+    };
+
+    Ok((game_summary, game_data))
+}
+
+/// The inverse of `game_export`. The boards and scores are left out, since they come from
+/// replaying the moves anyway.
+fn from_json_line(line: &str) -> Result<ImportedGame, Box<dyn Error>> {
+    let json: Value = serde_json::from_str(line)?;
+
+    let game_id = str_field(&json, "game_id")?.to_owned();
+    let created_at = DateTime::parse_from_rfc3339(str_field(&json, "created_at")?)?;
+    let game_creation_time_sec = u32::try_from(created_at.timestamp())?;
+
+    let player_ids = field(&json, "players")?
+        .as_array()
+        .ok_or("'players' isn't a list")?
+        .iter()
+        .map(|player_id| player_id.as_str().map(str::to_owned).ok_or("'players' has a player that isn't a string"))
+        .collect::<Result<Vec<String>, _>>()?;
+    let seat_of = |player_id: &str| player_ids.iter()
+        .position(|id| id == player_id)
+        .ok_or_else(|| format!("'{}' isn't one of the players", player_id));
+    let first_seat = seat_of(str_field(&json, "first_player")?)?;
+    let seed = field(&json, "seed")?.as_u64().ok_or("'seed' isn't an unsigned integer")?;
+
+    let rules = field(&json, "rules")?;
+    let rule_set = RuleSet::new(
+        u32_field(rules, "hand_size")?,
+        u32_field(rules, "expedition_cost")?,
+        u32_field(rules, "multiplier_per_wager")?,
+        u32_field(rules, "bonus_threshold")?,
+        u32_field(rules, "bonus_points")?,
+        bool_field(rules, "final_turn")?,
+        bool_field(rules, "purple")?,
+    ).with_num_players(u32_field(rules, "num_players")?);
+
+    let result_json = field(&json, "result")?;
+    let result = match str_field(result_json, "kind")? {
+        "deck_exhausted" => RecordedResult::Scores(
+            field(result_json, "scores")?
+                .as_array()
+                .ok_or("'scores' isn't a list")?
+                .iter()
+                .map(|score| score.as_i64().and_then(|score| i32::try_from(score).ok()).ok_or("'scores' has a score that isn't an integer"))
+                .collect::<Result<Vec<i32>, _>>()?
+        ),
+        "resigned" => RecordedResult::Resigned(seat_of(str_field(result_json, "player")?)?),
+        "timed_out" => RecordedResult::TimedOut(seat_of(str_field(result_json, "player")?)?),
+        "draw_agreed" => RecordedResult::DrawAgreed,
+        "unfinished" => RecordedResult::Unfinished,
+        kind => return Err(format!("Unknown result '{}'", kind).into()),
+    };
+
+    let actions = field(&json, "moves")?
+        .as_array()
+        .ok_or("'moves' isn't a list")?
+        .iter()
+        .map(|move_json| parse_move(move_json, &rule_set))
+        .collect::<Result<Vec<Action>, _>>()?;
+
+    Ok(ImportedGame {
+        game_id,
+        game_creation_time_sec,
+        record: GameRecord::new(player_ids, seed, first_seat, rule_set, result, actions),
+    })
+}
+
+fn parse_move(move_json: &Value, rule_set: &RuleSet) -> Result<Action, Box<dyn Error>> {
+    let card_json = field(move_json, "card")?;
+    let card = Card::new(
+        parse_color(str_field(card_json, "color")?, rule_set)?,
+        parse_value(str_field(card_json, "value")?)?,
+    );
+    let target = match str_field(move_json, "play")? {
+        "expedition" => CardTarget::Player,
+        "discard" => CardTarget::Neutral,
+        play => return Err(format!("Unknown play '{}'", play).into()),
+    };
+    // No draw at all on the final turn is written down as a draw from the main pile.
+    let draw_pile = match field(move_json, "draw")? {
+        Value::Null => DrawPile::Main,
+        draw => match draw.as_str().ok_or("'draw' isn't a string")? {
+            "main" => DrawPile::Main,
+            color => DrawPile::Neutral(parse_color(color, rule_set)?),
+        },
+    };
+
+    Ok(Action::new(card, target, draw_pile))
+}
+
+fn parse_color(name: &str, rule_set: &RuleSet) -> Result<CardColor, String> {
+    rule_set.colors()
+        .iter()
+        .copied()
+        .find(|color| color_name(*color) == name)
+        .ok_or_else(|| format!("Unknown color '{}'", name))
+}
+
+fn parse_value(name: &str) -> Result<CardValue, String> {
+    (1..=10)
+        .filter_map(|value| CardValue::try_from(value).ok())
+        .find(|value| value_name(*value) == name)
+        .ok_or_else(|| format!("Unknown card value '{}'", name))
+}
+
+fn field<'a>(json: &'a Value, name: &str) -> Result<&'a Value, String> {
+    json.get(name).ok_or_else(|| format!("'{}' is missing", name))
+}
+
+fn str_field<'a>(json: &'a Value, name: &str) -> Result<&'a str, String> {
+    field(json, name)?.as_str().ok_or_else(|| format!("'{}' isn't a string", name))
+}
+
+fn u32_field(json: &Value, name: &str) -> Result<u32, String> {
+    field(json, name)?
+        .as_u64()
+        .and_then(|value| u32::try_from(value).ok())
+        .ok_or_else(|| format!("'{}' isn't an unsigned integer", name))
+}
+
+fn bool_field(json: &Value, name: &str) -> Result<bool, String> {
+    field(json, name)?.as_bool().ok_or_else(|| format!("'{}' isn't true or false", name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game_export;
+    use rules::engine;
+
+    #[test]
+    fn exported_games_import_as_they_were() {
+        let player_ids = vec!["alice".to_owned(), "bob".to_owned()];
+        let rule_set = RuleSet::standard();
+        let unplayed = GameRecord::new(player_ids.clone(), 11, 0, rule_set, RecordedResult::Unfinished, Vec::new());
+        let mut state = notation::deal(&unplayed).unwrap();
+        let mut actions = Vec::new();
+        while !engine::is_terminal(&state) {
+            let action = engine::legal_actions(&state)[0];
+            state = engine::apply(state, &action).unwrap();
+            actions.push(action);
+        }
+        let record = GameRecord::new(player_ids, 11, 0, rule_set, RecordedResult::Scores(notation::final_scores(&state)), actions);
+        let notation_text = notation::write(&record).unwrap();
+
+        let game_summary = DbGameSummary {
+            game_id: "game1".to_owned(),
+            game_creation_time_sec: 1590969600,
+            game_type: DbGameType::LostCities,
+            game_status: DbGameStatus::Completed,
+            player_ids: vec!["alice".to_owned(), "bob".to_owned()],
+            game_summary_blob_opt: None,
+        };
+        let game_data = DbGameData {
+            game_id: "game1".to_owned(),
+            game_data_blob: notation_text.into_bytes(),
+        };
+        let json_line = game_export::to_json_line(&game_summary, &game_data).unwrap();
+
+        let imported = from_json_line(&json_line).unwrap();
+        assert_eq!(imported.record, record);
+        assert_eq!(to_db_rows(imported).unwrap(), (game_summary, game_data));
+    }
+
+    #[test]
+    fn games_that_dont_replay_are_rejected() {
+        let player_ids = vec!["alice".to_owned(), "bob".to_owned()];
+        let record = GameRecord::new(player_ids, 11, 0, RuleSet::standard(), RecordedResult::Scores(vec![0, 0]), Vec::new());
+        let game = ImportedGame {
+            game_id: "game1".to_owned(),
+            game_creation_time_sec: 0,
+            record,
+        };
+
+        assert!(to_db_rows(game).is_err());
+    }
+}
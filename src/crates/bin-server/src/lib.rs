@@ -1,2 +1,5 @@
+pub mod client_protocol;
+pub mod game_export;
+pub mod game_import;
 pub mod server_impl;
 pub mod wire_api;
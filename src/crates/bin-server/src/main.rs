@@ -1,13 +1,27 @@
 use std::net::SocketAddr;
 use tonic::transport::Server;
+use bin_server::client_protocol;
+use bin_server::game_export::{self, ExportFilter};
+use bin_server::game_import::{self, ImportSummary};
 use bin_server::server_impl::LostCitiesBackendServer;
 use bin_server::wire_api::proto_lost_cities::proto_lost_cities_server::ProtoLostCitiesServer;
-use std::{env, process};
+use chrono::NaiveDate;
+use std::{env, io, process};
+use std::path::Path;
+use storage::v2::config::DatabaseMode;
 
 const DEFAULT_PORT: u16 = 8051;
+const EXPORT_GAMES_COMMAND: &str = "export-games";
+const IMPORT_GAMES_COMMAND: &str = "import-games";
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    match env::args().nth(1).as_deref() {
+        Some(EXPORT_GAMES_COMMAND) => return export_games(),
+        Some(IMPORT_GAMES_COMMAND) => return import_games(),
+        _ => {},
+    }
+
     let (_, port) = get_cli_args();
 
     let server_impl = LostCitiesBackendServer::start()?;
@@ -18,7 +32,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("Going to listen on '{:?}'", addr);
 
     Server::builder()
-        .add_service(ProtoLostCitiesServer::new(server_impl))
+        .add_service(ProtoLostCitiesServer::with_interceptor(server_impl, client_protocol::check_protocol_version))
         .serve(addr)
         .await?;
 
@@ -47,10 +61,64 @@ fn get_cli_args() -> (String, u16) {
     (program_name, port)
 }
 
+/// Admin command. Dump completed games from the database to stdout as JSON Lines, for analytics.
+fn export_games() -> Result<(), Box<dyn std::error::Error>> {
+    let mut cli_args = env::args();
+    let program_name = cli_args.next().unwrap_or_default();
+    cli_args.next(); // EXPORT_GAMES_COMMAND
+
+    let parse_date = |date_str: Option<String>| date_str
+        .and_then(|date_str| NaiveDate::parse_from_str(&date_str, "%Y-%m-%d").ok())
+        .unwrap_or_else(|| print_usage_exit(&program_name));
+
+    let mut filter = ExportFilter::all_games();
+    while let Some(flag) = cli_args.next() {
+        filter = match flag.as_str() {
+            "--from" => filter.with_from_date(parse_date(cli_args.next())),
+            "--to" => filter.with_to_date(parse_date(cli_args.next())),
+            "--player" => filter.with_player_id(cli_args.next().unwrap_or_else(|| print_usage_exit(&program_name))),
+            _ => print_usage_exit(&program_name),
+        };
+    }
+
+    let stdout = io::stdout();
+    let num_exported = game_export::export_completed_games(&DatabaseMode::Prod, &filter, &mut stdout.lock())?;
+    eprintln!("Exported {} games.", num_exported);
+
+    Ok(())
+}
+
+/// Admin command. Load completed games into the database, from `export-games` files or text notation.
+fn import_games() -> Result<(), Box<dyn std::error::Error>> {
+    let mut cli_args = env::args();
+    let program_name = cli_args.next().unwrap_or_default();
+    cli_args.next(); // IMPORT_GAMES_COMMAND
+
+    let file_paths: Vec<String> = cli_args.collect();
+    if file_paths.is_empty() {
+        print_usage_exit(&program_name);
+    }
+
+    let mut summary = ImportSummary::empty();
+    for file_path in file_paths {
+        game_import::import_games_from_file(&DatabaseMode::Prod, Path::new(&file_path), &mut summary)?;
+    }
+    println!("{}", summary);
+
+    if *summary.failed() > 0 {
+        process::exit(1);
+    }
+    Ok(())
+}
+
 fn print_usage_exit(program_name: &str) -> ! {
     eprintln!();
     eprintln!("Usage:  \t{} <server port>", program_name);
+    eprintln!("        \t{} {} [--from YYYY-MM-DD] [--to YYYY-MM-DD] [--player <player id>]", program_name, EXPORT_GAMES_COMMAND);
+    eprintln!("        \t{} {} <games.jsonl or notation file>...", program_name, IMPORT_GAMES_COMMAND);
     eprintln!("Example:\t{} 3000", program_name);
+    eprintln!("        \t{} {} --from 2020-06-01 --player alice > games.jsonl", program_name, EXPORT_GAMES_COMMAND);
+    eprintln!("        \t{} {} games.jsonl", program_name, IMPORT_GAMES_COMMAND);
     eprintln!();
     process::exit(1);
 }
@@ -1,9 +1,11 @@
 use tonic::{Request, Response, Status};
 use crate::wire_api::proto_lost_cities::proto_lost_cities_server::ProtoLostCities;
-use crate::wire_api::proto_lost_cities::{ProtoHostGameReq, ProtoHostGameReply, ProtoJoinGameReq, ProtoJoinGameReply, ProtoGetGameStateReq, ProtoGetGameStateReply, ProtoPlayCardReq, ProtoPlayCardReply, ProtoDescribeGameReq, ProtoQueryGamesReq, ProtoGetMatchableGamesReq, ProtoQueryGamesReply, ProtoDescribeGameReply, ProtoGetMatchableGamesReply, ProtoGameMetadata, ProtoGameStatus};
+use crate::wire_api::proto_lost_cities::{ProtoHostGameReq, ProtoHostGameReply, ProtoJoinGameReq, ProtoJoinGameReply, ProtoGetGameStateReq, ProtoGetGameStateReply, ProtoPlayCardReq, ProtoPlayCardReply, ProtoDescribeGameReq, ProtoQueryGamesReq, ProtoGetMatchableGamesReq, ProtoQueryGamesReply, ProtoDescribeGameReply, ProtoGetMatchableGamesReply, ProtoGameMetadata, ProtoGameStatus, ProtoGetWatchableGamesReq, ProtoGetWatchableGamesReply, ProtoGetSpectatorGameStateReq, ProtoGetSpectatorGameStateReply, ProtoHostMatchReq, ProtoHostMatchReply, ProtoJoinMatchReq, ProtoJoinMatchReply, ProtoGetMatchStateReq, ProtoGetMatchStateReply, ProtoResignReq, ProtoResignReply, ProtoOfferDrawReq, ProtoOfferDrawReply, ProtoRespondToDrawOfferReq, ProtoRespondToDrawOfferReply, ProtoRequestTakeBackReq, ProtoRequestTakeBackReply, ProtoRespondToTakeBackReq, ProtoRespondToTakeBackReply, ProtoAnalyzeGameReq, ProtoAnalyzeGameReply, ProtoExportGameReq, ProtoExportGameReply, ProtoHandshakeReq, ProtoHandshakeReply};
 use std::convert::TryInto;
 use game_api::types::{GameMetadata, Play};
+use game_api::wire_protocol;
 use chrono::Utc;
+use crate::client_protocol::{self, ClientFeatures};
 use crate::wire_api::error_converters::IntoTonicStatus;
 use backend_engine::game_api::GameApi2Immut;
 use std::error::Error;
@@ -29,13 +31,17 @@ impl LostCitiesBackendServer {
 impl ProtoLostCities for LostCitiesBackendServer {
 
     async fn host_game(&self, request: Request<ProtoHostGameReq>) -> Result<Response<ProtoHostGameReply>, Status> {
+        let client_features = ClientFeatures::from_metadata(request.metadata());
         let req = request.into_inner();
         println!("{} - [WIRE] {:?}", Utc::now(), req);
 
-        let (game_id, player_id) = req.try_into()?;
+        let (game_id, player_id, time_control, opponent, rule_set) = req.try_into()?;
+        if let Some(status) = client_features.rule_set_error(&rule_set) {
+            return Err(status);
+        }
 
         let _ = self.game_api
-            .host_game(game_id, player_id)
+            .host_game(game_id, player_id, time_control, opponent, rule_set)
             .await
             .map_err(|e| e.into_status())?;
 
@@ -45,10 +51,19 @@ impl ProtoLostCities for LostCitiesBackendServer {
     }
 
     async fn join_game(&self, request: Request<ProtoJoinGameReq>) -> Result<Response<ProtoJoinGameReply>, Status> {
+        let client_features = ClientFeatures::from_metadata(request.metadata());
         let req = request.into_inner();
         println!("{} - [WIRE] {:?}", Utc::now(), req);
 
-        let (game_id, player_id) = req.try_into()?;
+        let (game_id, player_id): (String, String) = req.try_into()?;
+
+        let game_metadata = self.game_api
+            .describe_game(game_id.clone())
+            .await
+            .map_err(|e| e.into_status())?;
+        if let Some(status) = client_features.rule_set_error(game_metadata.rule_set()) {
+            return Err(status);
+        }
 
         let _ = self.game_api
             .join_game(game_id, player_id)
@@ -61,6 +76,7 @@ impl ProtoLostCities for LostCitiesBackendServer {
     }
 
     async fn get_game_state(&self, request: Request<ProtoGetGameStateReq>) -> Result<Response<ProtoGetGameStateReply>, Status> {
+        let client_features = ClientFeatures::from_metadata(request.metadata());
         let req = request.into_inner();
         println!("{} - [WIRE] {:?}", Utc::now(), req);
 
@@ -69,6 +85,10 @@ impl ProtoLostCities for LostCitiesBackendServer {
         let game_state = self.game_api
             .get_game_state(game_id, player_id).await
             .map_err(|e| e.into_status())?;
+        // The player may have hosted the game from a newer client.
+        if let Some(status) = client_features.rule_set_error(game_state.rule_set()) {
+            return Err(status);
+        }
 
         let reply = game_state.into();
         println!("{} - [WIRE] {:?}", Utc::now(), reply);
@@ -110,6 +130,7 @@ impl ProtoLostCities for LostCitiesBackendServer {
     }
 
     async fn query_games(&self, request: Request<ProtoQueryGamesReq>) -> Result<Response<ProtoQueryGamesReply>, Status> {
+        let client_features = ClientFeatures::from_metadata(request.metadata());
         let req = request.into_inner();
         println!("{} - [WIRE] {:?}", Utc::now(), req);
 
@@ -121,19 +142,25 @@ impl ProtoLostCities for LostCitiesBackendServer {
             ProtoGameStatus::EndWin => self.game_api.query_completed_games(player_id).await,
             ProtoGameStatus::EndLose => self.game_api.query_completed_games(player_id).await,
             ProtoGameStatus::EndDraw => self.game_api.query_completed_games(player_id).await,
+            ProtoGameStatus::EndWinOnTime => self.game_api.query_completed_games(player_id).await,
+            ProtoGameStatus::EndLoseOnTime => self.game_api.query_completed_games(player_id).await,
+            ProtoGameStatus::EndWinByResignation => self.game_api.query_completed_games(player_id).await,
+            ProtoGameStatus::EndLoseByResignation => self.game_api.query_completed_games(player_id).await,
+            ProtoGameStatus::EndDrawByAgreement => self.game_api.query_completed_games(player_id).await,
             ProtoGameStatus::Unmatched => self.game_api.query_unmatched_games(player_id).await,
             ProtoGameStatus::NoGameStatus => return Err(Status::invalid_argument("Unspecified game status")),
         };
         let games = result.map_err(|e| e.into_status())?;
 
         let reply = ProtoQueryGamesReply {
-            games: into_proto_game_metadata_vec(games)
+            games: into_proto_game_metadata_vec(client_features.showable(games))
         };
         println!("{} - [WIRE] {:?}", Utc::now(), reply);
         Ok(Response::new(reply))
     }
 
     async fn get_matchable_games(&self, request: Request<ProtoGetMatchableGamesReq>) -> Result<Response<ProtoGetMatchableGamesReply>, Status> {
+        let client_features = ClientFeatures::from_metadata(request.metadata());
         let req = request.into_inner();
         println!("{} - [WIRE] {:?}", Utc::now(), req);
 
@@ -145,7 +172,234 @@ impl ProtoLostCities for LostCitiesBackendServer {
             .map_err(|e| e.into_status())?;
 
         let reply = ProtoGetMatchableGamesReply {
-            games: into_proto_game_metadata_vec(games)
+            games: into_proto_game_metadata_vec(client_features.showable(games))
+        };
+        println!("{} - [WIRE] {:?}", Utc::now(), reply);
+        Ok(Response::new(reply))
+    }
+
+    async fn get_watchable_games(&self, request: Request<ProtoGetWatchableGamesReq>) -> Result<Response<ProtoGetWatchableGamesReply>, Status> {
+        let client_features = ClientFeatures::from_metadata(request.metadata());
+        let req = request.into_inner();
+        println!("{} - [WIRE] {:?}", Utc::now(), req);
+
+        let player_id = req.try_into()?;
+
+        let games = self.game_api
+            .query_watchable_games(player_id)
+            .await
+            .map_err(|e| e.into_status())?;
+
+        let reply = ProtoGetWatchableGamesReply {
+            games: into_proto_game_metadata_vec(client_features.showable(games))
+        };
+        println!("{} - [WIRE] {:?}", Utc::now(), reply);
+        Ok(Response::new(reply))
+    }
+
+    async fn get_spectator_game_state(&self, request: Request<ProtoGetSpectatorGameStateReq>) -> Result<Response<ProtoGetSpectatorGameStateReply>, Status> {
+        let client_features = ClientFeatures::from_metadata(request.metadata());
+        let req = request.into_inner();
+        println!("{} - [WIRE] {:?}", Utc::now(), req);
+
+        let (game_id, player_id) = req.try_into()?;
+
+        let game_state = self.game_api
+            .get_spectator_game_state(game_id, player_id)
+            .await
+            .map_err(|e| e.into_status())?;
+        if let Some(status) = client_features.rule_set_error(game_state.rule_set()) {
+            return Err(status);
+        }
+
+        let reply = game_state.into();
+        println!("{} - [WIRE] {:?}", Utc::now(), reply);
+        Ok(Response::new(reply))
+    }
+
+    async fn host_match(&self, request: Request<ProtoHostMatchReq>) -> Result<Response<ProtoHostMatchReply>, Status> {
+        let client_features = ClientFeatures::from_metadata(request.metadata());
+        let req = request.into_inner();
+        println!("{} - [WIRE] {:?}", Utc::now(), req);
+
+        let (match_id, player_id, num_rounds, time_control, rule_set) = req.try_into()?;
+        if let Some(status) = client_features.rule_set_error(&rule_set) {
+            return Err(status);
+        }
+
+        let _ = self.game_api
+            .host_match(match_id, player_id, num_rounds, time_control, rule_set)
+            .await
+            .map_err(|e| e.into_status())?;
+
+        let reply = ProtoHostMatchReply {};
+        println!("{} - [WIRE] {:?}", Utc::now(), reply);
+        Ok(Response::new(reply))
+    }
+
+    async fn join_match(&self, request: Request<ProtoJoinMatchReq>) -> Result<Response<ProtoJoinMatchReply>, Status> {
+        let req = request.into_inner();
+        println!("{} - [WIRE] {:?}", Utc::now(), req);
+
+        let (match_id, player_id) = req.try_into()?;
+
+        let _ = self.game_api
+            .join_match(match_id, player_id)
+            .await
+            .map_err(|e| e.into_status())?;
+
+        let reply = ProtoJoinMatchReply {};
+        println!("{} - [WIRE] {:?}", Utc::now(), reply);
+        Ok(Response::new(reply))
+    }
+
+    async fn get_match_state(&self, request: Request<ProtoGetMatchStateReq>) -> Result<Response<ProtoGetMatchStateReply>, Status> {
+        let req = request.into_inner();
+        println!("{} - [WIRE] {:?}", Utc::now(), req);
+
+        let (match_id, player_id) = req.try_into()?;
+
+        let match_state = self.game_api
+            .get_match_state(match_id, player_id)
+            .await
+            .map_err(|e| e.into_status())?;
+
+        let reply = match_state.into();
+        println!("{} - [WIRE] {:?}", Utc::now(), reply);
+        Ok(Response::new(reply))
+    }
+
+    async fn resign(&self, request: Request<ProtoResignReq>) -> Result<Response<ProtoResignReply>, Status> {
+        let req = request.into_inner();
+        println!("{} - [WIRE] {:?}", Utc::now(), req);
+
+        let (game_id, player_id) = req.try_into()?;
+
+        let _ = self.game_api
+            .resign(game_id, player_id)
+            .await
+            .map_err(|e| e.into_status())?;
+
+        let reply = ProtoResignReply {};
+        println!("{} - [WIRE] {:?}", Utc::now(), reply);
+        Ok(Response::new(reply))
+    }
+
+    async fn offer_draw(&self, request: Request<ProtoOfferDrawReq>) -> Result<Response<ProtoOfferDrawReply>, Status> {
+        let req = request.into_inner();
+        println!("{} - [WIRE] {:?}", Utc::now(), req);
+
+        let (game_id, player_id) = req.try_into()?;
+
+        let _ = self.game_api
+            .offer_draw(game_id, player_id)
+            .await
+            .map_err(|e| e.into_status())?;
+
+        let reply = ProtoOfferDrawReply {};
+        println!("{} - [WIRE] {:?}", Utc::now(), reply);
+        Ok(Response::new(reply))
+    }
+
+    async fn respond_to_draw_offer(&self, request: Request<ProtoRespondToDrawOfferReq>) -> Result<Response<ProtoRespondToDrawOfferReply>, Status> {
+        let req = request.into_inner();
+        println!("{} - [WIRE] {:?}", Utc::now(), req);
+
+        let (game_id, player_id, accept) = req.try_into()?;
+
+        let _ = self.game_api
+            .respond_to_draw_offer(game_id, player_id, accept)
+            .await
+            .map_err(|e| e.into_status())?;
+
+        let reply = ProtoRespondToDrawOfferReply {};
+        println!("{} - [WIRE] {:?}", Utc::now(), reply);
+        Ok(Response::new(reply))
+    }
+
+    async fn request_take_back(&self, request: Request<ProtoRequestTakeBackReq>) -> Result<Response<ProtoRequestTakeBackReply>, Status> {
+        let req = request.into_inner();
+        println!("{} - [WIRE] {:?}", Utc::now(), req);
+
+        let (game_id, player_id) = req.try_into()?;
+
+        let _ = self.game_api
+            .request_take_back(game_id, player_id)
+            .await
+            .map_err(|e| e.into_status())?;
+
+        let reply = ProtoRequestTakeBackReply {};
+        println!("{} - [WIRE] {:?}", Utc::now(), reply);
+        Ok(Response::new(reply))
+    }
+
+    async fn respond_to_take_back(&self, request: Request<ProtoRespondToTakeBackReq>) -> Result<Response<ProtoRespondToTakeBackReply>, Status> {
+        let req = request.into_inner();
+        println!("{} - [WIRE] {:?}", Utc::now(), req);
+
+        let (game_id, player_id, accept) = req.try_into()?;
+
+        let _ = self.game_api
+            .respond_to_take_back(game_id, player_id, accept)
+            .await
+            .map_err(|e| e.into_status())?;
+
+        let reply = ProtoRespondToTakeBackReply {};
+        println!("{} - [WIRE] {:?}", Utc::now(), reply);
+        Ok(Response::new(reply))
+    }
+
+    async fn analyze_game(&self, request: Request<ProtoAnalyzeGameReq>) -> Result<Response<ProtoAnalyzeGameReply>, Status> {
+        let req = request.into_inner();
+        println!("{} - [WIRE] {:?}", Utc::now(), req);
+
+        let (game_id, player_id) = req.try_into()?;
+
+        let analysis = self.game_api
+            .analyze_game(game_id, player_id)
+            .await
+            .map_err(|e| e.into_status())?;
+
+        let reply = analysis.into();
+        println!("{} - [WIRE] {:?}", Utc::now(), reply);
+        Ok(Response::new(reply))
+    }
+
+    async fn export_game(&self, request: Request<ProtoExportGameReq>) -> Result<Response<ProtoExportGameReply>, Status> {
+        let req = request.into_inner();
+        println!("{} - [WIRE] {:?}", Utc::now(), req);
+
+        let (game_id, player_id) = req.try_into()?;
+
+        let notation = self.game_api
+            .export_game(game_id, player_id)
+            .await
+            .map_err(|e| e.into_status())?;
+
+        let reply = ProtoExportGameReply {
+            notation,
+        };
+        println!("{} - [WIRE] {:?}", Utc::now(), reply);
+        Ok(Response::new(reply))
+    }
+
+    /// Tell the client which protocol version this server speaks, or turn it away if it's too old
+    /// to understand it. `client_protocol::check_protocol_version` has already seen the version
+    /// header, but the client's own word for it in the request wins.
+    async fn handshake(&self, request: Request<ProtoHandshakeReq>) -> Result<Response<ProtoHandshakeReply>, Status> {
+        let req = request.into_inner();
+        println!("{} - [WIRE] {:?}", Utc::now(), req);
+
+        if let Some(status) = client_protocol::client_version_error(req.client_protocol_version, wire_protocol::MIN_CLIENT_PROTOCOL_VERSION) {
+            return Err(status);
+        }
+
+        let reply = ProtoHandshakeReply {
+            server_protocol_version: wire_protocol::PROTOCOL_VERSION,
+            min_client_protocol_version: wire_protocol::MIN_CLIENT_PROTOCOL_VERSION,
+            server_features: wire_protocol::FEATURES.iter()
+                .map(|feature| (*feature).to_owned())
+                .collect(),
         };
         println!("{} - [WIRE] {:?}", Utc::now(), reply);
         Ok(Response::new(reply))
@@ -153,10 +407,56 @@ impl ProtoLostCities for LostCitiesBackendServer {
 }
 
 fn into_proto_game_metadata_vec(game_metadata_vec: Vec<GameMetadata>) -> Vec<ProtoGameMetadata> {
-    let games: Vec<ProtoGameMetadata> = Vec::with_capacity(game_metadata_vec.len());
+    let mut games: Vec<ProtoGameMetadata> = Vec::with_capacity(game_metadata_vec.len());
     for game_metadata in game_metadata_vec {
-        ProtoGameMetadata::from(game_metadata);
+        games.push(ProtoGameMetadata::from(game_metadata));
     }
 
     games
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wire_api::proto_lost_cities::{ProtoOpponent, ProtoRuleSet};
+    use game_api::types::RuleSet;
+    use game_api::wire_protocol;
+    use tonic::Code;
+    use tonic::metadata::MetadataValue;
+
+    fn request_from<T>(message: T, features: &[&str]) -> Request<T> {
+        let mut request = Request::new(message);
+        request.metadata_mut().insert(wire_protocol::PROTOCOL_FEATURES_HEADER, MetadataValue::from_str(&features.join(",")).unwrap());
+        request
+    }
+
+    #[tokio::test]
+    async fn clients_without_purple_cant_join_or_watch_six_color_games() -> Result<(), Box<dyn Error>> {
+        let server = LostCitiesBackendServer::start()?;
+        let game_id = "server-purple".to_owned();
+        let old_features: &[&str] = &[];
+
+        server.host_game(request_from(ProtoHostGameReq {
+            game_id: game_id.clone(),
+            player_id: "mememe".to_owned(),
+            time_control: None,
+            opponent: ProtoOpponent::OpponentHuman as i32,
+            rule_set: Some(ProtoRuleSet::from(RuleSet::new(8, 20, 1, 8, 20, false, true))),
+        }, wire_protocol::FEATURES)).await?;
+
+        let join = |features: &[&str]| request_from(ProtoJoinGameReq { game_id: game_id.clone(), player_id: "youyou".to_owned() }, features);
+        assert_eq!(server.join_game(join(old_features)).await.unwrap_err().code(), Code::FailedPrecondition);
+        server.join_game(join(wire_protocol::FEATURES)).await?;
+
+        let watchable = |features: &[&str]| request_from(ProtoGetWatchableGamesReq { player_id: "watcher".to_owned() }, features);
+        let is_listed = |reply: Response<ProtoGetWatchableGamesReply>| reply.into_inner().games.iter().any(|game| game.game_id == game_id);
+        assert!(!is_listed(server.get_watchable_games(watchable(old_features)).await?));
+        assert!(is_listed(server.get_watchable_games(watchable(wire_protocol::FEATURES)).await?));
+
+        let spectate = |features: &[&str]| request_from(ProtoGetSpectatorGameStateReq { game_id: game_id.clone(), player_id: "watcher".to_owned() }, features);
+        assert_eq!(server.get_spectator_game_state(spectate(old_features)).await.unwrap_err().code(), Code::FailedPrecondition);
+        server.get_spectator_game_state(spectate(wire_protocol::FEATURES)).await?;
+
+        Ok(())
+    }
+}
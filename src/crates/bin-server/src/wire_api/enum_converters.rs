@@ -3,7 +3,7 @@
 /// this. So I will create explicit methods and contain the "dark magic" within these small methods.
 ///
 /// See https://github.com/danburkert/prost/issues/69
-use crate::wire_api::proto_lost_cities::{ProtoColor, ProtoDrawPile, ProtoGameStatus, ProtoPlayTarget};
+use crate::wire_api::proto_lost_cities::{ProtoColor, ProtoDrawPile, ProtoGameStatus, ProtoPlayTarget, ProtoTimeControlType, ProtoOpponent};
 use std::convert::TryFrom;
 use tonic::{Code, Status};
 
@@ -42,3 +42,21 @@ impl TryFrom<i32> for ProtoGameStatus {
             .ok_or(Status::new(Code::InvalidArgument, format!("Illegal Color i32 value '{}'", value)))
     }
 }
+
+impl TryFrom<i32> for ProtoTimeControlType {
+    type Error = Status;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        ProtoTimeControlType::from_i32(value)
+            .ok_or(Status::new(Code::InvalidArgument, format!("Illegal TimeControlType i32 value '{}'", value)))
+    }
+}
+
+impl TryFrom<i32> for ProtoOpponent {
+    type Error = Status;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        ProtoOpponent::from_i32(value)
+            .ok_or(Status::new(Code::InvalidArgument, format!("Illegal Opponent i32 value '{}'", value)))
+    }
+}
@@ -1,4 +1,7 @@
-use backend_engine::backend_error::BackendGameError;
+use crate::wire_api::proto_lost_cities::{ProtoRuleViolation, ProtoRule, ProtoCard, ProtoColor};
+use backend_engine::backend_error::{BackendGameError, Reason};
+use bytes::Bytes;
+use prost::Message;
 use tonic::{Status, Code};
 
 pub trait IntoTonicStatus {
@@ -24,9 +27,16 @@ impl IntoTonicStatus for BackendGameError {
             },
             BackendGameError::InvalidPlay(reason) => {
                 println!("INFO: User can't play card for reason {}", reason);
-                Status::new(
+                // The client reads the details to tell the player which rule they broke. The
+                // message is for anyone who can't.
+                let mut details = Vec::new();
+                if let Err(e) = ProtoRuleViolation::from(reason).encode(&mut details) {
+                    println!("WARN: Failed to encode rule violation {:?}: {}", reason, e);
+                }
+                Status::with_details(
                     Code::InvalidArgument,
-                    format!("Can't play card. RULE: {}", reason)
+                    format!("Can't play card. RULE: {}", reason),
+                    Bytes::from(details),
                 )
             },
             BackendGameError::Internal(cause) => {
@@ -36,3 +46,72 @@ impl IntoTonicStatus for BackendGameError {
         }
     }
 }
+
+impl From<Reason> for ProtoRuleViolation {
+    fn from(reason: Reason) -> Self {
+        let (rule, card, color) = match reason {
+            Reason::NotYourTurn => (ProtoRule::RuleNotYourTurn, None, None),
+            Reason::CardNotInHand(card) => (ProtoRule::RuleCardNotInHand, Some(card), None),
+            Reason::CantPlayDecreasingCardValue(card) => (ProtoRule::RuleDecreasingCardValue, Some(card), None),
+            Reason::NeutralDrawPileEmpty(color) => (ProtoRule::RuleDiscardPileEmpty, None, Some(color)),
+            Reason::MainDrawPileEmpty => (ProtoRule::RuleMainDrawPileEmpty, None, None),
+            Reason::CantRedrawCardJustPlayed(card) => (ProtoRule::RuleRedrawCardJustPlayed, Some(card), None),
+            Reason::GameOver => (ProtoRule::RuleGameOver, None, None),
+            Reason::OutOfTime => (ProtoRule::RuleOutOfTime, None, None),
+            Reason::DrawAlreadyOffered => (ProtoRule::RuleDrawAlreadyOffered, None, None),
+            Reason::NoDrawOffer => (ProtoRule::RuleNoDrawOffer, None, None),
+            Reason::NoMoveToTakeBack => (ProtoRule::RuleNoMoveToTakeBack, None, None),
+            Reason::TakeBackAlreadyRequested => (ProtoRule::RuleTakeBackAlreadyRequested, None, None),
+            Reason::NoTakeBackRequest => (ProtoRule::RuleNoTakeBackRequest, None, None),
+            Reason::NoDrawOnFinalTurn => (ProtoRule::RuleNoDrawOnFinalTurn, None, None),
+            Reason::InvalidRuleSet => (ProtoRule::RuleInvalidRuleSet, None, None),
+            Reason::TwoPlayerOnly => (ProtoRule::RuleTwoPlayerOnly, None, None),
+            Reason::AlreadySeated => (ProtoRule::RuleAlreadySeated, None, None),
+            Reason::GameNotOver => (ProtoRule::RuleGameNotOver, None, None),
+        };
+
+        ProtoRuleViolation {
+            rule: rule as i32,
+            card: card.map(ProtoCard::from),
+            color: color.map_or(ProtoColor::NoColor, ProtoColor::from) as i32,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use client_engine::client_game_api::error::{ClientGameError, RuleViolation};
+    use game_api::types::{Card, CardColor, CardValue};
+    use std::convert::TryFrom;
+
+    #[test]
+    fn rule_violations_are_in_the_status_details() {
+        let card = Card::new(CardColor::Blue, CardValue::try_from(4).unwrap());
+        let status = BackendGameError::InvalidPlay(Reason::CantPlayDecreasingCardValue(card)).into_status();
+
+        assert_eq!(status.code(), Code::InvalidArgument);
+        let proto_rule_violation = ProtoRuleViolation::decode(status.details()).unwrap();
+        assert_eq!(proto_rule_violation.rule, ProtoRule::RuleDecreasingCardValue as i32);
+        assert_eq!(proto_rule_violation.card, Some(ProtoCard::from(card)));
+        assert_eq!(proto_rule_violation.color, ProtoColor::NoColor as i32);
+    }
+
+    #[test]
+    fn rule_violations_round_trip_to_the_client() {
+        let card = Card::new(CardColor::Blue, CardValue::try_from(4).unwrap());
+        let cases = vec![
+            (Reason::NotYourTurn, RuleViolation::NotYourTurn),
+            (Reason::CantPlayDecreasingCardValue(card), RuleViolation::DecreasingCardValue(card)),
+            (Reason::NeutralDrawPileEmpty(CardColor::Green), RuleViolation::DiscardPileEmpty(CardColor::Green)),
+            (Reason::MainDrawPileEmpty, RuleViolation::MainDrawPileEmpty),
+        ];
+
+        for (reason, expected) in cases {
+            match ClientGameError::from(BackendGameError::InvalidPlay(reason).into_status()) {
+                ClientGameError::RuleViolation(rule_violation) => assert_eq!(rule_violation, expected),
+                other => panic!("Expected {:?} but got {:?}", expected, other),
+            }
+        }
+    }
+}
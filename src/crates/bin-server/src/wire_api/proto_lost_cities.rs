@@ -11,6 +11,14 @@ pub struct ProtoHostGameReq {
     pub game_id: std::string::String,
     #[prost(string, tag = "2")]
     pub player_id: std::string::String,
+    /// Leave unset for an untimed game.
+    #[prost(message, optional, tag = "3")]
+    pub time_control: ::std::option::Option<ProtoTimeControl>,
+    #[prost(enumeration = "ProtoOpponent", tag = "4")]
+    pub opponent: i32,
+    /// Leave unset for the standard rules.
+    #[prost(message, optional, tag = "5")]
+    pub rule_set: ::std::option::Option<ProtoRuleSet>,
 }
 /// Nothing
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -58,6 +66,104 @@ pub struct ProtoPlayCardReq {
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ProtoPlayCardReply {}
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoResignReq {
+    #[prost(string, tag = "1")]
+    pub game_id: std::string::String,
+    #[prost(string, tag = "2")]
+    pub player_id: std::string::String,
+}
+/// Nothing
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoResignReply {}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoOfferDrawReq {
+    #[prost(string, tag = "1")]
+    pub game_id: std::string::String,
+    #[prost(string, tag = "2")]
+    pub player_id: std::string::String,
+}
+/// Nothing
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoOfferDrawReply {}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoRespondToDrawOfferReq {
+    #[prost(string, tag = "1")]
+    pub game_id: std::string::String,
+    #[prost(string, tag = "2")]
+    pub player_id: std::string::String,
+    #[prost(bool, tag = "3")]
+    pub accept: bool,
+}
+/// Nothing
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoRespondToDrawOfferReply {}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoRequestTakeBackReq {
+    #[prost(string, tag = "1")]
+    pub game_id: std::string::String,
+    #[prost(string, tag = "2")]
+    pub player_id: std::string::String,
+}
+/// Nothing
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoRequestTakeBackReply {}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoRespondToTakeBackReq {
+    #[prost(string, tag = "1")]
+    pub game_id: std::string::String,
+    #[prost(string, tag = "2")]
+    pub player_id: std::string::String,
+    #[prost(bool, tag = "3")]
+    pub accept: bool,
+}
+/// Nothing
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoRespondToTakeBackReply {}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoAnalyzeGameReq {
+    #[prost(string, tag = "1")]
+    pub game_id: std::string::String,
+    #[prost(string, tag = "2")]
+    pub player_id: std::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoAnalyzeGameReply {
+    #[prost(message, optional, tag = "1")]
+    pub analysis: ::std::option::Option<ProtoGameAnalysis>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoExportGameReq {
+    #[prost(string, tag = "1")]
+    pub game_id: std::string::String,
+    #[prost(string, tag = "2")]
+    pub player_id: std::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoExportGameReply {
+    /// See rules::notation for the format.
+    #[prost(string, tag = "1")]
+    pub notation: std::string::String,
+}
+/// The first call a client makes, so neither side has to guess what the other understands.
+/// See game_api::wire_protocol.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoHandshakeReq {
+    #[prost(uint32, tag = "1")]
+    pub client_protocol_version: u32,
+    #[prost(string, repeated, tag = "2")]
+    pub client_features: ::std::vec::Vec<std::string::String>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoHandshakeReply {
+    #[prost(uint32, tag = "1")]
+    pub server_protocol_version: u32,
+    /// Older clients get FAILED_PRECONDITION instead of this reply.
+    #[prost(uint32, tag = "2")]
+    pub min_client_protocol_version: u32,
+    #[prost(string, repeated, tag = "3")]
+    pub server_features: ::std::vec::Vec<std::string::String>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ProtoDescribeGameReq {
     #[prost(string, tag = "1")]
     pub game_id: std::string::String,
@@ -89,6 +195,69 @@ pub struct ProtoGetMatchableGamesReply {
     #[prost(message, repeated, tag = "1")]
     pub games: ::std::vec::Vec<ProtoGameMetadata>,
 }
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoGetWatchableGamesReq {
+    #[prost(string, tag = "1")]
+    pub player_id: std::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoGetWatchableGamesReply {
+    #[prost(message, repeated, tag = "1")]
+    pub games: ::std::vec::Vec<ProtoGameMetadata>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoGetSpectatorGameStateReq {
+    #[prost(string, tag = "1")]
+    pub game_id: std::string::String,
+    #[prost(string, tag = "2")]
+    pub player_id: std::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoGetSpectatorGameStateReply {
+    #[prost(message, optional, tag = "1")]
+    pub game: ::std::option::Option<ProtoSpectatorGame>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoHostMatchReq {
+    #[prost(string, tag = "1")]
+    pub match_id: std::string::String,
+    #[prost(string, tag = "2")]
+    pub player_id: std::string::String,
+    /// 0 means use the default number of rounds.
+    #[prost(uint32, tag = "3")]
+    pub num_rounds: u32,
+    /// Leave unset for untimed rounds.
+    #[prost(message, optional, tag = "4")]
+    pub time_control: ::std::option::Option<ProtoTimeControl>,
+    /// Leave unset for the standard rules. Every round uses the same rules.
+    #[prost(message, optional, tag = "5")]
+    pub rule_set: ::std::option::Option<ProtoRuleSet>,
+}
+/// Nothing
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoHostMatchReply {}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoJoinMatchReq {
+    #[prost(string, tag = "1")]
+    pub match_id: std::string::String,
+    #[prost(string, tag = "2")]
+    pub player_id: std::string::String,
+}
+/// Nothing
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoJoinMatchReply {}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoGetMatchStateReq {
+    #[prost(string, tag = "1")]
+    pub match_id: std::string::String,
+    #[prost(string, tag = "2")]
+    pub player_id: std::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoGetMatchStateReply {
+    #[prost(message, optional, tag = "1")]
+    pub match_state: ::std::option::Option<ProtoMatch>,
+}
 // =======================================
 // Sub types
 // =======================================
@@ -112,6 +281,8 @@ pub struct ProtoPlayHistory {
     pub blue: ::std::vec::Vec<u32>,
     #[prost(uint32, repeated, tag = "5")]
     pub yellow: ::std::vec::Vec<u32>,
+    #[prost(uint32, repeated, tag = "6")]
+    pub purple: ::std::vec::Vec<u32>,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ProtoScore {
@@ -127,6 +298,8 @@ pub struct ProtoScore {
     pub blue: i32,
     #[prost(sint32, tag = "6")]
     pub yellow: i32,
+    #[prost(sint32, tag = "7")]
+    pub purple: i32,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ProtoGameMetadata {
@@ -140,6 +313,12 @@ pub struct ProtoGameMetadata {
     pub status: i32,
     #[prost(uint64, tag = "5")]
     pub created_time_ms: u64,
+    /// Unset means the standard rules.
+    #[prost(message, optional, tag = "6")]
+    pub rule_set: ::std::option::Option<ProtoRuleSet>,
+    /// Unset means Lost Cities.
+    #[prost(enumeration = "ProtoGameType", tag = "7")]
+    pub game_type: i32,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ProtoGame {
@@ -159,6 +338,153 @@ pub struct ProtoGame {
     pub my_score: ::std::option::Option<ProtoScore>,
     #[prost(message, optional, tag = "8")]
     pub op_score: ::std::option::Option<ProtoScore>,
+    /// Only set for timed games.
+    #[prost(message, optional, tag = "9")]
+    pub clock: ::std::option::Option<ProtoClock>,
+    #[prost(enumeration = "ProtoDrawOffer", tag = "10")]
+    pub draw_offer: i32,
+    #[prost(enumeration = "ProtoTakeBackRequest", tag = "11")]
+    pub take_back_request: i32,
+    /// Main draw pile plus whatever of the opponent's hand the player hasn't seen.
+    #[prost(message, repeated, tag = "12")]
+    pub unseen_cards: ::std::vec::Vec<ProtoCard>,
+    #[prost(message, optional, tag = "13")]
+    pub rule_set: ::std::option::Option<ProtoRuleSet>,
+    /// Everyone after the first opponent, in turn order. Empty for two player games.
+    #[prost(message, repeated, tag = "14")]
+    pub more_opponents: ::std::vec::Vec<ProtoOpponentBoard>,
+    /// Not set before the first turn.
+    #[prost(message, optional, tag = "15")]
+    pub last_move: ::std::option::Option<ProtoLastMove>,
+    /// Only set once the game is over.
+    #[prost(message, optional, tag = "16")]
+    pub summary: ::std::option::Option<ProtoGameSummary>,
+}
+/// The most recent turn, so the client can highlight what changed.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoLastMove {
+    /// Whose turn it was: 0 for me, 1 for the first opponent, and so on in turn order.
+    #[prost(uint32, tag = "1")]
+    pub seat_offset: u32,
+    #[prost(message, optional, tag = "2")]
+    pub card: ::std::option::Option<ProtoCard>,
+    #[prost(enumeration = "ProtoPlayTarget", tag = "3")]
+    pub target: i32,
+    /// NO_DRAW_PILE on a final turn, when nothing is drawn.
+    #[prost(enumeration = "ProtoDrawPile", tag = "4")]
+    pub draw_pile: i32,
+    /// Only set for DISCARD_DRAW. Nobody else gets to see what came off the main draw pile.
+    #[prost(message, optional, tag = "5")]
+    pub drawn_card: ::std::option::Option<ProtoCard>,
+}
+/// How a finished game played out. The end reason is in the game's status.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoGameSummary {
+    /// Me first, then the opponents in turn order.
+    #[prost(message, repeated, tag = "1")]
+    pub players: ::std::vec::Vec<ProtoPlayerSummary>,
+    #[prost(uint32, tag = "2")]
+    pub num_turns: u32,
+    /// 0 if the server didn't keep track.
+    #[prost(uint64, tag = "3")]
+    pub duration_ms: u64,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoPlayerSummary {
+    #[prost(message, repeated, tag = "1")]
+    pub final_hand: ::std::vec::Vec<ProtoCard>,
+    #[prost(message, optional, tag = "2")]
+    pub score: ::std::option::Option<ProtoScore>,
+}
+/// One opponent's side of the board, for three and four player games.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoOpponentBoard {
+    #[prost(message, optional, tag = "1")]
+    pub plays: ::std::option::Option<ProtoPlayHistory>,
+    #[prost(message, optional, tag = "2")]
+    pub score: ::std::option::Option<ProtoScore>,
+}
+/// The game as seen by someone who isn't playing. No hands are included.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoSpectatorGame {
+    /// The host first, then everyone else in turn order. Statuses refer to players by their index here.
+    #[prost(message, repeated, tag = "1")]
+    pub seats: ::std::vec::Vec<ProtoSpectatorSeat>,
+    #[prost(message, optional, tag = "2")]
+    pub discard_pile: ::std::option::Option<ProtoDiscardPile>,
+    #[prost(uint32, tag = "3")]
+    pub draw_pile_cards_remaining: u32,
+    #[prost(message, optional, tag = "4")]
+    pub status: ::std::option::Option<ProtoSpectatorStatus>,
+    #[prost(message, optional, tag = "5")]
+    pub rule_set: ::std::option::Option<ProtoRuleSet>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoSpectatorSeat {
+    #[prost(string, tag = "1")]
+    pub player_id: std::string::String,
+    #[prost(message, optional, tag = "2")]
+    pub plays: ::std::option::Option<ProtoPlayHistory>,
+    #[prost(message, optional, tag = "3")]
+    pub score: ::std::option::Option<ProtoScore>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoSpectatorStatus {
+    #[prost(bool, tag = "1")]
+    pub is_complete: bool,
+    /// Only set while in progress.
+    #[prost(uint32, tag = "2")]
+    pub turn_seat: u32,
+    /// Only set once complete. More than one means a tie.
+    #[prost(uint32, repeated, tag = "3")]
+    pub winning_seats: ::std::vec::Vec<u32>,
+    /// Only set once complete. A resignation or timeout is by whichever seat isn't winning.
+    #[prost(enumeration = "ProtoEndReason", tag = "4")]
+    pub end_reason: i32,
+}
+/// A series of games between the same two players, with scores summed across rounds.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoMatch {
+    /// Empty if nobody has joined yet.
+    #[prost(string, tag = "1")]
+    pub opponent_player_id: std::string::String,
+    #[prost(uint32, tag = "2")]
+    pub num_rounds: u32,
+    #[prost(message, repeated, tag = "3")]
+    pub rounds: ::std::vec::Vec<ProtoMatchRound>,
+    #[prost(sint32, tag = "4")]
+    pub my_score_total: i32,
+    #[prost(sint32, tag = "5")]
+    pub op_score_total: i32,
+    #[prost(enumeration = "ProtoMatchStatus", tag = "6")]
+    pub status: i32,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoMatchRound {
+    #[prost(string, tag = "1")]
+    pub game_id: std::string::String,
+    #[prost(enumeration = "ProtoGameStatus", tag = "2")]
+    pub status: i32,
+    #[prost(sint32, tag = "3")]
+    pub my_score: i32,
+    #[prost(sint32, tag = "4")]
+    pub op_score: i32,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoTimeControl {
+    #[prost(enumeration = "ProtoTimeControlType", tag = "1")]
+    pub time_control_type: i32,
+    #[prost(uint32, tag = "2")]
+    pub seconds: u32,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoClock {
+    #[prost(message, optional, tag = "1")]
+    pub time_control: ::std::option::Option<ProtoTimeControl>,
+    #[prost(uint64, tag = "2")]
+    pub my_remaining_ms: u64,
+    #[prost(uint64, tag = "3")]
+    pub op_remaining_ms: u64,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ProtoDiscardPile {
@@ -172,6 +498,8 @@ pub struct ProtoDiscardPile {
     pub blue: ::std::option::Option<ProtoDiscardPileSurface>,
     #[prost(message, optional, tag = "5")]
     pub yellow: ::std::option::Option<ProtoDiscardPileSurface>,
+    #[prost(message, optional, tag = "6")]
+    pub purple: ::std::option::Option<ProtoDiscardPileSurface>,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ProtoDiscardPileSurface {
@@ -180,6 +508,77 @@ pub struct ProtoDiscardPileSurface {
     #[prost(uint32, tag = "2")]
     pub remaining: u32,
 }
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoGameAnalysis {
+    /// Best first. Only set on the player's turn.
+    #[prost(message, repeated, tag = "1")]
+    pub move_hints: ::std::vec::Vec<ProtoMoveEvaluation>,
+    /// Only set once the game is over.
+    #[prost(message, repeated, tag = "2")]
+    pub mistakes: ::std::vec::Vec<ProtoMistake>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoMoveEvaluation {
+    #[prost(message, optional, tag = "1")]
+    pub card: ::std::option::Option<ProtoCard>,
+    #[prost(enumeration = "ProtoPlayTarget", tag = "2")]
+    pub target: i32,
+    #[prost(enumeration = "ProtoDrawPile", tag = "3")]
+    pub draw_pile: i32,
+    #[prost(enumeration = "ProtoColor", tag = "4")]
+    pub discard_draw_color: i32,
+    /// Estimated final score difference (mine minus my opponent's)
+    #[prost(double, tag = "5")]
+    pub value: f64,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoMistake {
+    /// Counts both players' moves, starting from 1
+    #[prost(uint32, tag = "1")]
+    pub turn: u32,
+    #[prost(message, optional, tag = "2")]
+    pub played: ::std::option::Option<ProtoMoveEvaluation>,
+    #[prost(message, optional, tag = "3")]
+    pub best: ::std::option::Option<ProtoMoveEvaluation>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoRuleSet {
+    #[prost(uint32, tag = "1")]
+    pub hand_size: u32,
+    #[prost(uint32, tag = "2")]
+    pub expedition_cost: u32,
+    #[prost(uint32, tag = "3")]
+    pub multiplier_per_wager: u32,
+    #[prost(uint32, tag = "4")]
+    pub bonus_threshold: u32,
+    #[prost(uint32, tag = "5")]
+    pub bonus_points: u32,
+    /// Whether the opponent gets one more turn (without drawing) after the last card is drawn.
+    #[prost(bool, tag = "6")]
+    pub final_turn: bool,
+    /// Play with the sixth (purple) expedition.
+    #[prost(bool, tag = "7")]
+    pub purple: bool,
+    /// Unset means two players.
+    #[prost(uint32, tag = "8")]
+    pub num_players: u32,
+}
+// =======================================
+// Errors
+// =======================================
+
+/// Sent as the details of an INVALID_ARGUMENT status when a request breaks a rule of the game.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoRuleViolation {
+    #[prost(enumeration = "ProtoRule", tag = "1")]
+    pub rule: i32,
+    /// Only set for RULE_CARD_NOT_IN_HAND, RULE_DECREASING_CARD_VALUE, and RULE_REDRAW_CARD_JUST_PLAYED.
+    #[prost(message, optional, tag = "2")]
+    pub card: ::std::option::Option<ProtoCard>,
+    /// Only set for RULE_DISCARD_PILE_EMPTY.
+    #[prost(enumeration = "ProtoColor", tag = "3")]
+    pub color: i32,
+}
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum ProtoColor {
@@ -189,6 +588,8 @@ pub enum ProtoColor {
     White = 3,
     Blue = 4,
     Yellow = 5,
+    /// Only in six color games. Five color clients can't play those.
+    Purple = 6,
 }
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
@@ -214,6 +615,89 @@ pub enum ProtoGameStatus {
     EndLose = 4,
     EndDraw = 5,
     Unmatched = 6,
+    EndWinOnTime = 7,
+    EndLoseOnTime = 8,
+    EndWinByResignation = 9,
+    EndLoseByResignation = 10,
+    EndDrawByAgreement = 11,
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum ProtoMatchStatus {
+    NoMatchStatus = 0,
+    MatchUnmatched = 1,
+    MatchInProgress = 2,
+    MatchWin = 3,
+    MatchLose = 4,
+    MatchDraw = 5,
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum ProtoGameType {
+    NoGameType = 0,
+    LostCities = 1,
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum ProtoTimeControlType {
+    NoTimeControl = 0,
+    /// Every move gets the same amount of time.
+    PerMove = 1,
+    /// Each player has a fixed amount of time for the whole game.
+    TimeBank = 2,
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum ProtoDrawOffer {
+    NoDrawOffer = 0,
+    DrawOfferedByMe = 1,
+    DrawOfferedByOpponent = 2,
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum ProtoEndReason {
+    DeckExhausted = 0,
+    TimedOut = 1,
+    Resigned = 2,
+    DrawAgreed = 3,
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum ProtoTakeBackRequest {
+    NoTakeBackRequest = 0,
+    TakeBackRequestedByMe = 1,
+    TakeBackRequestedByOpponent = 2,
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum ProtoOpponent {
+    OpponentHuman = 0,
+    EasyComputer = 1,
+    MediumComputer = 2,
+    HardComputer = 3,
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum ProtoRule {
+    NoRule = 0,
+    RuleNotYourTurn = 1,
+    RuleCardNotInHand = 2,
+    RuleDecreasingCardValue = 3,
+    RuleDiscardPileEmpty = 4,
+    RuleRedrawCardJustPlayed = 5,
+    RuleGameOver = 6,
+    RuleOutOfTime = 7,
+    RuleDrawAlreadyOffered = 8,
+    RuleNoDrawOffer = 9,
+    RuleNoMoveToTakeBack = 10,
+    RuleTakeBackAlreadyRequested = 11,
+    RuleNoTakeBackRequest = 12,
+    RuleNoDrawOnFinalTurn = 13,
+    RuleInvalidRuleSet = 14,
+    RuleTwoPlayerOnly = 15,
+    RuleGameNotOver = 16,
+    RuleMainDrawPileEmpty = 17,
+    RuleAlreadySeated = 18,
 }
 #[doc = r" Generated server implementations."]
 pub mod proto_lost_cities_server {
@@ -250,9 +734,60 @@ pub mod proto_lost_cities_server {
             &self,
             request: tonic::Request<super::ProtoGetMatchableGamesReq>,
         ) -> Result<tonic::Response<super::ProtoGetMatchableGamesReply>, tonic::Status>;
+        async fn get_watchable_games(
+            &self,
+            request: tonic::Request<super::ProtoGetWatchableGamesReq>,
+        ) -> Result<tonic::Response<super::ProtoGetWatchableGamesReply>, tonic::Status>;
+        async fn get_spectator_game_state(
+            &self,
+            request: tonic::Request<super::ProtoGetSpectatorGameStateReq>,
+        ) -> Result<tonic::Response<super::ProtoGetSpectatorGameStateReply>, tonic::Status>;
+        async fn host_match(
+            &self,
+            request: tonic::Request<super::ProtoHostMatchReq>,
+        ) -> Result<tonic::Response<super::ProtoHostMatchReply>, tonic::Status>;
+        async fn join_match(
+            &self,
+            request: tonic::Request<super::ProtoJoinMatchReq>,
+        ) -> Result<tonic::Response<super::ProtoJoinMatchReply>, tonic::Status>;
+        async fn get_match_state(
+            &self,
+            request: tonic::Request<super::ProtoGetMatchStateReq>,
+        ) -> Result<tonic::Response<super::ProtoGetMatchStateReply>, tonic::Status>;
+        async fn resign(
+            &self,
+            request: tonic::Request<super::ProtoResignReq>,
+        ) -> Result<tonic::Response<super::ProtoResignReply>, tonic::Status>;
+        async fn offer_draw(
+            &self,
+            request: tonic::Request<super::ProtoOfferDrawReq>,
+        ) -> Result<tonic::Response<super::ProtoOfferDrawReply>, tonic::Status>;
+        async fn respond_to_draw_offer(
+            &self,
+            request: tonic::Request<super::ProtoRespondToDrawOfferReq>,
+        ) -> Result<tonic::Response<super::ProtoRespondToDrawOfferReply>, tonic::Status>;
+        async fn request_take_back(
+            &self,
+            request: tonic::Request<super::ProtoRequestTakeBackReq>,
+        ) -> Result<tonic::Response<super::ProtoRequestTakeBackReply>, tonic::Status>;
+        async fn respond_to_take_back(
+            &self,
+            request: tonic::Request<super::ProtoRespondToTakeBackReq>,
+        ) -> Result<tonic::Response<super::ProtoRespondToTakeBackReply>, tonic::Status>;
+        async fn analyze_game(
+            &self,
+            request: tonic::Request<super::ProtoAnalyzeGameReq>,
+        ) -> Result<tonic::Response<super::ProtoAnalyzeGameReply>, tonic::Status>;
+        async fn export_game(
+            &self,
+            request: tonic::Request<super::ProtoExportGameReq>,
+        ) -> Result<tonic::Response<super::ProtoExportGameReply>, tonic::Status>;
+        async fn handshake(
+            &self,
+            request: tonic::Request<super::ProtoHandshakeReq>,
+        ) -> Result<tonic::Response<super::ProtoHandshakeReply>, tonic::Status>;
     }
     #[derive(Debug)]
-    #[doc(hidden)]
     pub struct ProtoLostCitiesServer<T: ProtoLostCities> {
         inner: _Inner<T>,
     }
@@ -269,17 +804,23 @@ pub mod proto_lost_cities_server {
             Self { inner }
         }
     }
-    impl<T: ProtoLostCities> Service<http::Request<HyperBody>> for ProtoLostCitiesServer<T> {
+    impl<T, B> Service<http::Request<B>> for ProtoLostCitiesServer<T>
+    where
+        T: ProtoLostCities,
+        B: HttpBody + Send + Sync + 'static,
+        B::Error: Into<StdError> + Send + 'static,
+    {
         type Response = http::Response<tonic::body::BoxBody>;
         type Error = Never;
         type Future = BoxFuture<Self::Response, Self::Error>;
         fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
             Poll::Ready(Ok(()))
         }
-        fn call(&mut self, req: http::Request<HyperBody>) -> Self::Future {
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
             let inner = self.inner.clone();
             match req.uri().path() {
                 "/proto_lost_cities.ProtoLostCities/HostGame" => {
+                    #[allow(non_camel_case_types)]
                     struct HostGameSvc<T: ProtoLostCities>(pub Arc<T>);
                     impl<T: ProtoLostCities> tonic::server::UnaryService<super::ProtoHostGameReq> for HostGameSvc<T> {
                         type Response = super::ProtoHostGameReply;
@@ -289,7 +830,7 @@ pub mod proto_lost_cities_server {
                             request: tonic::Request<super::ProtoHostGameReq>,
                         ) -> Self::Future {
                             let inner = self.0.clone();
-                            let fut = async move { inner.host_game(request).await };
+                            let fut = async move { (*inner).host_game(request).await };
                             Box::pin(fut)
                         }
                     }
@@ -310,6 +851,7 @@ pub mod proto_lost_cities_server {
                     Box::pin(fut)
                 }
                 "/proto_lost_cities.ProtoLostCities/JoinGame" => {
+                    #[allow(non_camel_case_types)]
                     struct JoinGameSvc<T: ProtoLostCities>(pub Arc<T>);
                     impl<T: ProtoLostCities> tonic::server::UnaryService<super::ProtoJoinGameReq> for JoinGameSvc<T> {
                         type Response = super::ProtoJoinGameReply;
@@ -319,7 +861,7 @@ pub mod proto_lost_cities_server {
                             request: tonic::Request<super::ProtoJoinGameReq>,
                         ) -> Self::Future {
                             let inner = self.0.clone();
-                            let fut = async move { inner.join_game(request).await };
+                            let fut = async move { (*inner).join_game(request).await };
                             Box::pin(fut)
                         }
                     }
@@ -340,6 +882,7 @@ pub mod proto_lost_cities_server {
                     Box::pin(fut)
                 }
                 "/proto_lost_cities.ProtoLostCities/GetGameState" => {
+                    #[allow(non_camel_case_types)]
                     struct GetGameStateSvc<T: ProtoLostCities>(pub Arc<T>);
                     impl<T: ProtoLostCities>
                         tonic::server::UnaryService<super::ProtoGetGameStateReq>
@@ -352,7 +895,7 @@ pub mod proto_lost_cities_server {
                             request: tonic::Request<super::ProtoGetGameStateReq>,
                         ) -> Self::Future {
                             let inner = self.0.clone();
-                            let fut = async move { inner.get_game_state(request).await };
+                            let fut = async move { (*inner).get_game_state(request).await };
                             Box::pin(fut)
                         }
                     }
@@ -373,6 +916,7 @@ pub mod proto_lost_cities_server {
                     Box::pin(fut)
                 }
                 "/proto_lost_cities.ProtoLostCities/PlayCard" => {
+                    #[allow(non_camel_case_types)]
                     struct PlayCardSvc<T: ProtoLostCities>(pub Arc<T>);
                     impl<T: ProtoLostCities> tonic::server::UnaryService<super::ProtoPlayCardReq> for PlayCardSvc<T> {
                         type Response = super::ProtoPlayCardReply;
@@ -382,7 +926,7 @@ pub mod proto_lost_cities_server {
                             request: tonic::Request<super::ProtoPlayCardReq>,
                         ) -> Self::Future {
                             let inner = self.0.clone();
-                            let fut = async move { inner.play_card(request).await };
+                            let fut = async move { (*inner).play_card(request).await };
                             Box::pin(fut)
                         }
                     }
@@ -403,6 +947,7 @@ pub mod proto_lost_cities_server {
                     Box::pin(fut)
                 }
                 "/proto_lost_cities.ProtoLostCities/DescribeGame" => {
+                    #[allow(non_camel_case_types)]
                     struct DescribeGameSvc<T: ProtoLostCities>(pub Arc<T>);
                     impl<T: ProtoLostCities>
                         tonic::server::UnaryService<super::ProtoDescribeGameReq>
@@ -415,7 +960,7 @@ pub mod proto_lost_cities_server {
                             request: tonic::Request<super::ProtoDescribeGameReq>,
                         ) -> Self::Future {
                             let inner = self.0.clone();
-                            let fut = async move { inner.describe_game(request).await };
+                            let fut = async move { (*inner).describe_game(request).await };
                             Box::pin(fut)
                         }
                     }
@@ -436,6 +981,7 @@ pub mod proto_lost_cities_server {
                     Box::pin(fut)
                 }
                 "/proto_lost_cities.ProtoLostCities/QueryGames" => {
+                    #[allow(non_camel_case_types)]
                     struct QueryGamesSvc<T: ProtoLostCities>(pub Arc<T>);
                     impl<T: ProtoLostCities> tonic::server::UnaryService<super::ProtoQueryGamesReq>
                         for QueryGamesSvc<T>
@@ -447,7 +993,7 @@ pub mod proto_lost_cities_server {
                             request: tonic::Request<super::ProtoQueryGamesReq>,
                         ) -> Self::Future {
                             let inner = self.0.clone();
-                            let fut = async move { inner.query_games(request).await };
+                            let fut = async move { (*inner).query_games(request).await };
                             Box::pin(fut)
                         }
                     }
@@ -468,6 +1014,7 @@ pub mod proto_lost_cities_server {
                     Box::pin(fut)
                 }
                 "/proto_lost_cities.ProtoLostCities/GetMatchableGames" => {
+                    #[allow(non_camel_case_types)]
                     struct GetMatchableGamesSvc<T: ProtoLostCities>(pub Arc<T>);
                     impl<T: ProtoLostCities>
                         tonic::server::UnaryService<super::ProtoGetMatchableGamesReq>
@@ -480,7 +1027,7 @@ pub mod proto_lost_cities_server {
                             request: tonic::Request<super::ProtoGetMatchableGamesReq>,
                         ) -> Self::Future {
                             let inner = self.0.clone();
-                            let fut = async move { inner.get_matchable_games(request).await };
+                            let fut = async move { (*inner).get_matchable_games(request).await };
                             Box::pin(fut)
                         }
                     }
@@ -500,6 +1047,432 @@ pub mod proto_lost_cities_server {
                     };
                     Box::pin(fut)
                 }
+                "/proto_lost_cities.ProtoLostCities/GetWatchableGames" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetWatchableGamesSvc<T: ProtoLostCities>(pub Arc<T>);
+                    impl<T: ProtoLostCities>
+                        tonic::server::UnaryService<super::ProtoGetWatchableGamesReq>
+                        for GetWatchableGamesSvc<T>
+                    {
+                        type Response = super::ProtoGetWatchableGamesReply;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ProtoGetWatchableGamesReq>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).get_watchable_games(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1.clone();
+                        let inner = inner.0;
+                        let method = GetWatchableGamesSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/proto_lost_cities.ProtoLostCities/GetSpectatorGameState" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetSpectatorGameStateSvc<T: ProtoLostCities>(pub Arc<T>);
+                    impl<T: ProtoLostCities>
+                        tonic::server::UnaryService<super::ProtoGetSpectatorGameStateReq>
+                        for GetSpectatorGameStateSvc<T>
+                    {
+                        type Response = super::ProtoGetSpectatorGameStateReply;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ProtoGetSpectatorGameStateReq>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut =
+                                async move { (*inner).get_spectator_game_state(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1.clone();
+                        let inner = inner.0;
+                        let method = GetSpectatorGameStateSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/proto_lost_cities.ProtoLostCities/HostMatch" => {
+                    #[allow(non_camel_case_types)]
+                    struct HostMatchSvc<T: ProtoLostCities>(pub Arc<T>);
+                    impl<T: ProtoLostCities> tonic::server::UnaryService<super::ProtoHostMatchReq> for HostMatchSvc<T> {
+                        type Response = super::ProtoHostMatchReply;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ProtoHostMatchReq>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).host_match(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1.clone();
+                        let inner = inner.0;
+                        let method = HostMatchSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/proto_lost_cities.ProtoLostCities/JoinMatch" => {
+                    #[allow(non_camel_case_types)]
+                    struct JoinMatchSvc<T: ProtoLostCities>(pub Arc<T>);
+                    impl<T: ProtoLostCities> tonic::server::UnaryService<super::ProtoJoinMatchReq> for JoinMatchSvc<T> {
+                        type Response = super::ProtoJoinMatchReply;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ProtoJoinMatchReq>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).join_match(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1.clone();
+                        let inner = inner.0;
+                        let method = JoinMatchSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/proto_lost_cities.ProtoLostCities/GetMatchState" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetMatchStateSvc<T: ProtoLostCities>(pub Arc<T>);
+                    impl<T: ProtoLostCities>
+                        tonic::server::UnaryService<super::ProtoGetMatchStateReq>
+                        for GetMatchStateSvc<T>
+                    {
+                        type Response = super::ProtoGetMatchStateReply;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ProtoGetMatchStateReq>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).get_match_state(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1.clone();
+                        let inner = inner.0;
+                        let method = GetMatchStateSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/proto_lost_cities.ProtoLostCities/Resign" => {
+                    #[allow(non_camel_case_types)]
+                    struct ResignSvc<T: ProtoLostCities>(pub Arc<T>);
+                    impl<T: ProtoLostCities> tonic::server::UnaryService<super::ProtoResignReq> for ResignSvc<T> {
+                        type Response = super::ProtoResignReply;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ProtoResignReq>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).resign(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1.clone();
+                        let inner = inner.0;
+                        let method = ResignSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/proto_lost_cities.ProtoLostCities/OfferDraw" => {
+                    #[allow(non_camel_case_types)]
+                    struct OfferDrawSvc<T: ProtoLostCities>(pub Arc<T>);
+                    impl<T: ProtoLostCities> tonic::server::UnaryService<super::ProtoOfferDrawReq> for OfferDrawSvc<T> {
+                        type Response = super::ProtoOfferDrawReply;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ProtoOfferDrawReq>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).offer_draw(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1.clone();
+                        let inner = inner.0;
+                        let method = OfferDrawSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/proto_lost_cities.ProtoLostCities/RespondToDrawOffer" => {
+                    #[allow(non_camel_case_types)]
+                    struct RespondToDrawOfferSvc<T: ProtoLostCities>(pub Arc<T>);
+                    impl<T: ProtoLostCities>
+                        tonic::server::UnaryService<super::ProtoRespondToDrawOfferReq>
+                        for RespondToDrawOfferSvc<T>
+                    {
+                        type Response = super::ProtoRespondToDrawOfferReply;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ProtoRespondToDrawOfferReq>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).respond_to_draw_offer(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1.clone();
+                        let inner = inner.0;
+                        let method = RespondToDrawOfferSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/proto_lost_cities.ProtoLostCities/RequestTakeBack" => {
+                    #[allow(non_camel_case_types)]
+                    struct RequestTakeBackSvc<T: ProtoLostCities>(pub Arc<T>);
+                    impl<T: ProtoLostCities>
+                        tonic::server::UnaryService<super::ProtoRequestTakeBackReq>
+                        for RequestTakeBackSvc<T>
+                    {
+                        type Response = super::ProtoRequestTakeBackReply;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ProtoRequestTakeBackReq>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).request_take_back(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1.clone();
+                        let inner = inner.0;
+                        let method = RequestTakeBackSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/proto_lost_cities.ProtoLostCities/RespondToTakeBack" => {
+                    #[allow(non_camel_case_types)]
+                    struct RespondToTakeBackSvc<T: ProtoLostCities>(pub Arc<T>);
+                    impl<T: ProtoLostCities>
+                        tonic::server::UnaryService<super::ProtoRespondToTakeBackReq>
+                        for RespondToTakeBackSvc<T>
+                    {
+                        type Response = super::ProtoRespondToTakeBackReply;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ProtoRespondToTakeBackReq>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).respond_to_take_back(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1.clone();
+                        let inner = inner.0;
+                        let method = RespondToTakeBackSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/proto_lost_cities.ProtoLostCities/AnalyzeGame" => {
+                    #[allow(non_camel_case_types)]
+                    struct AnalyzeGameSvc<T: ProtoLostCities>(pub Arc<T>);
+                    impl<T: ProtoLostCities> tonic::server::UnaryService<super::ProtoAnalyzeGameReq>
+                        for AnalyzeGameSvc<T>
+                    {
+                        type Response = super::ProtoAnalyzeGameReply;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ProtoAnalyzeGameReq>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).analyze_game(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1.clone();
+                        let inner = inner.0;
+                        let method = AnalyzeGameSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/proto_lost_cities.ProtoLostCities/ExportGame" => {
+                    #[allow(non_camel_case_types)]
+                    struct ExportGameSvc<T: ProtoLostCities>(pub Arc<T>);
+                    impl<T: ProtoLostCities> tonic::server::UnaryService<super::ProtoExportGameReq>
+                        for ExportGameSvc<T>
+                    {
+                        type Response = super::ProtoExportGameReply;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ProtoExportGameReq>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).export_game(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1.clone();
+                        let inner = inner.0;
+                        let method = ExportGameSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/proto_lost_cities.ProtoLostCities/Handshake" => {
+                    #[allow(non_camel_case_types)]
+                    struct HandshakeSvc<T: ProtoLostCities>(pub Arc<T>);
+                    impl<T: ProtoLostCities> tonic::server::UnaryService<super::ProtoHandshakeReq> for HandshakeSvc<T> {
+                        type Response = super::ProtoHandshakeReply;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ProtoHandshakeReq>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).handshake(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1.clone();
+                        let inner = inner.0;
+                        let method = HandshakeSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 _ => Box::pin(async move {
                     Ok(http::Response::builder()
                         .status(200)
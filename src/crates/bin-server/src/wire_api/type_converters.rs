@@ -1,5 +1,5 @@
-use crate::wire_api::proto_lost_cities::{ProtoCard, ProtoColor, ProtoDiscardPile, ProtoDiscardPileSurface, ProtoDrawPile, ProtoGame, ProtoGameStatus, ProtoGetGameStateReply, ProtoGetGameStateReq, ProtoHostGameReq, ProtoJoinGameReq, ProtoPlayCardReq, ProtoPlayHistory, ProtoPlayTarget, ProtoGameMetadata, ProtoDescribeGameReq, ProtoQueryGamesReq, ProtoGetMatchableGamesReq, ProtoScore};
-use game_api::types::{Card, CardColor, CardTarget, CardValue, DecoratedCard, DrawPile, GameResult, GameState, GameStatus, Play, GameMetadata};
+use crate::wire_api::proto_lost_cities::{ProtoCard, ProtoColor, ProtoDiscardPile, ProtoDiscardPileSurface, ProtoDrawPile, ProtoGame, ProtoGameStatus, ProtoGetGameStateReply, ProtoGetGameStateReq, ProtoHostGameReq, ProtoJoinGameReq, ProtoPlayCardReq, ProtoPlayHistory, ProtoPlayTarget, ProtoGameMetadata, ProtoDescribeGameReq, ProtoQueryGamesReq, ProtoGetMatchableGamesReq, ProtoScore, ProtoGetWatchableGamesReq, ProtoGetSpectatorGameStateReq, ProtoGetSpectatorGameStateReply, ProtoSpectatorGame, ProtoSpectatorSeat, ProtoSpectatorStatus, ProtoHostMatchReq, ProtoJoinMatchReq, ProtoGetMatchStateReq, ProtoGetMatchStateReply, ProtoMatch, ProtoMatchRound, ProtoMatchStatus, ProtoTimeControl, ProtoTimeControlType, ProtoClock, ProtoResignReq, ProtoOfferDrawReq, ProtoRespondToDrawOfferReq, ProtoDrawOffer, ProtoEndReason, ProtoRequestTakeBackReq, ProtoRespondToTakeBackReq, ProtoTakeBackRequest, ProtoOpponent, ProtoAnalyzeGameReq, ProtoAnalyzeGameReply, ProtoExportGameReq, ProtoGameAnalysis, ProtoMoveEvaluation, ProtoMistake, ProtoRuleSet, ProtoOpponentBoard, ProtoGameType, ProtoLastMove, ProtoGameSummary, ProtoPlayerSummary};
+use game_api::types::{Card, CardColor, CardTarget, CardValue, DecoratedCard, DrawPile, GameResult, EndReason, DrawOffer, TakeBackRequest, GameState, GameStatus, Play, GameMetadata, SpectatorGameState, SpectatorStatus, MatchState, MatchStatus, DEFAULT_MATCH_ROUNDS, TimeControl, Opponent, Difficulty, GameClock, GameAnalysis, MoveEvaluation, Mistake, RuleSet, OpponentBoard, GameType, LastMove, LastDraw, GameSummary, PlayerSummary};
 use std::collections::HashMap;
 use std::convert::TryFrom;
 use tonic::{Code, Status};
@@ -7,7 +7,7 @@ use tonic::{Code, Status};
 // ============================= Request converters ===================================
 // ============================= Proto -> App =========================================
 
-impl TryFrom<ProtoHostGameReq> for (String, String) {
+impl TryFrom<ProtoHostGameReq> for (String, String, Option<TimeControl>, Opponent, RuleSet) {
     type Error = Status;
 
     fn try_from(req: ProtoHostGameReq) -> Result<Self, Self::Error> {
@@ -18,7 +18,61 @@ impl TryFrom<ProtoHostGameReq> for (String, String) {
             return Err(Status::new(Code::InvalidArgument, "Missing PlayedId"));
         }
 
-        Ok((req.game_id, req.player_id))
+        let time_control = match req.time_control {
+            Some(proto_time_control) => Option::<TimeControl>::try_from(proto_time_control)?,
+            None => None,
+        };
+
+        let opponent = match ProtoOpponent::try_from(req.opponent)? {
+            ProtoOpponent::OpponentHuman => Opponent::Human,
+            ProtoOpponent::EasyComputer => Opponent::Computer(Difficulty::Easy),
+            ProtoOpponent::MediumComputer => Opponent::Computer(Difficulty::Medium),
+            ProtoOpponent::HardComputer => Opponent::Computer(Difficulty::Hard),
+        };
+
+        let rule_set = match req.rule_set {
+            Some(proto_rule_set) => RuleSet::from(proto_rule_set),
+            None => RuleSet::standard(),
+        };
+
+        Ok((req.game_id, req.player_id, time_control, opponent, rule_set))
+    }
+}
+
+/// Whether the rules are actually playable is up to the backend.
+impl From<ProtoRuleSet> for RuleSet {
+    fn from(proto_rule_set: ProtoRuleSet) -> Self {
+        RuleSet::new(
+            proto_rule_set.hand_size,
+            proto_rule_set.expedition_cost,
+            proto_rule_set.multiplier_per_wager,
+            proto_rule_set.bonus_threshold,
+            proto_rule_set.bonus_points,
+            proto_rule_set.final_turn,
+            proto_rule_set.purple,
+        ).with_num_players(match proto_rule_set.num_players {
+            0 => 2,
+            num_players => num_players,
+        })
+    }
+}
+
+/// An unset time control type means the game is untimed.
+impl TryFrom<ProtoTimeControl> for Option<TimeControl> {
+    type Error = Status;
+
+    fn try_from(proto_time_control: ProtoTimeControl) -> Result<Self, Self::Error> {
+        let seconds = proto_time_control.seconds;
+        let time_control_type = ProtoTimeControlType::try_from(proto_time_control.time_control_type)?;
+        if time_control_type != ProtoTimeControlType::NoTimeControl && seconds == 0 {
+            return Err(Status::new(Code::InvalidArgument, "Time control must allow more than 0 seconds"));
+        }
+
+        match time_control_type {
+            ProtoTimeControlType::NoTimeControl => Ok(None),
+            ProtoTimeControlType::PerMove => Ok(Some(TimeControl::PerMove(seconds))),
+            ProtoTimeControlType::TimeBank => Ok(Some(TimeControl::TimeBank(seconds))),
+        }
     }
 }
 
@@ -126,6 +180,197 @@ impl TryFrom<ProtoGetMatchableGamesReq> for String {
     }
 }
 
+impl TryFrom<ProtoGetWatchableGamesReq> for String {
+    type Error = Status;
+
+    fn try_from(req: ProtoGetWatchableGamesReq) -> Result<Self, Self::Error> {
+        if req.player_id.is_empty() {
+            return Err(Status::new(Code::InvalidArgument, "Missing PlayedId"));
+        }
+
+        Ok(req.player_id)
+    }
+}
+
+impl TryFrom<ProtoGetSpectatorGameStateReq> for (String, String) {
+    type Error = Status;
+
+    fn try_from(req: ProtoGetSpectatorGameStateReq) -> Result<Self, Self::Error> {
+        if req.game_id.is_empty() {
+            return Err(Status::new(Code::InvalidArgument, "Missing GameId"));
+        }
+        if req.player_id.is_empty() {
+            return Err(Status::new(Code::InvalidArgument, "Missing PlayedId"));
+        }
+
+        Ok((req.game_id, req.player_id))
+    }
+}
+
+impl TryFrom<ProtoHostMatchReq> for (String, String, u32, Option<TimeControl>, RuleSet) {
+    type Error = Status;
+
+    fn try_from(req: ProtoHostMatchReq) -> Result<Self, Self::Error> {
+        if req.match_id.is_empty() {
+            return Err(Status::new(Code::InvalidArgument, "Missing MatchId"));
+        }
+        if req.player_id.is_empty() {
+            return Err(Status::new(Code::InvalidArgument, "Missing PlayerId"));
+        }
+        let num_rounds = if req.num_rounds == 0 {
+            DEFAULT_MATCH_ROUNDS
+        } else {
+            req.num_rounds
+        };
+        let time_control = match req.time_control {
+            Some(proto_time_control) => Option::<TimeControl>::try_from(proto_time_control)?,
+            None => None,
+        };
+
+        let rule_set = match req.rule_set {
+            Some(proto_rule_set) => RuleSet::from(proto_rule_set),
+            None => RuleSet::standard(),
+        };
+
+        Ok((req.match_id, req.player_id, num_rounds, time_control, rule_set))
+    }
+}
+
+impl TryFrom<ProtoJoinMatchReq> for (String, String) {
+    type Error = Status;
+
+    fn try_from(req: ProtoJoinMatchReq) -> Result<Self, Self::Error> {
+        if req.match_id.is_empty() {
+            return Err(Status::new(Code::InvalidArgument, "Missing MatchId"));
+        }
+        if req.player_id.is_empty() {
+            return Err(Status::new(Code::InvalidArgument, "Missing PlayerId"));
+        }
+
+        Ok((req.match_id, req.player_id))
+    }
+}
+
+impl TryFrom<ProtoGetMatchStateReq> for (String, String) {
+    type Error = Status;
+
+    fn try_from(req: ProtoGetMatchStateReq) -> Result<Self, Self::Error> {
+        if req.match_id.is_empty() {
+            return Err(Status::new(Code::InvalidArgument, "Missing MatchId"));
+        }
+        if req.player_id.is_empty() {
+            return Err(Status::new(Code::InvalidArgument, "Missing PlayerId"));
+        }
+
+        Ok((req.match_id, req.player_id))
+    }
+}
+
+impl TryFrom<ProtoResignReq> for (String, String) {
+    type Error = Status;
+
+    fn try_from(req: ProtoResignReq) -> Result<Self, Self::Error> {
+        if req.game_id.is_empty() {
+            return Err(Status::new(Code::InvalidArgument, "Missing GameId"));
+        }
+        if req.player_id.is_empty() {
+            return Err(Status::new(Code::InvalidArgument, "Missing PlayerId"));
+        }
+
+        Ok((req.game_id, req.player_id))
+    }
+}
+
+impl TryFrom<ProtoOfferDrawReq> for (String, String) {
+    type Error = Status;
+
+    fn try_from(req: ProtoOfferDrawReq) -> Result<Self, Self::Error> {
+        if req.game_id.is_empty() {
+            return Err(Status::new(Code::InvalidArgument, "Missing GameId"));
+        }
+        if req.player_id.is_empty() {
+            return Err(Status::new(Code::InvalidArgument, "Missing PlayerId"));
+        }
+
+        Ok((req.game_id, req.player_id))
+    }
+}
+
+impl TryFrom<ProtoRespondToDrawOfferReq> for (String, String, bool) {
+    type Error = Status;
+
+    fn try_from(req: ProtoRespondToDrawOfferReq) -> Result<Self, Self::Error> {
+        if req.game_id.is_empty() {
+            return Err(Status::new(Code::InvalidArgument, "Missing GameId"));
+        }
+        if req.player_id.is_empty() {
+            return Err(Status::new(Code::InvalidArgument, "Missing PlayerId"));
+        }
+
+        Ok((req.game_id, req.player_id, req.accept))
+    }
+}
+
+impl TryFrom<ProtoRequestTakeBackReq> for (String, String) {
+    type Error = Status;
+
+    fn try_from(req: ProtoRequestTakeBackReq) -> Result<Self, Self::Error> {
+        if req.game_id.is_empty() {
+            return Err(Status::new(Code::InvalidArgument, "Missing GameId"));
+        }
+        if req.player_id.is_empty() {
+            return Err(Status::new(Code::InvalidArgument, "Missing PlayerId"));
+        }
+
+        Ok((req.game_id, req.player_id))
+    }
+}
+
+impl TryFrom<ProtoRespondToTakeBackReq> for (String, String, bool) {
+    type Error = Status;
+
+    fn try_from(req: ProtoRespondToTakeBackReq) -> Result<Self, Self::Error> {
+        if req.game_id.is_empty() {
+            return Err(Status::new(Code::InvalidArgument, "Missing GameId"));
+        }
+        if req.player_id.is_empty() {
+            return Err(Status::new(Code::InvalidArgument, "Missing PlayerId"));
+        }
+
+        Ok((req.game_id, req.player_id, req.accept))
+    }
+}
+
+impl TryFrom<ProtoAnalyzeGameReq> for (String, String) {
+    type Error = Status;
+
+    fn try_from(req: ProtoAnalyzeGameReq) -> Result<Self, Self::Error> {
+        if req.game_id.is_empty() {
+            return Err(Status::new(Code::InvalidArgument, "Missing GameId"));
+        }
+        if req.player_id.is_empty() {
+            return Err(Status::new(Code::InvalidArgument, "Missing PlayerId"));
+        }
+
+        Ok((req.game_id, req.player_id))
+    }
+}
+
+impl TryFrom<ProtoExportGameReq> for (String, String) {
+    type Error = Status;
+
+    fn try_from(req: ProtoExportGameReq) -> Result<Self, Self::Error> {
+        if req.game_id.is_empty() {
+            return Err(Status::new(Code::InvalidArgument, "Missing GameId"));
+        }
+        if req.player_id.is_empty() {
+            return Err(Status::new(Code::InvalidArgument, "Missing PlayerId"));
+        }
+
+        Ok((req.game_id, req.player_id))
+    }
+}
+
 // ============================= From<Proto> for App ==================================
 
 impl TryFrom<ProtoCard> for Card {
@@ -151,6 +396,7 @@ impl TryFrom<ProtoColor> for CardColor {
             ProtoColor::White => Ok(CardColor::White),
             ProtoColor::Blue => Ok(CardColor::Blue),
             ProtoColor::Yellow => Ok(CardColor::Yellow),
+            ProtoColor::Purple => Ok(CardColor::Purple),
         }
     }
 }
@@ -175,6 +421,19 @@ impl From<GameState> for ProtoGetGameStateReply {
                 *game_state.game_board().op_score_total(),
                 game_state.game_board().op_score_per_color().clone(),
             ))),
+            clock: game_state.clock().as_ref().map(ProtoClock::from),
+            draw_offer: ProtoDrawOffer::from(*game_state.draw_offer()) as i32,
+            take_back_request: ProtoTakeBackRequest::from(*game_state.take_back_request()) as i32,
+            unseen_cards: game_state.unseen_cards().iter()
+                .map(|card| ProtoCard::from(*card))
+                .collect(),
+            rule_set: Some(ProtoRuleSet::from(*game_state.rule_set())),
+            more_opponents: game_state.game_board().opponents().iter()
+                .skip(1)
+                .map(into_proto_opponent_board)
+                .collect(),
+            last_move: game_state.last_move().map(ProtoLastMove::from),
+            summary: game_state.summary().as_ref().map(ProtoGameSummary::from),
         };
 
         ProtoGetGameStateReply {
@@ -184,6 +443,88 @@ impl From<GameState> for ProtoGetGameStateReply {
     }
 }
 
+impl From<SpectatorGameState> for ProtoGetSpectatorGameStateReply {
+    fn from(game_state: SpectatorGameState) -> Self {
+        let seats = game_state.seats()
+            .iter()
+            .map(|seat| ProtoSpectatorSeat {
+                player_id: seat.player_id().to_owned(),
+                plays: Some(into_proto_play_history(seat.plays())),
+                score: Some(ProtoScore::from((*seat.score_total(), seat.score_per_color().clone()))),
+            })
+            .collect();
+        let status = match game_state.status() {
+            SpectatorStatus::InProgress(seat) => ProtoSpectatorStatus {
+                is_complete: false,
+                turn_seat: *seat as u32,
+                winning_seats: Vec::new(),
+                end_reason: ProtoEndReason::DeckExhausted as i32,
+            },
+            SpectatorStatus::Complete(winning_seats, end_reason) => ProtoSpectatorStatus {
+                is_complete: true,
+                turn_seat: 0,
+                winning_seats: winning_seats.iter().map(|seat| *seat as u32).collect(),
+                end_reason: ProtoEndReason::from(*end_reason) as i32,
+            },
+        };
+        let proto_game = ProtoSpectatorGame {
+            seats,
+            discard_pile: Some(into_proto_discard_pile(game_state.neutral_draw_pile())),
+            draw_pile_cards_remaining: *game_state.draw_pile_cards_remaining() as u32,
+            status: Some(status),
+            rule_set: Some(ProtoRuleSet::from(*game_state.rule_set())),
+        };
+
+        ProtoGetSpectatorGameStateReply {
+            game: Some(proto_game),
+        }
+    }
+}
+
+impl From<MatchState> for ProtoGetMatchStateReply {
+    fn from(match_state: MatchState) -> Self {
+        let rounds = match_state.rounds()
+            .iter()
+            .map(|round| ProtoMatchRound {
+                game_id: round.game_id().to_owned(),
+                status: ProtoGameStatus::from(*round.status()) as i32,
+                my_score: *round.my_score(),
+                op_score: *round.op_score(),
+            })
+            .collect();
+
+        let proto_match = ProtoMatch {
+            opponent_player_id: match_state.op_player_id().clone().unwrap_or_default(),
+            num_rounds: *match_state.num_rounds(),
+            rounds,
+            my_score_total: *match_state.my_score_total(),
+            op_score_total: *match_state.op_score_total(),
+            status: ProtoMatchStatus::from(*match_state.status()) as i32,
+        };
+
+        ProtoGetMatchStateReply {
+            match_state: Some(proto_match),
+        }
+    }
+}
+
+impl From<GameAnalysis> for ProtoAnalyzeGameReply {
+    fn from(analysis: GameAnalysis) -> Self {
+        ProtoAnalyzeGameReply {
+            analysis: Some(ProtoGameAnalysis {
+                move_hints: analysis.move_hints()
+                    .iter()
+                    .map(|move_evaluation| ProtoMoveEvaluation::from(*move_evaluation))
+                    .collect(),
+                mistakes: analysis.mistakes()
+                    .iter()
+                    .map(|mistake| ProtoMistake::from(*mistake))
+                    .collect(),
+            }),
+        }
+    }
+}
+
 // ============================= From<App> for Proto ==================================
 
 fn into_proto_card_vec(hand: &Vec<DecoratedCard>) -> Vec<ProtoCard> {
@@ -192,6 +533,13 @@ fn into_proto_card_vec(hand: &Vec<DecoratedCard>) -> Vec<ProtoCard> {
         .collect()
 }
 
+fn into_proto_opponent_board(opponent: &OpponentBoard) -> ProtoOpponentBoard {
+    ProtoOpponentBoard {
+        plays: Some(into_proto_play_history(opponent.plays())),
+        score: Some(ProtoScore::from((*opponent.score_total(), opponent.score_per_color().clone()))),
+    }
+}
+
 fn into_proto_play_history(plays: &HashMap<CardColor, Vec<CardValue>>) -> ProtoPlayHistory {
     let inner_converter = |color| {
         plays
@@ -211,6 +559,7 @@ fn into_proto_play_history(plays: &HashMap<CardColor, Vec<CardValue>>) -> ProtoP
         green: inner_converter(CardColor::Green),
         white: inner_converter(CardColor::White),
         yellow: inner_converter(CardColor::Yellow),
+        purple: inner_converter(CardColor::Purple),
     }
 }
 
@@ -230,6 +579,7 @@ fn into_proto_discard_pile(neutral_draw_pile: &HashMap<CardColor, (CardValue, us
         white: inner_converter(CardColor::White),
         blue: inner_converter(CardColor::Blue),
         yellow: inner_converter(CardColor::Yellow),
+        purple: inner_converter(CardColor::Purple),
     }
 }
 
@@ -250,6 +600,7 @@ impl From<CardColor> for ProtoColor {
             CardColor::White => ProtoColor::White,
             CardColor::Blue => ProtoColor::Blue,
             CardColor::Yellow => ProtoColor::Yellow,
+            CardColor::Purple => ProtoColor::Purple,
         };
 
         proto_color
@@ -265,6 +616,74 @@ impl From<CardTarget> for ProtoPlayTarget {
     }
 }
 
+impl From<LastMove> for ProtoLastMove {
+    fn from(last_move: LastMove) -> Self {
+        let (draw_pile, drawn_card) = match last_move.draw() {
+            LastDraw::MainPile => (ProtoDrawPile::MainDraw, None),
+            LastDraw::DiscardPile(card) => (ProtoDrawPile::DiscardDraw, Some(ProtoCard::from(*card))),
+            LastDraw::Nothing => (ProtoDrawPile::NoDrawPile, None),
+        };
+
+        ProtoLastMove {
+            seat_offset: last_move.opponent_index().map(|i| i as u32 + 1).unwrap_or(0),
+            card: Some((*last_move.card()).into()),
+            target: ProtoPlayTarget::from(*last_move.target()) as i32,
+            draw_pile: draw_pile as i32,
+            drawn_card,
+        }
+    }
+}
+
+impl From<&GameSummary> for ProtoGameSummary {
+    fn from(summary: &GameSummary) -> Self {
+        ProtoGameSummary {
+            players: summary.players().iter()
+                .map(ProtoPlayerSummary::from)
+                .collect(),
+            num_turns: *summary.num_turns() as u32,
+            duration_ms: summary.duration_ms().unwrap_or(0),
+        }
+    }
+}
+
+impl From<&PlayerSummary> for ProtoPlayerSummary {
+    fn from(player_summary: &PlayerSummary) -> Self {
+        ProtoPlayerSummary {
+            final_hand: player_summary.final_hand().iter()
+                .map(|card| ProtoCard::from(*card))
+                .collect(),
+            score: Some(ProtoScore::from((*player_summary.score_total(), player_summary.score_per_color().clone()))),
+        }
+    }
+}
+
+impl From<MoveEvaluation> for ProtoMoveEvaluation {
+    fn from(move_evaluation: MoveEvaluation) -> Self {
+        let (draw_pile, draw_color) = match move_evaluation.draw_pile() {
+            DrawPile::Main => (ProtoDrawPile::MainDraw, ProtoColor::NoColor),
+            DrawPile::Neutral(color) => (ProtoDrawPile::DiscardDraw, ProtoColor::from(*color)),
+        };
+
+        ProtoMoveEvaluation {
+            card: Some((*move_evaluation.card()).into()),
+            target: ProtoPlayTarget::from(*move_evaluation.target()) as i32,
+            draw_pile: draw_pile as i32,
+            discard_draw_color: draw_color as i32,
+            value: *move_evaluation.value(),
+        }
+    }
+}
+
+impl From<Mistake> for ProtoMistake {
+    fn from(mistake: Mistake) -> Self {
+        ProtoMistake {
+            turn: *mistake.turn(),
+            played: Some((*mistake.played()).into()),
+            best: Some((*mistake.best()).into()),
+        }
+    }
+}
+
 impl From<GameMetadata> for ProtoGameMetadata {
     fn from(game_metadata: GameMetadata) -> Self {
         let (guest_player_id, status): (&str, ProtoGameStatus) = match game_metadata.matched_data() {
@@ -280,6 +699,47 @@ impl From<GameMetadata> for ProtoGameMetadata {
             guest_player_id: guest_player_id.to_owned(),
             status: status as i32,
             created_time_ms: game_metadata.creation_time_ms(),
+            rule_set: Some(ProtoRuleSet::from(*game_metadata.rule_set())),
+            game_type: ProtoGameType::from(*game_metadata.game_type()) as i32,
+        }
+    }
+}
+
+impl From<&GameClock> for ProtoClock {
+    fn from(clock: &GameClock) -> Self {
+        ProtoClock {
+            time_control: Some(ProtoTimeControl::from(*clock.time_control())),
+            my_remaining_ms: *clock.my_remaining_ms(),
+            op_remaining_ms: *clock.op_remaining_ms(),
+        }
+    }
+}
+
+impl From<TimeControl> for ProtoTimeControl {
+    fn from(time_control: TimeControl) -> Self {
+        let (time_control_type, seconds) = match time_control {
+            TimeControl::PerMove(seconds) => (ProtoTimeControlType::PerMove, seconds),
+            TimeControl::TimeBank(seconds) => (ProtoTimeControlType::TimeBank, seconds),
+        };
+
+        ProtoTimeControl {
+            time_control_type: time_control_type as i32,
+            seconds,
+        }
+    }
+}
+
+impl From<RuleSet> for ProtoRuleSet {
+    fn from(rule_set: RuleSet) -> Self {
+        ProtoRuleSet {
+            hand_size: *rule_set.hand_size(),
+            expedition_cost: *rule_set.expedition_cost(),
+            multiplier_per_wager: *rule_set.multiplier_per_wager(),
+            bonus_threshold: *rule_set.bonus_threshold(),
+            bonus_points: *rule_set.bonus_points(),
+            final_turn: *rule_set.final_turn(),
+            purple: *rule_set.purple(),
+            num_players: *rule_set.num_players(),
         }
     }
 }
@@ -294,15 +754,73 @@ impl From<GameStatus> for ProtoGameStatus {
                     ProtoGameStatus::OpponentTurn
                 }
             }
-            GameStatus::Complete(result) => match result {
-                GameResult::Win => ProtoGameStatus::EndWin,
-                GameResult::Lose => ProtoGameStatus::EndLose,
-                GameResult::Draw => ProtoGameStatus::EndDraw,
+            GameStatus::Complete(result, end_reason) => match (result, end_reason) {
+                (GameResult::Win, EndReason::Timeout) => ProtoGameStatus::EndWinOnTime,
+                (GameResult::Lose, EndReason::Timeout) => ProtoGameStatus::EndLoseOnTime,
+                (GameResult::Win, EndReason::Resignation) => ProtoGameStatus::EndWinByResignation,
+                (GameResult::Lose, EndReason::Resignation) => ProtoGameStatus::EndLoseByResignation,
+                (_, EndReason::DrawAgreed) => ProtoGameStatus::EndDrawByAgreement,
+                (GameResult::Win, _) => ProtoGameStatus::EndWin,
+                (GameResult::Lose, _) => ProtoGameStatus::EndLose,
+                (GameResult::Draw, _) => ProtoGameStatus::EndDraw,
             },
         }
     }
 }
 
+impl From<EndReason> for ProtoEndReason {
+    fn from(end_reason: EndReason) -> Self {
+        match end_reason {
+            EndReason::DeckExhausted => ProtoEndReason::DeckExhausted,
+            EndReason::Timeout => ProtoEndReason::TimedOut,
+            EndReason::Resignation => ProtoEndReason::Resigned,
+            EndReason::DrawAgreed => ProtoEndReason::DrawAgreed,
+        }
+    }
+}
+
+impl From<Option<DrawOffer>> for ProtoDrawOffer {
+    fn from(draw_offer: Option<DrawOffer>) -> Self {
+        match draw_offer {
+            None => ProtoDrawOffer::NoDrawOffer,
+            Some(DrawOffer::OfferedByMe) => ProtoDrawOffer::DrawOfferedByMe,
+            Some(DrawOffer::OfferedByOpponent) => ProtoDrawOffer::DrawOfferedByOpponent,
+        }
+    }
+}
+
+impl From<Option<TakeBackRequest>> for ProtoTakeBackRequest {
+    fn from(take_back_request: Option<TakeBackRequest>) -> Self {
+        match take_back_request {
+            None => ProtoTakeBackRequest::NoTakeBackRequest,
+            Some(TakeBackRequest::RequestedByMe) => ProtoTakeBackRequest::TakeBackRequestedByMe,
+            Some(TakeBackRequest::RequestedByOpponent) => ProtoTakeBackRequest::TakeBackRequestedByOpponent,
+        }
+    }
+}
+
+impl From<MatchStatus> for ProtoMatchStatus {
+    fn from(match_status: MatchStatus) -> Self {
+        match match_status {
+            MatchStatus::Unmatched => ProtoMatchStatus::MatchUnmatched,
+            MatchStatus::InProgress => ProtoMatchStatus::MatchInProgress,
+            MatchStatus::Complete(result) => match result {
+                GameResult::Win => ProtoMatchStatus::MatchWin,
+                GameResult::Lose => ProtoMatchStatus::MatchLose,
+                GameResult::Draw => ProtoMatchStatus::MatchDraw,
+            },
+        }
+    }
+}
+
+impl From<GameType> for ProtoGameType {
+    fn from(game_type: GameType) -> Self {
+        match game_type {
+            GameType::LostCities => ProtoGameType::LostCities,
+        }
+    }
+}
+
 impl From<(i32, HashMap<CardColor, i32>)> for ProtoScore {
     fn from((score_total, score_per_color): (i32, HashMap<CardColor, i32>)) -> Self {
         ProtoScore {
@@ -312,6 +830,7 @@ impl From<(i32, HashMap<CardColor, i32>)> for ProtoScore {
             white: score_per_color.get(&CardColor::White).map_or(0, |v| *v),
             blue: score_per_color.get(&CardColor::Blue).map_or(0, |v| *v),
             yellow: score_per_color.get(&CardColor::Yellow).map_or(0, |v| *v),
+            purple: score_per_color.get(&CardColor::Purple).map_or(0, |v| *v),
         }
     }
 }
@@ -0,0 +1,78 @@
+use bin_simulator::report::Report;
+use bin_simulator::simulation;
+use game_api::types::RuleSet;
+use rules::deck::DeckFactory;
+use std::env;
+use std::process;
+
+const DEFAULT_SEED: u64 = 0;
+
+/// Play a batch of computer-vs-computer games without a server and print the stats as JSON.
+/// Game `i` uses deck seed `seed + i`, and the starting player alternates, so runs are
+/// reproducible (as long as no time-budgeted strategy is involved).
+fn main() {
+    let (program_name, games, p1_spec, p2_spec, seed) = get_cli_args();
+
+    let mut p1 = simulation::parse_strategy(&p1_spec, seed).unwrap_or_else(|message| {
+        eprintln!("{}", message);
+        print_usage_exit(&program_name);
+    });
+    let mut p2 = simulation::parse_strategy(&p2_spec, seed.wrapping_add(1)).unwrap_or_else(|message| {
+        eprintln!("{}", message);
+        print_usage_exit(&program_name);
+    });
+
+    let deck_factory = DeckFactory::new(&RuleSet::standard());
+    let mut records = Vec::with_capacity(games as usize);
+    for game in 0..games {
+        let deck = deck_factory.new_shuffled_deck_with_seed(seed.wrapping_add(game));
+        let record = simulation::play_game(deck, p1.as_mut(), p2.as_mut(), game % 2 == 0)
+            .unwrap_or_else(|message| {
+                eprintln!("Game {} failed: {}", game, message);
+                process::exit(1);
+            });
+        records.push(record);
+    }
+
+    let report = Report::new(&p1_spec, &p2_spec, &records);
+    println!("{}", serde_json::to_string_pretty(&report).expect("Report is always serializable"));
+}
+
+fn get_cli_args() -> (String, u64, String, String, u64) {
+    let mut cli_args = env::args();
+
+    // Arg 0
+    let program_name = cli_args.next().unwrap_or_else(|| {
+        eprintln!("Program name is somehow missing? You should never see this.");
+        process::exit(1);
+    });
+
+    // Args 1-3
+    let games = cli_args.next()
+        .and_then(|games_str| games_str.parse().ok())
+        .unwrap_or_else(|| print_usage_exit(&program_name));
+    let p1_spec = cli_args.next().unwrap_or_else(|| print_usage_exit(&program_name));
+    let p2_spec = cli_args.next().unwrap_or_else(|| print_usage_exit(&program_name));
+
+    // Arg 4
+    let seed = cli_args.next()
+        .map(|seed_str| seed_str.parse().unwrap_or_else(|_| {
+            print_usage_exit(&program_name);
+        }))
+        .unwrap_or(DEFAULT_SEED);
+
+    (program_name, games, p1_spec, p2_spec, seed)
+}
+
+fn print_usage_exit(program_name: &str) -> ! {
+    eprintln!();
+    eprintln!("Usage:  \t{} <games> <p1 strategy> <p2 strategy> [seed]", program_name);
+    eprintln!("Example:\t{} 100 heuristic mc:300 42", program_name);
+    eprintln!();
+    eprintln!("Strategies:");
+    eprintln!("  heuristic       \tthe easy computer opponent");
+    eprintln!("  mc:<iterations> \tMonte Carlo, this many simulations per move");
+    eprintln!("  mc:<millis>ms   \tMonte Carlo, this much time per move");
+    eprintln!();
+    process::exit(1);
+}
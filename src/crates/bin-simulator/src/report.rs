@@ -0,0 +1,193 @@
+use crate::simulation::GameRecord;
+use game_api::types::{CardColor, CardValue, RuleSet};
+use rules::scoring;
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap};
+
+/// What came out of a batch of simulated games. Serialized as the simulator's JSON output.
+#[derive(Debug, Serialize)]
+pub struct Report {
+    games: usize,
+    draws: usize,
+    turns: Distribution,
+    p1: PlayerReport,
+    p2: PlayerReport,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PlayerReport {
+    strategy: String,
+    wins: usize,
+    win_rate: f64,
+    score: Distribution,
+    colors: BTreeMap<String, ColorReport>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ColorReport {
+    /// How often this player started an expedition of this color.
+    start_rate: f64,
+    /// Averages over the games where the expedition was started.
+    mean_score: f64,
+    mean_cards: f64,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+pub struct Distribution {
+    mean: f64,
+    stddev: f64,
+    min: f64,
+    p25: f64,
+    median: f64,
+    p75: f64,
+    max: f64,
+}
+
+impl Report {
+    pub fn new(p1_strategy: &str, p2_strategy: &str, records: &[GameRecord]) -> Self {
+        let draws = records.iter()
+            .filter(|record| record.p1_score() == record.p2_score())
+            .count();
+        let turns: Vec<f64> = records.iter()
+            .map(|record| *record.turns() as f64)
+            .collect();
+
+        Report {
+            games: records.len(),
+            draws,
+            turns: Distribution::new(&turns),
+            p1: PlayerReport::new(
+                p1_strategy,
+                records.iter().map(|record| (*record.p1_score(), *record.p2_score(), record.p1_plays())),
+            ),
+            p2: PlayerReport::new(
+                p2_strategy,
+                records.iter().map(|record| (*record.p2_score(), *record.p1_score(), record.p2_plays())),
+            ),
+        }
+    }
+}
+
+impl PlayerReport {
+    /// `games` yields (my score, opponent's score, my plays) for every game.
+    fn new<'a, I>(strategy: &str, games: I) -> Self
+        where I: Iterator<Item = (i32, i32, &'a HashMap<CardColor, Vec<CardValue>>)>
+    {
+        let mut wins = 0;
+        let mut scores = Vec::new();
+        let mut started: HashMap<CardColor, Vec<&Vec<CardValue>>> = HashMap::new();
+        for (my_score, op_score, plays) in games {
+            if my_score > op_score {
+                wins += 1;
+            }
+            scores.push(my_score as f64);
+            for (color, column) in plays.iter().filter(|(_, column)| !column.is_empty()) {
+                started.entry(*color).or_default().push(column);
+            }
+        }
+
+        let games = scores.len();
+        let colors = RuleSet::standard().colors().iter()
+            .map(|color| {
+                let columns = started.remove(color).unwrap_or_default();
+                (format!("{:?}", color), ColorReport::new(&columns, games))
+            })
+            .collect();
+
+        PlayerReport {
+            strategy: strategy.to_owned(),
+            wins,
+            win_rate: ratio(wins, games),
+            score: Distribution::new(&scores),
+            colors,
+        }
+    }
+}
+
+impl ColorReport {
+    fn new(started_columns: &[&Vec<CardValue>], games: usize) -> Self {
+        let started = started_columns.len();
+        let total_score: i32 = started_columns.iter()
+            .map(|column| scoring::compute_score_for_color(column, &RuleSet::standard()))
+            .sum();
+        let total_cards: usize = started_columns.iter()
+            .map(|column| column.len())
+            .sum();
+
+        ColorReport {
+            start_rate: ratio(started, games),
+            mean_score: if started == 0 { 0.0 } else { total_score as f64 / started as f64 },
+            mean_cards: ratio(total_cards, started),
+        }
+    }
+}
+
+impl Distribution {
+    fn new(values: &[f64]) -> Self {
+        if values.is_empty() {
+            return Distribution { mean: 0.0, stddev: 0.0, min: 0.0, p25: 0.0, median: 0.0, p75: 0.0, max: 0.0 };
+        }
+
+        let mut sorted = values.to_vec();
+        sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        let count = sorted.len() as f64;
+        let mean = sorted.iter().sum::<f64>() / count;
+        let variance = sorted.iter().map(|value| (value - mean).powi(2)).sum::<f64>() / count;
+        // Nearest rank
+        let percentile = |p: f64| sorted[((p * count).ceil() as usize).max(1) - 1];
+
+        Distribution {
+            mean,
+            stddev: variance.sqrt(),
+            min: sorted[0],
+            p25: percentile(0.25),
+            median: percentile(0.5),
+            p75: percentile(0.75),
+            max: sorted[sorted.len() - 1],
+        }
+    }
+}
+
+fn ratio(numerator: usize, denominator: usize) -> f64 {
+    if denominator == 0 {
+        0.0
+    } else {
+        numerator as f64 / denominator as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn distribution_of_known_values() {
+        let distribution = Distribution::new(&[4.0, 1.0, 3.0, 2.0]);
+
+        assert_eq!(distribution, Distribution {
+            mean: 2.5,
+            stddev: 1.25_f64.sqrt(),
+            min: 1.0,
+            p25: 1.0,
+            median: 2.0,
+            p75: 3.0,
+            max: 4.0,
+        });
+    }
+
+    #[test]
+    fn counts_wins_and_draws() {
+        let records = vec![
+            GameRecord::new(10, 5, 40, HashMap::new(), HashMap::new()),
+            GameRecord::new(0, 0, 44, HashMap::new(), HashMap::new()),
+            GameRecord::new(-20, 30, 48, HashMap::new(), HashMap::new()),
+        ];
+        let report = Report::new("a", "b", &records);
+
+        assert_eq!(report.games, 3);
+        assert_eq!(report.draws, 1);
+        assert_eq!(report.p1.wins, 1);
+        assert_eq!(report.p2.wins, 1);
+        assert_eq!(report.turns.median, 44.0);
+    }
+}
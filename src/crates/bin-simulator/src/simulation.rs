@@ -0,0 +1,161 @@
+use bot::Strategy;
+use bot::heuristic::HeuristicStrategy;
+use bot::monte_carlo::{Budget, MonteCarloStrategy};
+use bot::view;
+use game_api::types::{Card, CardColor, CardValue, RuleSet};
+use rules::deck;
+use rules::engine::{self, RulesState};
+use rules::scoring;
+use std::collections::HashMap;
+use std::time::Duration;
+
+/// Everything worth remembering about one finished game.
+#[derive(Debug, PartialEq)]
+pub struct GameRecord {
+    p1_score: i32,
+    p2_score: i32,
+    turns: u32,
+    p1_plays: HashMap<CardColor, Vec<CardValue>>,
+    p2_plays: HashMap<CardColor, Vec<CardValue>>,
+}
+
+impl GameRecord {
+    pub fn new(
+        p1_score: i32,
+        p2_score: i32,
+        turns: u32,
+        p1_plays: HashMap<CardColor, Vec<CardValue>>,
+        p2_plays: HashMap<CardColor, Vec<CardValue>>,
+    ) -> Self {
+        GameRecord {
+            p1_score,
+            p2_score,
+            turns,
+            p1_plays,
+            p2_plays,
+        }
+    }
+
+    pub fn p1_score(&self) -> &i32 {
+        &self.p1_score
+    }
+
+    pub fn p2_score(&self) -> &i32 {
+        &self.p2_score
+    }
+
+    pub fn turns(&self) -> &u32 {
+        &self.turns
+    }
+
+    pub fn p1_plays(&self) -> &HashMap<CardColor, Vec<CardValue>> {
+        &self.p1_plays
+    }
+
+    pub fn p2_plays(&self) -> &HashMap<CardColor, Vec<CardValue>> {
+        &self.p2_plays
+    }
+}
+
+/// Play a whole game in-process: deal like the server does, then let each strategy see only its
+/// own `GameState` and apply its moves with the rules engine.
+pub fn play_game(
+    mut deck: Vec<Card>,
+    p1: &mut dyn Strategy,
+    p2: &mut dyn Strategy,
+    p1_starts: bool,
+) -> Result<GameRecord, String> {
+    let hands = deck::deal(&mut deck, &RuleSet::standard())
+        .ok_or("Deck is too small to deal")?;
+
+    let mut state = RulesState::new(
+        hands,
+        vec![HashMap::new(), HashMap::new()],
+        HashMap::new(),
+        deck,
+        if p1_starts { 0 } else { 1 },
+    );
+
+    let mut turns = 0;
+    while !engine::is_terminal(&state) {
+        let p1_turn = *state.turn() == 0;
+        let game_state = view::player_view(&state, *state.turn());
+        let player = if p1_turn { "p1" } else { "p2" };
+        let action = if p1_turn { p1.choose_action(&game_state) } else { p2.choose_action(&game_state) };
+        let action = action
+            .ok_or_else(|| format!("{} had no move on turn {}", player, turns + 1))?;
+        state = engine::apply(state, &action)
+            .map_err(|reason| format!("{} made an illegal move {:?} on turn {}: {:?}", player, action, turns + 1, reason))?;
+        turns += 1;
+    }
+
+    Ok(GameRecord {
+        p1_score: scoring::compute_score(&state.plays()[0], state.rule_set()).0,
+        p2_score: scoring::compute_score(&state.plays()[1], state.rule_set()).0,
+        turns,
+        p1_plays: state.plays()[0].to_owned(),
+        p2_plays: state.plays()[1].to_owned(),
+    })
+}
+
+/// Parse a strategy from the command line:
+/// * `heuristic` - the easy computer opponent
+/// * `mc:<iterations>` - Monte Carlo with a fixed number of simulations per move, e.g. `mc:300`
+/// * `mc:<millis>ms` - Monte Carlo with a time budget per move, e.g. `mc:500ms`
+pub fn parse_strategy(spec: &str, seed: u64) -> Result<Box<dyn Strategy>, String> {
+    if spec == "heuristic" {
+        return Ok(Box::new(HeuristicStrategy));
+    }
+
+    if let Some(budget) = spec.strip_prefix("mc:") {
+        let budget = if let Some(millis) = budget.strip_suffix("ms") {
+            millis.parse().map(|millis| Budget::Time(Duration::from_millis(millis)))
+        } else {
+            budget.parse().map(Budget::Iterations)
+        };
+
+        return budget
+            .map(|budget| Box::new(MonteCarloStrategy::with_seed(budget, seed)) as Box<dyn Strategy>)
+            .map_err(|_| format!("Illegal Monte Carlo budget in '{}'", spec));
+    }
+
+    Err(format!("Unknown strategy '{}'", spec))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rules::deck::DeckFactory;
+
+    fn heuristic_game(seed: u64) -> GameRecord {
+        let deck = DeckFactory::new(&RuleSet::standard()).new_shuffled_deck_with_seed(seed);
+        play_game(deck, &mut HeuristicStrategy, &mut HeuristicStrategy, true).unwrap()
+    }
+
+    #[test]
+    fn plays_until_the_deck_runs_out() {
+        let record = heuristic_game(42);
+
+        // 60 cards, 16 dealt, one drawn per turn. Turns drawing from the discards add more.
+        assert!(*record.turns() >= 44);
+        let cards_played: usize = record.p1_plays().values()
+            .chain(record.p2_plays().values())
+            .map(|column| column.len())
+            .sum();
+        assert!(cards_played <= *record.turns() as usize);
+    }
+
+    #[test]
+    fn same_seed_same_game() {
+        assert_eq!(heuristic_game(7), heuristic_game(7));
+    }
+
+    #[test]
+    fn parses_strategies() {
+        assert!(parse_strategy("heuristic", 1).is_ok());
+        assert!(parse_strategy("mc:300", 1).is_ok());
+        assert!(parse_strategy("mc:500ms", 1).is_ok());
+        assert!(parse_strategy("mc:lots", 1).is_err());
+        assert!(parse_strategy("random", 1).is_err());
+    }
+}
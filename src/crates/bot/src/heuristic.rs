@@ -0,0 +1,254 @@
+use crate::Strategy;
+use game_api::types::{Card, CardColor, CardTarget, CardValue, DecoratedCard, DrawPile, GameBoard, GameState, GameStatus, RuleSet};
+use rules::engine::Action;
+use rules::scoring;
+use std::collections::HashMap;
+
+/// Don't skip more than this many values when adding to an expedition, unless the game is about
+/// to end anyway.
+const MAX_GAP: i32 = 3;
+
+/// "About to end" = fewer than this many cards left in the main draw pile.
+const ENDGAME_DRAW_PILE_SIZE: usize = 10;
+
+/// Plays like a careful beginner: only commits to expeditions its hand can pay for, plays them in
+/// increasing order without skipping too much, and otherwise discards whatever is least useful to
+/// both itself and its opponent.
+pub struct HeuristicStrategy;
+
+impl Strategy for HeuristicStrategy {
+    fn choose_action(&mut self, game_state: &GameState) -> Option<Action> {
+        if let GameStatus::Complete(_, _) = game_state.status() {
+            return None;
+        }
+
+        let board = game_state.game_board();
+        let (card, target) = choose_card(board, game_state.my_hand(), game_state.rule_set())?;
+        let draw_pile = choose_draw_pile(board, &card, target);
+
+        Some(Action::new(card, target, draw_pile))
+    }
+}
+
+fn choose_card(board: &GameBoard, hand: &[DecoratedCard], rule_set: &RuleSet) -> Option<(Card, CardTarget)> {
+    let is_endgame = *board.draw_pile_cards_remaining() < ENDGAME_DRAW_PILE_SIZE;
+
+    // 1. Add to an expedition that's worth it, skipping as few values as possible.
+    let best_play = hand.iter()
+        .filter(|decorated_card| *decorated_card.is_playable())
+        .filter(|decorated_card| {
+            let color = *decorated_card.card().card_color();
+            is_started(color, board.my_plays()) || expedition_outlook(color, board.my_plays(), hand, rule_set) > 0
+        })
+        .map(|decorated_card| (gap(decorated_card.card(), board.my_plays()), decorated_card.card()))
+        .filter(|(gap, _)| *gap <= MAX_GAP || is_endgame)
+        .min_by_key(|(gap, card)| (*gap, **card));
+    if let Some((_, card)) = best_play {
+        return Some((*card, CardTarget::Player));
+    }
+
+    // 2. Otherwise, get rid of something.
+    hand.iter()
+        .min_by_key(|decorated_card| (discard_cost(decorated_card, board, hand, rule_set), *decorated_card.card()))
+        .map(|decorated_card| (*decorated_card.card(), CardTarget::Neutral))
+}
+
+/// Take a discarded card only if it goes right onto one of my expeditions. Nothing's drawn on a
+/// final turn, which is always marked as a draw from the empty main draw pile.
+fn choose_draw_pile(board: &GameBoard, played_card: &Card, target: CardTarget) -> DrawPile {
+    if *board.draw_pile_cards_remaining() == 0 {
+        return DrawPile::Main;
+    }
+
+    let mut my_plays = board.my_plays().clone();
+    if target == CardTarget::Player {
+        my_plays.entry(*played_card.card_color())
+            .or_default()
+            .push(*played_card.card_value());
+    }
+
+    board.neutral_draw_pile()
+        .iter()
+        .filter(|(color, _)| !(target == CardTarget::Neutral && *color == played_card.card_color()))
+        .filter(|(color, (top_value, _))| {
+            let top_card = Card::new(**color, *top_value);
+            is_started(**color, &my_plays) && gap(&top_card, &my_plays) >= 0
+        })
+        .map(|(color, (top_value, _))| (*top_value, *color))
+        .max()
+        .map(|(_, color)| DrawPile::Neutral(color))
+        .unwrap_or(DrawPile::Main)
+}
+
+/// What my expedition of this color would score if I played every playable card of that color
+/// from my hand, in order.
+fn expedition_outlook(
+    color: CardColor,
+    my_plays: &HashMap<CardColor, Vec<CardValue>>,
+    hand: &[DecoratedCard],
+    rule_set: &RuleSet,
+) -> i32 {
+    let mut column = my_plays.get(&color).cloned().unwrap_or_default();
+    let mut from_hand: Vec<CardValue> = hand.iter()
+        .filter(|decorated_card| *decorated_card.is_playable() && *decorated_card.card().card_color() == color)
+        .map(|decorated_card| *decorated_card.card().card_value())
+        .collect();
+    from_hand.sort();
+    column.extend(from_hand);
+
+    scoring::compute_score_for_color(&column, rule_set)
+}
+
+/// How useful a card is to keep in hand (to me), plus how useful it would be to my opponent if I
+/// discarded it.
+fn discard_cost(decorated_card: &DecoratedCard, board: &GameBoard, hand: &[DecoratedCard], rule_set: &RuleSet) -> i32 {
+    let card = decorated_card.card();
+    let value = u32::from(*card.card_value()) as i32;
+
+    let useful_to_me = if !*decorated_card.is_playable() {
+        0
+    } else if is_started(*card.card_color(), board.my_plays())
+        || expedition_outlook(*card.card_color(), board.my_plays(), hand, rule_set) > 0 {
+        10 + value
+    } else {
+        value / 2
+    };
+
+    let useful_to_op = if is_started(*card.card_color(), board.op_plays()) && gap(card, board.op_plays()) >= 0 {
+        10 + value
+    } else {
+        0
+    };
+
+    useful_to_me + useful_to_op
+}
+
+fn is_started(color: CardColor, plays: &HashMap<CardColor, Vec<CardValue>>) -> bool {
+    match plays.get(&color) {
+        Some(column) => !column.is_empty(),
+        None => false,
+    }
+}
+
+/// How many values playing this card would skip. Negative if it can't be played at all.
+fn gap(card: &Card, plays: &HashMap<CardColor, Vec<CardValue>>) -> i32 {
+    let top_value = plays.get(card.card_color())
+        .and_then(|column| column.last())
+        .map(|top_value| u32::from(*top_value))
+        .unwrap_or(1);
+
+    u32::from(*card.card_value()) as i32 - top_value as i32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rules::plays;
+    use std::convert::TryFrom;
+
+    fn card(color: CardColor, value: u32) -> Card {
+        Card::new(color, CardValue::try_from(value).unwrap())
+    }
+
+    fn plays(plays_vec: Vec<(CardColor, u32)>) -> HashMap<CardColor, Vec<CardValue>> {
+        let mut plays_map: HashMap<CardColor, Vec<CardValue>> = HashMap::new();
+        for (color, value) in plays_vec {
+            plays_map.entry(color)
+                .or_default()
+                .push(CardValue::try_from(value).unwrap());
+        }
+
+        plays_map
+    }
+
+    fn game_state(
+        my_plays: HashMap<CardColor, Vec<CardValue>>,
+        op_plays: HashMap<CardColor, Vec<CardValue>>,
+        neutral_draw_pile: Vec<(CardColor, u32)>,
+        hand: Vec<Card>,
+    ) -> GameState {
+        let neutral_draw_pile = neutral_draw_pile.into_iter()
+            .map(|(color, value)| (color, (CardValue::try_from(value).unwrap(), 1)))
+            .collect();
+        let hand = plays::decorate_hand(hand, &my_plays);
+
+        GameState::new(
+            GameBoard::new(
+                my_plays,
+                op_plays,
+                0,
+                0,
+                HashMap::new(),
+                HashMap::new(),
+                neutral_draw_pile,
+                30,
+            ),
+            hand,
+            GameStatus::InProgress(true),
+            None,
+            None,
+            None,
+            vec![],
+        )
+    }
+
+    #[test]
+    fn continues_expedition_with_smallest_gap() {
+        let game_state = game_state(
+            plays(vec![(CardColor::Red, 3)]),
+            HashMap::new(),
+            vec![],
+            vec![card(CardColor::Red, 8), card(CardColor::Red, 5), card(CardColor::Green, 2)],
+        );
+
+        assert_eq!(
+            HeuristicStrategy.choose_action(&game_state),
+            Some(Action::new(card(CardColor::Red, 5), CardTarget::Player, DrawPile::Main))
+        );
+    }
+
+    #[test]
+    fn discards_dead_card_first() {
+        let game_state = game_state(
+            plays(vec![(CardColor::Red, 9)]),
+            HashMap::new(),
+            vec![],
+            vec![card(CardColor::Blue, 10), card(CardColor::Red, 4)],
+        );
+
+        assert_eq!(
+            HeuristicStrategy.choose_action(&game_state),
+            Some(Action::new(card(CardColor::Red, 4), CardTarget::Neutral, DrawPile::Main))
+        );
+    }
+
+    #[test]
+    fn doesnt_feed_opponent() {
+        let game_state = game_state(
+            HashMap::new(),
+            plays(vec![(CardColor::White, 4)]),
+            vec![],
+            vec![card(CardColor::White, 9), card(CardColor::Yellow, 6)],
+        );
+
+        assert_eq!(
+            HeuristicStrategy.choose_action(&game_state),
+            Some(Action::new(card(CardColor::Yellow, 6), CardTarget::Neutral, DrawPile::Main))
+        );
+    }
+
+    #[test]
+    fn draws_discard_that_fits_an_expedition() {
+        let game_state = game_state(
+            plays(vec![(CardColor::Blue, 3)]),
+            HashMap::new(),
+            vec![(CardColor::Blue, 7), (CardColor::Green, 10)],
+            vec![card(CardColor::Blue, 5)],
+        );
+
+        assert_eq!(
+            HeuristicStrategy.choose_action(&game_state),
+            Some(Action::new(card(CardColor::Blue, 5), CardTarget::Player, DrawPile::Neutral(CardColor::Blue)))
+        );
+    }
+}
@@ -0,0 +1,28 @@
+use crate::heuristic::HeuristicStrategy;
+use crate::monte_carlo::{Budget, MonteCarloStrategy};
+use game_api::types::{Difficulty, GameState};
+use rules::engine::Action;
+use std::time::Duration;
+
+pub mod heuristic;
+pub mod monte_carlo;
+pub mod view;
+
+/// Player ID the computer plays under when a host picks it as their opponent.
+pub const BOT_PLAYER_ID: &str = "computer";
+
+/// A way of picking moves. Strategies only get the bot's own `GameState`, i.e. exactly what a
+/// human player would see, so they can't peek at the opponent's hand or the deck.
+pub trait Strategy {
+    /// Pick the next move. Returns None if there's nothing left to play.
+    fn choose_action(&mut self, game_state: &GameState) -> Option<Action>;
+}
+
+/// The computer opponent for each difficulty level.
+pub fn for_difficulty(difficulty: Difficulty) -> Box<dyn Strategy + Send> {
+    match difficulty {
+        Difficulty::Easy => Box::new(HeuristicStrategy),
+        Difficulty::Medium => Box::new(MonteCarloStrategy::new(Budget::Iterations(300))),
+        Difficulty::Hard => Box::new(MonteCarloStrategy::new(Budget::Time(Duration::from_secs(1)))),
+    }
+}
@@ -0,0 +1,318 @@
+use crate::Strategy;
+use crate::heuristic::HeuristicStrategy;
+use crate::view;
+use game_api::types::{Card, CardColor, CardValue, GameState, GameStatus};
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+use rules::deck::DeckFactory;
+use rules::engine::{self, Action, RulesState};
+use rules::scoring;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+/// How much thinking the bot gets per move. More thinking = stronger play.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Budget {
+    /// Simulate this many games.
+    Iterations(u32),
+    /// Simulate games until this much time has passed.
+    Time(Duration),
+}
+
+/// How adventurous the bot is when picking which move to try next. Roughly, a move needs to be
+/// this many points worse on average before the bot stops giving it a chance.
+const EXPLORATION: f64 = 20.0;
+
+/// Odds of a simulated player making a random move instead of the heuristic one, so playouts
+/// don't all look the same.
+const PLAYOUT_RANDOMNESS: f64 = 0.1;
+
+/// Information set Monte Carlo: the bot can't see its opponent's hand, the buried discards, or the
+/// order of the deck, so for every simulation it makes up a deal that's consistent with what it
+/// *can* see. Then it tries one of its moves, plays the rest of the game out with the heuristic
+/// bot in both seats, and keeps the move with the best average final score difference.
+pub struct MonteCarloStrategy {
+    budget: Budget,
+    rng: StdRng,
+}
+
+impl MonteCarloStrategy {
+    pub fn new(budget: Budget) -> Self {
+        MonteCarloStrategy {
+            budget,
+            rng: StdRng::from_entropy(),
+        }
+    }
+
+    /// Same moves every time for the same games, for tests and simulations.
+    pub fn with_seed(budget: Budget, seed: u64) -> Self {
+        MonteCarloStrategy {
+            budget,
+            rng: StdRng::seed_from_u64(seed),
+        }
+    }
+
+    fn is_out_of_budget(&self, iterations: u32, start: Instant) -> bool {
+        match self.budget {
+            Budget::Iterations(max_iterations) => iterations >= max_iterations,
+            Budget::Time(max_duration) => start.elapsed() >= max_duration,
+        }
+    }
+}
+
+impl MonteCarloStrategy {
+    /// Every legal move, with the average final score difference (mine minus my opponent's) of
+    /// the games simulated after it. Best first. Empty if there's nothing to play.
+    pub fn evaluate_actions(&mut self, game_state: &GameState) -> Vec<(Action, f64)> {
+        if let GameStatus::Complete(_, _) = game_state.status() {
+            return Vec::new();
+        }
+
+        // My moves don't depend on anything hidden, so any deal will do for listing them.
+        let actions = match determinize(game_state, &mut self.rng) {
+            Some(state) => engine::legal_actions(&state),
+            None => return Vec::new(),
+        };
+        if actions.is_empty() {
+            return Vec::new();
+        }
+
+        // (total score difference, number of simulations) for each move
+        let mut stats: Vec<(f64, u32)> = vec![(0.0, 0); actions.len()];
+        let start = Instant::now();
+        let mut iterations = 0;
+        while iterations == 0 || !self.is_out_of_budget(iterations, start) {
+            iterations += 1;
+
+            let action_index = select_action_to_try(&stats, iterations);
+            let state = match determinize(game_state, &mut self.rng)
+                .and_then(|state| engine::apply(state, &actions[action_index]).ok()) {
+                Some(state) => state,
+                None => continue,
+            };
+
+            let (total, visits) = &mut stats[action_index];
+            *total += playout(state, &mut self.rng) as f64;
+            *visits += 1;
+        }
+
+        let mut evaluations: Vec<(Action, f64)> = actions.into_iter()
+            .zip(stats)
+            .filter(|(_, (_, visits))| *visits > 0)
+            .map(|(action, (total, visits))| (action, total / visits as f64))
+            .collect();
+        evaluations.sort_by(|(_, a), (_, b)| b.partial_cmp(a).unwrap());
+
+        evaluations
+    }
+}
+
+impl Strategy for MonteCarloStrategy {
+    fn choose_action(&mut self, game_state: &GameState) -> Option<Action> {
+        if let GameStatus::Complete(_, _) = game_state.status() {
+            return None;
+        }
+
+        // No need to think about a forced move
+        let actions = engine::legal_actions(&determinize(game_state, &mut self.rng)?);
+        if actions.len() <= 1 {
+            return actions.into_iter().next();
+        }
+
+        self.evaluate_actions(game_state)
+            .into_iter()
+            .next()
+            .map(|(action, _)| action)
+    }
+}
+
+/// UCB1: try every move once, then favor moves that have done well, or haven't been tried much.
+fn select_action_to_try(stats: &[(f64, u32)], iterations: u32) -> usize {
+    if let Some(untried) = stats.iter().position(|(_, visits)| *visits == 0) {
+        return untried;
+    }
+
+    let log_iterations = (iterations as f64).ln();
+    let ucb = |(total, visits): &(f64, u32)| {
+        let visits = *visits as f64;
+        total / visits + EXPLORATION * (2.0 * log_iterations / visits).sqrt()
+    };
+
+    (0..stats.len())
+        .max_by(|a, b| ucb(&stats[*a]).partial_cmp(&ucb(&stats[*b])).unwrap())
+        .unwrap_or(0)
+}
+
+/// Make up a full game that looks exactly like `game_state` to the bot, with the bot in the first
+/// seat. Returns None if the game state doesn't add up to a real deck, or if there's more than one
+/// opponent to deal a hand to.
+pub fn determinize<R: Rng>(game_state: &GameState, rng: &mut R) -> Option<RulesState> {
+    let game_board = game_state.game_board();
+    if game_board.opponents().len() != 1 {
+        return None;
+    }
+
+    let my_hand: Vec<Card> = game_state.my_hand()
+        .iter()
+        .map(|decorated_card| *decorated_card.card())
+        .collect();
+
+    // 1. Everything I can't see
+    let mut unseen = DeckFactory::new(game_state.rule_set()).unshuffled_deck().clone();
+    let mut seen = my_hand.clone();
+    for plays in [game_board.my_plays(), game_board.op_plays()].iter() {
+        for (color, values) in plays.iter() {
+            seen.extend(values.iter().map(|value| Card::new(*color, *value)));
+        }
+    }
+    for (color, (top_value, _)) in game_board.neutral_draw_pile().iter() {
+        seen.push(Card::new(*color, *top_value));
+    }
+    for card in seen {
+        let index = unseen.iter().position(|unseen_card| *unseen_card == card)?;
+        unseen.swap_remove(index);
+    }
+    unseen.shuffle(rng);
+
+    // 2. Bury the right number of cards under each visible discard
+    let mut neutral_draw_pile: HashMap<CardColor, Vec<CardValue>> = HashMap::new();
+    for (color, (top_value, size)) in game_board.neutral_draw_pile().iter() {
+        let mut pile = Vec::with_capacity(*size);
+        for _ in 1..*size {
+            let index = unseen.iter().position(|card| card.card_color() == color)?;
+            pile.push(*unseen.swap_remove(index).card_value());
+        }
+        pile.push(*top_value);
+        neutral_draw_pile.insert(*color, pile);
+    }
+
+    // 3. Deal the opponent whatever doesn't fit in the deck
+    let main_draw_pile_size = *game_board.draw_pile_cards_remaining();
+    if unseen.len() < main_draw_pile_size {
+        return None;
+    }
+    let op_hand = unseen.split_off(main_draw_pile_size);
+
+    let is_my_turn = match game_state.status() {
+        GameStatus::InProgress(is_my_turn) => *is_my_turn,
+        GameStatus::Complete(_, _) => true,
+    };
+
+    Some(RulesState::new(
+        vec![my_hand, op_hand],
+        vec![game_board.my_plays().to_owned(), game_board.op_plays().to_owned()],
+        neutral_draw_pile,
+        unseen,
+        if is_my_turn { 0 } else { 1 },
+    ).with_rule_set(*game_state.rule_set()))
+}
+
+/// Finish the game and return the first seat's final score minus the second's.
+fn playout<R: Rng>(mut state: RulesState, rng: &mut R) -> i32 {
+    let mut heuristic = HeuristicStrategy;
+    while !engine::is_terminal(&state) {
+        let action = if rng.gen_bool(PLAYOUT_RANDOMNESS) {
+            None
+        } else {
+            heuristic.choose_action(&view::player_view(&state, *state.turn()))
+        };
+        let action = match action {
+            Some(action) if engine::validate(&state, &action).is_ok() => action,
+            _ => match engine::legal_actions(&state).choose(rng) {
+                Some(action) => *action,
+                None => break,
+            },
+        };
+
+        state = engine::apply(state, &action).expect("Action was validated above");
+    }
+
+    scoring::compute_score(&state.plays()[0], state.rule_set()).0 - scoring::compute_score(&state.plays()[1], state.rule_set()).0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use game_api::types::RuleSet;
+    use game_api::types::CardTarget;
+    use game_api::types::DrawPile;
+    use std::convert::TryFrom;
+
+    fn card(color: CardColor, value: u32) -> Card {
+        Card::new(color, CardValue::try_from(value).unwrap())
+    }
+
+    fn dealt_game() -> RulesState {
+        let mut deck = DeckFactory::new(&RuleSet::standard()).new_shuffled_deck().0;
+        let p1_hand = deck.split_off(deck.len() - 8);
+        let p2_hand = deck.split_off(deck.len() - 8);
+
+        RulesState::new(vec![p1_hand, p2_hand], vec![HashMap::new(), HashMap::new()], HashMap::new(), deck, 0)
+    }
+
+    #[test]
+    fn determinized_game_matches_what_bot_sees() {
+        let mut state = dealt_game();
+        let mut heuristic = HeuristicStrategy;
+        for _ in 0..10 {
+            let action = heuristic.choose_action(&view::player_view(&state, *state.turn())).unwrap();
+            state = engine::apply(state, &action).unwrap();
+        }
+
+        let game_state = view::player_view(&state, 0);
+        let determinized = determinize(&game_state, &mut StdRng::seed_from_u64(1)).unwrap();
+
+        assert_eq!(determinized.hands()[0], state.hands()[0]);
+        assert_eq!(determinized.plays(), state.plays());
+        assert_eq!(determinized.hands()[1].len(), 8);
+        assert_eq!(determinized.main_draw_pile().len(), state.main_draw_pile().len());
+        // A discard pile that has been drawn empty isn't visible to the bot, so it isn't recreated.
+        for (color, pile) in state.neutral_draw_pile().iter().filter(|(_, pile)| !pile.is_empty()) {
+            let determinized_pile = &determinized.neutral_draw_pile()[color];
+            assert_eq!(determinized_pile.len(), pile.len());
+            assert_eq!(determinized_pile.last(), pile.last());
+        }
+    }
+
+    #[test]
+    fn plays_the_last_good_card() {
+        // One card left in the deck, so this is the last move. Playing the R10 is worth 10 points,
+        // everything else is worth nothing.
+        let mut p1_plays = HashMap::new();
+        p1_plays.insert(CardColor::Red, vec![CardValue::try_from(8).unwrap(), CardValue::try_from(9).unwrap()]);
+        let mut state = dealt_game();
+        let mut p1_hand = vec![card(CardColor::Red, 10)];
+        p1_hand.extend(state.hands()[0].iter().filter(|card| *card.card_color() != CardColor::Red).take(7));
+        let main_draw_pile = state.main_draw_pile()
+            .iter()
+            .filter(|card| !p1_hand.contains(card) && *card.card_color() != CardColor::Red)
+            .take(1)
+            .cloned()
+            .collect();
+        state = RulesState::new(vec![p1_hand, state.hands()[1].clone()], vec![p1_plays, HashMap::new()], HashMap::new(), main_draw_pile, 0);
+
+        let game_state = view::player_view(&state, 0);
+        let action = MonteCarloStrategy::with_seed(Budget::Iterations(100), 7).choose_action(&game_state);
+
+        assert_eq!(action, Some(Action::new(card(CardColor::Red, 10), CardTarget::Player, DrawPile::Main)));
+    }
+
+    #[test]
+    fn evaluates_every_legal_move_best_first() {
+        let state = dealt_game();
+        let game_state = view::player_view(&state, 0);
+        let evaluations = MonteCarloStrategy::with_seed(Budget::Iterations(200), 5).evaluate_actions(&game_state);
+
+        assert_eq!(evaluations.len(), engine::legal_actions(&state).len());
+        assert!(evaluations.windows(2).all(|pair| pair[0].1 >= pair[1].1));
+    }
+
+    #[test]
+    fn time_budget_still_picks_a_move() {
+        let game_state = view::player_view(&dealt_game(), 0);
+        let action = MonteCarloStrategy::with_seed(Budget::Time(Duration::from_millis(50)), 3).choose_action(&game_state);
+
+        assert!(action.is_some());
+    }
+}
@@ -0,0 +1,64 @@
+use game_api::types::{Card, GameBoard, GameState, OpponentBoard};
+use rules::engine::{self, RulesState};
+use rules::{endgame, plays, scoring};
+use std::collections::HashMap;
+
+/// What the player in one seat would see of a full game, i.e. the same `GameState` the backend
+/// would send them. This lets strategies play simulated games without a backend.
+pub fn player_view(state: &RulesState, seat: usize) -> GameState {
+    let num_seats = state.hands().len();
+    let my_hand = &state.hands()[seat];
+    let my_plays = &state.plays()[seat];
+    // Everyone else, in turn order starting with whoever plays next.
+    let op_seats: Vec<usize> = (1..num_seats)
+        .map(|offset| (seat + offset) % num_seats)
+        .collect();
+
+    let mut concealed_neutral_draw_pile = HashMap::new();
+    for (color, value_vec) in state.neutral_draw_pile().iter() {
+        if let Some(top_card) = value_vec.last() {
+            concealed_neutral_draw_pile.insert(*color, (*top_card, value_vec.len()));
+        }
+    }
+
+    let (my_score_total, my_score_per_color) = scoring::compute_score(my_plays, state.rule_set());
+    let mut opponents: Vec<OpponentBoard> = op_seats.iter()
+        .map(|op_seat| {
+            let op_plays = &state.plays()[*op_seat];
+            let (op_score_total, op_score_per_color) = scoring::compute_score(op_plays, state.rule_set());
+            OpponentBoard::new(op_plays.to_owned(), op_score_total, op_score_per_color)
+        })
+        .collect();
+    let more_opponents = opponents.split_off(1);
+    let next_opponent = opponents.remove(0);
+
+    let game_board = GameBoard::new(
+        my_plays.to_owned(),
+        next_opponent.plays().to_owned(),
+        my_score_total,
+        *next_opponent.score_total(),
+        my_score_per_color,
+        next_opponent.score_per_color().to_owned(),
+        concealed_neutral_draw_pile,
+        state.main_draw_pile().len(),
+    ).with_more_opponents(more_opponents);
+    let status = endgame::get_game_status(&game_board, *state.turn() == seat, engine::is_terminal(state));
+
+    // Without a move history, there's no telling which of the opponents' cards came off a neutral
+    // pile, so their whole hands count as unseen.
+    let mut unseen_cards: Vec<Card> = state.main_draw_pile().to_owned();
+    for op_seat in op_seats {
+        unseen_cards.extend(state.hands()[op_seat].iter());
+    }
+    unseen_cards.sort();
+
+    GameState::new(
+        game_board,
+        plays::decorate_hand(my_hand.to_owned(), my_plays),
+        status,
+        None,
+        None,
+        None,
+        unseen_cards,
+    ).with_rule_set(*state.rule_set())
+}
@@ -1,3 +1,7 @@
+use crate::wire_api::proto_lost_cities::ProtoRuleViolation;
+use game_api::types::{Card, CardColor};
+use prost::Message;
+use std::convert::TryFrom;
 use std::error::Error;
 use std::fmt::{Display, Formatter};
 use std::fmt;
@@ -8,11 +12,13 @@ use std::borrow::Cow;
 pub enum ClientGameError {
     NotFound,
     UserInvalidArg,
+    RuleViolation(RuleViolation),
     GameNotStarted,
     BackendFault,
     BackendTimeout,
     BackendUnknown,
     MalformedResponse(/* message */ Cow<'static, str>),
+    UpgradeRequired(/* message */ String),
 }
 
 impl Error for ClientGameError {}
@@ -24,9 +30,11 @@ impl Display for ClientGameError {
             ClientGameError::BackendTimeout => f.write_str("Timeout while calling backend."),
             ClientGameError::BackendUnknown => f.write_str("Unknown backend failure. Should probably handle this branch before it gets to this point."),
             ClientGameError::UserInvalidArg => f.write_str("User fricked up."),
+            ClientGameError::RuleViolation(rule_violation) => rule_violation.fmt(f),
             ClientGameError::NotFound => f.write_str("Crap, where'd it go?"),
             ClientGameError::MalformedResponse(msg) => f.write_str(&format!("Server gave us a payload that ain't make sense: {}", msg)),
-            ClientGameError::GameNotStarted => f.write_str("Can't call that API because the game isn't started.")
+            ClientGameError::GameNotStarted => f.write_str("Can't call that API because the game isn't started."),
+            ClientGameError::UpgradeRequired(msg) => f.write_str(msg),
         }
     }
 }
@@ -34,10 +42,83 @@ impl Display for ClientGameError {
 impl From<tonic::Status> for ClientGameError {
     fn from(status: tonic::Status) -> Self {
         match status.code() {
-            Code::InvalidArgument => ClientGameError::UserInvalidArg,
+            // Older servers don't say which rule was broken.
+            Code::InvalidArgument => ProtoRuleViolation::decode(status.details())
+                .ok()
+                .and_then(|proto_rule_violation| RuleViolation::try_from(proto_rule_violation).ok())
+                .map_or(ClientGameError::UserInvalidArg, ClientGameError::RuleViolation),
+            // The client is too old for the server, or for the game it asked for. The server says
+            // how to upgrade.
+            Code::FailedPrecondition => ClientGameError::UpgradeRequired(status.message().to_owned()),
             Code::AlreadyExists => ClientGameError::UserInvalidArg,
             Code::NotFound => ClientGameError::NotFound,
             _ => ClientGameError::BackendUnknown
         }
     }
 }
+
+/// A rule of the game that the server wouldn't let the player break, and which card or pile it
+/// was about.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum RuleViolation {
+    NotYourTurn,
+    CardNotInHand(Card),
+    DecreasingCardValue(Card),
+    DiscardPileEmpty(CardColor),
+    MainDrawPileEmpty,
+    RedrawCardJustPlayed(Card),
+    GameOver,
+    OutOfTime,
+    DrawAlreadyOffered,
+    NoDrawOffer,
+    NoMoveToTakeBack,
+    TakeBackAlreadyRequested,
+    NoTakeBackRequest,
+    NoDrawOnFinalTurn,
+    InvalidRuleSet,
+    TwoPlayerOnly,
+    AlreadySeated,
+    GameNotOver,
+}
+
+/// Explains the rule to the player.
+impl Display for RuleViolation {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            RuleViolation::NotYourTurn => write!(f, "It's not your turn yet."),
+            RuleViolation::CardNotInHand(card) => write!(f, "The {} isn't in your hand.", card),
+            RuleViolation::DecreasingCardValue(card) => write!(f, "You can't play the {} on that expedition. Each card has to be the same or higher value than the last one played there.", card),
+            RuleViolation::DiscardPileEmpty(color) => write!(f, "There's nothing to draw from the {:?} discard pile.", color),
+            RuleViolation::MainDrawPileEmpty => write!(f, "There's nothing left in the main draw pile."),
+            RuleViolation::RedrawCardJustPlayed(card) => write!(f, "You can't pick the {} right back up after discarding it. Draw from somewhere else.", card),
+            RuleViolation::GameOver => write!(f, "The game is already over."),
+            RuleViolation::OutOfTime => write!(f, "You ran out of time, so you forfeit the game."),
+            RuleViolation::DrawAlreadyOffered => write!(f, "A draw has already been offered."),
+            RuleViolation::NoDrawOffer => write!(f, "Your opponent hasn't offered a draw."),
+            RuleViolation::NoMoveToTakeBack => write!(f, "You can only take back the most recent move, and only if you made it."),
+            RuleViolation::TakeBackAlreadyRequested => write!(f, "You already asked to take back your last move."),
+            RuleViolation::NoTakeBackRequest => write!(f, "Your opponent hasn't asked to take back a move."),
+            RuleViolation::NoDrawOnFinalTurn => write!(f, "The main draw pile is empty, so this is the final turn. Play or discard a card without drawing."),
+            RuleViolation::InvalidRuleSet => write!(f, "Those house rules don't work. There must be 2 to 4 players, every hand must fit in the deck with cards to spare, and the bonus threshold must be 1 to 12 cards."),
+            RuleViolation::TwoPlayerOnly => write!(f, "Draw offers, take-backs, analysis, and the computer opponent are only for two player games."),
+            RuleViolation::AlreadySeated => write!(f, "You already have a seat in this game."),
+            RuleViolation::GameNotOver => write!(f, "The game isn't over yet."),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use game_api::wire_protocol;
+
+    #[test]
+    fn failed_precondition_means_upgrade_required() {
+        let message = wire_protocol::client_upgrade_message(1, 2);
+
+        match ClientGameError::from(tonic::Status::failed_precondition(message.clone())) {
+            ClientGameError::UpgradeRequired(upgrade_message) => assert_eq!(upgrade_message, message),
+            other => panic!("Expected UpgradeRequired but got {:?}", other),
+        }
+    }
+}
@@ -1,9 +1,12 @@
 use game_api::api::GameApi2;
-use game_api::types::{GameState, Play, GameMetadata};
+use game_api::types::{GameState, Play, GameMetadata, SpectatorGameState, MatchState, TimeControl, Opponent, GameAnalysis, RuleSet};
 use std::borrow::Cow;
 use std::convert::TryFrom;
+use tonic::{Code, Request, Status};
+use tonic::metadata::MetadataValue;
 use tonic::transport::{Channel, Endpoint};
-use crate::wire_api::proto_lost_cities::{ProtoHostGameReq, ProtoJoinGameReq, ProtoGetGameStateReq, ProtoPlayCardReq, ProtoDescribeGameReq, ProtoQueryGamesReq, ProtoGameStatus, ProtoGameMetadata, ProtoGetMatchableGamesReq};
+use game_api::wire_protocol;
+use crate::wire_api::proto_lost_cities::{ProtoHostGameReq, ProtoJoinGameReq, ProtoGetGameStateReq, ProtoPlayCardReq, ProtoDescribeGameReq, ProtoQueryGamesReq, ProtoGameStatus, ProtoGameMetadata, ProtoGetMatchableGamesReq, ProtoGetWatchableGamesReq, ProtoGetSpectatorGameStateReq, ProtoHostMatchReq, ProtoJoinMatchReq, ProtoGetMatchStateReq, ProtoTimeControl, ProtoResignReq, ProtoOfferDrawReq, ProtoRespondToDrawOfferReq, ProtoRequestTakeBackReq, ProtoRespondToTakeBackReq, ProtoOpponent, ProtoAnalyzeGameReq, ProtoRuleSet, ProtoExportGameReq, ProtoHandshakeReq};
 use crate::wire_api::proto_lost_cities::proto_lost_cities_client::ProtoLostCitiesClient;
 use crate::client_game_api::error::ClientGameError;
 use std::error::Error;
@@ -20,9 +23,41 @@ impl GameClient {
 
         let connection = endpoint.connect().await?;
 
-        Ok(GameClient {
-            inner_client: ProtoLostCitiesClient::new(connection)
-        })
+        let mut client = GameClient {
+            inner_client: ProtoLostCitiesClient::with_interceptor(connection, add_protocol_headers)
+        };
+        client.handshake().await?;
+        Ok(client)
+    }
+
+    /// Make sure the server and this client can understand each other before anything else.
+    async fn handshake(&mut self) -> Result<(), ClientGameError> {
+        let request = tonic::Request::new(ProtoHandshakeReq {
+            client_protocol_version: wire_protocol::PROTOCOL_VERSION,
+            client_features: wire_protocol::FEATURES.iter()
+                .map(|feature| (*feature).to_owned())
+                .collect(),
+        });
+
+        match self.inner_client.handshake(request).await {
+            Ok(response) => {
+                let reply = response.into_inner();
+                if reply.min_client_protocol_version > wire_protocol::PROTOCOL_VERSION {
+                    return Err(ClientGameError::UpgradeRequired(wire_protocol::client_upgrade_message(
+                        wire_protocol::PROTOCOL_VERSION,
+                        reply.min_client_protocol_version,
+                    )));
+                }
+                println!("INFO: Server speaks protocol version {} with features: {}", reply.server_protocol_version, reply.server_features.join(", "));
+                Ok(())
+            },
+            // Servers from before the handshake don't have the RPC, but are still compatible.
+            Err(status) if status.code() == Code::Unimplemented => {
+                println!("INFO: Server is from before protocol versions. Carrying on anyway.");
+                Ok(())
+            },
+            Err(status) => Err(handle_error(status)),
+        }
     }
 
     async fn query_games(&mut self, player_id: String, status: ProtoGameStatus) -> Result<Vec<GameMetadata>, ClientGameError> {
@@ -47,10 +82,13 @@ impl GameClient {
 
 #[async_trait::async_trait]
 impl GameApi2<ClientGameError> for GameClient {
-    async fn host_game(&mut self, game_id: String, p1_id: String) -> Result<(), ClientGameError> {
+    async fn host_game(&mut self, game_id: String, p1_id: String, time_control: Option<TimeControl>, opponent: Opponent, rule_set: RuleSet) -> Result<(), ClientGameError> {
         let request = tonic::Request::new(ProtoHostGameReq {
             game_id,
-            player_id: p1_id
+            player_id: p1_id,
+            time_control: time_control.map(ProtoTimeControl::from),
+            opponent: ProtoOpponent::from(opponent) as i32,
+            rule_set: Some(ProtoRuleSet::from(rule_set)),
         });
 
         self.inner_client.host_game(request)
@@ -127,6 +165,24 @@ impl GameApi2<ClientGameError> for GameClient {
         Ok(games)
     }
 
+    async fn query_watchable_games(&mut self, player_id: String) -> Result<Vec<GameMetadata>, ClientGameError> {
+        let request = tonic::Request::new(ProtoGetWatchableGamesReq {
+            player_id
+        });
+
+        let proto_games: Vec<ProtoGameMetadata> = self.inner_client.get_watchable_games(request)
+            .await
+            .map_err(handle_error)
+            .map(|response| response.into_inner().games)?;
+
+        let mut games: Vec<GameMetadata> = Vec::with_capacity(proto_games.len());
+        for game_metadata in proto_games {
+            games.push(GameMetadata::try_from(game_metadata)?);
+        }
+
+        Ok(games)
+    }
+
     async fn get_game_state(&mut self, game_id: String, player_id: String) -> Result<GameState, ClientGameError> {
         let request = tonic::Request::new(ProtoGetGameStateReq {
             game_id,
@@ -141,6 +197,19 @@ impl GameApi2<ClientGameError> for GameClient {
 
     }
 
+    async fn get_spectator_game_state(&mut self, game_id: String, player_id: String) -> Result<SpectatorGameState, ClientGameError> {
+        let request = tonic::Request::new(ProtoGetSpectatorGameStateReq {
+            game_id,
+            player_id
+        });
+
+        self.inner_client.get_spectator_game_state(request)
+            .await
+            .map_err(handle_error)
+            .and_then(|response| response.into_inner().game.ok_or(ClientGameError::MalformedResponse(Cow::from("Missing Game inside SpectatorGameState"))))
+            .and_then(SpectatorGameState::try_from)
+    }
+
     async fn play_card(&mut self, play: Play) -> Result<(), ClientGameError> {
         let request = tonic::Request::new(ProtoPlayCardReq::from(play));
 
@@ -149,6 +218,146 @@ impl GameApi2<ClientGameError> for GameClient {
             .map_err(|e| handle_error(e))
             .map(|_response| ())
     }
+
+    async fn resign(&mut self, game_id: String, player_id: String) -> Result<(), ClientGameError> {
+        let request = tonic::Request::new(ProtoResignReq {
+            game_id,
+            player_id,
+        });
+
+        self.inner_client.resign(request)
+            .await
+            .map_err(handle_error)
+            .map(|_response| ())
+    }
+
+    async fn offer_draw(&mut self, game_id: String, player_id: String) -> Result<(), ClientGameError> {
+        let request = tonic::Request::new(ProtoOfferDrawReq {
+            game_id,
+            player_id,
+        });
+
+        self.inner_client.offer_draw(request)
+            .await
+            .map_err(handle_error)
+            .map(|_response| ())
+    }
+
+    async fn respond_to_draw_offer(&mut self, game_id: String, player_id: String, accept: bool) -> Result<(), ClientGameError> {
+        let request = tonic::Request::new(ProtoRespondToDrawOfferReq {
+            game_id,
+            player_id,
+            accept,
+        });
+
+        self.inner_client.respond_to_draw_offer(request)
+            .await
+            .map_err(handle_error)
+            .map(|_response| ())
+    }
+
+    async fn request_take_back(&mut self, game_id: String, player_id: String) -> Result<(), ClientGameError> {
+        let request = tonic::Request::new(ProtoRequestTakeBackReq {
+            game_id,
+            player_id,
+        });
+
+        self.inner_client.request_take_back(request)
+            .await
+            .map_err(handle_error)
+            .map(|_response| ())
+    }
+
+    async fn respond_to_take_back(&mut self, game_id: String, player_id: String, accept: bool) -> Result<(), ClientGameError> {
+        let request = tonic::Request::new(ProtoRespondToTakeBackReq {
+            game_id,
+            player_id,
+            accept,
+        });
+
+        self.inner_client.respond_to_take_back(request)
+            .await
+            .map_err(handle_error)
+            .map(|_response| ())
+    }
+
+    async fn analyze_game(&mut self, game_id: String, player_id: String) -> Result<GameAnalysis, ClientGameError> {
+        let request = tonic::Request::new(ProtoAnalyzeGameReq {
+            game_id,
+            player_id,
+        });
+
+        self.inner_client.analyze_game(request)
+            .await
+            .map_err(handle_error)
+            .and_then(|response| response.into_inner().analysis.ok_or(ClientGameError::MalformedResponse(Cow::from("Missing GameAnalysis inside AnalyzeGame"))))
+            .and_then(GameAnalysis::try_from)
+    }
+
+    async fn export_game(&mut self, game_id: String, player_id: String) -> Result<String, ClientGameError> {
+        let request = tonic::Request::new(ProtoExportGameReq {
+            game_id,
+            player_id,
+        });
+
+        self.inner_client.export_game(request)
+            .await
+            .map_err(handle_error)
+            .map(|response| response.into_inner().notation)
+    }
+
+    async fn host_match(&mut self, match_id: String, p1_id: String, num_rounds: u32, time_control: Option<TimeControl>, rule_set: RuleSet) -> Result<(), ClientGameError> {
+        let request = tonic::Request::new(ProtoHostMatchReq {
+            match_id,
+            player_id: p1_id,
+            num_rounds,
+            time_control: time_control.map(ProtoTimeControl::from),
+            rule_set: Some(ProtoRuleSet::from(rule_set)),
+        });
+
+        self.inner_client.host_match(request)
+            .await
+            .map_err(handle_error)
+            .map(|_response| ())
+    }
+
+    async fn join_match(&mut self, match_id: String, p2_id: String) -> Result<(), ClientGameError> {
+        let request = tonic::Request::new(ProtoJoinMatchReq {
+            match_id,
+            player_id: p2_id,
+        });
+
+        self.inner_client.join_match(request)
+            .await
+            .map_err(handle_error)
+            .map(|_response| ())
+    }
+
+    async fn get_match_state(&mut self, match_id: String, player_id: String) -> Result<MatchState, ClientGameError> {
+        let request = tonic::Request::new(ProtoGetMatchStateReq {
+            match_id,
+            player_id
+        });
+
+        self.inner_client.get_match_state(request)
+            .await
+            .map_err(handle_error)
+            .and_then(|response| response.into_inner().match_state.ok_or(ClientGameError::MalformedResponse(Cow::from("Missing Match inside MatchState"))))
+            .and_then(MatchState::try_from)
+    }
+}
+
+/// Runs before every request, so the server can turn this client away if it's too old, and keeps
+/// games this client can't draw away from it.
+#[allow(clippy::result_large_err)] // tonic decides the signature.
+fn add_protocol_headers(mut request: Request<()>) -> Result<Request<()>, Status> {
+    let version = MetadataValue::from_str(&wire_protocol::PROTOCOL_VERSION.to_string())
+        .map_err(|e| Status::internal(format!("Can't send protocol version: {}", e)))?;
+    let features = MetadataValue::from_str(&wire_protocol::FEATURES.join(","))
+        .map_err(|e| Status::internal(format!("Can't send protocol features: {}", e)))?;
+    request.metadata_mut().insert(wire_protocol::PROTOCOL_VERSION_HEADER, version);
+    request.metadata_mut().insert(wire_protocol::PROTOCOL_FEATURES_HEADER, features);
+    Ok(request)
 }
 
 fn handle_error(status: tonic::Status) -> ClientGameError {
@@ -4,7 +4,7 @@
 ///
 /// See https://github.com/danburkert/prost/issues/69
 use crate::client_game_api::error::ClientGameError;
-use crate::wire_api::proto_lost_cities::{ProtoColor, ProtoPlayTarget, ProtoDrawPile, ProtoGameStatus};
+use crate::wire_api::proto_lost_cities::{ProtoColor, ProtoPlayTarget, ProtoDrawPile, ProtoGameStatus, ProtoMatchStatus, ProtoTimeControlType, ProtoDrawOffer, ProtoEndReason, ProtoTakeBackRequest, ProtoGameType, ProtoRule};
 use std::borrow::Cow;
 use std::convert::TryFrom;
 
@@ -43,3 +43,66 @@ impl TryFrom<i32> for ProtoGameStatus {
             .ok_or(ClientGameError::MalformedResponse(Cow::from(format!("Illegal Color i32 value '{}'", value))))
     }
 }
+
+impl TryFrom<i32> for ProtoMatchStatus {
+    type Error = ClientGameError;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        ProtoMatchStatus::from_i32(value)
+            .ok_or(ClientGameError::MalformedResponse(Cow::from(format!("Illegal MatchStatus i32 value '{}'", value))))
+    }
+}
+
+impl TryFrom<i32> for ProtoGameType {
+    type Error = ClientGameError;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        ProtoGameType::from_i32(value)
+            .ok_or(ClientGameError::MalformedResponse(Cow::from(format!("Illegal GameType i32 value '{}'", value))))
+    }
+}
+
+impl TryFrom<i32> for ProtoTimeControlType {
+    type Error = ClientGameError;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        ProtoTimeControlType::from_i32(value)
+            .ok_or(ClientGameError::MalformedResponse(Cow::from(format!("Illegal TimeControlType i32 value '{}'", value))))
+    }
+}
+
+impl TryFrom<i32> for ProtoDrawOffer {
+    type Error = ClientGameError;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        ProtoDrawOffer::from_i32(value)
+            .ok_or(ClientGameError::MalformedResponse(Cow::from(format!("Illegal DrawOffer i32 value '{}'", value))))
+    }
+}
+
+impl TryFrom<i32> for ProtoEndReason {
+    type Error = ClientGameError;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        ProtoEndReason::from_i32(value)
+            .ok_or(ClientGameError::MalformedResponse(Cow::from(format!("Illegal EndReason i32 value '{}'", value))))
+    }
+}
+
+impl TryFrom<i32> for ProtoTakeBackRequest {
+    type Error = ClientGameError;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        ProtoTakeBackRequest::from_i32(value)
+            .ok_or(ClientGameError::MalformedResponse(Cow::from(format!("Illegal TakeBackRequest i32 value '{}'", value))))
+    }
+}
+
+impl TryFrom<i32> for ProtoRule {
+    type Error = ClientGameError;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        ProtoRule::from_i32(value)
+            .ok_or(ClientGameError::MalformedResponse(Cow::from(format!("Illegal Rule i32 value '{}'", value))))
+    }
+}
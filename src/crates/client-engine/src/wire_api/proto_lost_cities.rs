@@ -11,6 +11,14 @@ pub struct ProtoHostGameReq {
     pub game_id: std::string::String,
     #[prost(string, tag = "2")]
     pub player_id: std::string::String,
+    /// Leave unset for an untimed game.
+    #[prost(message, optional, tag = "3")]
+    pub time_control: ::std::option::Option<ProtoTimeControl>,
+    #[prost(enumeration = "ProtoOpponent", tag = "4")]
+    pub opponent: i32,
+    /// Leave unset for the standard rules.
+    #[prost(message, optional, tag = "5")]
+    pub rule_set: ::std::option::Option<ProtoRuleSet>,
 }
 /// Nothing
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -58,6 +66,104 @@ pub struct ProtoPlayCardReq {
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ProtoPlayCardReply {}
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoResignReq {
+    #[prost(string, tag = "1")]
+    pub game_id: std::string::String,
+    #[prost(string, tag = "2")]
+    pub player_id: std::string::String,
+}
+/// Nothing
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoResignReply {}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoOfferDrawReq {
+    #[prost(string, tag = "1")]
+    pub game_id: std::string::String,
+    #[prost(string, tag = "2")]
+    pub player_id: std::string::String,
+}
+/// Nothing
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoOfferDrawReply {}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoRespondToDrawOfferReq {
+    #[prost(string, tag = "1")]
+    pub game_id: std::string::String,
+    #[prost(string, tag = "2")]
+    pub player_id: std::string::String,
+    #[prost(bool, tag = "3")]
+    pub accept: bool,
+}
+/// Nothing
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoRespondToDrawOfferReply {}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoRequestTakeBackReq {
+    #[prost(string, tag = "1")]
+    pub game_id: std::string::String,
+    #[prost(string, tag = "2")]
+    pub player_id: std::string::String,
+}
+/// Nothing
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoRequestTakeBackReply {}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoRespondToTakeBackReq {
+    #[prost(string, tag = "1")]
+    pub game_id: std::string::String,
+    #[prost(string, tag = "2")]
+    pub player_id: std::string::String,
+    #[prost(bool, tag = "3")]
+    pub accept: bool,
+}
+/// Nothing
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoRespondToTakeBackReply {}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoAnalyzeGameReq {
+    #[prost(string, tag = "1")]
+    pub game_id: std::string::String,
+    #[prost(string, tag = "2")]
+    pub player_id: std::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoAnalyzeGameReply {
+    #[prost(message, optional, tag = "1")]
+    pub analysis: ::std::option::Option<ProtoGameAnalysis>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoExportGameReq {
+    #[prost(string, tag = "1")]
+    pub game_id: std::string::String,
+    #[prost(string, tag = "2")]
+    pub player_id: std::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoExportGameReply {
+    /// See rules::notation for the format.
+    #[prost(string, tag = "1")]
+    pub notation: std::string::String,
+}
+/// The first call a client makes, so neither side has to guess what the other understands.
+/// See game_api::wire_protocol.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoHandshakeReq {
+    #[prost(uint32, tag = "1")]
+    pub client_protocol_version: u32,
+    #[prost(string, repeated, tag = "2")]
+    pub client_features: ::std::vec::Vec<std::string::String>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoHandshakeReply {
+    #[prost(uint32, tag = "1")]
+    pub server_protocol_version: u32,
+    /// Older clients get FAILED_PRECONDITION instead of this reply.
+    #[prost(uint32, tag = "2")]
+    pub min_client_protocol_version: u32,
+    #[prost(string, repeated, tag = "3")]
+    pub server_features: ::std::vec::Vec<std::string::String>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ProtoDescribeGameReq {
     #[prost(string, tag = "1")]
     pub game_id: std::string::String,
@@ -89,6 +195,69 @@ pub struct ProtoGetMatchableGamesReply {
     #[prost(message, repeated, tag = "1")]
     pub games: ::std::vec::Vec<ProtoGameMetadata>,
 }
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoGetWatchableGamesReq {
+    #[prost(string, tag = "1")]
+    pub player_id: std::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoGetWatchableGamesReply {
+    #[prost(message, repeated, tag = "1")]
+    pub games: ::std::vec::Vec<ProtoGameMetadata>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoGetSpectatorGameStateReq {
+    #[prost(string, tag = "1")]
+    pub game_id: std::string::String,
+    #[prost(string, tag = "2")]
+    pub player_id: std::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoGetSpectatorGameStateReply {
+    #[prost(message, optional, tag = "1")]
+    pub game: ::std::option::Option<ProtoSpectatorGame>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoHostMatchReq {
+    #[prost(string, tag = "1")]
+    pub match_id: std::string::String,
+    #[prost(string, tag = "2")]
+    pub player_id: std::string::String,
+    /// 0 means use the default number of rounds.
+    #[prost(uint32, tag = "3")]
+    pub num_rounds: u32,
+    /// Leave unset for untimed rounds.
+    #[prost(message, optional, tag = "4")]
+    pub time_control: ::std::option::Option<ProtoTimeControl>,
+    /// Leave unset for the standard rules. Every round uses the same rules.
+    #[prost(message, optional, tag = "5")]
+    pub rule_set: ::std::option::Option<ProtoRuleSet>,
+}
+/// Nothing
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoHostMatchReply {}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoJoinMatchReq {
+    #[prost(string, tag = "1")]
+    pub match_id: std::string::String,
+    #[prost(string, tag = "2")]
+    pub player_id: std::string::String,
+}
+/// Nothing
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoJoinMatchReply {}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoGetMatchStateReq {
+    #[prost(string, tag = "1")]
+    pub match_id: std::string::String,
+    #[prost(string, tag = "2")]
+    pub player_id: std::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoGetMatchStateReply {
+    #[prost(message, optional, tag = "1")]
+    pub match_state: ::std::option::Option<ProtoMatch>,
+}
 // =======================================
 // Sub types
 // =======================================
@@ -112,6 +281,8 @@ pub struct ProtoPlayHistory {
     pub blue: ::std::vec::Vec<u32>,
     #[prost(uint32, repeated, tag = "5")]
     pub yellow: ::std::vec::Vec<u32>,
+    #[prost(uint32, repeated, tag = "6")]
+    pub purple: ::std::vec::Vec<u32>,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ProtoScore {
@@ -127,6 +298,8 @@ pub struct ProtoScore {
     pub blue: i32,
     #[prost(sint32, tag = "6")]
     pub yellow: i32,
+    #[prost(sint32, tag = "7")]
+    pub purple: i32,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ProtoGameMetadata {
@@ -140,6 +313,12 @@ pub struct ProtoGameMetadata {
     pub status: i32,
     #[prost(uint64, tag = "5")]
     pub created_time_ms: u64,
+    /// Unset means the standard rules.
+    #[prost(message, optional, tag = "6")]
+    pub rule_set: ::std::option::Option<ProtoRuleSet>,
+    /// Unset means Lost Cities.
+    #[prost(enumeration = "ProtoGameType", tag = "7")]
+    pub game_type: i32,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ProtoGame {
@@ -159,6 +338,153 @@ pub struct ProtoGame {
     pub my_score: ::std::option::Option<ProtoScore>,
     #[prost(message, optional, tag = "8")]
     pub op_score: ::std::option::Option<ProtoScore>,
+    /// Only set for timed games.
+    #[prost(message, optional, tag = "9")]
+    pub clock: ::std::option::Option<ProtoClock>,
+    #[prost(enumeration = "ProtoDrawOffer", tag = "10")]
+    pub draw_offer: i32,
+    #[prost(enumeration = "ProtoTakeBackRequest", tag = "11")]
+    pub take_back_request: i32,
+    /// Main draw pile plus whatever of the opponent's hand the player hasn't seen.
+    #[prost(message, repeated, tag = "12")]
+    pub unseen_cards: ::std::vec::Vec<ProtoCard>,
+    #[prost(message, optional, tag = "13")]
+    pub rule_set: ::std::option::Option<ProtoRuleSet>,
+    /// Everyone after the first opponent, in turn order. Empty for two player games.
+    #[prost(message, repeated, tag = "14")]
+    pub more_opponents: ::std::vec::Vec<ProtoOpponentBoard>,
+    /// Not set before the first turn.
+    #[prost(message, optional, tag = "15")]
+    pub last_move: ::std::option::Option<ProtoLastMove>,
+    /// Only set once the game is over.
+    #[prost(message, optional, tag = "16")]
+    pub summary: ::std::option::Option<ProtoGameSummary>,
+}
+/// The most recent turn, so the client can highlight what changed.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoLastMove {
+    /// Whose turn it was: 0 for me, 1 for the first opponent, and so on in turn order.
+    #[prost(uint32, tag = "1")]
+    pub seat_offset: u32,
+    #[prost(message, optional, tag = "2")]
+    pub card: ::std::option::Option<ProtoCard>,
+    #[prost(enumeration = "ProtoPlayTarget", tag = "3")]
+    pub target: i32,
+    /// NO_DRAW_PILE on a final turn, when nothing is drawn.
+    #[prost(enumeration = "ProtoDrawPile", tag = "4")]
+    pub draw_pile: i32,
+    /// Only set for DISCARD_DRAW. Nobody else gets to see what came off the main draw pile.
+    #[prost(message, optional, tag = "5")]
+    pub drawn_card: ::std::option::Option<ProtoCard>,
+}
+/// How a finished game played out. The end reason is in the game's status.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoGameSummary {
+    /// Me first, then the opponents in turn order.
+    #[prost(message, repeated, tag = "1")]
+    pub players: ::std::vec::Vec<ProtoPlayerSummary>,
+    #[prost(uint32, tag = "2")]
+    pub num_turns: u32,
+    /// 0 if the server didn't keep track.
+    #[prost(uint64, tag = "3")]
+    pub duration_ms: u64,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoPlayerSummary {
+    #[prost(message, repeated, tag = "1")]
+    pub final_hand: ::std::vec::Vec<ProtoCard>,
+    #[prost(message, optional, tag = "2")]
+    pub score: ::std::option::Option<ProtoScore>,
+}
+/// One opponent's side of the board, for three and four player games.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoOpponentBoard {
+    #[prost(message, optional, tag = "1")]
+    pub plays: ::std::option::Option<ProtoPlayHistory>,
+    #[prost(message, optional, tag = "2")]
+    pub score: ::std::option::Option<ProtoScore>,
+}
+/// The game as seen by someone who isn't playing. No hands are included.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoSpectatorGame {
+    /// The host first, then everyone else in turn order. Statuses refer to players by their index here.
+    #[prost(message, repeated, tag = "1")]
+    pub seats: ::std::vec::Vec<ProtoSpectatorSeat>,
+    #[prost(message, optional, tag = "2")]
+    pub discard_pile: ::std::option::Option<ProtoDiscardPile>,
+    #[prost(uint32, tag = "3")]
+    pub draw_pile_cards_remaining: u32,
+    #[prost(message, optional, tag = "4")]
+    pub status: ::std::option::Option<ProtoSpectatorStatus>,
+    #[prost(message, optional, tag = "5")]
+    pub rule_set: ::std::option::Option<ProtoRuleSet>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoSpectatorSeat {
+    #[prost(string, tag = "1")]
+    pub player_id: std::string::String,
+    #[prost(message, optional, tag = "2")]
+    pub plays: ::std::option::Option<ProtoPlayHistory>,
+    #[prost(message, optional, tag = "3")]
+    pub score: ::std::option::Option<ProtoScore>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoSpectatorStatus {
+    #[prost(bool, tag = "1")]
+    pub is_complete: bool,
+    /// Only set while in progress.
+    #[prost(uint32, tag = "2")]
+    pub turn_seat: u32,
+    /// Only set once complete. More than one means a tie.
+    #[prost(uint32, repeated, tag = "3")]
+    pub winning_seats: ::std::vec::Vec<u32>,
+    /// Only set once complete. A resignation or timeout is by whichever seat isn't winning.
+    #[prost(enumeration = "ProtoEndReason", tag = "4")]
+    pub end_reason: i32,
+}
+/// A series of games between the same two players, with scores summed across rounds.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoMatch {
+    /// Empty if nobody has joined yet.
+    #[prost(string, tag = "1")]
+    pub opponent_player_id: std::string::String,
+    #[prost(uint32, tag = "2")]
+    pub num_rounds: u32,
+    #[prost(message, repeated, tag = "3")]
+    pub rounds: ::std::vec::Vec<ProtoMatchRound>,
+    #[prost(sint32, tag = "4")]
+    pub my_score_total: i32,
+    #[prost(sint32, tag = "5")]
+    pub op_score_total: i32,
+    #[prost(enumeration = "ProtoMatchStatus", tag = "6")]
+    pub status: i32,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoMatchRound {
+    #[prost(string, tag = "1")]
+    pub game_id: std::string::String,
+    #[prost(enumeration = "ProtoGameStatus", tag = "2")]
+    pub status: i32,
+    #[prost(sint32, tag = "3")]
+    pub my_score: i32,
+    #[prost(sint32, tag = "4")]
+    pub op_score: i32,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoTimeControl {
+    #[prost(enumeration = "ProtoTimeControlType", tag = "1")]
+    pub time_control_type: i32,
+    #[prost(uint32, tag = "2")]
+    pub seconds: u32,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoClock {
+    #[prost(message, optional, tag = "1")]
+    pub time_control: ::std::option::Option<ProtoTimeControl>,
+    #[prost(uint64, tag = "2")]
+    pub my_remaining_ms: u64,
+    #[prost(uint64, tag = "3")]
+    pub op_remaining_ms: u64,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ProtoDiscardPile {
@@ -172,6 +498,8 @@ pub struct ProtoDiscardPile {
     pub blue: ::std::option::Option<ProtoDiscardPileSurface>,
     #[prost(message, optional, tag = "5")]
     pub yellow: ::std::option::Option<ProtoDiscardPileSurface>,
+    #[prost(message, optional, tag = "6")]
+    pub purple: ::std::option::Option<ProtoDiscardPileSurface>,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ProtoDiscardPileSurface {
@@ -180,6 +508,77 @@ pub struct ProtoDiscardPileSurface {
     #[prost(uint32, tag = "2")]
     pub remaining: u32,
 }
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoGameAnalysis {
+    /// Best first. Only set on the player's turn.
+    #[prost(message, repeated, tag = "1")]
+    pub move_hints: ::std::vec::Vec<ProtoMoveEvaluation>,
+    /// Only set once the game is over.
+    #[prost(message, repeated, tag = "2")]
+    pub mistakes: ::std::vec::Vec<ProtoMistake>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoMoveEvaluation {
+    #[prost(message, optional, tag = "1")]
+    pub card: ::std::option::Option<ProtoCard>,
+    #[prost(enumeration = "ProtoPlayTarget", tag = "2")]
+    pub target: i32,
+    #[prost(enumeration = "ProtoDrawPile", tag = "3")]
+    pub draw_pile: i32,
+    #[prost(enumeration = "ProtoColor", tag = "4")]
+    pub discard_draw_color: i32,
+    /// Estimated final score difference (mine minus my opponent's)
+    #[prost(double, tag = "5")]
+    pub value: f64,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoMistake {
+    /// Counts both players' moves, starting from 1
+    #[prost(uint32, tag = "1")]
+    pub turn: u32,
+    #[prost(message, optional, tag = "2")]
+    pub played: ::std::option::Option<ProtoMoveEvaluation>,
+    #[prost(message, optional, tag = "3")]
+    pub best: ::std::option::Option<ProtoMoveEvaluation>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoRuleSet {
+    #[prost(uint32, tag = "1")]
+    pub hand_size: u32,
+    #[prost(uint32, tag = "2")]
+    pub expedition_cost: u32,
+    #[prost(uint32, tag = "3")]
+    pub multiplier_per_wager: u32,
+    #[prost(uint32, tag = "4")]
+    pub bonus_threshold: u32,
+    #[prost(uint32, tag = "5")]
+    pub bonus_points: u32,
+    /// Whether the opponent gets one more turn (without drawing) after the last card is drawn.
+    #[prost(bool, tag = "6")]
+    pub final_turn: bool,
+    /// Play with the sixth (purple) expedition.
+    #[prost(bool, tag = "7")]
+    pub purple: bool,
+    /// Unset means two players.
+    #[prost(uint32, tag = "8")]
+    pub num_players: u32,
+}
+// =======================================
+// Errors
+// =======================================
+
+/// Sent as the details of an INVALID_ARGUMENT status when a request breaks a rule of the game.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProtoRuleViolation {
+    #[prost(enumeration = "ProtoRule", tag = "1")]
+    pub rule: i32,
+    /// Only set for RULE_CARD_NOT_IN_HAND, RULE_DECREASING_CARD_VALUE, and RULE_REDRAW_CARD_JUST_PLAYED.
+    #[prost(message, optional, tag = "2")]
+    pub card: ::std::option::Option<ProtoCard>,
+    /// Only set for RULE_DISCARD_PILE_EMPTY.
+    #[prost(enumeration = "ProtoColor", tag = "3")]
+    pub color: i32,
+}
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum ProtoColor {
@@ -189,6 +588,8 @@ pub enum ProtoColor {
     White = 3,
     Blue = 4,
     Yellow = 5,
+    /// Only in six color games. Five color clients can't play those.
+    Purple = 6,
 }
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
@@ -214,6 +615,89 @@ pub enum ProtoGameStatus {
     EndLose = 4,
     EndDraw = 5,
     Unmatched = 6,
+    EndWinOnTime = 7,
+    EndLoseOnTime = 8,
+    EndWinByResignation = 9,
+    EndLoseByResignation = 10,
+    EndDrawByAgreement = 11,
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum ProtoMatchStatus {
+    NoMatchStatus = 0,
+    MatchUnmatched = 1,
+    MatchInProgress = 2,
+    MatchWin = 3,
+    MatchLose = 4,
+    MatchDraw = 5,
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum ProtoGameType {
+    NoGameType = 0,
+    LostCities = 1,
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum ProtoTimeControlType {
+    NoTimeControl = 0,
+    /// Every move gets the same amount of time.
+    PerMove = 1,
+    /// Each player has a fixed amount of time for the whole game.
+    TimeBank = 2,
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum ProtoDrawOffer {
+    NoDrawOffer = 0,
+    DrawOfferedByMe = 1,
+    DrawOfferedByOpponent = 2,
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum ProtoEndReason {
+    DeckExhausted = 0,
+    TimedOut = 1,
+    Resigned = 2,
+    DrawAgreed = 3,
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum ProtoTakeBackRequest {
+    NoTakeBackRequest = 0,
+    TakeBackRequestedByMe = 1,
+    TakeBackRequestedByOpponent = 2,
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum ProtoOpponent {
+    OpponentHuman = 0,
+    EasyComputer = 1,
+    MediumComputer = 2,
+    HardComputer = 3,
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum ProtoRule {
+    NoRule = 0,
+    RuleNotYourTurn = 1,
+    RuleCardNotInHand = 2,
+    RuleDecreasingCardValue = 3,
+    RuleDiscardPileEmpty = 4,
+    RuleRedrawCardJustPlayed = 5,
+    RuleGameOver = 6,
+    RuleOutOfTime = 7,
+    RuleDrawAlreadyOffered = 8,
+    RuleNoDrawOffer = 9,
+    RuleNoMoveToTakeBack = 10,
+    RuleTakeBackAlreadyRequested = 11,
+    RuleNoTakeBackRequest = 12,
+    RuleNoDrawOnFinalTurn = 13,
+    RuleInvalidRuleSet = 14,
+    RuleTwoPlayerOnly = 15,
+    RuleGameNotOver = 16,
+    RuleMainDrawPileEmpty = 17,
+    RuleAlreadySeated = 18,
 }
 #[doc = r" Generated client implementations."]
 pub mod proto_lost_cities_client {
@@ -357,6 +841,214 @@ pub mod proto_lost_cities_client {
             );
             self.inner.unary(request.into_request(), path, codec).await
         }
+        pub async fn get_watchable_games(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ProtoGetWatchableGamesReq>,
+        ) -> Result<tonic::Response<super::ProtoGetWatchableGamesReply>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/proto_lost_cities.ProtoLostCities/GetWatchableGames",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn get_spectator_game_state(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ProtoGetSpectatorGameStateReq>,
+        ) -> Result<tonic::Response<super::ProtoGetSpectatorGameStateReply>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/proto_lost_cities.ProtoLostCities/GetSpectatorGameState",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn host_match(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ProtoHostMatchReq>,
+        ) -> Result<tonic::Response<super::ProtoHostMatchReply>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/proto_lost_cities.ProtoLostCities/HostMatch",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn join_match(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ProtoJoinMatchReq>,
+        ) -> Result<tonic::Response<super::ProtoJoinMatchReply>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/proto_lost_cities.ProtoLostCities/JoinMatch",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn get_match_state(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ProtoGetMatchStateReq>,
+        ) -> Result<tonic::Response<super::ProtoGetMatchStateReply>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/proto_lost_cities.ProtoLostCities/GetMatchState",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn resign(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ProtoResignReq>,
+        ) -> Result<tonic::Response<super::ProtoResignReply>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path =
+                http::uri::PathAndQuery::from_static("/proto_lost_cities.ProtoLostCities/Resign");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn offer_draw(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ProtoOfferDrawReq>,
+        ) -> Result<tonic::Response<super::ProtoOfferDrawReply>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/proto_lost_cities.ProtoLostCities/OfferDraw",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn respond_to_draw_offer(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ProtoRespondToDrawOfferReq>,
+        ) -> Result<tonic::Response<super::ProtoRespondToDrawOfferReply>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/proto_lost_cities.ProtoLostCities/RespondToDrawOffer",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn request_take_back(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ProtoRequestTakeBackReq>,
+        ) -> Result<tonic::Response<super::ProtoRequestTakeBackReply>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/proto_lost_cities.ProtoLostCities/RequestTakeBack",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn respond_to_take_back(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ProtoRespondToTakeBackReq>,
+        ) -> Result<tonic::Response<super::ProtoRespondToTakeBackReply>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/proto_lost_cities.ProtoLostCities/RespondToTakeBack",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn analyze_game(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ProtoAnalyzeGameReq>,
+        ) -> Result<tonic::Response<super::ProtoAnalyzeGameReply>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/proto_lost_cities.ProtoLostCities/AnalyzeGame",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn export_game(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ProtoExportGameReq>,
+        ) -> Result<tonic::Response<super::ProtoExportGameReply>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/proto_lost_cities.ProtoLostCities/ExportGame",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn handshake(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ProtoHandshakeReq>,
+        ) -> Result<tonic::Response<super::ProtoHandshakeReply>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/proto_lost_cities.ProtoLostCities/Handshake",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
     }
     impl<T: Clone> Clone for ProtoLostCitiesClient<T> {
         fn clone(&self) -> Self {
@@ -365,4 +1057,9 @@ pub mod proto_lost_cities_client {
             }
         }
     }
+    impl<T> std::fmt::Debug for ProtoLostCitiesClient<T> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "ProtoLostCitiesClient {{ ... }}")
+        }
+    }
 }
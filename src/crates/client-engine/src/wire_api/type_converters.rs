@@ -1,6 +1,6 @@
-use crate::client_game_api::error::ClientGameError;
-use crate::wire_api::proto_lost_cities::{ProtoPlayCardReq, ProtoPlayTarget, ProtoDrawPile, ProtoCard, ProtoColor, ProtoGame, ProtoGameStatus, ProtoPlayHistory, ProtoDiscardPile, ProtoDiscardPileSurface, ProtoGameMetadata, ProtoScore};
-use game_api::types::{Play, Card, CardColor, CardValue, CardTarget, DrawPile, GameState, GameStatus, GameResult, DecoratedCard, GameBoard, GameMetadata};
+use crate::client_game_api::error::{ClientGameError, RuleViolation};
+use crate::wire_api::proto_lost_cities::{ProtoPlayCardReq, ProtoPlayTarget, ProtoDrawPile, ProtoCard, ProtoColor, ProtoGame, ProtoGameStatus, ProtoPlayHistory, ProtoDiscardPile, ProtoDiscardPileSurface, ProtoGameMetadata, ProtoScore, ProtoSpectatorGame, ProtoSpectatorSeat, ProtoMatch, ProtoMatchRound, ProtoMatchStatus, ProtoClock, ProtoTimeControl, ProtoTimeControlType, ProtoDrawOffer, ProtoEndReason, ProtoTakeBackRequest, ProtoOpponent, ProtoGameAnalysis, ProtoMoveEvaluation, ProtoMistake, ProtoRuleSet, ProtoOpponentBoard, ProtoGameType, ProtoLastMove, ProtoGameSummary, ProtoPlayerSummary, ProtoRuleViolation, ProtoRule};
+use game_api::types::{Play, Card, CardColor, CardValue, CardTarget, DrawPile, GameState, GameStatus, GameResult, EndReason, DrawOffer, TakeBackRequest, DecoratedCard, GameBoard, GameMetadata, SpectatorGameState, SpectatorSeat, SpectatorStatus, MatchState, MatchRound, MatchStatus, GameClock, TimeControl, Opponent, Difficulty, GameAnalysis, MoveEvaluation, Mistake, RuleSet, OpponentBoard, GameType, LastMove, LastDraw, GameSummary, PlayerSummary};
 use std::borrow::Cow;
 use std::collections::HashMap;
 use std::convert::{TryFrom, TryInto};
@@ -13,6 +13,25 @@ fn card_value_from_proto(value_u32: u32) -> Result<CardValue, ClientGameError> {
         .map_err(|msg| ClientGameError::MalformedResponse(Cow::from(msg)))
 }
 
+/// GetGameState and match rounds are only for in-progress or completed games.
+fn started_game_status_from_proto(status_i32: i32) -> Result<GameStatus, ClientGameError> {
+    Ok(match ProtoGameStatus::try_from(status_i32)? {
+        ProtoGameStatus::NoGameStatus => return Err(ClientGameError::MalformedResponse(Cow::from("Missing game status"))),
+        ProtoGameStatus::Unmatched => return Err(ClientGameError::GameNotStarted),
+        // Valid options
+        ProtoGameStatus::YourTurn => GameStatus::InProgress(true),
+        ProtoGameStatus::OpponentTurn => GameStatus::InProgress(false),
+        ProtoGameStatus::EndWin => GameStatus::Complete(GameResult::Win, EndReason::DeckExhausted),
+        ProtoGameStatus::EndLose => GameStatus::Complete(GameResult::Lose, EndReason::DeckExhausted),
+        ProtoGameStatus::EndDraw => GameStatus::Complete(GameResult::Draw, EndReason::DeckExhausted),
+        ProtoGameStatus::EndWinOnTime => GameStatus::Complete(GameResult::Win, EndReason::Timeout),
+        ProtoGameStatus::EndLoseOnTime => GameStatus::Complete(GameResult::Lose, EndReason::Timeout),
+        ProtoGameStatus::EndWinByResignation => GameStatus::Complete(GameResult::Win, EndReason::Resignation),
+        ProtoGameStatus::EndLoseByResignation => GameStatus::Complete(GameResult::Lose, EndReason::Resignation),
+        ProtoGameStatus::EndDrawByAgreement => GameStatus::Complete(GameResult::Draw, EndReason::DrawAgreed),
+    })
+}
+
 // ============================= From<Proto> for App ==================================
 
 impl TryFrom<ProtoGameMetadata> for GameMetadata {
@@ -23,18 +42,31 @@ impl TryFrom<ProtoGameMetadata> for GameMetadata {
             ProtoGameStatus::NoGameStatus => return Err(ClientGameError::MalformedResponse(Cow::from("Missing game status"))),
             ProtoGameStatus::YourTurn => Some(GameStatus::InProgress(true)),
             ProtoGameStatus::OpponentTurn => Some(GameStatus::InProgress(false)),
-            ProtoGameStatus::EndWin => Some(GameStatus::Complete(GameResult::Win)),
-            ProtoGameStatus::EndLose => Some(GameStatus::Complete(GameResult::Lose)),
-            ProtoGameStatus::EndDraw => Some(GameStatus::Complete(GameResult::Draw)),
+            ProtoGameStatus::EndWin => Some(GameStatus::Complete(GameResult::Win, EndReason::DeckExhausted)),
+            ProtoGameStatus::EndLose => Some(GameStatus::Complete(GameResult::Lose, EndReason::DeckExhausted)),
+            ProtoGameStatus::EndDraw => Some(GameStatus::Complete(GameResult::Draw, EndReason::DeckExhausted)),
+            ProtoGameStatus::EndWinOnTime => Some(GameStatus::Complete(GameResult::Win, EndReason::Timeout)),
+            ProtoGameStatus::EndLoseOnTime => Some(GameStatus::Complete(GameResult::Lose, EndReason::Timeout)),
+            ProtoGameStatus::EndWinByResignation => Some(GameStatus::Complete(GameResult::Win, EndReason::Resignation)),
+            ProtoGameStatus::EndLoseByResignation => Some(GameStatus::Complete(GameResult::Lose, EndReason::Resignation)),
+            ProtoGameStatus::EndDrawByAgreement => Some(GameStatus::Complete(GameResult::Draw, EndReason::DrawAgreed)),
             ProtoGameStatus::Unmatched => None,
         };
 
+        // Older servers only ever hosted Lost Cities.
+        let game_type = match ProtoGameType::try_from(proto_game_metadata.game_type)? {
+            ProtoGameType::NoGameType | ProtoGameType::LostCities => GameType::LostCities,
+        };
+
         if proto_game_metadata.game_id.is_empty() {
             return Err(ClientGameError::MalformedResponse(Cow::from("Missing GameId")));
         }
         if proto_game_metadata.host_player_id.is_empty() {
             return Err(ClientGameError::MalformedResponse(Cow::from("Missing HostPlayerId")));
         }
+        // Older servers only ever hosted standard games.
+        let rule_set = proto_game_metadata.rule_set.map_or(RuleSet::standard(), RuleSet::from);
+
         if let Some(status) = opt_status {
             if proto_game_metadata.guest_player_id.is_empty() {
                 return Err(ClientGameError::MalformedResponse(Cow::from("Missing GuestPlayerId")));
@@ -45,13 +77,13 @@ impl TryFrom<ProtoGameMetadata> for GameMetadata {
                 proto_game_metadata.created_time_ms,
                 proto_game_metadata.guest_player_id,
                 status,
-            ))
+            ).with_rule_set(rule_set).with_game_type(game_type))
         } else {
             Ok(GameMetadata::new_unmatched(
                 proto_game_metadata.game_id,
                 proto_game_metadata.host_player_id,
                 proto_game_metadata.created_time_ms,
-            ))
+            ).with_rule_set(rule_set).with_game_type(game_type))
         }
     }
 }
@@ -85,7 +117,10 @@ impl TryFrom<ProtoGame> for GameState {
             op_score_per_color,
             neutral_board,
             proto_game.draw_pile_cards_remaining as usize,
-        );
+        ).with_more_opponents(proto_game.more_opponents
+            .into_iter()
+            .map(OpponentBoard::try_from)
+            .collect::<Result<Vec<OpponentBoard>, ClientGameError>>()?);
 
         let mut my_hand = Vec::with_capacity(proto_game.my_hand.len());
         for proto_card in proto_game.my_hand {
@@ -99,22 +134,413 @@ impl TryFrom<ProtoGame> for GameState {
         my_hand.sort();
         let my_hand = my_hand;
 
-        let status = match ProtoGameStatus::try_from(proto_game.status)? {
-            ProtoGameStatus::NoGameStatus => return Err(ClientGameError::MalformedResponse(Cow::from("Missing game status"))),
-            // GetGameState is only for in-progress or completed games.
-            ProtoGameStatus::Unmatched => return Err(ClientGameError::GameNotStarted),
-            // Valid options
-            ProtoGameStatus::YourTurn => GameStatus::InProgress(true),
-            ProtoGameStatus::OpponentTurn => GameStatus::InProgress(false),
-            ProtoGameStatus::EndWin => GameStatus::Complete(GameResult::Win),
-            ProtoGameStatus::EndLose => GameStatus::Complete(GameResult::Lose),
-            ProtoGameStatus::EndDraw => GameStatus::Complete(GameResult::Draw),
+        let status = started_game_status_from_proto(proto_game.status)?;
+
+        let clock = match proto_game.clock {
+            Some(proto_clock) => Some(GameClock::try_from(proto_clock)?),
+            None => None,
+        };
+
+        let draw_offer = match ProtoDrawOffer::try_from(proto_game.draw_offer)? {
+            ProtoDrawOffer::NoDrawOffer => None,
+            ProtoDrawOffer::DrawOfferedByMe => Some(DrawOffer::OfferedByMe),
+            ProtoDrawOffer::DrawOfferedByOpponent => Some(DrawOffer::OfferedByOpponent),
         };
 
-        Ok(GameState::new(
+        let take_back_request = match ProtoTakeBackRequest::try_from(proto_game.take_back_request)? {
+            ProtoTakeBackRequest::NoTakeBackRequest => None,
+            ProtoTakeBackRequest::TakeBackRequestedByMe => Some(TakeBackRequest::RequestedByMe),
+            ProtoTakeBackRequest::TakeBackRequestedByOpponent => Some(TakeBackRequest::RequestedByOpponent),
+        };
+
+        let mut unseen_cards = Vec::with_capacity(proto_game.unseen_cards.len());
+        for proto_card in proto_game.unseen_cards {
+            unseen_cards.push(proto_card.try_into()?);
+        }
+        unseen_cards.sort();
+
+        // Older servers only ever played by the standard rules.
+        let rule_set = proto_game.rule_set
+            .map(RuleSet::from)
+            .unwrap_or_else(RuleSet::standard);
+
+        let game_state = GameState::new(
             game_board,
             my_hand,
             status,
+            clock,
+            draw_offer,
+            take_back_request,
+            unseen_cards,
+        ).with_rule_set(rule_set);
+
+        // Older servers don't send these either.
+        let game_state = match proto_game.last_move {
+            Some(proto_last_move) => game_state.with_last_move(LastMove::try_from(proto_last_move)?),
+            None => game_state,
+        };
+        Ok(match (proto_game.summary, status) {
+            (Some(proto_summary), GameStatus::Complete(_, end_reason)) => game_state.with_summary(game_summary_from_proto(proto_summary, end_reason)?),
+            (Some(_), GameStatus::InProgress(_)) => return Err(ClientGameError::MalformedResponse(Cow::from("Summary of a game that isn't over"))),
+            (None, _) => game_state,
+        })
+    }
+}
+
+/// The end reason comes from the game's status.
+fn game_summary_from_proto(proto_summary: ProtoGameSummary, end_reason: EndReason) -> Result<GameSummary, ClientGameError> {
+    let players = proto_summary.players
+        .into_iter()
+        .map(PlayerSummary::try_from)
+        .collect::<Result<Vec<PlayerSummary>, ClientGameError>>()?;
+    let duration_ms = match proto_summary.duration_ms {
+        0 => None,
+        duration_ms => Some(duration_ms),
+    };
+
+    Ok(GameSummary::new(players, proto_summary.num_turns as usize, duration_ms, end_reason))
+}
+
+impl TryFrom<ProtoPlayerSummary> for PlayerSummary {
+    type Error = ClientGameError;
+
+    fn try_from(proto_player_summary: ProtoPlayerSummary) -> Result<Self, Self::Error> {
+        let mut final_hand = proto_player_summary.final_hand
+            .into_iter()
+            .map(Card::try_from)
+            .collect::<Result<Vec<Card>, ClientGameError>>()?;
+        final_hand.sort();
+        let (score_total, score_per_color) = proto_player_summary.score
+            .ok_or(ClientGameError::MalformedResponse(Cow::from("Missing Score in PlayerSummary")))?
+            .into();
+
+        Ok(PlayerSummary::new(final_hand, score_total, score_per_color))
+    }
+}
+
+impl TryFrom<ProtoLastMove> for LastMove {
+    type Error = ClientGameError;
+
+    fn try_from(proto_last_move: ProtoLastMove) -> Result<Self, Self::Error> {
+        let opponent_index = match proto_last_move.seat_offset {
+            0 => None,
+            seat_offset => Some(seat_offset as usize - 1),
+        };
+        let card = match proto_last_move.card {
+            None => return Err(ClientGameError::MalformedResponse(Cow::from("Missing Card in LastMove"))),
+            Some(proto_card) => Card::try_from(proto_card)?,
+        };
+        let target = match ProtoPlayTarget::try_from(proto_last_move.target)? {
+            ProtoPlayTarget::NoPlayTarget => return Err(ClientGameError::MalformedResponse(Cow::from("Unspecified PlayTarget"))),
+            ProtoPlayTarget::PlayerBoard => CardTarget::Player,
+            ProtoPlayTarget::Discard => CardTarget::Neutral,
+        };
+        let draw = match ProtoDrawPile::try_from(proto_last_move.draw_pile)? {
+            ProtoDrawPile::NoDrawPile => LastDraw::Nothing,
+            ProtoDrawPile::MainDraw => LastDraw::MainPile,
+            ProtoDrawPile::DiscardDraw => match proto_last_move.drawn_card {
+                None => return Err(ClientGameError::MalformedResponse(Cow::from("Missing drawn Card in LastMove"))),
+                Some(proto_card) => LastDraw::DiscardPile(Card::try_from(proto_card)?),
+            },
+        };
+
+        Ok(LastMove::new(opponent_index, card, target, draw))
+    }
+}
+
+impl TryFrom<ProtoRuleViolation> for RuleViolation {
+    type Error = ClientGameError;
+
+    fn try_from(proto_rule_violation: ProtoRuleViolation) -> Result<Self, Self::Error> {
+        let card = || match proto_rule_violation.card.clone() {
+            None => Err(ClientGameError::MalformedResponse(Cow::from("Missing Card in RuleViolation"))),
+            Some(proto_card) => Card::try_from(proto_card),
+        };
+
+        Ok(match ProtoRule::try_from(proto_rule_violation.rule)? {
+            ProtoRule::NoRule => return Err(ClientGameError::MalformedResponse(Cow::from("Unspecified Rule"))),
+            ProtoRule::RuleNotYourTurn => RuleViolation::NotYourTurn,
+            ProtoRule::RuleCardNotInHand => RuleViolation::CardNotInHand(card()?),
+            ProtoRule::RuleDecreasingCardValue => RuleViolation::DecreasingCardValue(card()?),
+            ProtoRule::RuleDiscardPileEmpty => RuleViolation::DiscardPileEmpty(ProtoColor::try_from(proto_rule_violation.color)?.try_into()?),
+            ProtoRule::RuleMainDrawPileEmpty => RuleViolation::MainDrawPileEmpty,
+            ProtoRule::RuleRedrawCardJustPlayed => RuleViolation::RedrawCardJustPlayed(card()?),
+            ProtoRule::RuleGameOver => RuleViolation::GameOver,
+            ProtoRule::RuleOutOfTime => RuleViolation::OutOfTime,
+            ProtoRule::RuleDrawAlreadyOffered => RuleViolation::DrawAlreadyOffered,
+            ProtoRule::RuleNoDrawOffer => RuleViolation::NoDrawOffer,
+            ProtoRule::RuleNoMoveToTakeBack => RuleViolation::NoMoveToTakeBack,
+            ProtoRule::RuleTakeBackAlreadyRequested => RuleViolation::TakeBackAlreadyRequested,
+            ProtoRule::RuleNoTakeBackRequest => RuleViolation::NoTakeBackRequest,
+            ProtoRule::RuleNoDrawOnFinalTurn => RuleViolation::NoDrawOnFinalTurn,
+            ProtoRule::RuleInvalidRuleSet => RuleViolation::InvalidRuleSet,
+            ProtoRule::RuleTwoPlayerOnly => RuleViolation::TwoPlayerOnly,
+            ProtoRule::RuleAlreadySeated => RuleViolation::AlreadySeated,
+            ProtoRule::RuleGameNotOver => RuleViolation::GameNotOver,
+        })
+    }
+}
+
+impl TryFrom<ProtoClock> for GameClock {
+    type Error = ClientGameError;
+
+    fn try_from(proto_clock: ProtoClock) -> Result<Self, Self::Error> {
+        let time_control = proto_clock.time_control
+            .ok_or(ClientGameError::MalformedResponse(Cow::from("Missing TimeControl inside Clock")))
+            .and_then(TimeControl::try_from)?;
+
+        Ok(GameClock::new(
+            time_control,
+            proto_clock.my_remaining_ms,
+            proto_clock.op_remaining_ms,
+        ))
+    }
+}
+
+impl TryFrom<ProtoTimeControl> for TimeControl {
+    type Error = ClientGameError;
+
+    fn try_from(proto_time_control: ProtoTimeControl) -> Result<Self, Self::Error> {
+        match ProtoTimeControlType::try_from(proto_time_control.time_control_type)? {
+            ProtoTimeControlType::NoTimeControl => Err(ClientGameError::MalformedResponse(Cow::from("Missing time control type"))),
+            ProtoTimeControlType::PerMove => Ok(TimeControl::PerMove(proto_time_control.seconds)),
+            ProtoTimeControlType::TimeBank => Ok(TimeControl::TimeBank(proto_time_control.seconds)),
+        }
+    }
+}
+
+impl From<ProtoRuleSet> for RuleSet {
+    fn from(proto_rule_set: ProtoRuleSet) -> Self {
+        RuleSet::new(
+            proto_rule_set.hand_size,
+            proto_rule_set.expedition_cost,
+            proto_rule_set.multiplier_per_wager,
+            proto_rule_set.bonus_threshold,
+            proto_rule_set.bonus_points,
+            proto_rule_set.final_turn,
+            proto_rule_set.purple,
+        ).with_num_players(match proto_rule_set.num_players {
+            // Older servers only ever had two players.
+            0 => 2,
+            num_players => num_players,
+        })
+    }
+}
+
+impl TryFrom<ProtoSpectatorGame> for SpectatorGameState {
+    type Error = ClientGameError;
+
+    fn try_from(proto_game: ProtoSpectatorGame) -> Result<Self, Self::Error> {
+        if proto_game.seats.len() < 2 {
+            return Err(ClientGameError::MalformedResponse(Cow::from("Missing required Seats")));
+        }
+        let num_seats = proto_game.seats.len();
+        let seats = proto_game.seats
+            .into_iter()
+            .map(SpectatorSeat::try_from)
+            .collect::<Result<Vec<SpectatorSeat>, ClientGameError>>()?;
+        let neutral_board = proto_game.discard_pile
+            .ok_or(ClientGameError::MalformedResponse(Cow::from("Missing required DiscardPile")))?
+            .try_into()?;
+
+        let proto_status = proto_game.status
+            .ok_or(ClientGameError::MalformedResponse(Cow::from("Missing required Status")))?;
+        let seat_from_proto = |seat_u32: u32| {
+            let seat = seat_u32 as usize;
+            if seat < num_seats {
+                Ok(seat)
+            } else {
+                Err(ClientGameError::MalformedResponse(Cow::from(format!("Seat {} is out of bounds", seat))))
+            }
+        };
+        let status = if proto_status.is_complete {
+            let winning_seats = proto_status.winning_seats
+                .into_iter()
+                .map(seat_from_proto)
+                .collect::<Result<Vec<usize>, ClientGameError>>()?;
+            if winning_seats.is_empty() {
+                return Err(ClientGameError::MalformedResponse(Cow::from("Missing required WinningSeats")));
+            }
+            let end_reason = match ProtoEndReason::try_from(proto_status.end_reason)? {
+                ProtoEndReason::DeckExhausted => EndReason::DeckExhausted,
+                ProtoEndReason::TimedOut => EndReason::Timeout,
+                ProtoEndReason::Resigned => EndReason::Resignation,
+                ProtoEndReason::DrawAgreed => EndReason::DrawAgreed,
+            };
+            SpectatorStatus::Complete(winning_seats, end_reason)
+        } else {
+            SpectatorStatus::InProgress(seat_from_proto(proto_status.turn_seat)?)
+        };
+
+        let rule_set = proto_game.rule_set
+            .map(RuleSet::from)
+            .unwrap_or_else(RuleSet::standard);
+
+        Ok(SpectatorGameState::new(
+            seats,
+            neutral_board,
+            proto_game.draw_pile_cards_remaining as usize,
+            status,
+        ).with_rule_set(rule_set))
+    }
+}
+
+impl TryFrom<ProtoOpponentBoard> for OpponentBoard {
+    type Error = ClientGameError;
+
+    fn try_from(proto_opponent: ProtoOpponentBoard) -> Result<Self, Self::Error> {
+        let plays = proto_opponent.plays
+            .ok_or(ClientGameError::MalformedResponse(Cow::from("Missing required OpponentPlays")))?
+            .try_into()?;
+        let (score_total, score_per_color) = proto_opponent.score
+            .ok_or(ClientGameError::MalformedResponse(Cow::from("Missing required OpponentScore")))?
+            .into();
+
+        Ok(OpponentBoard::new(
+            plays,
+            score_total,
+            score_per_color,
+        ))
+    }
+}
+
+impl TryFrom<ProtoSpectatorSeat> for SpectatorSeat {
+    type Error = ClientGameError;
+
+    fn try_from(proto_seat: ProtoSpectatorSeat) -> Result<Self, Self::Error> {
+        if proto_seat.player_id.is_empty() {
+            return Err(ClientGameError::MalformedResponse(Cow::from("Missing PlayerId")));
+        }
+        let plays = proto_seat.plays
+            .ok_or(ClientGameError::MalformedResponse(Cow::from("Missing required Plays")))?
+            .try_into()?;
+        let (score_total, score_per_color) = proto_seat.score
+            .ok_or(ClientGameError::MalformedResponse(Cow::from("Missing required Score")))?
+            .into();
+
+        Ok(SpectatorSeat::new(proto_seat.player_id, plays, score_total, score_per_color))
+    }
+}
+
+impl TryFrom<ProtoMatch> for MatchState {
+    type Error = ClientGameError;
+
+    fn try_from(proto_match: ProtoMatch) -> Result<Self, Self::Error> {
+        let status = match ProtoMatchStatus::try_from(proto_match.status)? {
+            ProtoMatchStatus::NoMatchStatus => return Err(ClientGameError::MalformedResponse(Cow::from("Missing match status"))),
+            ProtoMatchStatus::MatchUnmatched => MatchStatus::Unmatched,
+            ProtoMatchStatus::MatchInProgress => MatchStatus::InProgress,
+            ProtoMatchStatus::MatchWin => MatchStatus::Complete(GameResult::Win),
+            ProtoMatchStatus::MatchLose => MatchStatus::Complete(GameResult::Lose),
+            ProtoMatchStatus::MatchDraw => MatchStatus::Complete(GameResult::Draw),
+        };
+
+        let op_player_id = if proto_match.opponent_player_id.is_empty() {
+            if status != MatchStatus::Unmatched {
+                return Err(ClientGameError::MalformedResponse(Cow::from("Missing OpponentPlayerId")));
+            }
+            None
+        } else {
+            Some(proto_match.opponent_player_id)
+        };
+
+        let mut rounds = Vec::with_capacity(proto_match.rounds.len());
+        for proto_round in proto_match.rounds {
+            rounds.push(MatchRound::try_from(proto_round)?);
+        }
+
+        Ok(MatchState::new(
+            op_player_id,
+            proto_match.num_rounds,
+            rounds,
+            proto_match.my_score_total,
+            proto_match.op_score_total,
+            status,
+        ))
+    }
+}
+
+impl TryFrom<ProtoMatchRound> for MatchRound {
+    type Error = ClientGameError;
+
+    fn try_from(proto_round: ProtoMatchRound) -> Result<Self, Self::Error> {
+        if proto_round.game_id.is_empty() {
+            return Err(ClientGameError::MalformedResponse(Cow::from("Missing GameId")));
+        }
+        let status = started_game_status_from_proto(proto_round.status)?;
+
+        Ok(MatchRound::new(
+            proto_round.game_id,
+            status,
+            proto_round.my_score,
+            proto_round.op_score,
+        ))
+    }
+}
+
+impl TryFrom<ProtoGameAnalysis> for GameAnalysis {
+    type Error = ClientGameError;
+
+    fn try_from(proto_analysis: ProtoGameAnalysis) -> Result<Self, Self::Error> {
+        let move_hints = proto_analysis.move_hints
+            .into_iter()
+            .map(MoveEvaluation::try_from)
+            .collect::<Result<Vec<MoveEvaluation>, ClientGameError>>()?;
+        let mistakes = proto_analysis.mistakes
+            .into_iter()
+            .map(Mistake::try_from)
+            .collect::<Result<Vec<Mistake>, ClientGameError>>()?;
+
+        Ok(GameAnalysis::new(
+            move_hints,
+            mistakes,
+        ))
+    }
+}
+
+impl TryFrom<ProtoMoveEvaluation> for MoveEvaluation {
+    type Error = ClientGameError;
+
+    fn try_from(proto_move_evaluation: ProtoMoveEvaluation) -> Result<Self, Self::Error> {
+        let card = match proto_move_evaluation.card {
+            None => return Err(ClientGameError::MalformedResponse(Cow::from("Missing Card"))),
+            Some(proto_card) => Card::try_from(proto_card)?,
+        };
+        let target = match ProtoPlayTarget::try_from(proto_move_evaluation.target)? {
+            ProtoPlayTarget::NoPlayTarget => return Err(ClientGameError::MalformedResponse(Cow::from("Unspecified PlayTarget"))),
+            ProtoPlayTarget::PlayerBoard => CardTarget::Player,
+            ProtoPlayTarget::Discard => CardTarget::Neutral,
+        };
+        let draw_pile = match ProtoDrawPile::try_from(proto_move_evaluation.draw_pile)? {
+            ProtoDrawPile::NoDrawPile => return Err(ClientGameError::MalformedResponse(Cow::from("Unspecified DrawPile"))),
+            ProtoDrawPile::MainDraw => DrawPile::Main,
+            ProtoDrawPile::DiscardDraw => DrawPile::Neutral(CardColor::try_from(
+                ProtoColor::try_from(proto_move_evaluation.discard_draw_color)?,
+            )?),
+        };
+
+        Ok(MoveEvaluation::new(
+            card,
+            target,
+            draw_pile,
+            proto_move_evaluation.value,
+        ))
+    }
+}
+
+impl TryFrom<ProtoMistake> for Mistake {
+    type Error = ClientGameError;
+
+    fn try_from(proto_mistake: ProtoMistake) -> Result<Self, Self::Error> {
+        let played = proto_mistake.played
+            .ok_or(ClientGameError::MalformedResponse(Cow::from("Missing played move")))?;
+        let best = proto_mistake.best
+            .ok_or(ClientGameError::MalformedResponse(Cow::from("Missing best move")))?;
+
+        Ok(Mistake::new(
+            proto_mistake.turn,
+            MoveEvaluation::try_from(played)?,
+            MoveEvaluation::try_from(best)?,
         ))
     }
 }
@@ -157,6 +583,12 @@ impl TryFrom<ProtoPlayHistory> for HashMap<CardColor, Vec<CardValue>> {
                 .or_insert_with(|| Vec::new())
                 .push(card_value_from_proto(value_u32)?);
         }
+        for value_u32 in proto_play_history.purple {
+            play_history
+                .entry(CardColor::Purple)
+                .or_insert_with(Vec::new)
+                .push(card_value_from_proto(value_u32)?);
+        }
 
         Ok(play_history)
     }
@@ -170,6 +602,7 @@ impl From<ProtoScore> for (i32, HashMap<CardColor, i32>) {
         score_per_color.insert(CardColor::Green, proto_score.green);
         score_per_color.insert(CardColor::White, proto_score.white);
         score_per_color.insert(CardColor::Yellow, proto_score.yellow);
+        score_per_color.insert(CardColor::Purple, proto_score.purple);
 
         (proto_score.total, score_per_color)
     }
@@ -196,6 +629,9 @@ impl TryFrom<ProtoDiscardPile> for HashMap<CardColor, (CardValue, usize)> {
         if let Some(surface) = proto_discard_pile.yellow {
             neutral_board.insert(CardColor::Yellow, surface.try_into()?);
         }
+        if let Some(surface) = proto_discard_pile.purple {
+            neutral_board.insert(CardColor::Purple, surface.try_into()?);
+        }
 
         Ok(neutral_board)
     }
@@ -239,6 +675,7 @@ impl TryFrom<ProtoColor> for CardColor {
             ProtoColor::White => Ok(CardColor::White),
             ProtoColor::Blue => Ok(CardColor::Blue),
             ProtoColor::Yellow => Ok(CardColor::Yellow),
+            ProtoColor::Purple => Ok(CardColor::Purple),
         }
     }
 }
@@ -283,6 +720,7 @@ impl From<CardColor> for ProtoColor {
             CardColor::White => ProtoColor::White,
             CardColor::Blue => ProtoColor::Blue,
             CardColor::Yellow => ProtoColor::Yellow,
+            CardColor::Purple => ProtoColor::Purple,
         };
 
         proto_color
@@ -297,3 +735,43 @@ impl From<CardTarget> for ProtoPlayTarget {
         }
     }
 }
+
+impl From<TimeControl> for ProtoTimeControl {
+    fn from(time_control: TimeControl) -> Self {
+        let (time_control_type, seconds) = match time_control {
+            TimeControl::PerMove(seconds) => (ProtoTimeControlType::PerMove, seconds),
+            TimeControl::TimeBank(seconds) => (ProtoTimeControlType::TimeBank, seconds),
+        };
+
+        ProtoTimeControl {
+            time_control_type: time_control_type as i32,
+            seconds,
+        }
+    }
+}
+
+impl From<RuleSet> for ProtoRuleSet {
+    fn from(rule_set: RuleSet) -> Self {
+        ProtoRuleSet {
+            hand_size: *rule_set.hand_size(),
+            expedition_cost: *rule_set.expedition_cost(),
+            multiplier_per_wager: *rule_set.multiplier_per_wager(),
+            bonus_threshold: *rule_set.bonus_threshold(),
+            bonus_points: *rule_set.bonus_points(),
+            final_turn: *rule_set.final_turn(),
+            purple: *rule_set.purple(),
+            num_players: *rule_set.num_players(),
+        }
+    }
+}
+
+impl From<Opponent> for ProtoOpponent {
+    fn from(opponent: Opponent) -> Self {
+        match opponent {
+            Opponent::Human => ProtoOpponent::OpponentHuman,
+            Opponent::Computer(Difficulty::Easy) => ProtoOpponent::EasyComputer,
+            Opponent::Computer(Difficulty::Medium) => ProtoOpponent::MediumComputer,
+            Opponent::Computer(Difficulty::Hard) => ProtoOpponent::HardComputer,
+        }
+    }
+}
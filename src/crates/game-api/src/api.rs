@@ -1,5 +1,5 @@
 use std::error::Error;
-use crate::types::{GameState, Play, GameMetadata};
+use crate::types::{GameState, Play, GameMetadata, SpectatorGameState, MatchState, TimeControl, Opponent, GameAnalysis, RuleSet};
 
 /// The application layer API for the game.
 ///
@@ -11,9 +11,15 @@ pub trait GameApi2<E: Error + Send + Sync + 'static> {
 
     /// Create a new game with only the host player present.
     /// Returns game_id used for all future queries
-    async fn host_game(&mut self, game_id: String, p1_id: String) -> Result<(), E>;
+    ///
+    /// If a time control is given, a player who runs out of time forfeits the game.
+    ///
+    /// If the opponent is the computer, the game starts right away.
+    ///
+    /// The rule set is stored with the game and applies to everything from the deal to the score.
+    async fn host_game(&mut self, game_id: String, p1_id: String, time_control: Option<TimeControl>, opponent: Opponent, rule_set: RuleSet) -> Result<(), E>;
 
-    /// Player 2 joins the game.
+    /// A guest joins the game. Once every seat is taken, the game starts.
     async fn join_game(&mut self, game_id: String, p2_id: String) -> Result<(), E>;
 
     /// Get status of a game
@@ -31,10 +37,53 @@ pub trait GameApi2<E: Error + Send + Sync + 'static> {
     /// Get all (global) unmatched games - aka matchmaking LOL
     async fn query_all_unmatched_games(&mut self, player_id: String) -> Result<Vec<GameMetadata>, E>;
 
+    /// Get all (global) in-progress games that can be watched by a spectator.
+    async fn query_watchable_games(&mut self, player_id: String) -> Result<Vec<GameMetadata>, E>;
+
     /// Load the state of the game as observed by the requested player.
     async fn get_game_state(&mut self, game_id: String, player_id: String) -> Result<GameState, E>;
 
+    /// Load the state of the game as observed by a spectator. Neither player's hand is visible.
+    async fn get_spectator_game_state(&mut self, game_id: String, player_id: String) -> Result<SpectatorGameState, E>;
+
     /// Make a turn. Should call get_game_state() after this. Maybe not needed? Idk yet.
     async fn play_card(&mut self, play: Play) -> Result<(), E>;
 
+    /// Give up the game. The opponent wins, regardless of the score. With more than two players,
+    /// the game still ends for the whole table, and everyone else wins.
+    async fn resign(&mut self, game_id: String, player_id: String) -> Result<(), E>;
+
+    /// Offer the opponent to end the game as a draw. Playing a card doesn't withdraw the offer,
+    /// but the opponent playing a card instead of responding declines it.
+    async fn offer_draw(&mut self, game_id: String, player_id: String) -> Result<(), E>;
+
+    /// Accept or decline the opponent's pending draw offer.
+    async fn respond_to_draw_offer(&mut self, game_id: String, player_id: String, accept: bool) -> Result<(), E>;
+
+    /// Ask the opponent to let me undo my last move. Only the most recent move of the game can be
+    /// taken back, so this has to happen before the opponent plays.
+    async fn request_take_back(&mut self, game_id: String, player_id: String) -> Result<(), E>;
+
+    /// Accept or decline the opponent's pending take-back request. Accepting reverts their last
+    /// move, and it becomes their turn again.
+    async fn respond_to_take_back(&mut self, game_id: String, player_id: String, accept: bool) -> Result<(), E>;
+
+    /// Ask the computer how good each of my moves would be right now, and, once the game is over,
+    /// which of my moves were mistakes. Only uses what the player could see at the time.
+    async fn analyze_game(&mut self, game_id: String, player_id: String) -> Result<GameAnalysis, E>;
+
+    /// Write down a finished game in text notation, for sharing. Anyone can export any finished
+    /// game, like spectating.
+    async fn export_game(&mut self, game_id: String, player_id: String) -> Result<String, E>;
+
+    /// Create a new match (a series of `num_rounds` games) with only the host player present.
+    async fn host_match(&mut self, match_id: String, p1_id: String, num_rounds: u32, time_control: Option<TimeControl>, rule_set: RuleSet) -> Result<(), E>;
+
+    /// Player 2 joins the match. This deals the first round.
+    async fn join_match(&mut self, match_id: String, p2_id: String) -> Result<(), E>;
+
+    /// Load the state of the match as observed by the requested player. When a round completes,
+    /// the next one is dealt automatically, and shows up here as the current round.
+    async fn get_match_state(&mut self, match_id: String, player_id: String) -> Result<MatchState, E>;
+
 }
@@ -1,31 +1,26 @@
 /// For impls of the Display trait.
-use crate::types::{GameBoard, GameState, Card, CardColor, CardValue, DecoratedCard, DrawPile, CardTarget};
+use crate::types::{GameState, Card, CardColor, CardValue, DecoratedCard, DrawPile, CardTarget, SpectatorGameState, SpectatorSeat, SpectatorStatus, MatchState, GameStatus, EndReason, GameClock, TimeControl, DrawOffer, TakeBackRequest, RuleSet, LastMove, LastDraw};
 use std::fmt::{Display, Formatter};
 use std::fmt;
 use std::collections::HashMap;
 #[allow(unused_imports)] // Needs to be in scope, despite not used
 use std::convert::TryFrom;
 
-const BOARD_ROW_END_LINE: &str = "+-----------------------------------------------------------+";
-const BOARD_ROW_END_BLANK: &str = "|                                                           |";
-const BOARD_ROW_GRID_LINE: &str = "+-----------+-----------+-----------+-----------+-----------+";
-const BOARD_ROW_GRID_BLANK: &str = "|           |           |           |           |           |";
-const BOARD_NEUTRAL_HEADER: &str = "|    Red    |   Green   |   White   |   Blue    |  Yellow   |";
-const BOARD_NEUTRAL_CARD_BORDER: &str = "|  +-----+  |  +-----+  |  +-----+  |  +-----+  |  +-----+  |";
-const BOARD_ROW_SIZE: usize = BOARD_ROW_GRID_LINE.len();
+// The board is one column per color, so its width depends on the rule set. These are the
+// per-column pieces; see `BoardLines` for the full-width rows built out of them.
+const BOARD_COLUMN_WIDTH: usize = 11;
+const BOARD_GRID_LINE_CELL: &str = "-----------+";
+const BOARD_GRID_BLANK_CELL: &str = "           |";
+const BOARD_NEUTRAL_CARD_BORDER_CELL: &str = "  +-----+  |";
+const BOARD_NEUTRAL_CARD_HIGHLIGHT_CELL: &str = "  +=====+  |";
 const BOARD_PLAY_CARD_BORDER: &str = "  +-----+  ";
+const BOARD_PLAY_CARD_HIGHLIGHT: &str = "  +=====+  ";
 const BOARD_PLAY_CARD_BLANK: &str = "           ";
 const CARD_BORDER_SINGLE: &str = "+-----+";
-const HAND_BORDER: &str = "+-----+ +-----+ +-----+ +-----+ +-----+ +-----+ +-----+ +-----+";
-const HAND_ROW_SIZE: usize = HAND_BORDER.len();
-const HAND_SELECTION_ROW: &str = "  [1]     [2]     [3]     [4]     [5]     [6]     [7]     [8]";
-const COLOR_ORDER: [CardColor; 5] = [
-    CardColor::Red,
-    CardColor::Green,
-    CardColor::White,
-    CardColor::Blue,
-    CardColor::Yellow
-];
+const HAND_CARD_BORDER: &str = "+-----+";
+const HAND_CARD_HIGHLIGHT: &str = "+=====+";
+const MATCH_ROW_LINE: &str = "+-------+--------+--------+";
+const MATCH_HEADER: &str = "| Round |    You |   Them |";
 
 /// See repo level README for example.
 impl Display for GameState {
@@ -33,14 +28,80 @@ impl Display for GameState {
         let mut lines = Vec::new();
 
         lines.push("The board:");
-        let game_board_str = format!("{}", self.game_board());
+        let game_board = self.game_board();
+        let last_move = self.last_move().as_ref();
+        // Which expedition, if any, the last card was played on by this side.
+        let last_played = |mover: Option<usize>| last_move
+            .filter(|last_move| *last_move.opponent_index() == mover && *last_move.target() == CardTarget::Player)
+            .map(|last_move| *last_move.card().card_color());
+        let last_discard = last_move
+            .filter(|last_move| *last_move.target() == CardTarget::Neutral)
+            .map(|last_move| *last_move.card().card_color());
+        let op_labels = opponent_labels(game_board.opponents().len());
+        let op_sides: Vec<BoardSide> = game_board.opponents()
+            .iter()
+            .zip(op_labels.iter())
+            .enumerate()
+            .map(|(i, (opponent, label))| BoardSide {
+                label: label.clone(),
+                plays: opponent.plays(),
+                score_total: *opponent.score_total(),
+                score_per_color: opponent.score_per_color(),
+                last_played: last_played(Some(i)),
+            })
+            .collect();
+        let my_side = BoardSide {
+            label: "Your".to_owned(),
+            plays: game_board.my_plays(),
+            score_total: *game_board.my_score_total(),
+            score_per_color: game_board.my_score_per_color(),
+            last_played: last_played(None),
+        };
+        let game_board_str = draw_game_board(
+            self.rule_set().colors(),
+            &op_sides,
+            game_board.neutral_draw_pile(),
+            *game_board.draw_pile_cards_remaining(),
+            last_discard,
+            &my_side,
+        );
         lines.push(&game_board_str);
 
+        let last_move_str;
+        if let Some(last_move) = last_move {
+            last_move_str = describe_last_move(last_move, &op_labels);
+            lines.push(&last_move_str);
+        }
+
+        // Hand size depends on the rule set. The card I just took from a discard pile is highlighted.
+        let drawn_card = match last_move {
+            Some(last_move) => match (last_move.opponent_index(), last_move.draw()) {
+                (None, LastDraw::DiscardPile(card)) => Some(*card),
+                _ => None,
+            },
+            None => None,
+        };
+        let hand_border = self.my_hand()
+            .iter()
+            .map(|decorated_card| if Some(*decorated_card.card()) == drawn_card {
+                HAND_CARD_HIGHLIGHT
+            } else {
+                HAND_CARD_BORDER
+            })
+            .collect::<Vec<&str>>()
+            .join(" ");
+        let hand_row_size = hand_border.len();
+        let hand_selection_row = (1..=self.my_hand().len())
+            .map(|i| format!("{:^7}", format!("[{}]", i)))
+            .collect::<Vec<String>>()
+            .join(" ");
+        let hand_selection_row = hand_selection_row.trim_end();
+
         lines.push("");
         lines.push("Your hand:");
-        lines.push(HAND_BORDER);
-        let mut hand_number_line = String::with_capacity(HAND_ROW_SIZE);
-        let mut hand_color_line = String::with_capacity(HAND_ROW_SIZE);
+        lines.push(&hand_border);
+        let mut hand_number_line = String::with_capacity(hand_row_size);
+        let mut hand_color_line = String::with_capacity(hand_row_size);
         for (i, decorated_card) in self.my_hand().iter().enumerate() {
             if i != 0 {
                 hand_number_line.push(' ');
@@ -51,80 +112,342 @@ impl Display for GameState {
         }
         lines.push(&hand_number_line);
         lines.push(&hand_color_line);
-        lines.push(HAND_BORDER);
-        lines.push(HAND_SELECTION_ROW);
+        lines.push(&hand_border);
+        lines.push(hand_selection_row);
+
+        let clock_str;
+        if let Some(clock) = self.clock() {
+            clock_str = format!("{}", clock);
+            lines.push("");
+            lines.push(&clock_str);
+        }
+
+        match self.draw_offer() {
+            Some(DrawOffer::OfferedByMe) => {
+                lines.push("");
+                lines.push("You offered a draw. Waiting on your opponent to respond.");
+            },
+            Some(DrawOffer::OfferedByOpponent) => {
+                lines.push("");
+                lines.push("Your opponent has offered a draw.");
+            },
+            None => {},
+        }
+
+        match self.take_back_request() {
+            Some(TakeBackRequest::RequestedByMe) => {
+                lines.push("");
+                lines.push("You asked to take back your last move. Waiting on your opponent to respond.");
+            },
+            Some(TakeBackRequest::RequestedByOpponent) => {
+                lines.push("");
+                lines.push("Your opponent asked to take back their last move.");
+            },
+            None => {},
+        }
 
         f.write_str(&lines.join("\n"))
     }
 }
 
-impl Display for GameBoard {
+/// Spectators see every seat labeled by player: the host on the bottom, the guest on top, and no
+/// hands. Underneath is whose turn it is, or who won.
+impl Display for SpectatorGameState {
     fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
         let mut lines = Vec::new();
 
-        // Opponent's score
-        lines.push(BOARD_ROW_END_LINE);
-        let opponent_score_line = format!("| Opponent's total score: {:<4}{:30}|", self.op_score_total(), "");
-        lines.push(&opponent_score_line);
-        lines.push(BOARD_ROW_END_BLANK);
-        let opponent_score_per_color_line = draw_score_per_color(self.op_score_per_color());
-        lines.push(&opponent_score_per_color_line);
-
-        // Opponent's plays
-        lines.push(BOARD_ROW_GRID_LINE);
-        lines.push(BOARD_ROW_GRID_BLANK);
-        let op_plays_lines: Vec<String> = draw_op_plays(self.op_plays());
-        for line in op_plays_lines.iter() {
-            lines.push(line);
+        lines.push("The board:".to_owned());
+        // Everyone but the host is on top, in turn order.
+        let sides: Vec<BoardSide> = self.seats()
+            .iter()
+            .map(spectator_board_side)
+            .collect();
+        if let Some((host_side, guest_sides)) = sides.split_first() {
+            lines.push(draw_game_board(
+                self.rule_set().colors(),
+                guest_sides,
+                self.neutral_draw_pile(),
+                *self.draw_pile_cards_remaining(),
+                None,
+                host_side,
+            ));
         }
-        lines.push(BOARD_ROW_GRID_LINE);
-
-        // Neutral board
-        lines.push(BOARD_NEUTRAL_HEADER);
-        lines.push(BOARD_ROW_GRID_BLANK);
-        lines.push(BOARD_NEUTRAL_CARD_BORDER);
-        let (
-            neutral_value_line,
-            neutral_color_line,
-            neutral_draw_deck_size_line
-        ) = draw_neutral_board(self.neutral_draw_pile());
-        lines.push(&neutral_value_line);
-        lines.push(&neutral_color_line);
-        lines.push(BOARD_NEUTRAL_CARD_BORDER);
-        lines.push(&neutral_draw_deck_size_line);
-        lines.push(BOARD_ROW_GRID_BLANK);
-        lines.push(BOARD_NEUTRAL_HEADER);
-
-        // My plays
-        lines.push(BOARD_ROW_GRID_LINE);
-        let my_plays_lines: Vec<String> = draw_my_plays(self.my_plays());
-        for line in my_plays_lines.iter() {
-            lines.push(line);
+
+        let player_id = |seat: &usize| self.seats()
+            .get(*seat)
+            .map(|seat| seat.player_id())
+            .unwrap_or("?");
+        let losing_player_ids = |winning_seats: &Vec<usize>| self.seats()
+            .iter()
+            .enumerate()
+            .filter(|(seat, _)| !winning_seats.contains(seat))
+            .map(|(_, seat)| seat.player_id())
+            .collect::<Vec<&str>>()
+            .join("' and '");
+        lines.push(match self.status() {
+            SpectatorStatus::InProgress(seat) => format!("-- {}'s turn --", player_id(seat)),
+            SpectatorStatus::Complete(_, EndReason::DrawAgreed) => "The players agreed to a draw!".to_owned(),
+            SpectatorStatus::Complete(winning_seats, EndReason::Timeout) => {
+                format!("'{}' ran out of time!", losing_player_ids(winning_seats))
+            },
+            SpectatorStatus::Complete(winning_seats, EndReason::Resignation) => {
+                format!("'{}' resigned!", losing_player_ids(winning_seats))
+            },
+            SpectatorStatus::Complete(winning_seats, EndReason::DeckExhausted) if winning_seats.len() == 1 => {
+                format!("'{}' wins!", player_id(&winning_seats[0]))
+            },
+            SpectatorStatus::Complete(winning_seats, EndReason::DeckExhausted) => {
+                let winners: Vec<String> = winning_seats.iter()
+                    .map(|seat| format!("'{}'", player_id(seat)))
+                    .collect();
+                format!("It was a tie between {}!", winners.join(" and "))
+            },
+        });
+
+        f.write_str(&lines.join("\n"))
+    }
+}
+
+/// Running totals of a match, one row per round. A round that's still being played is marked
+/// with a `*`, since its score can still change.
+impl Display for MatchState {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        let mut lines = Vec::new();
+
+        lines.push(format!("Match standings (best of {}):", self.num_rounds()));
+        lines.push(MATCH_ROW_LINE.to_owned());
+        lines.push(MATCH_HEADER.to_owned());
+        lines.push(MATCH_ROW_LINE.to_owned());
+        for (i, round) in self.rounds().iter().enumerate() {
+            let marker = match round.status() {
+                GameStatus::InProgress(_) => '*',
+                GameStatus::Complete(_, _) => ' ',
+            };
+            lines.push(format!("| {:>3}{:<2} | {:>6} | {:>6} |", i + 1, marker, round.my_score(), round.op_score()));
         }
-        lines.push(BOARD_ROW_GRID_BLANK);
-
-        // My score
-        lines.push(BOARD_ROW_GRID_LINE);
-        let my_score_per_color_line = draw_score_per_color(self.my_score_per_color());
-        lines.push(&my_score_per_color_line);
-        lines.push(BOARD_ROW_END_BLANK);
-        let my_score_line = format!("| Your total score: {:<4}{:36}|", self.my_score_total(), "");
-        lines.push(&my_score_line);
-        lines.push(BOARD_ROW_END_LINE);
-
-        // Draw pile
-        let draw_pile_line = format!("Main draw pile: {} cards remaining", self.draw_pile_cards_remaining());
-        lines.push(&draw_pile_line);
-
-        // Fin
+        lines.push(MATCH_ROW_LINE.to_owned());
+        lines.push(format!("| Total | {:>6} | {:>6} |", self.my_score_total(), self.op_score_total()));
+        lines.push(MATCH_ROW_LINE.to_owned());
+
         f.write_str(&lines.join("\n"))
     }
 }
 
-fn draw_score_per_color(score_per_color: &HashMap<CardColor, i32>) -> String {
-    let mut cells = Vec::with_capacity(COLOR_ORDER.len());
+impl Display for GameClock {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        write!(
+            f,
+            "Time left ({}): You {} | Opponent {}",
+            self.time_control(),
+            draw_duration_ms(*self.my_remaining_ms()),
+            draw_duration_ms(*self.op_remaining_ms()),
+        )
+    }
+}
+
+impl Display for TimeControl {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        match self {
+            TimeControl::PerMove(seconds) => write!(f, "{} per move", draw_duration_ms(*seconds as u64 * 1000)),
+            TimeControl::TimeBank(seconds) => write!(f, "{} per game", draw_duration_ms(*seconds as u64 * 1000)),
+        }
+    }
+}
+
+/// E.g. "8 card hands, expeditions cost 20, +1x per wager, +20 bonus for 8+ cards"
+impl Display for RuleSet {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        write!(
+            f,
+            "{} card hands, expeditions cost {}, +{}x per wager, +{} bonus for {}+ cards",
+            self.hand_size(),
+            self.expedition_cost(),
+            self.multiplier_per_wager(),
+            self.bonus_points(),
+            self.bonus_threshold(),
+        )?;
+        if *self.final_turn() {
+            write!(f, ", final turn after the last draw")?;
+        }
+        if *self.purple() {
+            write!(f, ", {} expeditions", self.colors().len())?;
+        }
+        if *self.num_players() != 2 {
+            write!(f, ", {} players", self.num_players())?;
+        }
+        Ok(())
+    }
+}
+
+/// E.g. 75,500ms => "1:15"
+fn draw_duration_ms(duration_ms: u64) -> String {
+    let total_seconds = duration_ms / 1000;
+    format!("{}:{:02}", total_seconds / 60, total_seconds % 60)
+}
+
+/// One player's expeditions and score, and how to label them, e.g. "Your" => "Your total score: 12".
+struct BoardSide<'a> {
+    label: String,
+    plays: &'a HashMap<CardColor, Vec<CardValue>>,
+    score_total: i32,
+    score_per_color: &'a HashMap<CardColor, i32>,
+    /// The expedition the last move's card was played on, if it was this side's move. It's
+    /// highlighted.
+    last_played: Option<CardColor>,
+}
+
+fn spectator_board_side(seat: &SpectatorSeat) -> BoardSide<'_> {
+    BoardSide {
+        label: format!("{}'s", seat.player_id()),
+        plays: seat.plays(),
+        score_total: *seat.score_total(),
+        score_per_color: seat.score_per_color(),
+        last_played: None,
+    }
+}
+
+/// The full-width rows of the board, for however many colors are in play.
+struct BoardLines {
+    end_line: String,
+    end_blank: String,
+    grid_line: String,
+    grid_blank: String,
+    neutral_header: String,
+}
+
+impl BoardLines {
+    fn new(colors: &[CardColor]) -> Self {
+        let inner_width = board_width(colors) - 2;
+        let neutral_header = colors.iter()
+            .map(|color| format!("{:^width$}", color.to_string_long(), width = BOARD_COLUMN_WIDTH))
+            .collect::<Vec<String>>()
+            .join("|");
+
+        BoardLines {
+            end_line: format!("+{}+", "-".repeat(inner_width)),
+            end_blank: format!("|{}|", " ".repeat(inner_width)),
+            grid_line: format!("+{}", BOARD_GRID_LINE_CELL.repeat(colors.len())),
+            grid_blank: format!("|{}", BOARD_GRID_BLANK_CELL.repeat(colors.len())),
+            neutral_header: format!("|{}|", neutral_header),
+        }
+    }
+}
+
+/// One column per color, plus a border on either side and between each column.
+fn board_width(colors: &[CardColor]) -> usize {
+    colors.len() * (BOARD_COLUMN_WIDTH + 1) + 1
+}
+
+/// "Opponent's" when there's only one, otherwise numbered in turn order.
+fn opponent_labels(num_opponents: usize) -> Vec<String> {
+    if num_opponents == 1 {
+        return vec!["Opponent's".to_owned()];
+    }
+
+    (1..=num_opponents)
+        .map(|i| format!("Opponent {}'s", i))
+        .collect()
+}
+
+/// E.g. "Last move: Opponent discarded the Red 5, then took the Blue 7 from its discard pile."
+fn describe_last_move(last_move: &LastMove, op_labels: &[String]) -> String {
+    let (mover, whose) = match last_move.opponent_index() {
+        None => ("You", "your"),
+        Some(i) => (op_labels[*i].trim_end_matches("'s"), "their"),
+    };
+    let play = match last_move.target() {
+        CardTarget::Player => format!("played the {} on {} expedition", last_move.card(), whose),
+        CardTarget::Neutral => format!("discarded the {}", last_move.card()),
+    };
+    let draw = match last_move.draw() {
+        LastDraw::MainPile => "drew from the main pile".to_owned(),
+        LastDraw::DiscardPile(card) => format!("took the {} from its discard pile", card),
+        LastDraw::Nothing => "didn't draw, since it was the final turn".to_owned(),
+    };
+
+    format!("Last move: {} {}, then {}.", mover, play, draw)
+}
+
+/// Draws the board with the "top" sides stacked above the neutral board, and the "bottom" side
+/// below it. The card last played or discarded, if any, is highlighted.
+fn draw_game_board(
+    colors: &[CardColor],
+    top_sides: &[BoardSide],
+    neutral_draw_pile: &HashMap<CardColor, (CardValue, usize)>,
+    draw_pile_cards_remaining: usize,
+    last_discard: Option<CardColor>,
+    bottom_side: &BoardSide,
+) -> String {
+    let board_lines = BoardLines::new(colors);
+    let mut lines = Vec::new();
+
+    // Opponents' scores and plays
+    let mut top_lines = vec![board_lines.end_line.clone()];
+    for top_side in top_sides.iter() {
+        top_lines.push(draw_score_total(colors, &top_side.label, top_side.score_total));
+        top_lines.push(board_lines.end_blank.clone());
+        top_lines.push(draw_score_per_color(colors, top_side.score_per_color));
+
+        top_lines.push(board_lines.grid_line.clone());
+        top_lines.push(board_lines.grid_blank.clone());
+        top_lines.append(&mut draw_op_plays(colors, top_side.plays, top_side.last_played));
+        top_lines.push(board_lines.grid_line.clone());
+    }
+    for line in top_lines.iter() {
+        lines.push(line.as_str());
+    }
+
+    // Neutral board
+    lines.push(&board_lines.neutral_header);
+    lines.push(&board_lines.grid_blank);
+    let neutral_card_border = draw_neutral_card_border(colors, last_discard);
+    lines.push(&neutral_card_border);
+    let (
+        neutral_value_line,
+        neutral_color_line,
+        neutral_draw_deck_size_line
+    ) = draw_neutral_board(colors, neutral_draw_pile);
+    lines.push(&neutral_value_line);
+    lines.push(&neutral_color_line);
+    lines.push(&neutral_card_border);
+    lines.push(&neutral_draw_deck_size_line);
+    lines.push(&board_lines.grid_blank);
+    lines.push(&board_lines.neutral_header);
+
+    // My plays
+    lines.push(&board_lines.grid_line);
+    let my_plays_lines: Vec<String> = draw_my_plays(colors, bottom_side.plays, bottom_side.last_played);
+    for line in my_plays_lines.iter() {
+        lines.push(line);
+    }
+    lines.push(&board_lines.grid_blank);
+
+    // My score
+    lines.push(&board_lines.grid_line);
+    let my_score_per_color_line = draw_score_per_color(colors, bottom_side.score_per_color);
+    lines.push(&my_score_per_color_line);
+    lines.push(&board_lines.end_blank);
+    let my_score_line = draw_score_total(colors, &bottom_side.label, bottom_side.score_total);
+    lines.push(&my_score_line);
+    lines.push(&board_lines.end_line);
+
+    // Draw pile
+    let draw_pile_line = format!("Main draw pile: {} cards remaining", draw_pile_cards_remaining);
+    lines.push(&draw_pile_line);
+
+    // Fin
+    lines.join("\n")
+}
 
-    for color in COLOR_ORDER.iter() {
+fn draw_score_total(colors: &[CardColor], label: &str, score_total: i32) -> String {
+    let width = board_width(colors) - 3;
+    format!("| {:<width$.width$}|", format!("{} total score: {}", label, score_total), width = width)
+}
+
+fn draw_score_per_color(colors: &[CardColor], score_per_color: &HashMap<CardColor, i32>) -> String {
+    let mut cells = Vec::with_capacity(colors.len());
+
+    for color in colors.iter() {
         match score_per_color.get(color) {
             Some(score) => cells.push(format!("{:^11}", format!("{} pts", score))),
             None => cells.push(format!("{:^11}", "0 pts")),
@@ -134,16 +457,22 @@ fn draw_score_per_color(score_per_color: &HashMap<CardColor, i32>) -> String {
     format!("|{}|", cells.join(" "))
 }
 
-fn draw_op_plays(op_plays: &HashMap<CardColor, Vec<CardValue>>) -> Vec<String> {
+fn draw_op_plays(colors: &[CardColor], op_plays: &HashMap<CardColor, Vec<CardValue>>, highlighted_color: Option<CardColor>) -> Vec<String> {
     let mut columns_per_color: HashMap<CardColor, Vec<String>> = HashMap::new();
     for (color, card_value_vec) in op_plays.iter() {
+        // The last card played on the expedition is the one on the bottom of the column.
+        let last_card_border = if highlighted_color == Some(*color) {
+            BOARD_PLAY_CARD_HIGHLIGHT
+        } else {
+            BOARD_PLAY_CARD_BORDER
+        };
         let mut column: Vec<String> = Vec::new();
-        for card_val in card_value_vec.iter() {
-            column.push(BOARD_PLAY_CARD_BORDER.to_owned());
+        for (i, card_val) in card_value_vec.iter().enumerate() {
+            column.push(if i + 1 == card_value_vec.len() { last_card_border } else { BOARD_PLAY_CARD_BORDER }.to_owned());
             column.push(format!("  | {:^3} |  ", card_val.to_string_short()));
         }
         column.push(format!("  | {:^3} |  ", color.to_string_short()));
-        column.push(BOARD_PLAY_CARD_BORDER.to_owned());
+        column.push(last_card_border.to_owned());
 
         columns_per_color.insert(*color, column);
     }
@@ -158,9 +487,9 @@ fn draw_op_plays(op_plays: &HashMap<CardColor, Vec<CardValue>>) -> Vec<String> {
     // Horribly inefficient, but it's FE code for a FE I don't plan to support for very long.
     let mut rows: Vec<String> = Vec::with_capacity(num_rows);
     for i in 0..num_rows {
-        let mut cells_in_row: Vec<&str> = Vec::with_capacity(COLOR_ORDER.len());
+        let mut cells_in_row: Vec<&str> = Vec::with_capacity(colors.len());
 
-        for color in COLOR_ORDER.iter() {
+        for color in colors.iter() {
             if let Some(column) = columns_per_color.get(color) {
                 if let Some(cell) = column.get(i) {
                     cells_in_row.push(cell)
@@ -178,14 +507,26 @@ fn draw_op_plays(op_plays: &HashMap<CardColor, Vec<CardValue>>) -> Vec<String> {
     rows
 }
 
-fn draw_neutral_board(neutral_draw_pile: &HashMap<CardColor, (CardValue, usize)>) -> (String, String, String) {
-    let mut neutral_value_line = String::with_capacity(BOARD_ROW_SIZE);
-    let mut neutral_color_line = String::with_capacity(BOARD_ROW_SIZE);
-    let mut neutral_draw_deck_size_line = String::with_capacity(BOARD_ROW_SIZE);
+fn draw_neutral_card_border(colors: &[CardColor], highlighted_color: Option<CardColor>) -> String {
+    let cells: String = colors.iter()
+        .map(|color| if highlighted_color == Some(*color) {
+            BOARD_NEUTRAL_CARD_HIGHLIGHT_CELL
+        } else {
+            BOARD_NEUTRAL_CARD_BORDER_CELL
+        })
+        .collect();
+
+    format!("|{}", cells)
+}
+
+fn draw_neutral_board(colors: &[CardColor], neutral_draw_pile: &HashMap<CardColor, (CardValue, usize)>) -> (String, String, String) {
+    let mut neutral_value_line = String::with_capacity(board_width(colors));
+    let mut neutral_color_line = String::with_capacity(board_width(colors));
+    let mut neutral_draw_deck_size_line = String::with_capacity(board_width(colors));
     neutral_value_line.push('|');
     neutral_color_line.push('|');
     neutral_draw_deck_size_line.push('|');
-    for color in COLOR_ORDER.iter() {
+    for color in colors.iter() {
         match neutral_draw_pile.get(color) {
             Some((value, size)) => {
                 neutral_value_line.push_str(&format!("  | {:^3} |  |", value.to_string_short()));
@@ -207,16 +548,22 @@ fn draw_neutral_board(neutral_draw_pile: &HashMap<CardColor, (CardValue, usize)>
     )
 }
 
-fn draw_my_plays(my_plays: &HashMap<CardColor, Vec<CardValue>>) -> Vec<String> {
+fn draw_my_plays(colors: &[CardColor], my_plays: &HashMap<CardColor, Vec<CardValue>>, highlighted_color: Option<CardColor>) -> Vec<String> {
     let mut columns_per_color: HashMap<CardColor, Vec<String>> = HashMap::new();
     for (color, card_value_vec) in my_plays.iter() {
+        // The last card played on the expedition is the one on the bottom of the column.
+        let last_card_border = if highlighted_color == Some(*color) {
+            BOARD_PLAY_CARD_HIGHLIGHT
+        } else {
+            BOARD_PLAY_CARD_BORDER
+        };
         let mut column: Vec<String> = Vec::new();
-        for card_val in card_value_vec.iter() {
-            column.push(BOARD_PLAY_CARD_BORDER.to_owned());
+        for (i, card_val) in card_value_vec.iter().enumerate() {
+            column.push(if i + 1 == card_value_vec.len() { last_card_border } else { BOARD_PLAY_CARD_BORDER }.to_owned());
             column.push(format!("  | {:^3} |  ", card_val.to_string_short()));
         }
         column.push(format!("  | {:^3} |  ", color.to_string_short()));
-        column.push(BOARD_PLAY_CARD_BORDER.to_owned());
+        column.push(last_card_border.to_owned());
 
         columns_per_color.insert(*color, column);
     }
@@ -231,9 +578,9 @@ fn draw_my_plays(my_plays: &HashMap<CardColor, Vec<CardValue>>) -> Vec<String> {
     // Horribly inefficient, but it's FE code for a FE I don't plan to support for very long.
     let mut rows: Vec<String> = Vec::with_capacity(num_rows);
     for i in 0..num_rows {
-        let mut cells_in_row: Vec<&str> = Vec::with_capacity(COLOR_ORDER.len());
+        let mut cells_in_row: Vec<&str> = Vec::with_capacity(colors.len());
 
-        for color in COLOR_ORDER.iter() {
+        for color in colors.iter() {
             if let Some(column) = columns_per_color.get(color) {
                 if let Some(cell) = column.get(i) {
                     cells_in_row.push(cell)
@@ -286,6 +633,30 @@ impl Display for DrawPile {
     }
 }
 
+impl GameState {
+    /// The unseen cards, one line per color, e.g. "  Red     wgr 2 5 9".
+    pub fn draw_unseen_cards(&self) -> String {
+        let colors = self.rule_set().colors();
+        let mut lines = Vec::with_capacity(colors.len() + 1);
+        lines.push(format!("Unseen cards ({}):", self.unseen_cards().len()));
+
+        for color in colors.iter() {
+            let values: Vec<&str> = self.unseen_cards().iter()
+                .filter(|card| card.card_color() == color)
+                .map(|card| card.card_value().to_string_short())
+                .collect();
+            let values = if values.is_empty() {
+                "-".to_owned()
+            } else {
+                values.join(" ")
+            };
+            lines.push(format!("  {:<8}{}", color.to_string_long(), values));
+        }
+
+        lines.join("\n")
+    }
+}
+
 impl Card {
     pub fn draw_single(&self) -> String {
         format!(
@@ -306,6 +677,7 @@ impl CardColor {
             CardColor::White => "White",
             CardColor::Blue => "Blue",
             CardColor::Yellow => "Yellow",
+            CardColor::Purple => "Purple",
         }
     }
 
@@ -316,6 +688,7 @@ impl CardColor {
             CardColor::White => "Wht",
             CardColor::Blue => "Blu",
             CardColor::Yellow => "Ylw",
+            CardColor::Purple => "Prp",
         }
     }
 }
@@ -356,7 +729,15 @@ impl CardValue {
 mod tests {
     use super::*;
     use std::collections::HashMap;
-    use crate::types::GameStatus;
+    use crate::types::{GameBoard, GameStatus, OpponentBoard};
+
+    #[test]
+    fn duration_formatting() {
+        assert_eq!(draw_duration_ms(0), "0:00");
+        assert_eq!(draw_duration_ms(999), "0:00");
+        assert_eq!(draw_duration_ms(75_500), "1:15");
+        assert_eq!(draw_duration_ms(600_000), "10:00");
+    }
 
     #[test]
     fn eyeball_stdout_test() {
@@ -426,7 +807,11 @@ mod tests {
         let game_state = GameState::new(
             game_board,
             my_hand,
-            GameStatus::InProgress(true)
+            GameStatus::InProgress(true),
+            Some(GameClock::new(TimeControl::TimeBank(600), 312_000, 45_500)),
+            Some(DrawOffer::OfferedByOpponent),
+            None,
+            vec![],
         );
 
         println!();
@@ -434,4 +819,131 @@ mod tests {
 
         assert_eq!(1, 2);
     }
+
+    #[test]
+    fn spectators_see_every_seat_and_whose_turn_it_is() {
+        let seat = |player_id: &str| SpectatorSeat::new(player_id.to_owned(), HashMap::new(), 0, HashMap::new());
+        let seats = vec![seat("host"), seat("guest 1"), seat("guest 2")];
+
+        let game_state = SpectatorGameState::new(seats, HashMap::new(), 30, SpectatorStatus::InProgress(2));
+        let drawn = game_state.to_string();
+        for label in ["host's total score", "guest 1's total score", "guest 2's total score"].iter() {
+            assert!(drawn.contains(label), "Missing '{}' in:\n{}", label, drawn);
+        }
+        assert!(drawn.ends_with("-- guest 2's turn --"));
+
+        let seats = vec![seat("host"), seat("guest 1"), seat("guest 2")];
+        let game_state = SpectatorGameState::new(seats, HashMap::new(), 0, SpectatorStatus::Complete(vec![0, 2], EndReason::DeckExhausted));
+        assert!(game_state.to_string().ends_with("It was a tie between 'host' and 'guest 2'!"));
+
+        let seats = vec![seat("host"), seat("guest 1"), seat("guest 2")];
+        let game_state = SpectatorGameState::new(seats, HashMap::new(), 12, SpectatorStatus::Complete(vec![0, 2], EndReason::Timeout));
+        assert!(game_state.to_string().ends_with("'guest 1' ran out of time!"));
+
+        let seats = vec![seat("host"), seat("guest 1"), seat("guest 2")];
+        let game_state = SpectatorGameState::new(seats, HashMap::new(), 12, SpectatorStatus::Complete(vec![1, 2], EndReason::Resignation));
+        assert!(game_state.to_string().ends_with("'host' resigned!"));
+    }
+
+    #[test]
+    fn unseen_cards_grouped_by_color() {
+        let game_board = GameBoard::new(HashMap::new(), HashMap::new(), 0, 0, HashMap::new(), HashMap::new(), HashMap::new(), 2);
+        let game_state = GameState::new(
+            game_board,
+            vec![],
+            GameStatus::InProgress(true),
+            None,
+            None,
+            None,
+            vec![
+                Card::new(CardColor::Red, CardValue::Wager),
+                Card::new(CardColor::Red, CardValue::Nine),
+                Card::new(CardColor::Yellow, CardValue::Two),
+            ],
+        );
+
+        assert_eq!(game_state.draw_unseen_cards(), [
+            "Unseen cards (3):",
+            "  Red     wgr 9",
+            "  Green   -",
+            "  White   -",
+            "  Blue    -",
+            "  Yellow  2",
+        ].join("\n"));
+    }
+
+    #[test]
+    fn board_is_one_column_per_color() {
+        let five_colors = RuleSet::standard();
+        let board_lines = BoardLines::new(five_colors.colors());
+        assert_eq!(board_lines.grid_line, "+-----------+-----------+-----------+-----------+-----------+");
+        assert_eq!(board_lines.neutral_header, "|    Red    |   Green   |   White   |   Blue    |  Yellow   |");
+
+        let six_colors = RuleSet::new(8, 20, 1, 8, 20, false, true);
+        let mut my_plays = HashMap::new();
+        my_plays.insert(CardColor::Purple, vec![CardValue::Wager, CardValue::Seven]);
+        let game_board = GameBoard::new(my_plays, HashMap::new(), 0, 0, HashMap::new(), HashMap::new(), HashMap::new(), 60);
+        let game_state = GameState::new(game_board, vec![], GameStatus::InProgress(true), None, None, None, vec![])
+            .with_rule_set(six_colors);
+        let drawn = game_state.to_string();
+
+        let board_rows: Vec<&str> = drawn.lines()
+            .filter(|line| line.starts_with('|') || line.starts_with('+'))
+            .collect();
+        assert!(board_rows.iter().all(|row| row.len() == 73), "{}", drawn);
+        assert!(drawn.contains("  Purple   |"));
+        assert!(drawn.contains("| Prp |"));
+    }
+
+    #[test]
+    fn board_stacks_every_opponent() {
+        let game_board = GameBoard::new(HashMap::new(), HashMap::new(), 5, 7, HashMap::new(), HashMap::new(), HashMap::new(), 30)
+            .with_more_opponents(vec![OpponentBoard::new(HashMap::new(), -9, HashMap::new())]);
+        let drawn = GameState::new(game_board, vec![], GameStatus::InProgress(true), None, None, None, vec![]).to_string();
+
+        let scores: Vec<&str> = drawn.lines()
+            .filter(|line| line.contains("total score"))
+            .map(|line| line.trim_matches(|c| c == '|' || c == ' '))
+            .collect();
+        assert_eq!(scores, vec![
+            "Opponent 1's total score: 7",
+            "Opponent 2's total score: -9",
+            "Your total score: 5",
+        ]);
+    }
+
+    #[test]
+    fn last_move_is_highlighted() {
+        let red_5 = Card::new(CardColor::Red, CardValue::try_from(5).unwrap());
+        let blue_7 = Card::new(CardColor::Blue, CardValue::try_from(7).unwrap());
+        let mut op_plays = HashMap::new();
+        op_plays.insert(CardColor::Red, vec![CardValue::try_from(3).unwrap(), CardValue::try_from(5).unwrap()]);
+        let game_board = GameBoard::new(HashMap::new(), op_plays, 0, 0, HashMap::new(), HashMap::new(), HashMap::new(), 30);
+        let last_move = LastMove::new(Some(0), red_5, CardTarget::Player, LastDraw::DiscardPile(blue_7));
+
+        let drawn = GameState::new(game_board, vec![], GameStatus::InProgress(true), None, None, None, vec![])
+            .with_last_move(last_move)
+            .to_string();
+        // Only the top of the 5 and the bottom of the column.
+        assert_eq!(drawn.matches("+=====+").count(), 2);
+        assert!(drawn.contains(&draw_neutral_card_border(RuleSet::standard().colors(), None)));
+        assert!(drawn.contains("Last move: Opponent played the Red 5 on their expedition, then took the Blue 7 from its discard pile."));
+    }
+
+    #[test]
+    fn last_discard_is_highlighted() {
+        let red_5 = Card::new(CardColor::Red, CardValue::try_from(5).unwrap());
+        let mut neutral_draw_pile = HashMap::new();
+        neutral_draw_pile.insert(CardColor::Red, (CardValue::try_from(5).unwrap(), 1));
+        let game_board = GameBoard::new(HashMap::new(), HashMap::new(), 0, 0, HashMap::new(), HashMap::new(), neutral_draw_pile, 30);
+        let last_move = LastMove::new(None, red_5, CardTarget::Neutral, LastDraw::MainPile);
+
+        let drawn = GameState::new(game_board, vec![], GameStatus::InProgress(true), None, None, None, vec![])
+            .with_last_move(last_move)
+            .to_string();
+        // Above and below the top of the red discard pile.
+        assert_eq!(drawn.matches("+=====+").count(), 2);
+        assert!(drawn.contains(&draw_neutral_card_border(RuleSet::standard().colors(), Some(CardColor::Red))));
+        assert!(drawn.contains("Last move: You discarded the Red 5, then drew from the main pile."));
+    }
 }
@@ -1,5 +1,6 @@
 pub mod api;
 pub mod types;
+pub mod wire_protocol;
 
 mod display;
 mod rand_utils;
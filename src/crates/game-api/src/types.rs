@@ -5,9 +5,11 @@ use std::convert::TryFrom;
 #[derive(Debug)]
 pub struct GameMetadata {
     game_id: String,
+    game_type: GameType,
     host_player_id: String,
     creation_time_ms: u64,
     matched_data: Option<(String, GameStatus)>,
+    rule_set: RuleSet,
 }
 
 impl GameMetadata {
@@ -20,9 +22,11 @@ impl GameMetadata {
     ) -> Self {
         GameMetadata {
             game_id,
+            game_type: GameType::LostCities,
             host_player_id,
             creation_time_ms,
             matched_data: Some((guest_player_id, status)),
+            rule_set: RuleSet::standard(),
         }
     }
 
@@ -33,16 +37,33 @@ impl GameMetadata {
     ) -> Self {
         GameMetadata {
             game_id,
+            game_type: GameType::LostCities,
             host_player_id,
             creation_time_ms,
             matched_data: None,
+            rule_set: RuleSet::standard(),
         }
     }
 
+    /// For games not played by the standard rules.
+    pub fn with_rule_set(mut self, rule_set: RuleSet) -> Self {
+        self.rule_set = rule_set;
+        self
+    }
+
+    pub fn with_game_type(mut self, game_type: GameType) -> Self {
+        self.game_type = game_type;
+        self
+    }
+
     pub fn game_id(&self) -> &str {
         &self.game_id
     }
 
+    pub fn game_type(&self) -> &GameType {
+        &self.game_type
+    }
+
     pub fn host_player_id(&self) -> &str {
         &self.host_player_id
     }
@@ -54,16 +75,33 @@ impl GameMetadata {
     pub fn matched_data(&self) -> &Option<(String, GameStatus)> {
         &self.matched_data
     }
+
+    pub fn rule_set(&self) -> &RuleSet {
+        &self.rule_set
+    }
+}
+
+/// Which game is being played. Hosting, joining, and watching work the same for every game.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum GameType {
+    LostCities,
 }
 
 /// Everything within GameState's hierarchy is in reference to the requesting player.
 /// * "my" = the player's data
-/// * "op" = the opponent's data
+/// * "op" = the opponent's data. With more than two players, that's whoever plays after me.
 #[derive(Debug)]
 pub struct GameState {
     game_board: GameBoard,
     my_hand: Vec<DecoratedCard>,
     status: GameStatus,
+    clock: Option<GameClock>,
+    draw_offer: Option<DrawOffer>,
+    take_back_request: Option<TakeBackRequest>,
+    unseen_cards: Vec<Card>,
+    rule_set: RuleSet,
+    last_move: Option<LastMove>,
+    summary: Option<GameSummary>,
 }
 
 impl GameState {
@@ -71,14 +109,43 @@ impl GameState {
         game_board: GameBoard,
         my_hand: Vec<DecoratedCard>,
         status: GameStatus,
+        clock: Option<GameClock>,
+        draw_offer: Option<DrawOffer>,
+        take_back_request: Option<TakeBackRequest>,
+        unseen_cards: Vec<Card>,
     ) -> Self {
         GameState {
             game_board,
             my_hand,
             status,
+            clock,
+            draw_offer,
+            take_back_request,
+            unseen_cards,
+            rule_set: RuleSet::standard(),
+            last_move: None,
+            summary: None,
         }
     }
 
+    /// For games not played by the standard rules.
+    pub fn with_rule_set(mut self, rule_set: RuleSet) -> Self {
+        self.rule_set = rule_set;
+        self
+    }
+
+    /// Once the first turn has been taken.
+    pub fn with_last_move(mut self, last_move: LastMove) -> Self {
+        self.last_move = Some(last_move);
+        self
+    }
+
+    /// Once the game is over.
+    pub fn with_summary(mut self, summary: GameSummary) -> Self {
+        self.summary = Some(summary);
+        self
+    }
+
     pub fn game_board(&self) -> &GameBoard {
         &self.game_board
     }
@@ -90,16 +157,456 @@ impl GameState {
     pub fn status(&self) -> &GameStatus {
         &self.status
     }
+
+    /// None if the game isn't timed.
+    pub fn clock(&self) -> &Option<GameClock> {
+        &self.clock
+    }
+
+    /// None if nobody has a draw offer on the table.
+    pub fn draw_offer(&self) -> &Option<DrawOffer> {
+        &self.draw_offer
+    }
+
+    /// None if nobody is asking to take back their last move.
+    pub fn take_back_request(&self) -> &Option<TakeBackRequest> {
+        &self.take_back_request
+    }
+
+    /// Cards that are in the main draw pile or the opponent's hand, as far as I can tell, sorted by
+    /// color then value. Buried discards don't count: everyone saw them go down.
+    pub fn unseen_cards(&self) -> &Vec<Card> {
+        &self.unseen_cards
+    }
+
+    pub fn rule_set(&self) -> &RuleSet {
+        &self.rule_set
+    }
+
+    /// None before the first turn.
+    pub fn last_move(&self) -> &Option<LastMove> {
+        &self.last_move
+    }
+
+    /// None until the game is over.
+    pub fn summary(&self) -> &Option<GameSummary> {
+        &self.summary
+    }
+}
+
+/// The most recent turn, so the cards that moved can be highlighted on the board.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct LastMove {
+    opponent_index: Option<usize>,
+    card: Card,
+    target: CardTarget,
+    draw: LastDraw,
+}
+
+impl LastMove {
+    pub fn new(opponent_index: Option<usize>, card: Card, target: CardTarget, draw: LastDraw) -> Self {
+        LastMove {
+            opponent_index,
+            card,
+            target,
+            draw,
+        }
+    }
+
+    /// None if it was my move. Otherwise, whose it was, as an index into `GameBoard::opponents()`.
+    pub fn opponent_index(&self) -> &Option<usize> {
+        &self.opponent_index
+    }
+
+    pub fn card(&self) -> &Card {
+        &self.card
+    }
+
+    pub fn target(&self) -> &CardTarget {
+        &self.target
+    }
+
+    pub fn draw(&self) -> &LastDraw {
+        &self.draw
+    }
+}
+
+/// Where the last move's card came from.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum LastDraw {
+    /// Only the player who drew it knows which card it was.
+    MainPile,
+    /// Everyone saw this card come off the top of its discard pile.
+    DiscardPile(Card),
+    /// It was a final turn, so nothing was drawn.
+    Nothing,
+}
+
+/// How a finished game played out, with every hand turned face up.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GameSummary {
+    players: Vec<PlayerSummary>,
+    num_turns: usize,
+    duration_ms: Option<u64>,
+    end_reason: EndReason,
+}
+
+impl GameSummary {
+    pub fn new(players: Vec<PlayerSummary>, num_turns: usize, duration_ms: Option<u64>, end_reason: EndReason) -> Self {
+        GameSummary {
+            players,
+            num_turns,
+            duration_ms,
+            end_reason,
+        }
+    }
+
+    /// Me first, then the opponents in the same order as `GameBoard::opponents()`.
+    pub fn players(&self) -> &Vec<PlayerSummary> {
+        &self.players
+    }
+
+    /// Turns actually taken. Taken back moves don't count.
+    pub fn num_turns(&self) -> &usize {
+        &self.num_turns
+    }
+
+    /// From the deal to the end of the game. None if the server didn't keep track.
+    pub fn duration_ms(&self) -> &Option<u64> {
+        &self.duration_ms
+    }
+
+    pub fn end_reason(&self) -> &EndReason {
+        &self.end_reason
+    }
+}
+
+/// One player's side of a finished game.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlayerSummary {
+    final_hand: Vec<Card>,
+    score_total: i32,
+    score_per_color: HashMap<CardColor, i32>,
+}
+
+impl PlayerSummary {
+    pub fn new(final_hand: Vec<Card>, score_total: i32, score_per_color: HashMap<CardColor, i32>) -> Self {
+        PlayerSummary {
+            final_hand,
+            score_total,
+            score_per_color,
+        }
+    }
+
+    /// Whatever was still in hand when the game ended, sorted.
+    pub fn final_hand(&self) -> &Vec<Card> {
+        &self.final_hand
+    }
+
+    pub fn score_total(&self) -> &i32 {
+        &self.score_total
+    }
+
+    pub fn score_per_color(&self) -> &HashMap<CardColor, i32> {
+        &self.score_per_color
+    }
+}
+
+/// A pending request to undo the most recent move. Only the player who made the move can ask,
+/// and the request is dropped if the opponent plays a card instead of responding.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum TakeBackRequest {
+    RequestedByMe,
+    RequestedByOpponent,
+}
+
+/// A pending offer to end the game as a draw. It stands until the other player responds to it,
+/// or plays a card instead, which declines it.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum DrawOffer {
+    OfferedByMe,
+    OfferedByOpponent,
+}
+
+/// Time control is picked by the host. Running out of time forfeits the game.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum TimeControl {
+    /// Each move must be made within this many seconds.
+    PerMove(/* seconds */ u32),
+    /// Each player gets this many seconds for the whole game.
+    TimeBank(/* seconds */ u32),
+}
+
+/// Who the host wants to play against.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Opponent {
+    /// Whoever joins the game.
+    Human,
+    /// The computer takes the guest's seat right away and plays its turns on the server.
+    Computer(Difficulty),
+}
+
+/// The house rules a game is played by, picked by the host. `RuleSet::standard()` is the game as
+/// printed on the box.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct RuleSet {
+    hand_size: u32,
+    expedition_cost: u32,
+    multiplier_per_wager: u32,
+    bonus_threshold: u32,
+    bonus_points: u32,
+    final_turn: bool,
+    purple: bool,
+    num_players: u32,
+}
+
+impl RuleSet {
+    pub fn new(
+        hand_size: u32,
+        expedition_cost: u32,
+        multiplier_per_wager: u32,
+        bonus_threshold: u32,
+        bonus_points: u32,
+        final_turn: bool,
+        purple: bool,
+    ) -> Self {
+        RuleSet {
+            hand_size,
+            expedition_cost,
+            multiplier_per_wager,
+            bonus_threshold,
+            bonus_points,
+            final_turn,
+            purple,
+            num_players: 2,
+        }
+    }
+
+    pub fn standard() -> Self {
+        RuleSet::new(8, 20, 1, 8, 20, false, false)
+    }
+
+    /// For three and four player games. Every player gets their own expeditions, and everyone
+    /// shares the discard piles.
+    pub fn with_num_players(mut self, num_players: u32) -> Self {
+        self.num_players = num_players;
+        self
+    }
+
+    /// Cards dealt to each player, and held for the rest of the game.
+    pub fn hand_size(&self) -> &u32 {
+        &self.hand_size
+    }
+
+    /// Points an expedition must make back before it scores anything.
+    pub fn expedition_cost(&self) -> &u32 {
+        &self.expedition_cost
+    }
+
+    /// How much each wager adds to an expedition's multiplier, which starts at 1.
+    pub fn multiplier_per_wager(&self) -> &u32 {
+        &self.multiplier_per_wager
+    }
+
+    /// Expeditions with at least this many cards, wagers included, earn the bonus.
+    pub fn bonus_threshold(&self) -> &u32 {
+        &self.bonus_threshold
+    }
+
+    pub fn bonus_points(&self) -> &u32 {
+        &self.bonus_points
+    }
+
+    /// If set, drawing the last card doesn't end the game right away. Every other player gets one
+    /// final turn, in which they play or discard a card without drawing.
+    pub fn final_turn(&self) -> &bool {
+        &self.final_turn
+    }
+
+    /// If set, the game is played with the sixth (purple) expedition from the newer edition.
+    pub fn purple(&self) -> &bool {
+        &self.purple
+    }
+
+    /// Seats at the table. The game starts once they're all taken.
+    pub fn num_players(&self) -> &u32 {
+        &self.num_players
+    }
+
+    /// The expeditions in play, in the order they're laid out on the board.
+    pub fn colors(&self) -> &'static [CardColor] {
+        if self.purple {
+            &CARD_COLORS
+        } else {
+            &CARD_COLORS[..CARD_COLORS.len() - 1]
+        }
+    }
+}
+
+/// How hard the computer tries.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Difficulty {
+    /// Follows a few rules of thumb. Moves instantly.
+    Easy,
+    /// Thinks a few hundred games ahead.
+    Medium,
+    /// Thinks as far ahead as it can in a second or so.
+    Hard,
+}
+
+/// The clocks of a timed game, at the time the game state was loaded. The clock of whoever's
+/// turn it is keeps ticking after that, so the client should count down from here.
+#[derive(Debug)]
+pub struct GameClock {
+    time_control: TimeControl,
+    my_remaining_ms: u64,
+    op_remaining_ms: u64,
+}
+
+impl GameClock {
+    pub fn new(
+        time_control: TimeControl,
+        my_remaining_ms: u64,
+        op_remaining_ms: u64,
+    ) -> Self {
+        GameClock {
+            time_control,
+            my_remaining_ms,
+            op_remaining_ms,
+        }
+    }
+
+    pub fn time_control(&self) -> &TimeControl {
+        &self.time_control
+    }
+
+    pub fn my_remaining_ms(&self) -> &u64 {
+        &self.my_remaining_ms
+    }
+
+    /// With more than two players, this is whoever's turn it is, or whoever's next if it's mine.
+    pub fn op_remaining_ms(&self) -> &u64 {
+        &self.op_remaining_ms
+    }
+}
+
+/// The state of the game as observed by someone who isn't playing in it. Every hand is hidden.
+///
+/// Nobody is "my" or "op" here. Players are referred to by seat, which is their index in `seats`:
+/// the host is seat 0, and the rest follow in turn order.
+#[derive(Debug)]
+pub struct SpectatorGameState {
+    seats: Vec<SpectatorSeat>,
+    neutral_draw_pile: HashMap<CardColor, (CardValue, usize)>,
+    draw_pile_cards_remaining: usize,
+    status: SpectatorStatus,
+    rule_set: RuleSet,
+}
+
+impl SpectatorGameState {
+    pub fn new(
+        seats: Vec<SpectatorSeat>,
+        neutral_draw_pile: HashMap<CardColor, (CardValue, usize)>,
+        draw_pile_cards_remaining: usize,
+        status: SpectatorStatus,
+    ) -> Self {
+        SpectatorGameState {
+            seats,
+            neutral_draw_pile,
+            draw_pile_cards_remaining,
+            status,
+            rule_set: RuleSet::standard(),
+        }
+    }
+
+    /// For games not played by the standard rules.
+    pub fn with_rule_set(mut self, rule_set: RuleSet) -> Self {
+        self.rule_set = rule_set;
+        self
+    }
+
+    pub fn seats(&self) -> &Vec<SpectatorSeat> {
+        &self.seats
+    }
+
+    pub fn neutral_draw_pile(&self) -> &HashMap<CardColor, (CardValue, usize)> {
+        &self.neutral_draw_pile
+    }
+
+    pub fn draw_pile_cards_remaining(&self) -> &usize {
+        &self.draw_pile_cards_remaining
+    }
+
+    pub fn status(&self) -> &SpectatorStatus {
+        &self.status
+    }
+
+    pub fn rule_set(&self) -> &RuleSet {
+        &self.rule_set
+    }
+}
+
+/// One player's side of the table, as a spectator sees it.
+#[derive(Debug)]
+pub struct SpectatorSeat {
+    player_id: String,
+    plays: HashMap<CardColor, Vec<CardValue>>,
+    score_total: i32,
+    score_per_color: HashMap<CardColor, i32>,
+}
+
+impl SpectatorSeat {
+    pub fn new(
+        player_id: String,
+        plays: HashMap<CardColor, Vec<CardValue>>,
+        score_total: i32,
+        score_per_color: HashMap<CardColor, i32>,
+    ) -> Self {
+        SpectatorSeat {
+            player_id,
+            plays,
+            score_total,
+            score_per_color,
+        }
+    }
+
+    pub fn player_id(&self) -> &str {
+        &self.player_id
+    }
+
+    pub fn plays(&self) -> &HashMap<CardColor, Vec<CardValue>> {
+        &self.plays
+    }
+
+    pub fn score_total(&self) -> &i32 {
+        &self.score_total
+    }
+
+    pub fn score_per_color(&self) -> &HashMap<CardColor, i32> {
+        &self.score_per_color
+    }
 }
 
+/// Like `GameStatus`, but by seat instead of from one player's point of view.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum SpectatorStatus {
+    InProgress(/* Seat whose turn it is */ usize),
+    /// More than one winning seat means they tied. If the game ended by resignation or timeout,
+    /// it was the seat that isn't winning, and everyone else shares the win.
+    Complete(/* Winning seats */ Vec<usize>, EndReason),
+}
+
+/// Everything on the table: every player's expeditions, the shared discard piles, and how many
+/// cards are left to draw.
+///
+/// The opponents are in turn order, starting with whoever plays after me. In a two player game,
+/// that's the only one, and the "op" accessors are short hand for them.
 #[derive(Debug)]
 pub struct GameBoard {
     my_plays: HashMap<CardColor, Vec<CardValue>>,
-    op_plays: HashMap<CardColor, Vec<CardValue>>,
     my_score_total: i32,
-    op_score_total: i32,
     my_score_per_color: HashMap<CardColor, i32>,
-    op_score_per_color: HashMap<CardColor, i32>,
+    opponents: Vec<OpponentBoard>,
     neutral_draw_pile: HashMap<CardColor, (CardValue, usize)>,
     draw_pile_cards_remaining: usize,
 }
@@ -117,22 +624,26 @@ impl GameBoard {
     ) -> Self {
         GameBoard {
             my_plays,
-            op_plays,
             my_score_total,
-            op_score_total,
             my_score_per_color,
-            op_score_per_color,
+            opponents: vec![OpponentBoard::new(op_plays, op_score_total, op_score_per_color)],
             neutral_draw_pile,
             draw_pile_cards_remaining
         }
     }
 
+    /// For games with more than two players. These come after the next opponent in turn order.
+    pub fn with_more_opponents(mut self, mut more_opponents: Vec<OpponentBoard>) -> Self {
+        self.opponents.append(&mut more_opponents);
+        self
+    }
+
     pub fn my_plays(&self) -> &HashMap<CardColor, Vec<CardValue>> {
         &self.my_plays
     }
 
     pub fn op_plays(&self) -> &HashMap<CardColor, Vec<CardValue>> {
-        &self.op_plays
+        self.opponents[0].plays()
     }
 
     pub fn my_score_total(&self) -> &i32 {
@@ -140,7 +651,7 @@ impl GameBoard {
     }
 
     pub fn op_score_total(&self) -> &i32 {
-        &self.op_score_total
+        self.opponents[0].score_total()
     }
 
     pub fn my_score_per_color(&self) -> &HashMap<CardColor, i32> {
@@ -148,7 +659,12 @@ impl GameBoard {
     }
 
     pub fn op_score_per_color(&self) -> &HashMap<CardColor, i32> {
-        &self.op_score_per_color
+        self.opponents[0].score_per_color()
+    }
+
+    /// Every opponent, in turn order. Never empty.
+    pub fn opponents(&self) -> &Vec<OpponentBoard> {
+        &self.opponents
     }
 
     pub fn neutral_draw_pile(&self) -> &HashMap<CardColor, (CardValue, usize)> {
@@ -160,17 +676,182 @@ impl GameBoard {
     }
 }
 
+/// One opponent's side of the table.
+#[derive(Debug)]
+pub struct OpponentBoard {
+    plays: HashMap<CardColor, Vec<CardValue>>,
+    score_total: i32,
+    score_per_color: HashMap<CardColor, i32>,
+}
+
+impl OpponentBoard {
+    pub fn new(
+        plays: HashMap<CardColor, Vec<CardValue>>,
+        score_total: i32,
+        score_per_color: HashMap<CardColor, i32>,
+    ) -> Self {
+        OpponentBoard {
+            plays,
+            score_total,
+            score_per_color,
+        }
+    }
+
+    pub fn plays(&self) -> &HashMap<CardColor, Vec<CardValue>> {
+        &self.plays
+    }
+
+    pub fn score_total(&self) -> &i32 {
+        &self.score_total
+    }
+
+    pub fn score_per_color(&self) -> &HashMap<CardColor, i32> {
+        &self.score_per_color
+    }
+}
+
+/// If the host doesn't ask for a specific number of rounds, play the same number as the
+/// official rules.
+pub const DEFAULT_MATCH_ROUNDS: u32 = 3;
+
+/// A match is a series of games (rounds) between the same two players. The scores from each
+/// round are summed, and whoever has the highest cumulative score wins the match.
+///
+/// Like GameState, everything is in reference to the requesting player.
+#[derive(Debug)]
+pub struct MatchState {
+    op_player_id: Option<String>,
+    num_rounds: u32,
+    rounds: Vec<MatchRound>,
+    my_score_total: i32,
+    op_score_total: i32,
+    status: MatchStatus,
+}
+
+impl MatchState {
+    pub fn new(
+        op_player_id: Option<String>,
+        num_rounds: u32,
+        rounds: Vec<MatchRound>,
+        my_score_total: i32,
+        op_score_total: i32,
+        status: MatchStatus,
+    ) -> Self {
+        MatchState {
+            op_player_id,
+            num_rounds,
+            rounds,
+            my_score_total,
+            op_score_total,
+            status,
+        }
+    }
+
+    pub fn op_player_id(&self) -> &Option<String> {
+        &self.op_player_id
+    }
+
+    pub fn num_rounds(&self) -> &u32 {
+        &self.num_rounds
+    }
+
+    /// Only the rounds that have been dealt so far, in the order they were played.
+    pub fn rounds(&self) -> &Vec<MatchRound> {
+        &self.rounds
+    }
+
+    pub fn my_score_total(&self) -> &i32 {
+        &self.my_score_total
+    }
+
+    pub fn op_score_total(&self) -> &i32 {
+        &self.op_score_total
+    }
+
+    pub fn status(&self) -> &MatchStatus {
+        &self.status
+    }
+
+    /// The round currently being played, if there is one.
+    pub fn current_round(&self) -> Option<&MatchRound> {
+        match self.status {
+            MatchStatus::InProgress => self.rounds.last(),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct MatchRound {
+    game_id: String,
+    status: GameStatus,
+    my_score: i32,
+    op_score: i32,
+}
+
+impl MatchRound {
+    pub fn new(
+        game_id: String,
+        status: GameStatus,
+        my_score: i32,
+        op_score: i32,
+    ) -> Self {
+        MatchRound {
+            game_id,
+            status,
+            my_score,
+            op_score,
+        }
+    }
+
+    pub fn game_id(&self) -> &str {
+        &self.game_id
+    }
+
+    pub fn status(&self) -> &GameStatus {
+        &self.status
+    }
+
+    pub fn my_score(&self) -> &i32 {
+        &self.my_score
+    }
+
+    pub fn op_score(&self) -> &i32 {
+        &self.op_score
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum MatchStatus {
+    Unmatched,
+    InProgress,
+    Complete(GameResult),
+}
+
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub enum GameStatus {
     InProgress(/* Is my turn */ bool),
-    Complete(GameResult),
+    Complete(GameResult, EndReason),
 }
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub enum GameResult {
     Win,
     Lose,
-    Draw
+    Draw,
+}
+
+/// How the game ended. The result alone can't tell a resignation from a normal finish.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum EndReason {
+    /// The last card of the main deck was drawn, and the game was scored.
+    DeckExhausted,
+    /// The loser ran out of time. Like resigning, that ends the game for everyone.
+    Timeout,
+    /// The loser resigned. With more than two players, everyone else wins.
+    Resignation,
+    /// Both players agreed to a draw.
+    DrawAgreed,
 }
 
 /// DecoratedCard is basically the API layer's representation of a "Card" and the
@@ -230,8 +911,20 @@ pub enum CardColor {
     White,
     Blue,
     Yellow,
+    /// Only in six color games. See `RuleSet::purple()`.
+    Purple,
 }
 
+/// Every color there is, in board order. Purple has to stay last, see `RuleSet::colors()`.
+const CARD_COLORS: [CardColor; 6] = [
+    CardColor::Red,
+    CardColor::Green,
+    CardColor::White,
+    CardColor::Blue,
+    CardColor::Yellow,
+    CardColor::Purple,
+];
+
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
 pub enum CardValue {
     // Force enum variants i32 repr to start from 1.
@@ -248,6 +941,7 @@ pub enum CardValue {
 }
 
 /// IMPORTANT: Wire model uses this, so client and server compatibility are dependent on this not changing.
+/// If it ever has to, bump `wire_protocol::PROTOCOL_VERSION`.
 impl TryFrom<u32> for CardValue {
     type Error = String;
 
@@ -329,12 +1023,118 @@ pub enum CardTarget {
 }
 
 /// Where to draw the new card from.
-#[derive(PartialEq, Eq, Debug)]
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
 pub enum DrawPile {
     Main,
     Neutral(CardColor),
 }
 
+/// The computer's opinion of the moves in a game, from one player's point of view.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GameAnalysis {
+    move_hints: Vec<MoveEvaluation>,
+    mistakes: Vec<Mistake>,
+}
+
+impl GameAnalysis {
+    pub fn new(
+        move_hints: Vec<MoveEvaluation>,
+        mistakes: Vec<Mistake>,
+    ) -> Self {
+        GameAnalysis {
+            move_hints,
+            mistakes,
+        }
+    }
+
+    /// Every legal move right now, best first. Empty unless it's my turn.
+    pub fn move_hints(&self) -> &Vec<MoveEvaluation> {
+        &self.move_hints
+    }
+
+    /// My moves that were much worse than the best one, in the order they were played. Empty
+    /// until the game is over.
+    pub fn mistakes(&self) -> &Vec<Mistake> {
+        &self.mistakes
+    }
+}
+
+/// A move, and how well the game is expected to turn out after making it.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct MoveEvaluation {
+    card: Card,
+    target: CardTarget,
+    draw_pile: DrawPile,
+    value: f64,
+}
+
+impl MoveEvaluation {
+    pub fn new(
+        card: Card,
+        target: CardTarget,
+        draw_pile: DrawPile,
+        value: f64,
+    ) -> Self {
+        MoveEvaluation {
+            card,
+            target,
+            draw_pile,
+            value,
+        }
+    }
+
+    pub fn card(&self) -> &Card {
+        &self.card
+    }
+
+    pub fn target(&self) -> &CardTarget {
+        &self.target
+    }
+
+    pub fn draw_pile(&self) -> &DrawPile {
+        &self.draw_pile
+    }
+
+    /// Estimated final score difference (mine minus my opponent's).
+    pub fn value(&self) -> &f64 {
+        &self.value
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Mistake {
+    turn: u32,
+    played: MoveEvaluation,
+    best: MoveEvaluation,
+}
+
+impl Mistake {
+    pub fn new(
+        turn: u32,
+        played: MoveEvaluation,
+        best: MoveEvaluation,
+    ) -> Self {
+        Mistake {
+            turn,
+            played,
+            best,
+        }
+    }
+
+    /// Counts both players' moves, starting from 1.
+    pub fn turn(&self) -> &u32 {
+        &self.turn
+    }
+
+    pub fn played(&self) -> &MoveEvaluation {
+        &self.played
+    }
+
+    pub fn best(&self) -> &MoveEvaluation {
+        &self.best
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,46 @@
+/// Which version of the wire protocol the client and server speak. Bump `PROTOCOL_VERSION` whenever
+/// the wire model changes in a way the other side can't safely ignore, e.g. renumbering a card
+/// value or changing what a field means. New fields and RPCs don't count: older peers skip what
+/// they don't know, so those are features instead.
+pub const PROTOCOL_VERSION: u32 = 1;
+
+/// Clients and servers from before protocol versions don't send one. They speak the same wire
+/// model as version 1, just without the handshake.
+pub const LEGACY_PROTOCOL_VERSION: u32 = 0;
+
+/// The server turns away clients older than this, because the wire model changed under them.
+/// Nothing has changed incompatibly yet, so even legacy clients get in.
+pub const MIN_CLIENT_PROTOCOL_VERSION: u32 = LEGACY_PROTOCOL_VERSION;
+
+/// The client sends its `PROTOCOL_VERSION` in this metadata header on every request, so the server
+/// can check it no matter which RPC comes first.
+pub const PROTOCOL_VERSION_HEADER: &str = "protocol-version";
+
+/// The client sends its `FEATURES`, comma separated, in this metadata header on every request, so
+/// the server knows which games it can show without keeping track of connections.
+pub const PROTOCOL_FEATURES_HEADER: &str = "protocol-features";
+
+const UPGRADE_INSTRUCTIONS: &str = "Please upgrade: git pull, then cargo build --release --bin lost-cities-game-client.";
+
+/// Six color games. Older clients can't decode the purple `ProtoColor`.
+pub const FEATURE_PURPLE: &str = "purple";
+
+/// Everything this build can show that older ones can't.
+pub const FEATURES: &[&str] = &[
+    FEATURE_PURPLE,
+];
+
+/// What the server tells a client it turns away.
+pub fn client_upgrade_message(client_version: u32, min_client_version: u32) -> String {
+    format!(
+        "This client speaks protocol version {}, but the server needs version {} or newer. {}",
+        client_version,
+        min_client_version,
+        UPGRADE_INSTRUCTIONS,
+    )
+}
+
+/// What the server tells a client when it refuses a game the client couldn't show.
+pub fn missing_feature_message(feature: &str) -> String {
+    format!("This client doesn't support the '{}' feature that game needs. {}", feature, UPGRADE_INSTRUCTIONS)
+}
@@ -1,4 +1,4 @@
-use game_api::types::{Card, CardColor, CardValue};
+use game_api::types::{Card, CardValue, RuleSet};
 use rand::thread_rng;
 use crate::rand_util::PrngRand;
 use rand_core::RngCore;
@@ -23,20 +23,19 @@ const CARD_VALUES: [CardValue; 12] = [
     CardValue::Wager,
 ];
 
-const CARD_COLORS: [CardColor; 5] = [
-    CardColor::Red,
-    CardColor::Green,
-    CardColor::White,
-    CardColor::Blue,
-    CardColor::Yellow,
-];
+pub const CARDS_PER_COLOR: usize = CARD_VALUES.len();
+
+/// One full set of cards for each color in play.
+pub fn deck_size(rule_set: &RuleSet) -> usize {
+    CARDS_PER_COLOR * rule_set.colors().len()
+}
 
 impl DeckFactory {
-    pub fn new() -> Self {
-        let mut unshuffled_deck = Vec::with_capacity(CARD_VALUES.len() * CARD_COLORS.len());
+    pub fn new(rule_set: &RuleSet) -> Self {
+        let mut unshuffled_deck = Vec::with_capacity(deck_size(rule_set));
 
         for value in CARD_VALUES.iter() {
-            for &color in CARD_COLORS.iter() {
+            for &color in rule_set.colors().iter() {
                 unshuffled_deck.push(Card::new(color, *value));
             }
         }
@@ -46,12 +45,18 @@ impl DeckFactory {
         }
     }
 
+    /// Every card in the game, in no particular order.
+    pub fn unshuffled_deck(&self) -> &Vec<Card> {
+        &self.unshuffled_deck
+    }
+
     pub fn new_shuffled_deck(&self) -> (Vec<Card>, u64) {
         let seed = thread_rng().next_u64();
         (self.new_shuffled_deck_with_seed(seed), seed)
     }
 
-    fn new_shuffled_deck_with_seed(&self, seed_for_random: u64) -> Vec<Card> {
+    /// Same seed, same deck. Handy for reproducing a game.
+    pub fn new_shuffled_deck_with_seed(&self, seed_for_random: u64) -> Vec<Card> {
         let mut deck = self.unshuffled_deck.clone();
         let prng = &mut PrngRand::new(seed_for_random);
         // Let's get wild
@@ -65,13 +70,28 @@ impl DeckFactory {
     }
 }
 
+/// Deal each player a hand off the top of the deck, one card at a time, going around the table
+/// starting with the first seat. None if the deck runs out first.
+pub fn deal(deck: &mut Vec<Card>, rule_set: &RuleSet) -> Option<Vec<Vec<Card>>> {
+    let hand_size = *rule_set.hand_size() as usize;
+    let mut hands: Vec<Vec<Card>> = vec![Vec::with_capacity(hand_size); *rule_set.num_players() as usize];
+    for _ in 0..hand_size {
+        for hand in hands.iter_mut() {
+            hand.push(deck.pop()?);
+        }
+    }
+
+    Some(hands)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use game_api::types::CardColor;
 
     #[test]
     fn different_seeds_produce_different_decks() {
-        let deck_factory = DeckFactory::new();
+        let deck_factory = DeckFactory::new(&RuleSet::standard());
         let (deck2, seed2)  = deck_factory.new_shuffled_deck();
         let (deck1, seed1)  = deck_factory.new_shuffled_deck();
 
@@ -81,10 +101,40 @@ mod tests {
 
     #[test]
     fn same_seed_produces_same_deck() {
-        let deck_factory = DeckFactory::new();
+        let deck_factory = DeckFactory::new(&RuleSet::standard());
         let deck2 = deck_factory.new_shuffled_deck_with_seed(5);
         let deck1 = deck_factory.new_shuffled_deck_with_seed(5);
 
         assert_eq!(deck1, deck2);
     }
+
+    #[test]
+    fn deals_hand_size_from_rule_set() {
+        let mut deck = DeckFactory::new(&RuleSet::standard()).new_shuffled_deck_with_seed(5);
+        let top_card = *deck.last().unwrap();
+        let hands = deal(&mut deck, &RuleSet::new(5, 20, 1, 8, 20, false, false)).unwrap();
+
+        assert_eq!(hands.len(), 2);
+        assert!(hands.iter().all(|hand| hand.len() == 5));
+        assert_eq!(hands[0][0], top_card);
+        assert_eq!(deck.len(), deck_size(&RuleSet::standard()) - 10);
+
+        let hands = deal(&mut deck, &RuleSet::new(5, 20, 1, 8, 20, false, false).with_num_players(4)).unwrap();
+        assert_eq!(hands.len(), 4);
+        assert_eq!(deck.len(), deck_size(&RuleSet::standard()) - 30);
+
+        let mut short_deck = vec![top_card];
+        assert_eq!(deal(&mut short_deck, &RuleSet::standard()), None);
+    }
+
+    #[test]
+    fn purple_adds_a_sixth_color() {
+        let five_colors = DeckFactory::new(&RuleSet::standard());
+        let six_colors = DeckFactory::new(&RuleSet::new(8, 20, 1, 8, 20, false, true));
+
+        assert_eq!(five_colors.unshuffled_deck().len(), 60);
+        assert_eq!(six_colors.unshuffled_deck().len(), 72);
+        assert!(!five_colors.unshuffled_deck().iter().any(|card| *card.card_color() == CardColor::Purple));
+        assert_eq!(six_colors.unshuffled_deck().iter().filter(|card| *card.card_color() == CardColor::Purple).count(), CARDS_PER_COLOR);
+    }
 }
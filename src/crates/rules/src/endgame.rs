@@ -1,15 +1,32 @@
-use game_api::types::{GameBoard, GameStatus, GameResult};
+use game_api::types::{GameBoard, GameStatus, GameResult, EndReason, RuleSet};
 
-pub fn get_game_status(game_board: &GameBoard, is_my_turn: bool) -> GameStatus {
-    if *game_board.draw_pile_cards_remaining() > 0 {
-        GameStatus::InProgress(is_my_turn)
+/// Whether the game has played out. It ends when the main draw pile runs out, unless the rules
+/// give everyone else a final turn. Nobody draws on the final turn, so their hands shrink by one.
+/// Once the turn is back to whoever drew the last card, the next seat's hand is the smaller one.
+pub fn is_game_over(rule_set: &RuleSet, draw_pile_cards_remaining: usize, hand_sizes: &[usize], turn: usize) -> bool {
+    if draw_pile_cards_remaining > 0 {
+        return false;
+    }
+
+    !*rule_set.final_turn() || hand_sizes[(turn + 1) % hand_sizes.len()] < hand_sizes[turn]
+}
+
+pub fn get_game_status(game_board: &GameBoard, is_my_turn: bool, is_game_over: bool) -> GameStatus {
+    if !is_game_over {
+        return GameStatus::InProgress(is_my_turn);
+    }
+
+    // With more than one opponent, only the best of them matters.
+    let best_op_score_total = game_board.opponents()
+        .iter()
+        .map(|opponent| *opponent.score_total())
+        .max()
+        .unwrap_or(i32::MIN);
+    if *game_board.my_score_total() > best_op_score_total {
+        GameStatus::Complete(GameResult::Win, EndReason::DeckExhausted)
+    } else if *game_board.my_score_total() == best_op_score_total {
+        GameStatus::Complete(GameResult::Draw, EndReason::DeckExhausted)
     } else {
-        if game_board.my_score_total() > game_board.op_score_total() {
-            GameStatus::Complete(GameResult::Win)
-        } else if game_board.my_score_total() == game_board.op_score_total() {
-            GameStatus::Complete(GameResult::Draw)
-        } else {
-            GameStatus::Complete(GameResult::Lose)
-        }
+        GameStatus::Complete(GameResult::Lose, EndReason::DeckExhausted)
     }
 }
@@ -0,0 +1,455 @@
+/// "Engine" is the whole game, minus everything that isn't the game: no players, IDs, clocks,
+/// storage, or hidden information. Just the cards and whose turn it is.
+///
+/// Everything here is a pure function, so the backend, the client, and bots can all agree on what
+/// a legal move is and what happens when you make one.
+use crate::deck::{self, CARDS_PER_COLOR};
+use crate::{endgame, plays};
+use game_api::types::{Card, CardColor, CardTarget, CardValue, DrawPile, RuleSet};
+use std::collections::HashMap;
+
+/// The full, un-concealed state of a game. Players are identified by their seat, which is their
+/// index into the hands and plays. Turns go around the table in seat order.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RulesState {
+    hands: Vec<Vec<Card>>,
+    plays: Vec<HashMap<CardColor, Vec<CardValue>>>,
+
+    neutral_draw_pile: HashMap<CardColor, Vec<CardValue>>,
+    main_draw_pile: Vec<Card>,
+
+    turn: usize,
+
+    rule_set: RuleSet,
+}
+
+impl RulesState {
+    pub fn new(
+        hands: Vec<Vec<Card>>,
+        plays: Vec<HashMap<CardColor, Vec<CardValue>>>,
+        neutral_draw_pile: HashMap<CardColor, Vec<CardValue>>,
+        main_draw_pile: Vec<Card>,
+        turn: usize
+    ) -> Self {
+        RulesState {
+            hands,
+            plays,
+            neutral_draw_pile,
+            main_draw_pile,
+            turn,
+            rule_set: RuleSet::standard(),
+        }
+    }
+
+    /// For games not played by the standard rules.
+    pub fn with_rule_set(mut self, rule_set: RuleSet) -> Self {
+        self.rule_set = rule_set;
+        self
+    }
+
+    /// One per seat.
+    pub fn hands(&self) -> &Vec<Vec<Card>> {
+        &self.hands
+    }
+
+    /// One per seat.
+    pub fn plays(&self) -> &Vec<HashMap<CardColor, Vec<CardValue>>> {
+        &self.plays
+    }
+
+    pub fn neutral_draw_pile(&self) -> &HashMap<CardColor, Vec<CardValue>> {
+        &self.neutral_draw_pile
+    }
+
+    pub fn main_draw_pile(&self) -> &Vec<Card> {
+        &self.main_draw_pile
+    }
+
+    /// The seat of whoever's turn it is.
+    pub fn turn(&self) -> &usize {
+        &self.turn
+    }
+
+    pub fn rule_set(&self) -> &RuleSet {
+        &self.rule_set
+    }
+
+    /// Hand of whoever's turn it is.
+    pub fn hand_to_move(&self) -> &Vec<Card> {
+        &self.hands[self.turn]
+    }
+
+    /// Plays of whoever's turn it is.
+    pub fn plays_to_move(&self) -> &HashMap<CardColor, Vec<CardValue>> {
+        &self.plays[self.turn]
+    }
+
+    fn hand_to_move_mut(&mut self) -> &mut Vec<Card> {
+        &mut self.hands[self.turn]
+    }
+
+    fn plays_to_move_mut(&mut self) -> &mut HashMap<CardColor, Vec<CardValue>> {
+        &mut self.plays[self.turn]
+    }
+}
+
+/// One full turn: play (or discard) a card, then draw one.
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+pub struct Action {
+    card: Card,
+    target: CardTarget,
+    draw_pile: DrawPile,
+}
+
+impl Action {
+    pub fn new(card: Card, target: CardTarget, draw_pile: DrawPile) -> Self {
+        Action {
+            card,
+            target,
+            draw_pile,
+        }
+    }
+
+    pub fn card(&self) -> &Card {
+        &self.card
+    }
+
+    pub fn target(&self) -> &CardTarget {
+        &self.target
+    }
+
+    pub fn draw_pile(&self) -> &DrawPile {
+        &self.draw_pile
+    }
+}
+
+pub const MIN_PLAYERS: u32 = 2;
+pub const MAX_PLAYERS: u32 = 4;
+
+/// Every way an action can break the rules. Whose turn it is isn't one of them, since the engine
+/// always acts for the player to move.
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+pub enum Reason {
+    CardNotInHand,
+    CantPlayDecreasingCardValue,
+    NeutralDrawPileEmpty,
+    CantRedrawCardJustPlayed,
+    NoDrawOnFinalTurn,
+    GameOver,
+}
+
+/// Whether a game can be played by these rules at all: two to four players, every hand has to fit
+/// in the deck with cards to spare, and an expedition can't have more cards than there are of its
+/// color.
+pub fn is_valid_rule_set(rule_set: &RuleSet) -> bool {
+    *rule_set.num_players() >= MIN_PLAYERS
+        && *rule_set.num_players() <= MAX_PLAYERS
+        && *rule_set.hand_size() >= 1
+        && *rule_set.hand_size() * rule_set.num_players() < deck::deck_size(rule_set) as u32
+        && *rule_set.bonus_threshold() >= 1
+        && *rule_set.bonus_threshold() <= CARDS_PER_COLOR as u32
+}
+
+/// The game ends as soon as the last card is drawn from the main draw pile, or right after the
+/// final turn if the rules call for one.
+pub fn is_terminal(state: &RulesState) -> bool {
+    let hand_sizes: Vec<usize> = state.hands.iter().map(|hand| hand.len()).collect();
+    endgame::is_game_over(&state.rule_set, state.main_draw_pile.len(), &hand_sizes, state.turn)
+}
+
+/// The turn after the last card was drawn, if the rules allow one. There's nothing left to draw
+/// from the main draw pile, and nothing may be drawn from the neutral piles either.
+pub fn is_final_turn(state: &RulesState) -> bool {
+    state.main_draw_pile.is_empty() && !is_terminal(state)
+}
+
+/// Check an action for the player to move without applying it. On success, returns the index of
+/// the played card in their hand.
+pub fn validate(state: &RulesState, action: &Action) -> Result<usize, Reason> {
+    // RULE: Nothing happens after the game is over.
+    if is_terminal(state) {
+        return Err(Reason::GameOver);
+    }
+
+    // RULE: You can only play cards that are in your hand.
+    let card_in_hand_index = state.hand_to_move()
+        .iter()
+        .position(|card| *card == action.card)
+        .ok_or(Reason::CardNotInHand)?;
+
+    // RULE: You must play cards in increasing order.
+    if action.target == CardTarget::Player && !plays::is_card_playable(&action.card, state.plays_to_move()) {
+        return Err(Reason::CantPlayDecreasingCardValue);
+    }
+
+    if let DrawPile::Neutral(color_to_draw) = action.draw_pile {
+        // RULE: You don't draw on the final turn. (Drawing from the empty main pile means not drawing.)
+        if is_final_turn(state) {
+            return Err(Reason::NoDrawOnFinalTurn);
+        }
+
+        // RULE: You can't redraw the same card you just played.
+        if action.target == CardTarget::Neutral && color_to_draw == *action.card.card_color() {
+            return Err(Reason::CantRedrawCardJustPlayed);
+        }
+
+        // RULE: You can't draw from an empty pile.
+        if neutral_draw_pile_size(state, color_to_draw) == 0 {
+            return Err(Reason::NeutralDrawPileEmpty);
+        }
+    }
+
+    Ok(card_in_hand_index)
+}
+
+/// Take a turn for the player to move. The drawn card always goes at the end of their hand.
+pub fn apply(mut state: RulesState, action: &Action) -> Result<RulesState, Reason> {
+    let card_in_hand_index = validate(&state, action)?;
+
+    // 1. Remove the card from hand
+    state.hand_to_move_mut().remove(card_in_hand_index);
+
+    // 2. Add card on top of target pile
+    let target_pile = match action.target {
+        CardTarget::Player => state.plays_to_move_mut(),
+        CardTarget::Neutral => &mut state.neutral_draw_pile,
+    };
+    target_pile.entry(*action.card.card_color())
+        .or_default()
+        .push(*action.card.card_value());
+
+    // 3. Draw new card. Validation guarantees the pile isn't empty, unless it's the final turn.
+    let new_card = match action.draw_pile {
+        DrawPile::Main => state.main_draw_pile.pop(),
+        DrawPile::Neutral(color) => state.neutral_draw_pile
+            .get_mut(&color)
+            .and_then(|draw_pile| draw_pile.pop())
+            .map(|drawn_value| Card::new(color, drawn_value)),
+    };
+    if let Some(new_card) = new_card {
+        state.hand_to_move_mut().push(new_card);
+    }
+
+    // 4. Pass the turn to the next seat
+    state.turn = (state.turn + 1) % state.hands.len();
+
+    Ok(state)
+}
+
+/// Every legal action for the player to move, in a stable order. Identical cards in hand (i.e.
+/// wagers) only show up once. Empty once the game is over.
+pub fn legal_actions(state: &RulesState) -> Vec<Action> {
+    let mut actions = Vec::new();
+    if is_terminal(state) {
+        return actions;
+    }
+
+    let hand = state.hand_to_move();
+    for (i, card) in hand.iter().enumerate() {
+        if hand[..i].contains(card) {
+            continue;
+        }
+
+        let mut targets = vec![CardTarget::Neutral];
+        if plays::is_card_playable(card, state.plays_to_move()) {
+            targets.insert(0, CardTarget::Player);
+        }
+
+        for target in targets {
+            actions.push(Action::new(*card, target, DrawPile::Main));
+            if is_final_turn(state) {
+                continue;
+            }
+            for &color in state.rule_set.colors().iter() {
+                let is_redraw = target == CardTarget::Neutral && color == *card.card_color();
+                if !is_redraw && neutral_draw_pile_size(state, color) > 0 {
+                    actions.push(Action::new(*card, target, DrawPile::Neutral(color)));
+                }
+            }
+        }
+    }
+
+    actions
+}
+
+fn neutral_draw_pile_size(state: &RulesState, color: CardColor) -> usize {
+    state.neutral_draw_pile
+        .get(&color)
+        .map(|pile| pile.len())
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::convert::TryFrom;
+
+    fn card(color: CardColor, value: u32) -> Card {
+        Card::new(color, CardValue::try_from(value).unwrap())
+    }
+
+    fn small_state() -> RulesState {
+        let mut p1_plays = HashMap::new();
+        p1_plays.insert(CardColor::Red, vec![CardValue::try_from(5).unwrap()]);
+        let mut neutral_draw_pile = HashMap::new();
+        neutral_draw_pile.insert(CardColor::Blue, vec![CardValue::try_from(9).unwrap()]);
+
+        RulesState::new(
+            vec![
+                vec![card(CardColor::Red, 3), card(CardColor::Blue, 4), card(CardColor::Blue, 4)],
+                vec![card(CardColor::Green, 2)],
+            ],
+            vec![p1_plays, HashMap::new()],
+            neutral_draw_pile,
+            vec![card(CardColor::White, 7), card(CardColor::Yellow, 8)],
+            0,
+        )
+    }
+
+    #[test]
+    fn legal_actions_follow_the_rules() {
+        let actions = legal_actions(&small_state());
+
+        assert_eq!(actions, vec![
+            // R3 can't go on top of R5, so discard only
+            Action::new(card(CardColor::Red, 3), CardTarget::Neutral, DrawPile::Main),
+            Action::new(card(CardColor::Red, 3), CardTarget::Neutral, DrawPile::Neutral(CardColor::Blue)),
+            // Both B4s are the same move. Discarding it means the B9 gets buried, not redrawn.
+            Action::new(card(CardColor::Blue, 4), CardTarget::Player, DrawPile::Main),
+            Action::new(card(CardColor::Blue, 4), CardTarget::Player, DrawPile::Neutral(CardColor::Blue)),
+            Action::new(card(CardColor::Blue, 4), CardTarget::Neutral, DrawPile::Main),
+        ]);
+    }
+
+    #[test]
+    fn every_legal_action_applies() {
+        let state = small_state();
+        for action in legal_actions(&state) {
+            assert!(apply(state.clone(), &action).is_ok(), "{:?} was listed but not applied", action);
+        }
+    }
+
+    #[test]
+    fn apply_plays_draws_and_passes_the_turn() {
+        let action = Action::new(card(CardColor::Blue, 4), CardTarget::Player, DrawPile::Neutral(CardColor::Blue));
+        let state = apply(small_state(), &action).unwrap();
+
+        assert_eq!(state.hands()[0], vec![card(CardColor::Red, 3), card(CardColor::Blue, 4), card(CardColor::Blue, 9)]);
+        assert_eq!(state.plays()[0].get(&CardColor::Blue), Some(&vec![CardValue::try_from(4).unwrap()]));
+        assert_eq!(state.neutral_draw_pile().get(&CardColor::Blue), Some(&vec![]));
+        assert_eq!(state.main_draw_pile().len(), 2);
+        assert_eq!(*state.turn(), 1);
+    }
+
+    #[test]
+    fn apply_rejects_illegal_actions() {
+        let cases = vec![
+            (Action::new(card(CardColor::Green, 2), CardTarget::Neutral, DrawPile::Main), Reason::CardNotInHand),
+            (Action::new(card(CardColor::Red, 3), CardTarget::Player, DrawPile::Main), Reason::CantPlayDecreasingCardValue),
+            (Action::new(card(CardColor::Red, 3), CardTarget::Neutral, DrawPile::Neutral(CardColor::Red)), Reason::CantRedrawCardJustPlayed),
+            (Action::new(card(CardColor::Red, 3), CardTarget::Neutral, DrawPile::Neutral(CardColor::White)), Reason::NeutralDrawPileEmpty),
+        ];
+
+        for (action, reason) in cases {
+            assert_eq!(apply(small_state(), &action), Err(reason));
+        }
+    }
+
+    #[test]
+    fn nothing_to_do_after_last_draw() {
+        let mut state = small_state();
+        state = apply(state, &Action::new(card(CardColor::Red, 3), CardTarget::Neutral, DrawPile::Main)).unwrap();
+        assert!(!is_terminal(&state));
+        state = apply(state, &Action::new(card(CardColor::Green, 2), CardTarget::Player, DrawPile::Main)).unwrap();
+
+        assert!(is_terminal(&state));
+        assert!(legal_actions(&state).is_empty());
+        assert_eq!(
+            apply(state, &Action::new(card(CardColor::Blue, 4), CardTarget::Player, DrawPile::Main)),
+            Err(Reason::GameOver)
+        );
+    }
+
+    #[test]
+    fn final_turn_after_last_draw() {
+        let state = small_state();
+        let mut state = RulesState::new(
+            vec![
+                state.hands()[0].clone(),
+                vec![card(CardColor::Green, 2), card(CardColor::Green, 3), card(CardColor::Green, 4)],
+            ],
+            state.plays().clone(),
+            state.neutral_draw_pile().clone(),
+            state.main_draw_pile().clone(),
+            0,
+        ).with_rule_set(RuleSet::new(3, 20, 1, 8, 20, true, false));
+        state = apply(state, &Action::new(card(CardColor::Red, 3), CardTarget::Neutral, DrawPile::Main)).unwrap();
+        state = apply(state, &Action::new(card(CardColor::Green, 2), CardTarget::Player, DrawPile::Main)).unwrap();
+        assert!(state.main_draw_pile().is_empty());
+        assert!(is_final_turn(&state));
+
+        // Only the main draw pile, i.e. no draw at all.
+        assert!(legal_actions(&state).iter().all(|action| *action.draw_pile() == DrawPile::Main));
+        assert_eq!(
+            apply(state.clone(), &Action::new(card(CardColor::Blue, 4), CardTarget::Player, DrawPile::Neutral(CardColor::Red))),
+            Err(Reason::NoDrawOnFinalTurn)
+        );
+
+        state = apply(state, &Action::new(card(CardColor::Blue, 4), CardTarget::Player, DrawPile::Main)).unwrap();
+        assert_eq!(state.hands()[0].len(), 2);
+        assert!(is_terminal(&state));
+        assert!(legal_actions(&state).is_empty());
+    }
+
+    #[test]
+    fn turns_go_around_the_table() {
+        let green_hand = vec![card(CardColor::Green, 2), card(CardColor::Green, 3), card(CardColor::Green, 4)];
+        let mut state = RulesState::new(
+            vec![green_hand.clone(), green_hand.clone(), green_hand],
+            vec![HashMap::new(), HashMap::new(), HashMap::new()],
+            HashMap::new(),
+            vec![card(CardColor::White, 7), card(CardColor::Yellow, 8)],
+            1,
+        ).with_rule_set(RuleSet::new(3, 20, 1, 8, 20, true, false).with_num_players(3));
+        let play_green_2 = Action::new(card(CardColor::Green, 2), CardTarget::Player, DrawPile::Main);
+
+        // Seat 2 draws the last card, so seats 0 and 1 each get a final turn.
+        state = apply(state, &play_green_2).unwrap();
+        assert_eq!(*state.turn(), 2);
+        state = apply(state, &play_green_2).unwrap();
+        assert_eq!(*state.turn(), 0);
+        assert!(is_final_turn(&state));
+        state = apply(state, &play_green_2).unwrap();
+        assert_eq!(*state.turn(), 1);
+        assert!(is_final_turn(&state));
+
+        let play_green_3 = Action::new(card(CardColor::Green, 3), CardTarget::Player, DrawPile::Main);
+        state = apply(state, &play_green_3).unwrap();
+        assert_eq!(*state.turn(), 2);
+        assert!(is_terminal(&state));
+
+        // Every expedition is separate, even in the same color.
+        assert_eq!(state.plays()[0].get(&CardColor::Green).map(|values| values.len()), Some(1));
+        assert_eq!(state.plays()[1].get(&CardColor::Green).map(|values| values.len()), Some(2));
+        assert_eq!(state.plays()[2].get(&CardColor::Green).map(|values| values.len()), Some(1));
+    }
+
+    #[test]
+    fn rule_set_must_fit_the_deck() {
+        assert!(is_valid_rule_set(&RuleSet::standard()));
+        assert!(is_valid_rule_set(&RuleSet::new(29, 0, 0, 12, 0, true, false)));
+        assert!(!is_valid_rule_set(&RuleSet::new(0, 20, 1, 8, 20, false, false)));
+        assert!(!is_valid_rule_set(&RuleSet::new(30, 20, 1, 8, 20, false, false)));
+        assert!(!is_valid_rule_set(&RuleSet::new(8, 20, 1, 13, 20, false, false)));
+
+        // More players need more cards
+        assert!(is_valid_rule_set(&RuleSet::standard().with_num_players(4)));
+        assert!(!is_valid_rule_set(&RuleSet::new(15, 20, 1, 8, 20, false, false).with_num_players(4)));
+        assert!(!is_valid_rule_set(&RuleSet::standard().with_num_players(1)));
+        assert!(!is_valid_rule_set(&RuleSet::standard().with_num_players(5)));
+
+        // The sixth color makes room for bigger hands
+        assert!(is_valid_rule_set(&RuleSet::new(35, 20, 1, 8, 20, false, true)));
+        assert!(!is_valid_rule_set(&RuleSet::new(36, 20, 1, 8, 20, false, true)));
+    }
+}
@@ -1,5 +1,7 @@
 pub mod deck;
 pub mod endgame;
+pub mod engine;
+pub mod notation;
 pub mod plays;
 pub mod scoring;
 mod rand_util;
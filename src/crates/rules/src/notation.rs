@@ -0,0 +1,578 @@
+/// A compact text notation for a whole game, for pasting into chat and bug reports. It's modeled
+/// on chess's PGN: a header of tags, then one token per turn.
+///
+/// ```text
+/// [Seat1 "alice"]
+/// [Seat2 "bob"]
+/// [Seed "8675309"]
+/// [First "bob"]
+/// [Rules "h6,f"]
+/// [Result "45, -12"]
+///
+/// 1. Rw+ B7-
+/// 2. G10-B R4+G
+/// ```
+///
+/// The seed and the rule set pin down the deal, so the turns are all it takes to replay the game.
+/// `Rules` uses the same overrides of the standard rules as the client's prompt, and is left out
+/// for standard games. `Result` is either the final score of every seat, `<player> resigned`,
+/// `<player> ran out of time`, `draw agreed`, or `*` for a game that isn't over.
+///
+/// A turn is the card (color letter, then `2` to `10` or `w` for a wager), `+` to play it on your
+/// expedition or `-` to discard it, then the color letter of the discard pile to draw from. Nothing
+/// after the `+`/`-` means a draw from the main pile, or no draw at all on the final turn. Turns
+/// are numbered once per trip around the table.
+use crate::deck::{self, DeckFactory};
+use crate::engine::{self, Action, Reason, RulesState};
+use crate::scoring;
+use game_api::types::{Card, CardColor, CardTarget, CardValue, DrawPile, RuleSet};
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::fmt;
+
+/// Everything the notation holds about a game.
+#[derive(Clone, Debug, PartialEq)]
+pub struct GameRecord {
+    // By seat
+    player_ids: Vec<String>,
+    seed: u64,
+    first_seat: usize,
+    rule_set: RuleSet,
+    result: RecordedResult,
+    actions: Vec<Action>,
+}
+
+impl GameRecord {
+    pub fn new(
+        player_ids: Vec<String>,
+        seed: u64,
+        first_seat: usize,
+        rule_set: RuleSet,
+        result: RecordedResult,
+        actions: Vec<Action>,
+    ) -> Self {
+        GameRecord {
+            player_ids,
+            seed,
+            first_seat,
+            rule_set,
+            result,
+            actions,
+        }
+    }
+
+    /// One per seat.
+    pub fn player_ids(&self) -> &Vec<String> {
+        &self.player_ids
+    }
+
+    /// Seed of the shuffled deck. See `DeckFactory::new_shuffled_deck_with_seed()`.
+    pub fn seed(&self) -> &u64 {
+        &self.seed
+    }
+
+    /// The seat that took the first turn.
+    pub fn first_seat(&self) -> &usize {
+        &self.first_seat
+    }
+
+    pub fn rule_set(&self) -> &RuleSet {
+        &self.rule_set
+    }
+
+    pub fn result(&self) -> &RecordedResult {
+        &self.result
+    }
+
+    /// Every turn, oldest first.
+    pub fn actions(&self) -> &Vec<Action> {
+        &self.actions
+    }
+}
+
+/// How the game ended. Players are identified by their seat.
+#[derive(Clone, Debug, PartialEq)]
+pub enum RecordedResult {
+    /// The deck ran out. One total per seat.
+    Scores(Vec<i32>),
+    Resigned(usize),
+    TimedOut(usize),
+    DrawAgreed,
+    Unfinished,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum NotationError {
+    /// A tag the game can't be replayed without.
+    MissingTag(&'static str),
+    /// A header line that couldn't be read.
+    BadTag(String),
+    /// A turn that couldn't be read.
+    BadToken(String),
+    /// The players, rules, and first player don't make a game the engine can deal.
+    InvalidSetup(&'static str),
+    /// A turn the rules don't allow. Turns count from 1.
+    IllegalTurn(usize, Reason),
+    /// The result doesn't match how the turns played out.
+    WrongResult,
+}
+
+impl Error for NotationError {}
+
+impl Display for NotationError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            NotationError::MissingTag(tag) => write!(f, "The '{}' tag is missing.", tag),
+            NotationError::BadTag(line) => write!(f, "Couldn't read the tag '{}'.", line),
+            NotationError::BadToken(token) => write!(f, "Couldn't read the turn '{}'.", token),
+            NotationError::InvalidSetup(problem) => write!(f, "The game can't be set up: {}.", problem),
+            NotationError::IllegalTurn(turn, reason) => write!(f, "Turn {} breaks the rules: {:?}.", turn, reason),
+            NotationError::WrongResult => write!(f, "The result doesn't match the turns."),
+        }
+    }
+}
+
+/// Deal the game and play every turn through the rules engine. Returns the state after the last
+/// turn, once the result has been checked against it.
+pub fn replay(record: &GameRecord) -> Result<RulesState, NotationError> {
+    let mut state = deal(record)?;
+    for (i, action) in record.actions.iter().enumerate() {
+        state = engine::apply(state, action)
+            .map_err(|reason| NotationError::IllegalTurn(i + 1, reason))?;
+    }
+
+    let is_terminal = engine::is_terminal(&state);
+    let is_result_right = match &record.result {
+        RecordedResult::Scores(scores) => is_terminal && *scores == final_scores(&state),
+        RecordedResult::Resigned(seat) | RecordedResult::TimedOut(seat) => !is_terminal && *seat < record.player_ids.len(),
+        RecordedResult::DrawAgreed | RecordedResult::Unfinished => !is_terminal,
+    };
+    if !is_result_right {
+        return Err(NotationError::WrongResult);
+    }
+
+    Ok(state)
+}
+
+/// The game as it was before the first turn.
+pub fn deal(record: &GameRecord) -> Result<RulesState, NotationError> {
+    let rule_set = record.rule_set;
+    if !engine::is_valid_rule_set(&rule_set) {
+        return Err(NotationError::InvalidSetup("the rules don't work"));
+    }
+    if record.player_ids.len() != *rule_set.num_players() as usize {
+        return Err(NotationError::InvalidSetup("the number of players doesn't match the rules"));
+    }
+    if record.first_seat >= record.player_ids.len() {
+        return Err(NotationError::InvalidSetup("the first player isn't seated"));
+    }
+
+    let mut deck = DeckFactory::new(&rule_set).new_shuffled_deck_with_seed(record.seed);
+    let hands = deck::deal(&mut deck, &rule_set)
+        .ok_or(NotationError::InvalidSetup("the deck is too small"))?;
+    let num_seats = hands.len();
+
+    Ok(RulesState::new(hands, vec![HashMap::new(); num_seats], HashMap::new(), deck, record.first_seat)
+        .with_rule_set(rule_set))
+}
+
+/// Score of every seat, as it stands.
+pub fn final_scores(state: &RulesState) -> Vec<i32> {
+    state.plays()
+        .iter()
+        .map(|plays| scoring::compute_score(plays, state.rule_set()).0)
+        .collect()
+}
+
+/// Write down a game, once it's been replayed to make sure it's legal.
+pub fn write(record: &GameRecord) -> Result<String, NotationError> {
+    replay(record)?;
+
+    let mut lines = Vec::new();
+    for (seat, player_id) in record.player_ids.iter().enumerate() {
+        lines.push(format!("[Seat{} \"{}\"]", seat + 1, player_id));
+    }
+    lines.push(format!("[Seed \"{}\"]", record.seed));
+    lines.push(format!("[First \"{}\"]", record.player_ids[record.first_seat]));
+    if record.rule_set != RuleSet::standard() {
+        lines.push(format!("[Rules \"{}\"]", write_rule_set(&record.rule_set)));
+    }
+    lines.push(format!("[Result \"{}\"]", write_result(&record.result, &record.player_ids)));
+    lines.push(String::new());
+
+    for (round, actions) in record.actions.chunks(record.player_ids.len()).enumerate() {
+        let tokens: Vec<String> = actions.iter().map(write_action).collect();
+        lines.push(format!("{}. {}", round + 1, tokens.join(" ")));
+    }
+
+    Ok(lines.join("\n"))
+}
+
+/// Read a game, and replay it to make sure it's legal.
+pub fn parse(text: &str) -> Result<GameRecord, NotationError> {
+    let mut tags: HashMap<String, String> = HashMap::new();
+    let mut actions = Vec::new();
+
+    for line in text.lines().map(str::trim) {
+        if line.starts_with('[') {
+            let (name, value) = parse_tag(line)?;
+            tags.insert(name, value);
+            continue;
+        }
+
+        for token in line.split_whitespace() {
+            // Turn numbers are only there for people.
+            if token.ends_with('.') && token[..token.len() - 1].chars().all(|c| c.is_ascii_digit()) {
+                continue;
+            }
+            actions.push(parse_action(token)?);
+        }
+    }
+
+    let mut player_ids = Vec::new();
+    while let Some(player_id) = tags.get(&format!("Seat{}", player_ids.len() + 1)) {
+        player_ids.push(player_id.to_owned());
+    }
+    if player_ids.is_empty() {
+        return Err(NotationError::MissingTag("Seat1"));
+    }
+
+    let seed = tags.get("Seed")
+        .ok_or(NotationError::MissingTag("Seed"))?;
+    let seed = seed.parse()
+        .map_err(|_| NotationError::BadTag(format!("Seed \"{}\"", seed)))?;
+
+    let first = tags.get("First")
+        .ok_or(NotationError::MissingTag("First"))?;
+    let first_seat = seat_of(first, &player_ids)
+        .ok_or_else(|| NotationError::BadTag(format!("First \"{}\"", first)))?;
+
+    let rule_set = match tags.get("Rules") {
+        Some(rules) => parse_rule_set(rules)
+            .ok_or_else(|| NotationError::BadTag(format!("Rules \"{}\"", rules)))?,
+        None => RuleSet::standard(),
+    };
+
+    let result = tags.get("Result")
+        .ok_or(NotationError::MissingTag("Result"))?;
+    let result = parse_result(result, &player_ids)
+        .ok_or_else(|| NotationError::BadTag(format!("Result \"{}\"", result)))?;
+
+    let record = GameRecord::new(player_ids, seed, first_seat, rule_set, result, actions);
+    replay(&record)?;
+
+    Ok(record)
+}
+
+fn parse_tag(line: &str) -> Result<(String, String), NotationError> {
+    let bad_tag = || NotationError::BadTag(line.to_owned());
+
+    if !line.ends_with(']') || line.len() < 2 {
+        return Err(bad_tag());
+    }
+    let mut parts = line[1..line.len() - 1].splitn(2, ' ');
+    let name = parts.next().ok_or_else(bad_tag)?;
+    let value = parts.next().ok_or_else(bad_tag)?.trim();
+    if name.is_empty() || value.len() < 2 || !value.starts_with('"') || !value.ends_with('"') {
+        return Err(bad_tag());
+    }
+
+    Ok((name.to_owned(), value[1..value.len() - 1].to_owned()))
+}
+
+fn seat_of(player_id: &str, player_ids: &[String]) -> Option<usize> {
+    player_ids.iter().position(|id| id == player_id)
+}
+
+/// Only the rules that differ from the standard ones, e.g. "h6,f".
+fn write_rule_set(rule_set: &RuleSet) -> String {
+    let standard = RuleSet::standard();
+    let mut rules = Vec::new();
+    let numbered_rules = [
+        ("h", rule_set.hand_size(), standard.hand_size()),
+        ("c", rule_set.expedition_cost(), standard.expedition_cost()),
+        ("w", rule_set.multiplier_per_wager(), standard.multiplier_per_wager()),
+        ("b", rule_set.bonus_threshold(), standard.bonus_threshold()),
+        ("p", rule_set.bonus_points(), standard.bonus_points()),
+    ];
+    for (kind, number, standard_number) in numbered_rules.iter() {
+        if number != standard_number {
+            rules.push(format!("{}{}", kind, number));
+        }
+    }
+    if *rule_set.final_turn() {
+        rules.push("f".to_owned());
+    }
+    if *rule_set.purple() {
+        rules.push("e".to_owned());
+    }
+    if *rule_set.num_players() != *standard.num_players() {
+        rules.push(format!("n{}", rule_set.num_players()));
+    }
+
+    rules.join(",")
+}
+
+fn parse_rule_set(rules: &str) -> Option<RuleSet> {
+    let standard = RuleSet::standard();
+    let mut hand_size = *standard.hand_size();
+    let mut expedition_cost = *standard.expedition_cost();
+    let mut multiplier_per_wager = *standard.multiplier_per_wager();
+    let mut bonus_threshold = *standard.bonus_threshold();
+    let mut bonus_points = *standard.bonus_points();
+    let mut final_turn = *standard.final_turn();
+    let mut purple = *standard.purple();
+    let mut num_players = *standard.num_players();
+
+    for rule in rules.split(',').map(str::trim).filter(|rule| !rule.is_empty()) {
+        match rule {
+            "f" => final_turn = true,
+            "e" => purple = true,
+            _ => {
+                let (kind, number) = rule.split_at(1);
+                let number: u32 = number.parse().ok()?;
+                match kind {
+                    "h" => hand_size = number,
+                    "c" => expedition_cost = number,
+                    "w" => multiplier_per_wager = number,
+                    "b" => bonus_threshold = number,
+                    "p" => bonus_points = number,
+                    "n" => num_players = number,
+                    _ => return None,
+                }
+            },
+        }
+    }
+
+    Some(RuleSet::new(hand_size, expedition_cost, multiplier_per_wager, bonus_threshold, bonus_points, final_turn, purple)
+        .with_num_players(num_players))
+}
+
+fn write_result(result: &RecordedResult, player_ids: &[String]) -> String {
+    match result {
+        RecordedResult::Scores(scores) => scores.iter()
+            .map(i32::to_string)
+            .collect::<Vec<String>>()
+            .join(", "),
+        RecordedResult::Resigned(seat) => format!("{} resigned", player_ids[*seat]),
+        RecordedResult::TimedOut(seat) => format!("{} ran out of time", player_ids[*seat]),
+        RecordedResult::DrawAgreed => "draw agreed".to_owned(),
+        RecordedResult::Unfinished => "*".to_owned(),
+    }
+}
+
+fn parse_result(result: &str, player_ids: &[String]) -> Option<RecordedResult> {
+    const RESIGNED: &str = " resigned";
+    const TIMED_OUT: &str = " ran out of time";
+
+    Some(match result {
+        "*" => RecordedResult::Unfinished,
+        "draw agreed" => RecordedResult::DrawAgreed,
+        _ if result.ends_with(RESIGNED) => {
+            RecordedResult::Resigned(seat_of(&result[..result.len() - RESIGNED.len()], player_ids)?)
+        },
+        _ if result.ends_with(TIMED_OUT) => {
+            RecordedResult::TimedOut(seat_of(&result[..result.len() - TIMED_OUT.len()], player_ids)?)
+        },
+        _ => RecordedResult::Scores(result.split(',')
+            .map(|score| score.trim().parse().ok())
+            .collect::<Option<Vec<i32>>>()?),
+    })
+}
+
+/// e.g. "Rw+", "B7-", or "G10-B".
+fn write_action(action: &Action) -> String {
+    let value = match action.card().card_value() {
+        CardValue::Wager => "w".to_owned(),
+        value => u32::from(*value).to_string(),
+    };
+    let target = match action.target() {
+        CardTarget::Player => '+',
+        CardTarget::Neutral => '-',
+    };
+    let draw_pile = match action.draw_pile() {
+        DrawPile::Main => "",
+        DrawPile::Neutral(color) => color_letter(*color),
+    };
+
+    format!("{}{}{}{}", color_letter(*action.card().card_color()), value, target, draw_pile)
+}
+
+fn parse_action(token: &str) -> Result<Action, NotationError> {
+    let bad_token = || NotationError::BadToken(token.to_owned());
+
+    let target_index = token.find(&['+', '-'][..]).ok_or_else(bad_token)?;
+    let (card, draw_pile) = token.split_at(target_index);
+    let (target, draw_pile) = draw_pile.split_at(1);
+
+    if card.len() < 2 || !card.is_char_boundary(1) {
+        return Err(bad_token());
+    }
+    let (color, value) = card.split_at(1);
+    let color = parse_color(color).ok_or_else(bad_token)?;
+    let value = match value {
+        "w" | "W" => CardValue::Wager,
+        // Wagers are written as "w", not as their wire value.
+        "1" => return Err(bad_token()),
+        _ => value.parse()
+            .ok()
+            .and_then(|value: u32| CardValue::try_from(value).ok())
+            .ok_or_else(bad_token)?,
+    };
+
+    let target = if target == "+" { CardTarget::Player } else { CardTarget::Neutral };
+
+    let draw_pile = match draw_pile {
+        "" => DrawPile::Main,
+        color => DrawPile::Neutral(parse_color(color).ok_or_else(bad_token)?),
+    };
+
+    Ok(Action::new(Card::new(color, value), target, draw_pile))
+}
+
+fn color_letter(color: CardColor) -> &'static str {
+    match color {
+        CardColor::Red => "R",
+        CardColor::Green => "G",
+        CardColor::White => "W",
+        CardColor::Blue => "B",
+        CardColor::Yellow => "Y",
+        CardColor::Purple => "P",
+    }
+}
+
+fn parse_color(letter: &str) -> Option<CardColor> {
+    Some(match letter.to_uppercase().as_str() {
+        "R" => CardColor::Red,
+        "G" => CardColor::Green,
+        "W" => CardColor::White,
+        "B" => CardColor::Blue,
+        "Y" => CardColor::Yellow,
+        "P" => CardColor::Purple,
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Alternates between the first and last legal action until the game is over. The first one
+    /// always draws from the main pile, so the deck runs out eventually.
+    fn played_out_record(seed: u64, rule_set: RuleSet, first_seat: usize) -> GameRecord {
+        let mut deck = DeckFactory::new(&rule_set).new_shuffled_deck_with_seed(seed);
+        let hands = deck::deal(&mut deck, &rule_set).unwrap();
+        let num_seats = hands.len();
+        let mut state = RulesState::new(hands, vec![HashMap::new(); num_seats], HashMap::new(), deck, first_seat)
+            .with_rule_set(rule_set);
+
+        let mut actions = Vec::new();
+        loop {
+            let legal_actions = engine::legal_actions(&state);
+            let action = match if actions.len() % 2 == 0 { legal_actions.first() } else { legal_actions.last() } {
+                Some(action) => *action,
+                None => break,
+            };
+            state = engine::apply(state, &action).unwrap();
+            actions.push(action);
+        }
+
+        let player_ids = (1..=num_seats).map(|seat| format!("player{}", seat)).collect();
+        GameRecord::new(player_ids, seed, first_seat, rule_set, RecordedResult::Scores(final_scores(&state)), actions)
+    }
+
+    #[test]
+    fn written_games_parse_back() {
+        let rule_sets = vec![
+            RuleSet::standard(),
+            RuleSet::new(6, 25, 2, 7, 15, true, true).with_num_players(3),
+        ];
+
+        for rule_set in rule_sets {
+            let record = played_out_record(42, rule_set, 1);
+            let text = write(&record).unwrap();
+
+            assert_eq!(parse(&text), Ok(record));
+        }
+    }
+
+    #[test]
+    fn writes_tags_then_one_round_per_line() {
+        let record = played_out_record(7, RuleSet::standard().with_num_players(3), 2);
+        let text = write(&record).unwrap();
+        let lines: Vec<&str> = text.lines().collect();
+
+        assert_eq!(lines[..6], [
+            "[Seat1 \"player1\"]",
+            "[Seat2 \"player2\"]",
+            "[Seat3 \"player3\"]",
+            "[Seed \"7\"]",
+            "[First \"player3\"]",
+            "[Rules \"n3\"]",
+        ]);
+        assert!(lines[6].starts_with("[Result \""));
+        assert_eq!(lines[7], "");
+        assert!(lines[8].starts_with("1. "));
+        assert_eq!(lines[8].split_whitespace().count(), 4);
+    }
+
+    #[test]
+    fn reads_every_kind_of_turn() {
+        assert_eq!(parse_action("Rw+"), Ok(Action::new(Card::new(CardColor::Red, CardValue::Wager), CardTarget::Player, DrawPile::Main)));
+        assert_eq!(parse_action("G10-B"), Ok(Action::new(Card::new(CardColor::Green, CardValue::Ten), CardTarget::Neutral, DrawPile::Neutral(CardColor::Blue))));
+        assert_eq!(parse_action("p2+y"), Ok(Action::new(Card::new(CardColor::Purple, CardValue::Two), CardTarget::Player, DrawPile::Neutral(CardColor::Yellow))));
+
+        for &token in &["R5", "R1+", "R11+", "X5+", "+", "R5+Q", "R5+BB"] {
+            assert_eq!(parse_action(token), Err(NotationError::BadToken(token.to_owned())));
+        }
+    }
+
+    #[test]
+    fn reads_every_kind_of_result() {
+        let player_ids = vec!["alice".to_owned(), "bob".to_owned()];
+
+        assert_eq!(parse_result("45, -12", &player_ids), Some(RecordedResult::Scores(vec![45, -12])));
+        assert_eq!(parse_result("bob resigned", &player_ids), Some(RecordedResult::Resigned(1)));
+        assert_eq!(parse_result("alice ran out of time", &player_ids), Some(RecordedResult::TimedOut(0)));
+        assert_eq!(parse_result("draw agreed", &player_ids), Some(RecordedResult::DrawAgreed));
+        assert_eq!(parse_result("*", &player_ids), Some(RecordedResult::Unfinished));
+        assert_eq!(parse_result("carol resigned", &player_ids), None);
+    }
+
+    #[test]
+    fn rejects_games_that_break_the_rules() {
+        let record = played_out_record(42, RuleSet::standard(), 0);
+
+        // The same game dealt from a different deck goes wrong somewhere.
+        let other_deal = GameRecord { seed: 43, ..record.clone() };
+        assert!(matches!(write(&other_deal), Err(NotationError::IllegalTurn(_, _))));
+
+        let wrong_score = GameRecord { result: RecordedResult::Scores(vec![0, 0]), ..record.clone() };
+        assert_eq!(write(&wrong_score), Err(NotationError::WrongResult));
+
+        let resigned_after_the_end = GameRecord { result: RecordedResult::Resigned(0), ..record.clone() };
+        assert_eq!(write(&resigned_after_the_end), Err(NotationError::WrongResult));
+
+        let one_more_turn = GameRecord { actions: [&record.actions[..], &record.actions[..1]].concat(), ..record.clone() };
+        assert_eq!(write(&one_more_turn), Err(NotationError::IllegalTurn(record.actions.len() + 1, Reason::GameOver)));
+
+        let text = write(&record).unwrap().replace("[Seed \"42\"]\n", "");
+        assert_eq!(parse(&text), Err(NotationError::MissingTag("Seed")));
+    }
+
+    #[test]
+    fn unfinished_games_can_be_shared_too() {
+        let record = played_out_record(42, RuleSet::standard(), 0);
+        let unfinished = GameRecord {
+            result: RecordedResult::Unfinished,
+            actions: record.actions[..10].to_vec(),
+            ..record
+        };
+
+        let text = write(&unfinished).unwrap();
+        assert!(text.contains("[Result \"*\"]"));
+        assert_eq!(parse(&text), Ok(unfinished));
+    }
+}
@@ -11,22 +11,6 @@ pub fn decorate_hand(hand: Vec<Card>, previous_plays: &HashMap<CardColor, Vec<Ca
         .collect()
 }
 
-/// Consider moving this to UT utility, if that's the only place it's used.
-#[allow(dead_code)]
-fn get_allowed_plays(
-    hand: &Vec<Card>,
-    previous_plays: &HashMap<CardColor, Vec<CardValue>>
-) -> Vec<usize> {
-    let mut allowed_indexes = Vec::new();
-    for (i, card_in_hand) in hand.iter().enumerate() {
-        if is_card_playable(card_in_hand, previous_plays) {
-            allowed_indexes.push(i);
-        }
-    }
-
-    return allowed_indexes;
-}
-
 pub fn is_card_playable(
     attempted_play: &Card,
     previous_plays: &HashMap<CardColor, Vec<CardValue>>
@@ -86,7 +70,13 @@ mod tests {
         let previous_plays_map = previous_plays(previous_plays_vec);
 
         // 3. Call method under test
-        assert_eq!(get_allowed_plays(&hand, &previous_plays_map), expected_output);
+        let allowed_plays: Vec<usize> = decorate_hand(hand, &previous_plays_map)
+            .iter()
+            .enumerate()
+            .filter(|(_, decorated_card)| *decorated_card.is_playable())
+            .map(|(i, _)| i)
+            .collect();
+        assert_eq!(allowed_plays, expected_output);
     }
 
     #[test]
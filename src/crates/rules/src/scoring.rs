@@ -1,13 +1,13 @@
-use game_api::types::{CardValue, CardColor};
+use game_api::types::{CardValue, CardColor, GameState, RuleSet};
 use std::collections::HashMap;
 
 /// Returns a tuple of the (total, per-color) score.
-pub fn compute_score(plays: &HashMap<CardColor, Vec<CardValue>>) -> (i32, HashMap<CardColor, i32>) {
+pub fn compute_score(plays: &HashMap<CardColor, Vec<CardValue>>, rule_set: &RuleSet) -> (i32, HashMap<CardColor, i32>) {
     let mut score_total = 0;
     let mut score_per_color = HashMap::with_capacity(plays.len());
 
     for (color, cards) in plays.iter() {
-        let score = compute_score_for_color(cards);
+        let score = compute_score_for_color(cards, rule_set);
         score_total += score;
         score_per_color.insert(*color, score);
     }
@@ -18,41 +18,195 @@ pub fn compute_score(plays: &HashMap<CardColor, Vec<CardValue>>) -> (i32, HashMa
     )
 }
 
-fn compute_score_for_color(column: &Vec<CardValue>) -> i32 {
+/// Score of a single expedition.
+pub fn compute_score_for_color(column: &Vec<CardValue>, rule_set: &RuleSet) -> i32 {
     if column.len() == 0 {
         return 0;
     }
 
-    let mut score = -20;
-    let mut wager_multiplier = 1;
+    let mut score = -(*rule_set.expedition_cost() as i32);
+    let mut wagers = 0;
     for card in column.iter() {
         match card {
-            CardValue::Wager => wager_multiplier += 1,
-            CardValue::Two => score += 2,
-            CardValue::Three => score += 3,
-            CardValue::Four => score += 4,
-            CardValue::Five => score += 5,
-            CardValue::Six => score += 6,
-            CardValue::Seven => score += 7,
-            CardValue::Eight => score += 8,
-            CardValue::Nine => score += 9,
-            CardValue::Ten => score += 10,
+            CardValue::Wager => wagers += 1,
+            _ => score += points(card),
         }
     }
 
-    let bonus = if column.len() >= 8 { 20 } else { 0 };
+    return score * wager_multiplier(wagers, rule_set) + bonus(column.len(), rule_set);
+}
+
+fn wager_multiplier(wagers: usize, rule_set: &RuleSet) -> i32 {
+    1 + (wagers as i32) * (*rule_set.multiplier_per_wager() as i32)
+}
+
+fn bonus(expedition_length: usize, rule_set: &RuleSet) -> i32 {
+    if expedition_length >= *rule_set.bonus_threshold() as usize {
+        *rule_set.bonus_points() as i32
+    } else {
+        0
+    }
+}
+
+/// Face value of a card. Wagers are worth nothing on their own.
+fn points(card_value: &CardValue) -> i32 {
+    match card_value {
+        CardValue::Wager => 0,
+        CardValue::Two => 2,
+        CardValue::Three => 3,
+        CardValue::Four => 4,
+        CardValue::Five => 5,
+        CardValue::Six => 6,
+        CardValue::Seven => 7,
+        CardValue::Eight => 8,
+        CardValue::Nine => 9,
+        CardValue::Ten => 10,
+    }
+}
 
-    return score * wager_multiplier + bonus;
+/// Where a single expedition could still end up, from one player's point of view.
+#[derive(Debug, PartialEq)]
+pub struct ExpeditionOutlook {
+    max_score: i32,
+    points_to_break_even: u32,
+    expected_score: f64,
+}
+
+impl ExpeditionOutlook {
+    /// Best case: every card that can still legally land on the expedition ends up there, with
+    /// however many of the remaining wagers help most.
+    pub fn max_score(&self) -> &i32 {
+        &self.max_score
+    }
+
+    /// Points the expedition still needs before it stops scoring negative. Wagers don't move this.
+    pub fn points_to_break_even(&self) -> &u32 {
+        &self.points_to_break_even
+    }
+
+    /// Rough guess, assuming the playable cards in hand get played and the rest show up as often
+    /// as the draw odds say they will.
+    pub fn expected_score(&self) -> &f64 {
+        &self.expected_score
+    }
+
+    /// For an expedition not yet started, whether starting it is expected to pay off. For one
+    /// already underway, whether it's on track to finish in the positive.
+    pub fn is_worth_it(&self) -> bool {
+        self.expected_score > 0.0
+    }
+}
+
+/// Outlook for every color, from the perspective of whoever the game state belongs to.
+///
+/// Each unseen card is assumed to reach us with the chance of it being in the draw pile rather than
+/// an opponent's hand, and us, rather than one of the `num_opponents` other players, drawing it.
+pub fn expedition_outlooks(game_state: &GameState, num_opponents: usize) -> HashMap<CardColor, ExpeditionOutlook> {
+    let game_board = game_state.game_board();
+    // Whoever gets to a card first, any of us is as likely as the next.
+    let my_share = 1.0 / (num_opponents + 1) as f64;
+    let unseen_total = game_state.unseen_cards().len();
+    let draw_chance = if unseen_total == 0 {
+        0.0
+    } else {
+        (*game_board.draw_pile_cards_remaining() as f64 / unseen_total as f64).min(1.0) * my_share
+    };
+
+    game_state.rule_set().colors().iter()
+        .map(|color| {
+            let column = game_board.my_plays().get(color).cloned().unwrap_or_default();
+            let held: Vec<CardValue> = game_state.my_hand().iter()
+                .map(|decorated_card| decorated_card.card())
+                .filter(|card| card.card_color() == color)
+                .map(|card| *card.card_value())
+                .collect();
+
+            let mut reachable: Vec<(CardValue, f64)> = game_state.unseen_cards().iter()
+                .filter(|card| card.card_color() == color)
+                .map(|card| (*card.card_value(), draw_chance))
+                .collect();
+            // The top of the discard pile is up for grabs by any of us.
+            if let Some((top_value, _)) = game_board.neutral_draw_pile().get(color) {
+                reachable.push((*top_value, my_share));
+            }
+
+            (*color, expedition_outlook(&column, &held, &reachable, game_state.rule_set()))
+        })
+        .collect()
+}
+
+/// Outlook for a single expedition.
+///
+/// `column` is what's already been played, `held` is what's in hand, and `reachable` pairs every
+/// other card that could still be drawn with the chance of drawing it.
+pub fn expedition_outlook(
+    column: &[CardValue],
+    held: &[CardValue],
+    reachable: &[(CardValue, f64)],
+    rule_set: &RuleSet,
+) -> ExpeditionOutlook {
+    let expedition_cost = *rule_set.expedition_cost() as i32;
+    let top = column.last().copied().unwrap_or(CardValue::Wager);
+    let is_playable = |value: &CardValue| *value >= top;
+    let played_points: i32 = column.iter().map(points).sum();
+    let played_wagers = column.iter().filter(|v| **v == CardValue::Wager).count();
+
+    // Best case
+    let candidates: Vec<CardValue> = held.iter()
+        .chain(reachable.iter().map(|(value, _)| value))
+        .copied()
+        .filter(is_playable)
+        .collect();
+    let candidate_points: i32 = candidates.iter().map(points).sum();
+    let candidate_numbers = candidates.iter().filter(|v| **v != CardValue::Wager).count();
+    let candidate_wagers = candidates.len() - candidate_numbers;
+    let max_score = (0..=candidate_wagers)
+        .map(|wagers| {
+            let len = column.len() + candidate_numbers + wagers;
+            (played_points + candidate_points - expedition_cost) * wager_multiplier(played_wagers + wagers, rule_set)
+                + bonus(len, rule_set)
+        })
+        .max()
+        .unwrap_or(0);
+
+    // Break even
+    let points_to_break_even = (expedition_cost - played_points).max(0) as u32;
+
+    // Expected
+    let held_playable: Vec<&CardValue> = held.iter().filter(|v| is_playable(v)).collect();
+    let reachable_playable: Vec<&(CardValue, f64)> = reachable.iter().filter(|(v, _)| is_playable(v)).collect();
+    let expected_points = (played_points + held_playable.iter().map(|v| points(v)).sum::<i32>()) as f64
+        + reachable_playable.iter().map(|(v, chance)| points(v) as f64 * chance).sum::<f64>();
+    let expected_len = (column.len() + held_playable.len()) as f64
+        + reachable_playable.iter().map(|(_, chance)| chance).sum::<f64>();
+    let expected_net = expected_points - expedition_cost as f64;
+    // Only lay down the wagers in hand if they're going to multiply a gain rather than a loss.
+    let held_wagers = held_playable.iter().filter(|v| ***v == CardValue::Wager).count();
+    let multiplier = wager_multiplier(played_wagers + if expected_net > 0.0 { held_wagers } else { 0 }, rule_set);
+    let expected_bonus = if expected_len >= *rule_set.bonus_threshold() as f64 {
+        *rule_set.bonus_points() as f64
+    } else {
+        0.0
+    };
+    let expected_score = expected_net * multiplier as f64 + expected_bonus;
+
+    ExpeditionOutlook {
+        max_score,
+        points_to_break_even,
+        expected_score,
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use game_api::types::{Card, DecoratedCard, GameBoard, GameStatus};
+    use crate::deck::DeckFactory;
     use std::convert::TryFrom;
 
     fn compute_score_test_helper(expected_score: i32, previous_plays_vec: Vec<(CardColor, u32)>) {
         let previous_plays = previous_plays(previous_plays_vec);
-        let (total_score, _) = compute_score(&previous_plays);
+        let (total_score, _) = compute_score(&previous_plays, &RuleSet::standard());
 
         assert_eq!(total_score, expected_score, "Plays {:?} did not match expected score {}", &previous_plays, expected_score);
     }
@@ -78,7 +232,7 @@ mod tests {
             cards.push(CardValue::try_from(val).unwrap());
         }
 
-        assert_eq!(compute_score_for_color(&cards), expected_score, "Column {:?} did not match expected score {}", &cards, expected_score);
+        assert_eq!(compute_score_for_color(&cards, &RuleSet::standard()), expected_score, "Column {:?} did not match expected score {}", &cards, expected_score);
     }
 
     #[test]
@@ -196,6 +350,20 @@ mod tests {
         compute_score_for_color_test_helper(156, vec![1, 1, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
     }
 
+    #[test]
+    fn compute_score_for_color_house_rules() {
+        // Cheaper expeditions, wagers count double, and a bigger bonus for 6 cards.
+        let rule_set = RuleSet::new(8, 10, 2, 6, 30, false, false);
+        let column = |card_values: Vec<u32>| card_values.into_iter()
+            .map(|v| CardValue::try_from(v).unwrap())
+            .collect::<Vec<CardValue>>();
+
+        assert_eq!(compute_score_for_color(&column(vec![]), &rule_set), 0);
+        assert_eq!(compute_score_for_color(&column(vec![4]), &rule_set), -6);
+        assert_eq!(compute_score_for_color(&column(vec![1, 5, 6, 7]), &rule_set), 24);
+        assert_eq!(compute_score_for_color(&column(vec![2, 3, 4, 5, 6, 7]), &rule_set), 47);
+    }
+
     #[test]
     fn literally_the_worst_score() {
         compute_score_test_helper(-400, vec![
@@ -217,6 +385,129 @@ mod tests {
         ]);
     }
 
+    fn values(card_values: Vec<u32>) -> Vec<CardValue> {
+        card_values.into_iter().map(|v| CardValue::try_from(v).unwrap()).collect()
+    }
+
+    #[test]
+    fn expedition_outlook_certain_cards() {
+        // Nothing left to chance: 3 + 5 + 7 + 9 in hand.
+        let outlook = expedition_outlook(&[], &values(vec![3, 5, 7, 9]), &[], &RuleSet::standard());
+        assert_eq!(outlook.max_score(), &4);
+        assert_eq!(outlook.points_to_break_even(), &20);
+        assert_eq!(outlook.expected_score(), &4.0);
+        assert!(outlook.is_worth_it());
+
+        // Already played 6 and 8, so 6 more to break even. The 4 in hand is too low to play.
+        let outlook = expedition_outlook(&values(vec![6, 8]), &values(vec![4, 10]), &[], &RuleSet::standard());
+        assert_eq!(outlook.max_score(), &4);
+        assert_eq!(outlook.points_to_break_even(), &6);
+        assert_eq!(outlook.expected_score(), &4.0);
+    }
+
+    #[test]
+    fn expedition_outlook_only_uses_wagers_when_they_help() {
+        // Best case skips the wager: (2 + 3 - 20) is better unmultiplied.
+        let outlook = expedition_outlook(&[], &values(vec![1, 2, 3]), &[], &RuleSet::standard());
+        assert_eq!(outlook.max_score(), &-15);
+        assert_eq!(outlook.expected_score(), &-15.0);
+        assert!(!outlook.is_worth_it());
+
+        // ... but doubles a gain.
+        let outlook = expedition_outlook(&[], &values(vec![1, 8, 9, 10]), &[], &RuleSet::standard());
+        assert_eq!(outlook.max_score(), &14);
+        assert_eq!(outlook.expected_score(), &14.0);
+
+        // Wagers can't follow numbers.
+        let outlook = expedition_outlook(&values(vec![8]), &values(vec![1, 9, 10]), &[], &RuleSet::standard());
+        assert_eq!(outlook.max_score(), &7);
+    }
+
+    #[test]
+    fn expedition_outlook_weighs_reachable_cards() {
+        let reachable = vec![
+            (CardValue::Ten, 0.5),
+            (CardValue::Nine, 0.5),
+        ];
+        let outlook = expedition_outlook(&[], &values(vec![4, 5]), &reachable, &RuleSet::standard());
+        assert_eq!(outlook.max_score(), &8);
+        assert_eq!(outlook.expected_score(), &(9.0 + 9.5 - 20.0));
+
+        // Bonus for a long expedition.
+        let outlook = expedition_outlook(&[], &values(vec![2, 3, 4, 5, 6, 7, 8]), &reachable, &RuleSet::standard());
+        assert_eq!(outlook.max_score(), &(35 + 19 - 20 + 20));
+    }
+
+    #[test]
+    fn expedition_outlooks_from_game_state() {
+        let mut my_plays = HashMap::new();
+        my_plays.insert(CardColor::Red, values(vec![1, 5]));
+        let mut neutral_draw_pile = HashMap::new();
+        neutral_draw_pile.insert(CardColor::Red, (CardValue::Six, 1));
+        let game_board = GameBoard::new(
+            my_plays, HashMap::new(), 0, 0, HashMap::new(), HashMap::new(), neutral_draw_pile, 2);
+        let hand = vec![DecoratedCard::new(Card::new(CardColor::Red, CardValue::Seven), true)];
+        // Two cards in the draw pile, two in the opponent's hand: a 1 in 4 chance of drawing each.
+        let unseen_cards = vec![
+            Card::new(CardColor::Red, CardValue::Two),
+            Card::new(CardColor::Red, CardValue::Ten),
+            Card::new(CardColor::Blue, CardValue::Four),
+            Card::new(CardColor::Blue, CardValue::Eight),
+        ];
+        let game_state = GameState::new(
+            game_board, hand, GameStatus::InProgress(true), None, None, None, unseen_cards);
+
+        let outlooks = expedition_outlooks(&game_state, 1);
+        assert_eq!(outlooks.len(), 5);
+
+        // The 2 can't be played on the 5 any more.
+        let red = &outlooks[&CardColor::Red];
+        assert_eq!(red.max_score(), &((5 + 7 + 6 + 10 - 20) * 2));
+        assert_eq!(red.points_to_break_even(), &15);
+        assert_eq!(red.expected_score(), &((5.0 + 7.0 + 3.0 + 2.5 - 20.0) * 2.0));
+
+        let blue = &outlooks[&CardColor::Blue];
+        assert_eq!(blue.max_score(), &-8);
+        assert_eq!(blue.expected_score(), &-17.0);
+        assert!(!blue.is_worth_it());
+    }
+
+    #[test]
+    fn expedition_outlooks_split_the_odds_between_all_players() {
+        let mut my_plays = HashMap::new();
+        my_plays.insert(CardColor::Red, values(vec![1, 5]));
+        let mut op_plays = HashMap::new();
+        op_plays.insert(CardColor::Red, values(vec![8, 9, 10]));
+        let mut neutral_draw_pile = HashMap::new();
+        neutral_draw_pile.insert(CardColor::Red, (CardValue::Six, 1));
+        let hand = vec![DecoratedCard::new(Card::new(CardColor::Red, CardValue::Seven), true)];
+        // Every card not on the board or in my hand, all 53 of them still in the draw pile.
+        let mut unseen_cards = DeckFactory::new(&RuleSet::standard()).unshuffled_deck().clone();
+        for value in [CardValue::Wager, CardValue::Five, CardValue::Eight, CardValue::Nine, CardValue::Ten, CardValue::Six, CardValue::Seven].iter() {
+            let i = unseen_cards.iter()
+                .position(|card| card.card_color() == &CardColor::Red && card.card_value() == value)
+                .unwrap();
+            unseen_cards.remove(i);
+        }
+        let game_board = GameBoard::new(
+            my_plays, op_plays, 0, 0, HashMap::new(), HashMap::new(), neutral_draw_pile, 53);
+        let game_state = GameState::new(
+            game_board, hand, GameStatus::InProgress(true), None, None, None, unseen_cards);
+        let is_about = |actual: &f64, expected: f64| (actual - expected).abs() < 1e-9;
+
+        // Head to head, I get every other card: half of blue's 54 points.
+        let outlooks = expedition_outlooks(&game_state, 1);
+        assert!(is_about(outlooks[&CardColor::Red].expected_score(), (5.0 + 7.0 + 3.0 - 20.0) * 2.0));
+        assert!(is_about(outlooks[&CardColor::Blue].expected_score(), 27.0 - 20.0));
+        assert!(outlooks[&CardColor::Blue].is_worth_it());
+
+        // With two opponents, only every third card, including the discarded 6.
+        let outlooks = expedition_outlooks(&game_state, 2);
+        assert!(is_about(outlooks[&CardColor::Red].expected_score(), (5.0 + 7.0 + 2.0 - 20.0) * 2.0));
+        assert!(is_about(outlooks[&CardColor::Blue].expected_score(), 18.0 - 20.0));
+        assert!(!outlooks[&CardColor::Blue].is_worth_it());
+    }
+
     // It's hard to exhaustively test all scenarios. Only add more tests if I find bugs
     // with existing implementation. #TDD
 }
@@ -7,5 +7,5 @@ pub mod storage_types;
 pub mod v2;
 pub mod local_disk_storage;
 
-#[cfg(test)]
+// Also used by other crates' tests, so it can't be cfg(test).
 pub mod test_utils;
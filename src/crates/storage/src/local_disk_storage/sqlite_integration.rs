@@ -1,6 +1,6 @@
 use rusqlite::{NO_PARAMS, Connection, OpenFlags, Row, ToSql};
 use std::path::Path;
-use crate::local_disk_storage::sqlite_tables::{SqlGameSummary, SqlGameData};
+use crate::local_disk_storage::sqlite_tables::{SqlGameSummary, SqlGameData, SqlGamePlayer};
 
 type SqliteResult<T> = Result<T, rusqlite::Error>;
 
@@ -28,6 +28,20 @@ impl SqliteWrapper {
         })
     }
 
+    /// For tools that only read, like the analytics export. Doesn't create the file if it's
+    /// missing. It's not opened read-only, because then sqlite can't roll back a write that was
+    /// cut off by the server going down, and refuses to read anything at all.
+    pub fn connect_existing<P: AsRef<Path>>(db_file_path: P) -> SqliteResult<Self> {
+        let connection = Connection::open_with_flags(
+            db_file_path,
+            OpenFlags::SQLITE_OPEN_READ_WRITE | OpenFlags::SQLITE_OPEN_NO_MUTEX
+        )?;
+
+        Ok(SqliteWrapper {
+            connection
+        })
+    }
+
     pub fn create_table<R: SqlTableRow>(&self) -> SqliteResult<()> {
         self.connection.execute(R::table_create_statement(), NO_PARAMS)?;
         Ok(())
@@ -51,6 +65,70 @@ impl SqliteWrapper {
         }
     }
 
+    /// Every seat of the game, in seat order.
+    pub fn select_game_players(&self, game_id: &str) -> SqliteResult<Vec<SqlGamePlayer>> {
+        let mut statement = self.connection.prepare("\
+            SELECT game_id, seat, player_id \
+            FROM game_player \
+            WHERE game_id = :game_id \
+            ORDER BY seat \
+        ")?;
+        let row_results_iter = statement.query_map_named(&[(":game_id", &game_id)], SqlGamePlayer::try_from_row)?;
+
+        row_results_iter.collect()
+    }
+
+    /// Seat exactly these players in the game, replacing whoever was seated before.
+    pub fn replace_game_players(&self, game_id: &str, game_players: &[SqlGamePlayer]) -> SqliteResult<()> {
+        self.connection.execute_named("DELETE FROM game_player WHERE game_id = :game_id", &[(":game_id", &game_id)])?;
+        for game_player in game_players {
+            self.insert_row(game_player)?;
+        }
+
+        Ok(())
+    }
+
+    /// Every game with the given status which was created within the time range (inclusive),
+    /// along with its data, oldest first. Games without a `game_data` row are left out. If a
+    /// player is given, only their games are included.
+    pub fn select_games_by_status(
+        &self,
+        game_status: u8,
+        min_creation_time_sec: u32,
+        max_creation_time_sec: u32,
+        player_id: Option<&str>,
+    ) -> SqliteResult<Vec<(SqlGameSummary, SqlGameData)>> {
+        let mut statement = self.connection.prepare("\
+            SELECT \
+                game_summary.game_id AS game_id, \
+                game_creation_time_sec, \
+                game_type, \
+                game_status, \
+                game_summary_blob, \
+                game_data_blob \
+            FROM game_summary \
+            INNER JOIN game_data ON game_summary.game_id = game_data.game_id \
+            WHERE game_status = :game_status \
+                AND game_creation_time_sec BETWEEN :min_creation_time_sec AND :max_creation_time_sec \
+                AND (:player_id IS NULL OR EXISTS ( \
+                    SELECT 1 FROM game_player \
+                    WHERE game_player.game_id = game_summary.game_id AND game_player.player_id = :player_id \
+                )) \
+            ORDER BY game_creation_time_sec, game_summary.game_id \
+        ")?;
+        let row_results_iter = statement.query_map_named(
+            &[
+                (":game_status", &game_status),
+                (":min_creation_time_sec", &min_creation_time_sec),
+                (":max_creation_time_sec", &max_creation_time_sec),
+                (":player_id", &player_id),
+            ],
+            |row| Ok((SqlGameSummary::try_from_row(row)?, SqlGameData::try_from_row(row)?))
+        )?;
+
+        row_results_iter.collect()
+    }
+
     pub fn insert_row<R: SqlTableRow>(&self, item: &R) -> SqliteResult<()> {
         let statement_and_params = item.insert_statement_and_params();
         self.prepare_and_execute_named(statement_and_params, "INSERT")
@@ -84,6 +162,7 @@ impl SqliteWrapper {
 pub(crate) fn create_all_tables(sqlite_wrapper: &SqliteWrapper) -> SqliteResult<()> {
     sqlite_wrapper.create_table::<SqlGameSummary>()?;
     sqlite_wrapper.create_table::<SqlGameData>()?;
+    sqlite_wrapper.create_table::<SqlGamePlayer>()?;
 
     Ok(())
 }
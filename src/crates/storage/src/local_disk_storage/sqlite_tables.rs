@@ -153,3 +153,77 @@ impl SqlTableRow for SqlGameData {
         }
     }
 }
+
+/// One row per seat. The summary's players live here, so they can be queried like any other column.
+#[derive(Debug, PartialEq, Clone)]
+pub struct SqlGamePlayer {
+    pub game_id: String,
+    pub seat: u8,
+    pub player_id: String,
+}
+
+impl SqlGamePlayer {
+    fn as_named_params(&self) -> Vec<(&'static str, &dyn ToSql)> {
+        vec![
+            (":game_id", &self.game_id),
+            (":seat", &self.seat),
+            (":player_id", &self.player_id),
+        ]
+    }
+}
+
+impl SqlTableRow for SqlGamePlayer {
+    fn table_create_statement() -> &'static str {
+        "CREATE TABLE IF NOT EXISTS game_player ( \
+            game_id TEXT NOT NULL, \
+            seat INTEGER NOT NULL, \
+            player_id TEXT NOT NULL, \
+            PRIMARY KEY (game_id, seat) \
+        )"
+    }
+
+    /// Only finds the row of a game with a single player. See `SqliteWrapper::select_game_players()`.
+    fn select_statement(game_id: &str) -> String {
+        format!(
+            "SELECT game_id, seat, player_id \
+                FROM game_player \
+                WHERE game_id = '{}'",
+            game_id
+        )
+    }
+
+    fn try_from_row(row: &Row) -> rusqlite::Result<Self> {
+        Ok(SqlGamePlayer {
+            game_id: row.get("game_id")?,
+            seat: row.get("seat")?,
+            player_id: row.get("player_id")?,
+        })
+    }
+
+    fn insert_statement_and_params(&self) -> StatementAndParams<'_> {
+        let sql_statement = "\
+            INSERT INTO game_player \
+            (game_id, seat, player_id) VALUES \
+            (:game_id, :seat, :player_id) \
+        ";
+
+        StatementAndParams {
+            sql_statement,
+            named_params: self.as_named_params()
+        }
+    }
+
+    fn update_statement_and_params(&self) -> StatementAndParams<'_> {
+        let sql_statement = "\
+            UPDATE game_player \
+            SET \
+                player_id = :player_id \
+            WHERE game_id = :game_id AND seat = :seat \
+        ";
+
+        StatementAndParams {
+            sql_statement,
+            named_params: self.as_named_params()
+        }
+    }
+}
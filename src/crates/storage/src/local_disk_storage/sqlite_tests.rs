@@ -1,5 +1,5 @@
 use crate::local_disk_storage::sqlite_integration::{SqliteWrapper, create_all_tables};
-use crate::local_disk_storage::sqlite_tables::{SqlGameSummary, SqlGameData};
+use crate::local_disk_storage::sqlite_tables::{SqlGameSummary, SqlGameData, SqlGamePlayer};
 use crate::test_utils::{TestFileHandle, rand_str};
 
 #[test]
@@ -86,3 +86,67 @@ fn test_accessing_sql_game_data() {
     assert_eq!(obj_read2, obj_wrote2);
     assert_ne!(obj_read2, obj_read1);
 }
+
+#[test]
+fn test_selecting_games_by_status() {
+    // Setup
+    let db_file = TestFileHandle::new(format!("./frj-game-{}.db", rand_str()));
+    db_file.rm("before");
+    let sqlite = SqliteWrapper::connect(&db_file.file_path).expect("SqliteWrapper::create");
+    create_all_tables(&sqlite).expect("create_all_tables");
+
+    let games = vec![
+        // (game_id, game_creation_time_sec, game_status)
+        ("in-range-2", 300, 3),
+        ("in-range-1", 200, 3),
+        ("in-progress", 200, 2),
+        ("too-old", 100, 3),
+        ("too-new", 500, 3),
+    ];
+    for (game_id, game_creation_time_sec, game_status) in games {
+        sqlite.insert_row(&SqlGameSummary {
+            game_id: game_id.to_owned(),
+            game_creation_time_sec,
+            game_type: 1,
+            game_status,
+            game_summary_blob_opt: Some(vec![1, 2, 3]),
+        }).expect("insert_row game_summary");
+        sqlite.insert_row(&SqlGameData {
+            game_id: game_id.to_owned(),
+            game_data_blob: game_id.as_bytes().to_owned(),
+        }).expect("insert_row game_data");
+        sqlite.replace_game_players(game_id, &[
+            SqlGamePlayer { game_id: game_id.to_owned(), seat: 0, player_id: "alice".to_owned() },
+            SqlGamePlayer { game_id: game_id.to_owned(), seat: 1, player_id: game_id.to_owned() },
+        ]).expect("replace_game_players");
+    }
+    // A summary without data is skipped.
+    sqlite.insert_row(&SqlGameSummary {
+        game_id: "no-data".to_owned(),
+        game_creation_time_sec: 250,
+        game_type: 1,
+        game_status: 3,
+        game_summary_blob_opt: None,
+    }).expect("insert_row game_summary");
+
+    // SELECT
+    let rows = sqlite.select_games_by_status(3, 200, 400, None).expect("select_games_by_status");
+    let game_ids: Vec<&str> = rows.iter().map(|(summary, _)| summary.game_id.as_str()).collect();
+    assert_eq!(game_ids, vec!["in-range-1", "in-range-2"]);
+    for (summary, data) in rows.iter() {
+        assert_eq!(summary.game_summary_blob_opt, Some(vec![1, 2, 3]));
+        assert_eq!(data.game_id, summary.game_id);
+        assert_eq!(data.game_data_blob, summary.game_id.as_bytes());
+    }
+
+    // Only one of them was against this guest.
+    let rows = sqlite.select_games_by_status(3, 200, 400, Some("in-range-2")).expect("select_games_by_status by player");
+    let game_ids: Vec<&str> = rows.iter().map(|(summary, _)| summary.game_id.as_str()).collect();
+    assert_eq!(game_ids, vec!["in-range-2"]);
+    let player_ids: Vec<String> = sqlite.select_game_players("in-range-2")
+        .expect("select_game_players")
+        .into_iter()
+        .map(|game_player| game_player.player_id)
+        .collect();
+    assert_eq!(player_ids, vec!["alice", "in-range-2"]);
+}
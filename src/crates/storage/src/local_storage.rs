@@ -1,19 +1,22 @@
-use crate::storage_types::{StorageGameMetadata, StorageError, StorageGameState};
-use crate::storage_api::GameStore;
+use crate::storage_types::{StorageGameMetadata, StorageError, StorageGameState, StorageGameStatus, StorageMatch};
+use crate::storage_api::{GameStore, GameStateRecord};
 use std::collections::HashMap;
 
-pub struct InMemoryStore {
+pub struct InMemoryStore<S = StorageGameState> {
     // Indexed by game_id
     metadata_map: HashMap<String, StorageGameMetadata>,
     // Indexed by game_id
-    state_map: HashMap<String, StorageGameState>,
+    state_map: HashMap<String, S>,
+    // Indexed by match_id
+    match_map: HashMap<String, StorageMatch>,
 }
 
-impl InMemoryStore {
+impl<S> InMemoryStore<S> {
     pub fn new() -> Self {
         InMemoryStore {
             metadata_map: HashMap::new(),
             state_map: HashMap::new(),
+            match_map: HashMap::new(),
         }
     }
 }
@@ -28,7 +31,7 @@ impl InMemoryStore {
 ///
 /// Read methods return clones of the storage's data, so callers can freely mutate the returned data without
 /// corrupting the storage.
-impl GameStore for InMemoryStore {
+impl<S: GameStateRecord> GameStore<S> for InMemoryStore<S> {
 
     fn create_game_metadata(&mut self, game_metadata: StorageGameMetadata) -> Result<(), StorageError> {
         if self.metadata_map.contains_key(game_metadata.game_id()) {
@@ -39,7 +42,7 @@ impl GameStore for InMemoryStore {
         Ok(())
     }
 
-    fn create_game_state(&mut self, game_state: StorageGameState) -> Result<(), StorageError> {
+    fn create_game_state(&mut self, game_state: S) -> Result<(), StorageError> {
         if self.state_map.contains_key(game_state.game_id()) {
             return Err(StorageError::AlreadyExists);
         }
@@ -48,6 +51,15 @@ impl GameStore for InMemoryStore {
         Ok(())
     }
 
+    fn create_match(&mut self, storage_match: StorageMatch) -> Result<(), StorageError> {
+        if self.match_map.contains_key(storage_match.match_id()) {
+            return Err(StorageError::AlreadyExists);
+        }
+
+        self.match_map.insert(storage_match.match_id().to_owned(), storage_match);
+        Ok(())
+    }
+
     fn update_game_metadata(&mut self, game_metadata: StorageGameMetadata) -> Result<(), StorageError> {
         if !self.metadata_map.contains_key(game_metadata.game_id()) {
             return Err(StorageError::NotFound);
@@ -57,7 +69,7 @@ impl GameStore for InMemoryStore {
         Ok(())
     }
 
-    fn update_game_state(&mut self, game_state: StorageGameState) -> Result<(), StorageError> {
+    fn update_game_state(&mut self, game_state: S) -> Result<(), StorageError> {
         if !self.state_map.contains_key(game_state.game_id()) {
             return Err(StorageError::NotFound);
         }
@@ -66,6 +78,15 @@ impl GameStore for InMemoryStore {
         Ok(())
     }
 
+    fn update_match(&mut self, storage_match: StorageMatch) -> Result<(), StorageError> {
+        if !self.match_map.contains_key(storage_match.match_id()) {
+            return Err(StorageError::NotFound);
+        }
+
+        self.match_map.insert(storage_match.match_id().to_owned(), storage_match);
+        Ok(())
+    }
+
     fn load_game_metadata(&self, game_id: &str) -> Result<StorageGameMetadata, StorageError> {
         match self.metadata_map.get(game_id) {
             None => Err(StorageError::NotFound),
@@ -73,27 +94,40 @@ impl GameStore for InMemoryStore {
         }
     }
 
-    fn load_game_state(&self, game_id: &str) -> Result<StorageGameState, StorageError> {
+    fn load_game_state(&self, game_id: &str) -> Result<S, StorageError> {
         match self.state_map.get(game_id) {
             None => Err(StorageError::NotFound),
             Some(game_state) => Ok((*game_state).clone())
         }
     }
+
+    fn load_match(&self, match_id: &str) -> Result<StorageMatch, StorageError> {
+        match self.match_map.get(match_id) {
+            None => Err(StorageError::NotFound),
+            Some(storage_match) => Ok((*storage_match).clone())
+        }
+    }
+
+    fn query_game_metadata(&self, game_status: StorageGameStatus) -> Result<Vec<StorageGameMetadata>, StorageError> {
+        Ok(self.metadata_map
+            .values()
+            .filter(|game_meta| *game_meta.game_status() == game_status)
+            .cloned()
+            .collect())
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::storage_types::StorageGameStatus;
 
     #[test]
     fn create_load_game_metadata() {
-        let mut local_store = InMemoryStore::new();
+        let mut local_store: InMemoryStore = InMemoryStore::new();
 
         let metadata = StorageGameMetadata::new(
             "game-123".to_owned(),
-            "p1".to_owned(),
-            None,
+            vec!["p1".to_owned()],
             StorageGameStatus::InProgress
         );
 
@@ -117,12 +151,11 @@ mod tests {
 
     #[test]
     fn update_game_metadata() {
-        let mut local_store = InMemoryStore::new();
+        let mut local_store: InMemoryStore = InMemoryStore::new();
 
         let metadata = StorageGameMetadata::new(
             "game-123".to_owned(),
-            "p1".to_owned(),
-            None,
+            vec!["p1".to_owned()],
             StorageGameStatus::InProgress
         );
 
@@ -135,7 +168,7 @@ mod tests {
             ()
         );
         let mut metadata2 = metadata.clone();
-        metadata2.set_p2_id("p2p2".to_owned());
+        metadata2.push_player_id("p2p2".to_owned());
         assert_eq!(
             local_store.update_game_metadata(metadata2.clone()).ok().unwrap(),
             ()
@@ -157,13 +190,11 @@ mod tests {
 
         let game_state = StorageGameState::new(
             "game-123".to_owned(),
-            Vec::new(),
-            Vec::new(),
-            HashMap::new(),
-            HashMap::new(),
+            vec![Vec::new(), Vec::new()],
+            vec![HashMap::new(), HashMap::new()],
             HashMap::new(),
             Vec::new(),
-            true
+            0
         );
 
         assert_eq!(
@@ -190,13 +221,11 @@ mod tests {
 
         let game_state = StorageGameState::new(
             "game-123".to_owned(),
-            Vec::new(),
-            Vec::new(),
-            HashMap::new(),
-            HashMap::new(),
+            vec![Vec::new(), Vec::new()],
+            vec![HashMap::new(), HashMap::new()],
             HashMap::new(),
             Vec::new(),
-            true
+            0
         );
 
         assert_eq!(
@@ -209,13 +238,11 @@ mod tests {
         );
         let updated_game_state = StorageGameState::new(
             "game-123".to_owned(),
-            Vec::new(),
-            Vec::new(),
-            HashMap::new(),
-            HashMap::new(),
+            vec![Vec::new(), Vec::new()],
+            vec![HashMap::new(), HashMap::new()],
             HashMap::new(),
             Vec::new(),
-            false
+            1
         );
         assert_eq!(
             local_store.update_game_state(updated_game_state.clone()).ok().unwrap(),
@@ -231,4 +258,78 @@ mod tests {
         );
         assert_ne!(game_state, updated_game_state);
     }
+
+    #[test]
+    fn query_game_metadata() {
+        let mut local_store: InMemoryStore = InMemoryStore::new();
+
+        let in_progress = StorageGameMetadata::new(
+            "game-123".to_owned(),
+            vec!["p1".to_owned(), "p2".to_owned()],
+            StorageGameStatus::InProgress
+        );
+        let completed = StorageGameMetadata::new(
+            "game-456".to_owned(),
+            vec!["p1".to_owned(), "p2".to_owned()],
+            StorageGameStatus::Completed
+        );
+
+        assert_eq!(
+            local_store.query_game_metadata(StorageGameStatus::InProgress).ok().unwrap(),
+            vec![]
+        );
+        local_store.create_game_metadata(in_progress.clone()).unwrap();
+        local_store.create_game_metadata(completed.clone()).unwrap();
+        assert_eq!(
+            local_store.query_game_metadata(StorageGameStatus::InProgress).ok().unwrap(),
+            vec![in_progress]
+        );
+        assert_eq!(
+            local_store.query_game_metadata(StorageGameStatus::Completed).ok().unwrap(),
+            vec![completed]
+        );
+    }
+
+    #[test]
+    fn create_update_load_match() {
+        let mut local_store: InMemoryStore = InMemoryStore::new();
+
+        let storage_match = StorageMatch::new(
+            "match-123".to_owned(),
+            "p1".to_owned(),
+            None,
+            3,
+            true,
+            Vec::new(),
+        );
+
+        assert_eq!(
+            local_store.load_match(storage_match.match_id()).err().unwrap(),
+            StorageError::NotFound
+        );
+        assert_eq!(
+            local_store.update_match(storage_match.clone()).err().unwrap(),
+            StorageError::NotFound
+        );
+        assert_eq!(
+            local_store.create_match(storage_match.clone()).ok().unwrap(),
+            ()
+        );
+        assert_eq!(
+            local_store.create_match(storage_match.clone()).err().unwrap(),
+            StorageError::AlreadyExists
+        );
+        let mut storage_match2 = storage_match.clone();
+        storage_match2.set_p2_id("p2".to_owned());
+        storage_match2.push_game_id("game-1".to_owned());
+        assert_eq!(
+            local_store.update_match(storage_match2.clone()).ok().unwrap(),
+            ()
+        );
+        assert_eq!(
+            local_store.load_match(storage_match.match_id()).ok().unwrap(),
+            storage_match2
+        );
+        assert_ne!(storage_match, storage_match2);
+    }
 }
@@ -1,4 +1,4 @@
-use crate::storage_types::{StorageGameMetadata, StorageError, StorageGameState};
+use crate::storage_types::{StorageGameMetadata, StorageError, StorageGameState, StorageGameStatus, StorageMatch};
 
 /// A GameStore is the storage layer of the game engine. It is responsible for durably persisting the state
 /// of the game. It is NOT responsible for applying rules of the game to the provided game board state.
@@ -12,20 +12,37 @@ use crate::storage_types::{StorageGameMetadata, StorageError, StorageGameState};
 /// renamed to LocalCache.
 ///
 /// You should instead see `GameDatabase` trait in the v2 API.
-pub trait GameStore {
+///
+/// Game states are whatever the game persists, `StorageGameState` for Lost Cities.
+pub trait GameStore<S: GameStateRecord = StorageGameState> {
 
     // C
     fn create_game_metadata(&mut self, game_metadata: StorageGameMetadata) -> Result<(), StorageError>;
-    fn create_game_state(&mut self, storage_game_state: StorageGameState) -> Result<(), StorageError>;
+    fn create_game_state(&mut self, storage_game_state: S) -> Result<(), StorageError>;
+    fn create_match(&mut self, storage_match: StorageMatch) -> Result<(), StorageError>;
 
     // U
     fn update_game_metadata(&mut self, game_metadata: StorageGameMetadata) -> Result<(), StorageError>;
-    fn update_game_state(&mut self, storage_game_state: StorageGameState) -> Result<(), StorageError>;
+    fn update_game_state(&mut self, storage_game_state: S) -> Result<(), StorageError>;
+    fn update_match(&mut self, storage_match: StorageMatch) -> Result<(), StorageError>;
 
     // R
     fn load_game_metadata(&self, game_id: &str) -> Result<StorageGameMetadata, StorageError>;
-    fn load_game_state(&self, game_id: &str) -> Result<StorageGameState, StorageError>;
+    fn load_game_state(&self, game_id: &str) -> Result<S, StorageError>;
+    fn load_match(&self, match_id: &str) -> Result<StorageMatch, StorageError>;
+    fn query_game_metadata(&self, game_status: StorageGameStatus) -> Result<Vec<StorageGameMetadata>, StorageError>;
 
     // D
     // none yet
 }
+
+/// The store doesn't look inside a game's state, other than to tell which game it belongs to.
+pub trait GameStateRecord: Clone {
+    fn game_id(&self) -> &str;
+}
+
+impl GameStateRecord for StorageGameState {
+    fn game_id(&self) -> &str {
+        self.game_id()
+    }
+}
@@ -1,32 +1,49 @@
 use std::collections::HashMap;
 use std::error::Error;
 use std::fmt::{Debug, Display, Formatter};
+use std::time::{SystemTime, UNIX_EPOCH};
 use core::fmt;
 // This is a broken layer of abstraction. But I'm sick of re-writing the same types for now. I'm trying to learn rust!
-use game_api::types::{Card, CardColor, CardValue};
+use game_api::types::{Card, CardColor, CardValue, TimeControl, CardTarget, DrawPile, Difficulty, RuleSet};
+use crate::v2::db_types::DbGameType;
 
 const MISSING_P2_ID_MSG: &str = "Player 2 id is missing from metadata. If this happens, I was probably not as careful as I assumed and I should rename this method.";
 
 #[derive(Clone, PartialEq, Debug)]
 pub struct StorageGameMetadata {
     game_id: String,
-    p1_id: String,
-    p2_id: Option<String>,
+    game_type: DbGameType,
+    // The host, then the guests in the order they joined. A player's index is their seat.
+    player_ids: Vec<String>,
+    num_seats: usize,
     game_status: StorageGameStatus,
+    match_id: Option<String>,
+    time_control: Option<TimeControl>,
+    computer: Option<Difficulty>,
+    rule_set: RuleSet,
+    creation_time_ms: u64,
 }
 
 impl StorageGameMetadata {
     pub fn new(
         game_id: String,
-        p1_id: String,
-        p2_id: Option<String>,
+        player_ids: Vec<String>,
         game_status: StorageGameStatus
     ) -> Self {
         StorageGameMetadata {
             game_id,
-            p1_id,
-            p2_id,
+            game_type: DbGameType::LostCities,
+            player_ids,
+            num_seats: 2,
             game_status,
+            match_id: None,
+            time_control: None,
+            computer: None,
+            rule_set: RuleSet::standard(),
+            creation_time_ms: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|duration| duration.as_millis() as u64)
+                .unwrap_or(0),
         }
     }
 
@@ -34,6 +51,134 @@ impl StorageGameMetadata {
         &self.game_id
     }
 
+    pub fn game_type(&self) -> &DbGameType {
+        &self.game_type
+    }
+
+    pub fn set_game_type(&mut self, game_type: DbGameType) {
+        self.game_type = game_type;
+    }
+
+    /// Everyone seated so far, host first.
+    pub fn player_ids(&self) -> &Vec<String> {
+        &self.player_ids
+    }
+
+    pub fn host_id(&self) -> &str {
+        &self.player_ids[0]
+    }
+
+    /// The seat of a player in this game, if they're in it.
+    pub fn seat(&self, player_id: &str) -> Option<usize> {
+        self.player_ids.iter().position(|seated_id| seated_id == player_id)
+    }
+
+    /// How many players the game is for. Decided by the game when it's hosted.
+    pub fn num_seats(&self) -> &usize {
+        &self.num_seats
+    }
+
+    pub fn set_num_seats(&mut self, num_seats: usize) {
+        self.num_seats = num_seats;
+    }
+
+    /// Once every seat is taken, the game can start.
+    pub fn is_full(&self) -> bool {
+        self.player_ids.len() >= self.num_seats
+    }
+
+    pub fn game_status(&self) -> &StorageGameStatus {
+        &self.game_status
+    }
+
+    pub fn creation_time_ms(&self) -> u64 {
+        self.creation_time_ms
+    }
+
+    pub fn push_player_id(&mut self, player_id: String) {
+        self.player_ids.push(player_id);
+    }
+
+    pub fn set_game_status(&mut self, game_status: StorageGameStatus) {
+        self.game_status = game_status;
+    }
+
+    /// The match this game is a round of, if any.
+    pub fn match_id_opt(&self) -> &Option<String> {
+        &self.match_id
+    }
+
+    pub fn set_match_id(&mut self, match_id: String) {
+        self.match_id.replace(match_id);
+    }
+
+    pub fn time_control(&self) -> &Option<TimeControl> {
+        &self.time_control
+    }
+
+    pub fn set_time_control(&mut self, time_control: TimeControl) {
+        self.time_control.replace(time_control);
+    }
+
+    /// Set if the guest is the computer, which takes its turns on the server.
+    pub fn computer(&self) -> &Option<Difficulty> {
+        &self.computer
+    }
+
+    pub fn set_computer(&mut self, difficulty: Difficulty) {
+        self.computer.replace(difficulty);
+    }
+
+    /// Picked by the host. The game state gets a copy once the game starts.
+    pub fn rule_set(&self) -> &RuleSet {
+        &self.rule_set
+    }
+
+    pub fn set_rule_set(&mut self, rule_set: RuleSet) {
+        self.rule_set = rule_set;
+    }
+}
+
+/// A match is a series of games between the same two players. Each game is a "round".
+#[derive(Clone, PartialEq, Debug)]
+pub struct StorageMatch {
+    match_id: String,
+    p1_id: String,
+    p2_id: Option<String>,
+    num_rounds: u32,
+    p1_starts_first_round: bool,
+    // In the order they were dealt.
+    game_ids: Vec<String>,
+    // Picked by the host. Every round is dealt with them.
+    time_control: Option<TimeControl>,
+    rule_set: RuleSet,
+}
+
+impl StorageMatch {
+    pub fn new(
+        match_id: String,
+        p1_id: String,
+        p2_id: Option<String>,
+        num_rounds: u32,
+        p1_starts_first_round: bool,
+        game_ids: Vec<String>,
+    ) -> Self {
+        StorageMatch {
+            match_id,
+            p1_id,
+            p2_id,
+            num_rounds,
+            p1_starts_first_round,
+            game_ids,
+            time_control: None,
+            rule_set: RuleSet::standard(),
+        }
+    }
+
+    pub fn match_id(&self) -> &str {
+        &self.match_id
+    }
+
     pub fn p1_id(&self) -> &str {
         &self.p1_id
     }
@@ -46,18 +191,46 @@ impl StorageGameMetadata {
         self.p2_id.as_ref().expect(MISSING_P2_ID_MSG)
     }
 
-    pub fn game_status(&self) -> &StorageGameStatus {
-        &self.game_status
+    pub fn num_rounds(&self) -> &u32 {
+        &self.num_rounds
     }
 
-    pub fn creation_time_ms(&self) -> u64 {
-        // TODO persist/return actual creation time
-        1
+    pub fn game_ids(&self) -> &Vec<String> {
+        &self.game_ids
+    }
+
+    pub fn time_control(&self) -> &Option<TimeControl> {
+        &self.time_control
+    }
+
+    pub fn rule_set(&self) -> &RuleSet {
+        &self.rule_set
     }
 
     pub fn set_p2_id(&mut self, p2_id: String) {
         self.p2_id.replace(p2_id);
     }
+
+    pub fn set_time_control(&mut self, time_control: TimeControl) {
+        self.time_control.replace(time_control);
+    }
+
+    pub fn set_rule_set(&mut self, rule_set: RuleSet) {
+        self.rule_set = rule_set;
+    }
+
+    /// Players take turns going first, starting with whoever was picked to start the first round.
+    pub fn p1_starts_round(&self, round_index: usize) -> bool {
+        self.p1_starts_first_round ^ (round_index % 2 == 1)
+    }
+
+    pub fn has_more_rounds(&self) -> bool {
+        (self.game_ids.len() as u32) < self.num_rounds
+    }
+
+    pub fn push_game_id(&mut self, game_id: String) {
+        self.game_ids.push(game_id);
+    }
 }
 
 #[derive(Copy, Clone, PartialEq, Debug)]
@@ -66,43 +239,69 @@ pub enum StorageGameStatus {
     Completed,
 }
 
+/// Players are identified by their seat, which is their index into the hands, plays, and clocks.
 #[derive(Clone, Debug, PartialEq)]
 pub struct StorageGameState {
     // Maybe metadata should be in here instead? leaving it out for now. Idk.
     game_id: String,
 
-    p1_hand: Vec<Card>,
-    p2_hand: Vec<Card>,
-
-    p1_plays: HashMap<CardColor, Vec<CardValue>>,
-    p2_plays: HashMap<CardColor, Vec<CardValue>>,
+    hands: Vec<Vec<Card>>,
+    plays: Vec<HashMap<CardColor, Vec<CardValue>>>,
 
     neutral_draw_pile: HashMap<CardColor, Vec<CardValue>>,
     main_draw_pile: Vec<Card>,
 
-    p1_turn: bool,
+    turn: usize,
+    first_turn: usize,
+
+    // Seed of the shuffled deck. None if the deck wasn't shuffled from a seed.
+    seed: Option<u64>,
+
+    // When the cards were dealt, and when the game ended. None if nobody kept track.
+    start_time_ms: Option<u64>,
+    end_time_ms: Option<u64>,
+
+    // Only for timed games.
+    clock: Option<StorageClock>,
+
+    // Set if the game ended before the main draw pile ran out (other than by timeout, which
+    // the clock keeps track of).
+    early_end: Option<StorageEarlyEnd>,
+    draw_offer: Option</* Offered by seat */ usize>,
+
+    // Every move so far, oldest first.
+    move_history: Vec<StorageMove>,
+    take_back_request: Option</* Requested by seat */ usize>,
+
+    rule_set: RuleSet,
 }
 
 impl StorageGameState {
     pub fn new(
         game_id: String,
-        p1_hand: Vec<Card>,
-        p2_hand: Vec<Card>,
-        p1_plays: HashMap<CardColor, Vec<CardValue>>,
-        p2_plays: HashMap<CardColor, Vec<CardValue>>,
+        hands: Vec<Vec<Card>>,
+        plays: Vec<HashMap<CardColor, Vec<CardValue>>>,
         neutral_draw_pile: HashMap<CardColor, Vec<CardValue>>,
         main_draw_pile: Vec<Card>,
-        p1_turn: bool
+        turn: usize
     ) -> Self {
         StorageGameState {
             game_id,
-            p1_hand,
-            p2_hand,
-            p1_plays,
-            p2_plays,
+            hands,
+            plays,
             neutral_draw_pile,
             main_draw_pile,
-            p1_turn
+            turn,
+            first_turn: turn,
+            seed: None,
+            start_time_ms: None,
+            end_time_ms: None,
+            clock: None,
+            early_end: None,
+            draw_offer: None,
+            move_history: Vec::new(),
+            take_back_request: None,
+            rule_set: RuleSet::standard(),
         }
     }
 
@@ -110,36 +309,24 @@ impl StorageGameState {
         &self.game_id
     }
 
-    pub fn p1_hand(&self) -> &Vec<Card> {
-        &self.p1_hand
-    }
-
-    pub fn p1_hand_mut(&mut self) -> &mut Vec<Card> {
-        &mut self.p1_hand
-    }
-
-    pub fn p2_hand(&self) -> &Vec<Card> {
-        &self.p2_hand
-    }
-
-    pub fn p2_hand_mut(&mut self) -> &mut Vec<Card> {
-        &mut self.p2_hand
+    pub fn num_seats(&self) -> usize {
+        self.hands.len()
     }
 
-    pub fn p1_plays(&self) -> &HashMap<CardColor, Vec<CardValue>> {
-        &self.p1_plays
+    pub fn hands(&self) -> &Vec<Vec<Card>> {
+        &self.hands
     }
 
-    pub fn p1_plays_mut(&mut self) -> &mut HashMap<CardColor, Vec<CardValue>> {
-        &mut self.p1_plays
+    pub fn hands_mut(&mut self) -> &mut Vec<Vec<Card>> {
+        &mut self.hands
     }
 
-    pub fn p2_plays(&self) -> &HashMap<CardColor, Vec<CardValue>> {
-        &self.p2_plays
+    pub fn plays(&self) -> &Vec<HashMap<CardColor, Vec<CardValue>>> {
+        &self.plays
     }
 
-    pub fn p2_plays_mut(&mut self) -> &mut HashMap<CardColor, Vec<CardValue>> {
-        &mut self.p2_plays
+    pub fn plays_mut(&mut self) -> &mut Vec<HashMap<CardColor, Vec<CardValue>>> {
+        &mut self.plays
     }
 
     pub fn neutral_draw_pile(&self) -> &HashMap<CardColor, Vec<CardValue>> {
@@ -158,25 +345,239 @@ impl StorageGameState {
         &mut self.main_draw_pile
     }
 
-    pub fn p1_turn(&self) -> &bool {
-        &self.p1_turn
+    /// The seat of whoever's turn it is.
+    pub fn turn(&self) -> &usize {
+        &self.turn
+    }
+
+    pub fn set_turn(&mut self, seat: usize) {
+        self.turn = seat;
+    }
+
+    /// The seat that took the first turn.
+    pub fn first_turn(&self) -> &usize {
+        &self.first_turn
+    }
+
+    /// Same seed, same deck. See `DeckFactory::new_shuffled_deck_with_seed()`.
+    pub fn seed(&self) -> &Option<u64> {
+        &self.seed
+    }
+
+    pub fn set_seed(&mut self, seed: u64) {
+        self.seed.replace(seed);
+    }
+
+    pub fn start_time_ms(&self) -> &Option<u64> {
+        &self.start_time_ms
+    }
+
+    pub fn set_start_time_ms(&mut self, start_time_ms: u64) {
+        self.start_time_ms.replace(start_time_ms);
+    }
+
+    pub fn end_time_ms(&self) -> &Option<u64> {
+        &self.end_time_ms
+    }
+
+    pub fn set_end_time_ms(&mut self, end_time_ms: u64) {
+        self.end_time_ms.replace(end_time_ms);
+    }
+
+    pub fn clock(&self) -> &Option<StorageClock> {
+        &self.clock
+    }
+
+    pub fn clock_mut(&mut self) -> &mut Option<StorageClock> {
+        &mut self.clock
+    }
+
+    pub fn set_clock(&mut self, clock: StorageClock) {
+        self.clock.replace(clock);
+    }
+
+    pub fn rule_set(&self) -> &RuleSet {
+        &self.rule_set
+    }
+
+    pub fn set_rule_set(&mut self, rule_set: RuleSet) {
+        self.rule_set = rule_set;
+    }
+
+    pub fn early_end(&self) -> &Option<StorageEarlyEnd> {
+        &self.early_end
+    }
+
+    pub fn set_early_end(&mut self, early_end: StorageEarlyEnd) {
+        self.early_end.replace(early_end);
+    }
+
+    pub fn draw_offer(&self) -> &Option<usize> {
+        &self.draw_offer
+    }
+
+    pub fn set_draw_offer(&mut self, offered_by_seat: usize) {
+        self.draw_offer.replace(offered_by_seat);
+    }
+
+    pub fn clear_draw_offer(&mut self) {
+        self.draw_offer = None;
+    }
+
+    pub fn move_history(&self) -> &Vec<StorageMove> {
+        &self.move_history
+    }
+
+    pub fn move_history_mut(&mut self) -> &mut Vec<StorageMove> {
+        &mut self.move_history
+    }
+
+    pub fn take_back_request(&self) -> &Option<usize> {
+        &self.take_back_request
+    }
+
+    pub fn set_take_back_request(&mut self, requested_by_seat: usize) {
+        self.take_back_request.replace(requested_by_seat);
     }
 
-    pub fn swap_turn(&mut self) {
-        self.p1_turn = !self.p1_turn
+    pub fn clear_take_back_request(&mut self) {
+        self.take_back_request = None;
     }
 
-    pub fn convert_to_player_aware(self, is_player_1: bool) -> PlayerAwareStorageGameState {
+    pub fn convert_to_player_aware(self, seat: usize) -> PlayerAwareStorageGameState {
         PlayerAwareStorageGameState {
             inner: self,
-            is_player_1
+            seat
         }
     }
 }
+/// Everything needed to undo a move exactly as it happened.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct StorageMove {
+    // The mover's seat
+    seat: usize,
+    // Where the played card was in the mover's hand, so it goes back to the same spot.
+    hand_index: usize,
+    card: Card,
+    target: CardTarget,
+    draw_pile: DrawPile,
+    // None on a final turn, when nothing is drawn.
+    drawn_card: Option<Card>,
+}
+
+impl StorageMove {
+    pub fn new(
+        seat: usize,
+        hand_index: usize,
+        card: Card,
+        target: CardTarget,
+        draw_pile: DrawPile,
+        drawn_card: Option<Card>,
+    ) -> Self {
+        StorageMove {
+            seat,
+            hand_index,
+            card,
+            target,
+            draw_pile,
+            drawn_card,
+        }
+    }
+
+    pub fn seat(&self) -> &usize {
+        &self.seat
+    }
+
+    pub fn hand_index(&self) -> &usize {
+        &self.hand_index
+    }
+
+    pub fn card(&self) -> &Card {
+        &self.card
+    }
+
+    pub fn target(&self) -> &CardTarget {
+        &self.target
+    }
+
+    pub fn draw_pile(&self) -> &DrawPile {
+        &self.draw_pile
+    }
+
+    pub fn drawn_card(&self) -> &Option<Card> {
+        &self.drawn_card
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum StorageEarlyEnd {
+    Resigned(/* Seat */ usize),
+    DrawAgreed,
+}
+
+/// The clocks of every player in a timed game, by seat. Only the clock of whoever's turn it is is
+/// running, starting from `turn_start_ms`. The remaining times are as of the start of the current
+/// turn.
+#[derive(Clone, Debug, PartialEq)]
+pub struct StorageClock {
+    time_control: TimeControl,
+    remaining_ms: Vec<u64>,
+    // Millis since epoch
+    turn_start_ms: u64,
+    // Once set, the game is over.
+    timed_out: Option</* Seat */ usize>,
+}
+
+impl StorageClock {
+    pub fn new(
+        time_control: TimeControl,
+        remaining_ms: Vec<u64>,
+        turn_start_ms: u64,
+    ) -> Self {
+        StorageClock {
+            time_control,
+            remaining_ms,
+            turn_start_ms,
+            timed_out: None,
+        }
+    }
+
+    pub fn time_control(&self) -> &TimeControl {
+        &self.time_control
+    }
+
+    pub fn num_seats(&self) -> usize {
+        self.remaining_ms.len()
+    }
+
+    pub fn remaining_ms(&self, seat: usize) -> &u64 {
+        &self.remaining_ms[seat]
+    }
+
+    pub fn remaining_ms_mut(&mut self, seat: usize) -> &mut u64 {
+        &mut self.remaining_ms[seat]
+    }
+
+    pub fn turn_start_ms(&self) -> &u64 {
+        &self.turn_start_ms
+    }
+
+    pub fn set_turn_start_ms(&mut self, turn_start_ms: u64) {
+        self.turn_start_ms = turn_start_ms;
+    }
+
+    pub fn timed_out(&self) -> &Option<usize> {
+        &self.timed_out
+    }
+
+    pub fn set_timed_out(&mut self, seat: usize) {
+        self.timed_out.replace(seat);
+    }
+}
 
 pub struct PlayerAwareStorageGameState {
     inner: StorageGameState,
-    is_player_1: bool,
+    seat: usize,
 }
 
 impl PlayerAwareStorageGameState {
@@ -186,39 +587,23 @@ impl PlayerAwareStorageGameState {
     }
 
     pub fn my_hand(&self) -> &Vec<Card> {
-        if self.is_player_1 {
-            &self.inner.p1_hand
-        } else {
-            &self.inner.p2_hand
-        }
+        &self.inner.hands[self.seat]
     }
 
     pub fn my_hand_mut(&mut self) -> &mut Vec<Card> {
-        if self.is_player_1 {
-            &mut self.inner.p1_hand
-        } else {
-            &mut self.inner.p2_hand
-        }
+        &mut self.inner.hands[self.seat]
     }
 
     pub fn my_plays(&self) -> &HashMap<CardColor, Vec<CardValue>> {
-        if self.is_player_1 {
-            &self.inner.p1_plays
-        } else {
-            &self.inner.p2_plays
-        }
+        &self.inner.plays[self.seat]
     }
 
     pub fn my_plays_mut(&mut self) -> &mut HashMap<CardColor, Vec<CardValue>> {
-        if self.is_player_1 {
-            &mut self.inner.p1_plays
-        } else {
-            &mut self.inner.p2_plays
-        }
+        &mut self.inner.plays[self.seat]
     }
 
     pub fn is_my_turn(&self) -> bool {
-        self.is_player_1 ^ !self.inner.p1_turn
+        self.seat == self.inner.turn
     }
 
     pub fn neutral_draw_pile(&self) -> &HashMap<CardColor, Vec<CardValue>> {
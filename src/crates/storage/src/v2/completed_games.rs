@@ -0,0 +1,62 @@
+use crate::v2::config::DatabaseMode;
+use crate::v2::db_types::{DbGameSummary, DbGameData, DbError, DbGameStatus};
+use crate::local_disk_storage::sqlite_integration::{self, SqliteWrapper};
+use crate::local_disk_storage::sqlite_tables::{SqlGameSummary, SqlGameData, SqlGamePlayer};
+use std::convert::TryFrom;
+
+/// Every completed game created within the time range (inclusive), oldest first. If a player is
+/// given, only their games are included.
+///
+/// This is for offline tools, like the analytics export. It skips the database task and reads the
+/// SQLite file directly, which is safe to do alongside the server.
+pub fn load_completed_games(
+    mode: &DatabaseMode,
+    min_creation_time_sec: u32,
+    max_creation_time_sec: u32,
+    player_id: Option<&str>,
+) -> Result<Vec<(DbGameSummary, DbGameData)>, DbError> {
+    let sqlite = SqliteWrapper::connect_existing(mode.db_file_location())?;
+
+    sqlite.select_games_by_status(DbGameStatus::Completed.into(), min_creation_time_sec, max_creation_time_sec, player_id)?
+        .into_iter()
+        .map(|(sql_game_summary, sql_game_data)| {
+            let sql_game_players = sqlite.select_game_players(&sql_game_summary.game_id)?;
+            Ok((
+                DbGameSummary::try_from((sql_game_summary, sql_game_players))?,
+                DbGameData::from(sql_game_data),
+            ))
+        })
+        .collect()
+}
+
+/// Save a completed game that was played somewhere else, like on another server. Returns false
+/// without touching anything if a game with this ID was already saved, so importing the same
+/// games twice is harmless.
+///
+/// The data goes in before the summary, so a summary is never left without its data. If a previous
+/// attempt only got as far as the data, it's overwritten. The players go in last, like they do for
+/// games saved by the server.
+pub fn save_completed_game(
+    mode: &DatabaseMode,
+    game_summary: DbGameSummary,
+    game_data: DbGameData,
+) -> Result<bool, DbError> {
+    let sqlite = SqliteWrapper::connect(mode.db_file_location())?;
+    sqlite_integration::create_all_tables(&sqlite)?;
+
+    if sqlite.select_row::<SqlGameSummary>(&game_summary.game_id)?.is_some() {
+        return Ok(false);
+    }
+
+    let sql_game_data = SqlGameData::from(game_data);
+    if sqlite.select_row::<SqlGameData>(&sql_game_data.game_id)?.is_some() {
+        sqlite.update_row(&sql_game_data)?;
+    } else {
+        sqlite.insert_row(&sql_game_data)?;
+    }
+    let (sql_game_summary, sql_game_players): (SqlGameSummary, Vec<SqlGamePlayer>) = game_summary.into();
+    sqlite.insert_row(&sql_game_summary)?;
+    sqlite.replace_game_players(&sql_game_summary.game_id, &sql_game_players)?;
+
+    Ok(true)
+}
@@ -11,7 +11,7 @@ pub enum DatabaseMode {
 }
 
 impl DatabaseMode {
-    fn db_file_location(&self) -> &str {
+    pub(crate) fn db_file_location(&self) -> &str {
         match self {
             DatabaseMode::Prod => "/tmp/frj-game.prod.db",
             DatabaseMode::Test(file) => file,
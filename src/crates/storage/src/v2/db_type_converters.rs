@@ -3,7 +3,7 @@
 // Storage layer: SqlGameData
 
 use crate::v2::db_types::{DbGameData, DbGameSummary, DbError, DbGameType, DbGameStatus, DbErrorCause};
-use crate::local_disk_storage::sqlite_tables::{SqlGameData, SqlGameSummary};
+use crate::local_disk_storage::sqlite_tables::{SqlGameData, SqlGameSummary, SqlGamePlayer};
 use std::convert::TryFrom;
 
 impl From<SqlGameData> for DbGameData {
@@ -26,31 +26,45 @@ impl From<DbGameData> for SqlGameData {
 
 // ------- Game Summary -------
 // Application layer: DbGameSummary
-// Storage layer: SqlGameSummary
+// Storage layer: SqlGameSummary, plus one SqlGamePlayer per seat (in seat order)
 
-impl TryFrom<SqlGameSummary> for DbGameSummary {
+impl TryFrom<(SqlGameSummary, Vec<SqlGamePlayer>)> for DbGameSummary {
     type Error = DbError;
 
-    fn try_from(sql_game_summary: SqlGameSummary) -> Result<Self, Self::Error> {
+    fn try_from((sql_game_summary, sql_game_players): (SqlGameSummary, Vec<SqlGamePlayer>)) -> Result<Self, Self::Error> {
         Ok(DbGameSummary {
             game_id: sql_game_summary.game_id,
             game_creation_time_sec: sql_game_summary.game_creation_time_sec,
             game_type: DbGameType::try_from(sql_game_summary.game_type)?,
             game_status: DbGameStatus::try_from(sql_game_summary.game_status)?,
+            player_ids: sql_game_players.into_iter()
+                .map(|sql_game_player| sql_game_player.player_id)
+                .collect(),
             game_summary_blob_opt: sql_game_summary.game_summary_blob_opt,
         })
     }
 }
 
-impl From<DbGameSummary> for SqlGameSummary {
+impl From<DbGameSummary> for (SqlGameSummary, Vec<SqlGamePlayer>) {
     fn from(db_game_summary: DbGameSummary) -> Self {
-        SqlGameSummary {
+        let game_id = &db_game_summary.game_id;
+        let sql_game_players = db_game_summary.player_ids.iter()
+            .enumerate()
+            .map(|(seat, player_id)| SqlGamePlayer {
+                game_id: game_id.clone(),
+                seat: seat as u8,
+                player_id: player_id.clone(),
+            })
+            .collect();
+        let sql_game_summary = SqlGameSummary {
             game_id: db_game_summary.game_id,
             game_creation_time_sec: db_game_summary.game_creation_time_sec,
             game_type: db_game_summary.game_type.into(),
             game_status: db_game_summary.game_status.into(),
             game_summary_blob_opt: db_game_summary.game_summary_blob_opt,
-        }
+        };
+
+        (sql_game_summary, sql_game_players)
     }
 }
 
@@ -8,6 +8,8 @@ pub struct DbGameSummary {
     pub game_creation_time_sec: u32,
     pub game_type: DbGameType,
     pub game_status: DbGameStatus,
+    /// Everyone seated, host first. A player's index is their seat.
+    pub player_ids: Vec<String>,
     pub game_summary_blob_opt: Option<Vec<u8>>
 }
 
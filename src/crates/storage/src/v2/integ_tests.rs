@@ -1,6 +1,7 @@
 use crate::v2::config::{connect_to_database, DatabaseMode};
 use crate::test_utils::{TestFileHandle, rand_str};
-use crate::v2::db_types::{DbGameData, DbError};
+use crate::v2::completed_games::{load_completed_games, save_completed_game};
+use crate::v2::db_types::{DbGameData, DbError, DbGameSummary, DbGameType, DbGameStatus};
 use tokio::task;
 use tokio::sync::oneshot;
 
@@ -50,3 +51,27 @@ async fn database_integration_test() {
     // Verify that the write from earlier succeeded to notify client
     assert_eq!(write_notifier_rx.try_recv(), Ok(Ok(())));
 }
+
+#[test]
+fn saving_completed_game_is_idempotent() {
+    let db_file = TestFileHandle::new(format!("./safe-to-delete.test-{}.db", rand_str()));
+    let mode = DatabaseMode::Test(db_file.file_path.clone());
+    let game_summary = DbGameSummary {
+        game_id: "imported".to_owned(),
+        game_creation_time_sec: 1000,
+        game_type: DbGameType::LostCities,
+        game_status: DbGameStatus::Completed,
+        player_ids: vec!["alice".to_owned(), "bob".to_owned()],
+        game_summary_blob_opt: Some(vec![1, 2, 3]),
+    };
+    let game_data = DbGameData {
+        game_id: "imported".to_owned(),
+        game_data_blob: vec![4, 5, 6],
+    };
+
+    assert_eq!(save_completed_game(&mode, game_summary.clone(), game_data.clone()), Ok(true));
+    assert_eq!(save_completed_game(&mode, game_summary.clone(), game_data.clone()), Ok(false));
+
+    let completed_games = load_completed_games(&mode, 0, 2000, None).expect("load_completed_games");
+    assert_eq!(completed_games, vec![(game_summary, game_data)]);
+}
@@ -1,3 +1,4 @@
+pub mod completed_games;
 pub mod config;
 pub mod db_api;
 pub mod db_types;
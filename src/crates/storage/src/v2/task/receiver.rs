@@ -1,6 +1,6 @@
 use crate::v2::task::events::{DbTaskEvent, WriteTargetTable};
 use crate::local_disk_storage::sqlite_integration::SqliteWrapper;
-use crate::local_disk_storage::sqlite_tables::{SqlGameData, SqlGameSummary};
+use crate::local_disk_storage::sqlite_tables::{SqlGameData, SqlGameSummary, SqlGamePlayer};
 use crate::v2::db_types::{DbGameSummary, DbError, DbGameData};
 use crate::v2::db_api::DbResult;
 use crossbeam::channel::Receiver;
@@ -106,8 +106,12 @@ impl DbManager {
         }
     }
 
+    /// The players are written after the summary, so a duplicate summary doesn't touch the
+    /// existing game's players.
     pub fn create_game_summary(&self, game_summary: DbGameSummary) -> DbResult<()> {
-        Ok(self.sqlite.insert_row(&SqlGameSummary::from(game_summary))?)
+        let (sql_game_summary, sql_game_players): (SqlGameSummary, Vec<SqlGamePlayer>) = game_summary.into();
+        self.sqlite.insert_row(&sql_game_summary)?;
+        Ok(self.sqlite.replace_game_players(&sql_game_summary.game_id, &sql_game_players)?)
     }
 
     pub fn create_game_data(&self, game_data: DbGameData) -> DbResult<()> {
@@ -115,7 +119,9 @@ impl DbManager {
     }
 
     pub fn update_game_summary(&self, game_summary: DbGameSummary) -> DbResult<()> {
-        Ok(self.sqlite.update_row(&SqlGameSummary::from(game_summary))?)
+        let (sql_game_summary, sql_game_players): (SqlGameSummary, Vec<SqlGamePlayer>) = game_summary.into();
+        self.sqlite.update_row(&sql_game_summary)?;
+        Ok(self.sqlite.replace_game_players(&sql_game_summary.game_id, &sql_game_players)?)
     }
 
     pub fn update_game_data(&self, game_data: DbGameData) -> DbResult<()> {
@@ -126,8 +132,9 @@ impl DbManager {
         let sql_game_summary = self.sqlite
             .select_row::<SqlGameSummary>(&game_id)?
             .ok_or(DbError::NotFound)?;
+        let sql_game_players = self.sqlite.select_game_players(&game_id)?;
 
-        DbGameSummary::try_from(sql_game_summary)
+        DbGameSummary::try_from((sql_game_summary, sql_game_players))
     }
 
     pub fn get_game_data(&self, game_id: String) -> DbResult<DbGameData> {